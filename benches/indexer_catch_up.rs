@@ -0,0 +1,105 @@
+// Indexer Catch-up Benchmarks
+// 
+// Criterion benchmarks of an indexer catching up from genesis over thousands of blocks:
+// - One range worker, fetching the blocks one range after the other
+// - Several range workers fetching ranges concurrently
+//
+// Run with `cargo bench --bench indexer_catch_up`. The chain is built once: CATCH_UP_BLOCKS
+// empty blocks with a mint every MINT_EVERY blocks. Each iteration spawns an indexer at
+// height 1 and stops once it streamed every mint. The mean catch-up time is written to the
+// github-action-benchmark report.
+
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use fuels::prelude::*;
+use tokio::runtime::Runtime;
+
+use rosetta_stone::{bench_report::BenchReport, HarnessConfig, Indexer, IndexerConfig, TestHarness};
+
+const CATCH_UP_BLOCKS: u32 = 5_000;
+const MINT_EVERY: u32 = 500;
+const WORKERS: [usize; 2] = [1, 4];
+
+// Builds the chain to catch up on and returns the number of mints in it
+async fn build_chain(harness: &TestHarness) -> Result<usize> {
+    let user = harness.user(0);
+    let mut mints = 0;
+    while harness.provider.latest_block_height().await? < CATCH_UP_BLOCKS {
+        harness.mint_to(user, 1).await?;
+        mints += 1;
+        harness.provider.produce_blocks(MINT_EVERY - 1, None).await?;
+    }
+    Ok(mints)
+}
+
+// Spawns an indexer at height 1 and waits until it streamed `mints` events
+async fn catch_up(harness: &TestHarness, workers: usize, mints: usize) -> Result<()> {
+    let config = IndexerConfig::default()
+        .with_from_height(1)
+        .with_range_workers(workers);
+    let mut indexer = Indexer::for_token_and_vault(&harness.token, &harness.vault, config).await?;
+    for _ in 0..mints {
+        indexer.next_event_within(Duration::from_secs(60)).await?;
+    }
+    Ok(())
+}
+
+fn indexer_catch_up(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("failed to start tokio runtime");
+    let (harness, mints) = runtime
+        .block_on(async {
+            let harness = TestHarness::new(HarnessConfig::default()).await?;
+            let mints = build_chain(&harness).await?;
+            Ok::<_, Error>((harness, mints))
+        })
+        .expect("failed to build the chain");
+    let blocks = runtime
+        .block_on(harness.provider.latest_block_height())
+        .expect("failed to read the chain height");
+
+    let mut report = BenchReport::new();
+    let mut group = c.benchmark_group("indexer_catch_up");
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .throughput(Throughput::Elements(blocks as u64));
+
+    for workers in WORKERS {
+        // Total time and catch-up count over every sample, warm-up included
+        let tally = Cell::new((Duration::ZERO, 0u64));
+        let name = format!("workers_{}", workers);
+        group.bench_function(&name, |b| {
+            b.to_async(&runtime).iter_custom(|iters| {
+                let (harness, tally) = (&harness, &tally);
+                async move {
+                    let start = Instant::now();
+                    for _ in 0..iters {
+                        catch_up(harness, workers, mints)
+                            .await
+                            .expect("indexer failed to catch up");
+                    }
+                    let elapsed = start.elapsed();
+
+                    let (total, count) = tally.get();
+                    tally.set((total + elapsed, count + iters));
+                    elapsed
+                }
+            })
+        });
+        let (total, count) = tally.get();
+        report.record_per_op(format!("indexer_catch_up_{}", name), total, count);
+    }
+
+    group.finish();
+
+    println!("⏩ Caught up on {} blocks with {} mints", blocks, mints);
+    let report_path = report.write_default().expect("failed to write benchmark report");
+    println!("📊 Benchmark report written to {}", report_path.display());
+}
+
+criterion_group!(benches, indexer_catch_up);
+criterion_main!(benches);
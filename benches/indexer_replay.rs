@@ -0,0 +1,184 @@
+// Indexer Replay Benchmarks
+// 
+// Criterion benchmarks of the indexer's receipt decoding over a replay of 100k transactions:
+// - Trying every event type on every watched log with ContractEvent::decode_all
+// - The EventDecoder the indexer uses, decoding straight from the receipt data
+//
+// Run with `cargo bench --bench indexer_replay`. The receipts of a mint, a deposit and a
+// withdrawal are recorded once on a local node and replayed, so the node takes no part in
+// the measurement. The mean time per transaction is written to the github-action-benchmark
+// report, and the run fails when EventDecoder is less than MIN_SPEEDUP times faster.
+
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use fuels::{
+    core::codec::LogDecoder,
+    prelude::*,
+    tx::Receipt,
+    types::{ContractId, Identity},
+};
+use tokio::runtime::Runtime;
+
+use rosetta_stone::{
+    bench_report::BenchReport, ContractEvent, EventDecoder, HarnessConfig, TestHarness, SUB_ID,
+};
+
+const REPLAY_TXS: usize = 100_000;
+// How many times faster EventDecoder must be than decoding every type on every log
+const MIN_SPEEDUP: f64 = 2.0;
+
+// The receipts of one mint, deposit and withdrawal, and the decoder for their logs
+async fn record_receipts() -> Result<(LogDecoder, Vec<ContractId>, Vec<Vec<Receipt>>)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let vault = harness.vault_as(user);
+
+    let mint = harness
+        .token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 1_000)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let deposit = vault
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(500)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    let withdraw = vault
+        .methods()
+        .withdraw(200)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let mut decoder = harness.token.log_decoder();
+    decoder.merge(harness.vault.log_decoder());
+    let contracts = vec![harness.token.contract_id(), harness.vault.contract_id()];
+    let receipts = vec![
+        mint.tx_status.receipts,
+        deposit.tx_status.receipts,
+        withdraw.tx_status.receipts,
+    ];
+
+    Ok((decoder, contracts, receipts))
+}
+
+// The indexer's decoding before EventDecoder: every event type tried on every watched log
+fn decode_each_type(
+    decoder: &LogDecoder,
+    contracts: &[ContractId],
+    receipts: &[Receipt],
+) -> Vec<(ContractId, ContractEvent)> {
+    let mut events = vec![];
+    for receipt in receipts {
+        let Receipt::LogData { id, .. } = receipt else {
+            continue;
+        };
+        if !contracts.contains(id) {
+            continue;
+        }
+        let decoded = ContractEvent::decode_all(decoder, std::slice::from_ref(receipt))
+            .expect("recorded logs decode");
+        events.extend(decoded.into_iter().map(|event| (*id, event)));
+    }
+    events
+}
+
+fn indexer_replay(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("failed to start tokio runtime");
+    let (decoder, contracts, recorded) = runtime
+        .block_on(record_receipts())
+        .expect("failed to record receipts");
+    let replay: Vec<&[Receipt]> = recorded
+        .iter()
+        .map(Vec::as_slice)
+        .cycle()
+        .take(REPLAY_TXS)
+        .collect();
+
+    // Both paths must agree before their speed is compared
+    let mut event_decoder = EventDecoder::new(decoder.clone(), contracts.clone());
+    for receipts in &recorded {
+        let expected = decode_each_type(&decoder, &contracts, receipts);
+        assert_eq!(event_decoder.decode(receipts).expect("recorded logs decode"), expected);
+        assert!(!expected.is_empty());
+    }
+    let replay_events: usize = replay
+        .iter()
+        .map(|receipts| decode_each_type(&decoder, &contracts, receipts).len())
+        .sum();
+
+    let mut report = BenchReport::new();
+    let mut group = c.benchmark_group("indexer_replay");
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .throughput(Throughput::Elements(REPLAY_TXS as u64));
+
+    // Benchmarks one decoding path and returns its mean time per transaction in seconds
+    let mut bench_replay = |name: &str, decode: &mut dyn FnMut(&[Receipt]) -> usize| -> f64 {
+        // Total time and transaction count over every sample, warm-up included
+        let tally = Cell::new((Duration::ZERO, 0u64));
+        group.bench_function(name, |b| {
+            b.iter_custom(|iters| {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    let events: usize = replay.iter().map(|receipts| decode(receipts)).sum();
+                    assert_eq!(events, replay_events);
+                }
+                let elapsed = start.elapsed();
+
+                let (total, count) = tally.get();
+                tally.set((total + elapsed, count + iters * REPLAY_TXS as u64));
+                elapsed
+            })
+        });
+        let (total, count) = tally.get();
+        report.record_per_op(format!("indexer_replay_{}", name), total, count);
+        total.as_secs_f64() / count.max(1) as f64
+    };
+
+    let decode_all = bench_replay("decode_all", &mut |receipts| {
+        decode_each_type(&decoder, &contracts, receipts).len()
+    });
+    let mut event_decoder = EventDecoder::new(decoder.clone(), contracts.clone());
+    let in_place = bench_replay("event_decoder", &mut |receipts| {
+        event_decoder.decode(receipts).expect("recorded logs decode").len()
+    });
+
+    group.finish();
+
+    let speedup = decode_all / in_place;
+    println!(
+        "⚡ EventDecoder: {:.2}x faster than decode_all ({:.0} ns vs {:.0} ns per transaction)",
+        speedup,
+        in_place * 1e9,
+        decode_all * 1e9
+    );
+    // The report is smaller-is-better, so it tracks the time ratio rather than the speedup
+    report.record("indexer_replay_event_decoder_time_ratio", "ratio", in_place / decode_all);
+
+    let report_path = report.write_default().expect("failed to write benchmark report");
+    println!("📊 Benchmark report written to {}", report_path.display());
+
+    assert!(
+        speedup >= MIN_SPEEDUP,
+        "EventDecoder is only {:.2}x faster than decode_all, expected at least {}x",
+        speedup,
+        MIN_SPEEDUP
+    );
+}
+
+criterion_group!(benches, indexer_replay);
+criterion_main!(benches);
@@ -0,0 +1,159 @@
+// Throughput Benchmarks
+// 
+// Criterion benchmarks of mint, transfer and deposit latency against a local node:
+// - Sequential operations, one transaction each
+// - Batched mints, several calls in one multi-call transaction
+// - Sequential mints broken down into build, sign, submit and confirm time
+//
+// Run with `cargo bench --bench throughput`. Besides criterion's own reports, the
+// mean time per operation and the mint latency breakdown are written to the
+// github-action-benchmark report.
+
+use std::{
+    cell::Cell,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+    Throughput,
+};
+use fuels::{prelude::*, types::Identity};
+use tokio::runtime::Runtime;
+
+use rosetta_stone::{
+    bench_report::BenchReport,
+    latency::{call_with_breakdown, timed_wallet, LatencyBreakdown},
+    HarnessConfig, TestHarness, SUB_ID,
+};
+
+const BATCH_SIZE: u64 = 5;
+
+// Benchmarks `op` and records its mean time per operation into `report`.
+// `ops_per_call` is how many operations one call of `op` performs.
+fn bench_op<F, Fut>(
+    group: &mut BenchmarkGroup<WallTime>,
+    runtime: &Runtime,
+    report: &mut BenchReport,
+    name: &str,
+    ops_per_call: u64,
+    op: F,
+) where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    // Total time and operation count over every sample, warm-up included
+    let tally = Cell::new((Duration::ZERO, 0u64));
+
+    group.throughput(Throughput::Elements(ops_per_call));
+    group.bench_function(name, |b| {
+        b.to_async(runtime).iter_custom(|iters| {
+            let (op, tally) = (&op, &tally);
+            async move {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    op().await.expect("benchmarked operation failed");
+                }
+                let elapsed = start.elapsed();
+
+                let (total, count) = tally.get();
+                tally.set((total + elapsed, count + iters * ops_per_call));
+                elapsed
+            }
+        })
+    });
+
+    let (total, count) = tally.get();
+    report.record_per_op(name, total, count);
+}
+
+fn throughput(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("failed to start tokio runtime");
+    let harness = &runtime
+        .block_on(TestHarness::new(HarnessConfig::default().with_coins(10, 1_000_000_000)))
+        .expect("failed to set up harness");
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let alice_identity = Identity::Address(alice.address().into());
+
+    // Alice needs tokens to transfer and deposit
+    runtime
+        .block_on(harness.mint_to(alice, u64::MAX / 2))
+        .expect("failed to fund alice");
+    let vault = &harness.vault_as(alice);
+
+    let mut report = BenchReport::new();
+    let mut group = c.benchmark_group("throughput");
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(5));
+
+    bench_op(&mut group, &runtime, &mut report, "sequential_mint", 1, || async move {
+        harness.mint_to(alice, 1).await
+    });
+
+    bench_op(&mut group, &runtime, &mut report, "sequential_transfer", 1, || async move {
+        alice
+            .transfer(bob.address(), 1, harness.asset_id, TxPolicies::default())
+            .await?;
+        Ok(())
+    });
+
+    bench_op(&mut group, &runtime, &mut report, "sequential_deposit", 1, || async move {
+        vault
+            .methods()
+            .deposit()
+            .call_params(CallParameters::default().with_amount(1).with_asset_id(harness.asset_id))?
+            .call()
+            .await?;
+        Ok(())
+    });
+
+    // One multi-call of BATCH_SIZE mints, reported per mint call. The
+    // transaction is sent as built, since the typed `call` would need a tuple
+    // with one `()` per call.
+    let name = format!("batched_mint_{}", BATCH_SIZE);
+    bench_op(&mut group, &runtime, &mut report, &name, BATCH_SIZE, || async move {
+        let mut multi_call = CallHandler::new_multi_call(harness.admin.clone());
+        for _ in 0..BATCH_SIZE {
+            multi_call =
+                multi_call.add_call(harness.token.methods().mint(alice_identity, Some(SUB_ID), 1));
+        }
+        let tx = multi_call
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(BATCH_SIZE as usize))
+            .build_tx()
+            .await?;
+        harness
+            .provider
+            .send_transaction_and_await_commit(tx)
+            .await?
+            .check(None)?;
+        Ok(())
+    });
+
+    // BATCH_SIZE sequential mints, each timed phase by phase
+    let breakdown = runtime
+        .block_on(async {
+            let timed = timed_wallet(&harness.admin)?;
+            let token = harness.token.clone().with_account(timed.clone());
+            let mut breakdowns = vec![];
+            for _ in 0..BATCH_SIZE {
+                let call = token
+                    .methods()
+                    .mint(alice_identity, Some(SUB_ID), 1)
+                    .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+                breakdowns.push(call_with_breakdown(call, &timed).await?.1);
+            }
+            Ok::<_, Error>(LatencyBreakdown::sum(&breakdowns))
+        })
+        .expect("failed to break down mint latency");
+    println!("⏱️  {} sequential mints: {}", BATCH_SIZE, breakdown);
+    breakdown.record_into(&mut report, &format!("mint_batch_{}", BATCH_SIZE));
+
+    let report_path = report.write_default().expect("failed to write benchmark report");
+    println!("📊 Benchmark report written to {}", report_path.display());
+}
+
+criterion_group!(benches, throughput);
+criterion_main!(benches);
@@ -1,7 +1,137 @@
-//! This build script is used to compile the sway project using `forc` prior to running tests.
+//! This build script compiles the sway projects with `forc` prior to building the crate.
+//!
+//! `abigen!` reads the ABI files under `out/debug` at compile time, so the Sway
+//! workspace has to be built first. `forc build` only runs when an artifact of a
+//! workspace member is missing or older than the member's sources, which keeps
+//! `cargo test` from a clean checkout working without a manual build step and
+//! no-op builds fast.
+//!
+//! `ROSETTA_BUILD_PROFILE=release` additionally builds `out/release` and points
+//! `rosetta_stone::artifacts` there; the ABIs are still read from `out/debug`.
+//!
+//! Without the `abigen` feature nothing reads the ABIs and `forc` is not run.
 
-use std::process::Command;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+const FORC_WORKSPACE: &str = "Forc.toml";
+const BUILD_PROFILE_ENV: &str = "ROSETTA_BUILD_PROFILE";
 
 fn main() {
-    Command::new("forc").args(&["build"]).status().unwrap();
+    println!("cargo:rerun-if-changed={FORC_WORKSPACE}");
+    println!("cargo:rerun-if-env-changed=FORC");
+    println!("cargo:rerun-if-env-changed={BUILD_PROFILE_ENV}");
+
+    let profile = std::env::var(BUILD_PROFILE_ENV).unwrap_or_else(|_| "debug".to_string());
+    if profile != "debug" && profile != "release" {
+        panic!("{BUILD_PROFILE_ENV} must be `debug` or `release`, got `{profile}`");
+    }
+    println!("cargo:rustc-env={BUILD_PROFILE_ENV}={profile}");
+
+    if std::env::var_os("CARGO_FEATURE_ABIGEN").is_none() {
+        return;
+    }
+
+    let members = workspace_members();
+    for member in &members {
+        println!("cargo:rerun-if-changed={}", member.join("Forc.toml").display());
+        println!("cargo:rerun-if-changed={}", member.join("src").display());
+    }
+
+    // The debug build provides the ABIs for `abigen!` whatever the profile
+    forc_build_if_stale(&members, "debug");
+    if profile == "release" {
+        forc_build_if_stale(&members, "release");
+    }
+}
+
+// Runs `forc build` for `profile` if any member's artifacts of that profile are stale
+fn forc_build_if_stale(members: &[PathBuf], profile: &str) {
+    let stale: Vec<&PathBuf> = members.iter().filter(|member| is_stale(member, profile)).collect();
+    if stale.is_empty() {
+        return;
+    }
+
+    let forc = std::env::var("FORC").unwrap_or_else(|_| "forc".to_string());
+    let mut command = Command::new(&forc);
+    command.arg("build");
+    if profile == "release" {
+        command.arg("--release");
+    }
+
+    let names: Vec<String> = stale.iter().map(|member| member.display().to_string()).collect();
+    println!("cargo:warning=running `{forc} build` ({profile}) for {}", names.join(", "));
+
+    match command.status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!("`{forc} build` ({profile}) failed with {status}"),
+        Err(error) => panic!(
+            "could not run `{forc} build` ({error}); install the Fuel toolchain with fuelup \
+             or set FORC to the forc binary"
+        ),
+    }
+}
+
+// The member directories listed in the workspace's `members = [...]`
+fn workspace_members() -> Vec<PathBuf> {
+    let manifest = fs::read_to_string(FORC_WORKSPACE).expect("reading Forc.toml");
+    manifest
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with("members"))
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with(']'))
+        .map(|line| line.trim().trim_end_matches(',').trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+// Whether the member's bytecode or ABI of `profile` is missing or older than its
+// manifest or sources
+fn is_stale(member: &Path, profile: &str) -> bool {
+    let Some(name) = project_name(member) else {
+        return true;
+    };
+    let out = member.join("out").join(profile);
+    let artifacts = [out.join(format!("{name}.bin")), out.join(format!("{name}-abi.json"))];
+
+    let built = artifacts.iter().map(|path| modified(path)).collect::<Option<Vec<_>>>();
+    let Some(built) = built.and_then(|times| times.into_iter().min()) else {
+        return true;
+    };
+    let sources = newest_modified(&member.join("src")).max(modified(&member.join("Forc.toml")));
+    sources.is_some_and(|sources| sources > built)
+}
+
+// The `name` of the member's `[project]`
+fn project_name(member: &Path) -> Option<String> {
+    let manifest = fs::read_to_string(member.join("Forc.toml")).ok()?;
+    manifest.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "name").then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+// The latest modification time of any file under `dir`
+fn newest_modified(dir: &Path) -> Option<SystemTime> {
+    let entries = fs::read_dir(dir).ok()?;
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                newest_modified(&path)
+            } else {
+                modified(&path)
+            }
+        })
+        .max()
 }
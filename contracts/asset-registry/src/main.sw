@@ -0,0 +1,91 @@
+contract;
+
+use std::{
+    auth::msg_sender,
+    hash::Hash,
+    logging::log,
+    storage::storage_string::*,
+    string::String,
+};
+
+// Maps asset ids to display metadata. An asset can only be registered by the
+// contract that mints it: the registry derives the asset id from the calling
+// contract's id and the given sub id.
+
+storage {
+    names: StorageMap<AssetId, StorageString> = StorageMap {},
+    symbols: StorageMap<AssetId, StorageString> = StorageMap {},
+    logo_uris: StorageMap<AssetId, StorageString> = StorageMap {},
+    decimals: StorageMap<AssetId, u8> = StorageMap {},
+    /// Number of registered assets.
+    total_registered: u64 = 0,
+}
+
+pub struct AssetInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub logo_uri: String,
+}
+
+// Event structs
+pub struct AssetRegisteredEvent {
+    pub asset_id: AssetId,
+    pub contract_id: ContractId,
+}
+
+abi AssetRegistry {
+    /// Register (or update) the metadata of the caller's asset with `sub_id`.
+    #[storage(read, write)]
+    fn register(sub_id: SubId, name: String, symbol: String, decimals: u8, logo_uri: String) -> AssetId;
+
+    /// Get the metadata of `asset`, if registered.
+    #[storage(read)]
+    fn asset_info(asset: AssetId) -> Option<AssetInfo>;
+
+    /// Get the number of registered assets.
+    #[storage(read)]
+    fn total_registered() -> u64;
+}
+
+impl AssetRegistry for Contract {
+    #[storage(read, write)]
+    fn register(sub_id: SubId, name: String, symbol: String, decimals: u8, logo_uri: String) -> AssetId {
+        let sender = msg_sender().unwrap();
+        require(sender.is_contract_id(), "Only the minting contract can register");
+        let contract_id = sender.as_contract_id().unwrap();
+        let asset_id = AssetId::new(contract_id, sub_id);
+
+        if storage.decimals.get(asset_id).try_read().is_none() {
+            storage.total_registered.write(storage.total_registered.read() + 1);
+        }
+        storage.names.get(asset_id).write_slice(name);
+        storage.symbols.get(asset_id).write_slice(symbol);
+        storage.logo_uris.get(asset_id).write_slice(logo_uri);
+        storage.decimals.insert(asset_id, decimals);
+
+        log(AssetRegisteredEvent {
+            asset_id,
+            contract_id,
+        });
+        asset_id
+    }
+
+    #[storage(read)]
+    fn asset_info(asset: AssetId) -> Option<AssetInfo> {
+        match storage.decimals.get(asset).try_read() {
+            Some(decimals) => Some(AssetInfo {
+                name: storage.names.get(asset).read_slice().unwrap_or(String::new()),
+                symbol: storage.symbols.get(asset).read_slice().unwrap_or(String::new()),
+                decimals,
+                logo_uri: storage.logo_uris.get(asset).read_slice().unwrap_or(String::new()),
+            }),
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn total_registered() -> u64 {
+        storage.total_registered.read()
+    }
+}
@@ -0,0 +1,178 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+};
+
+/// An English auction of the coins forwarded to `start`.
+pub struct AuctionInfo {
+    pub seller: Identity,
+    pub item_asset: AssetId,
+    pub item_amount: u64,
+    pub bid_asset: AssetId,
+    pub reserve_price: u64,
+    /// Last block height at which bids are accepted.
+    pub end_height: u32,
+    pub highest_bidder: Option<Identity>,
+    pub highest_bid: u64,
+    pub settled: bool,
+}
+
+storage {
+    /// Auctions by id.
+    auctions: StorageMap<u64, AuctionInfo> = StorageMap {},
+    /// Number of auctions started; also the next id.
+    auction_count: u64 = 0,
+}
+
+// Event structs
+pub struct AuctionStartedEvent {
+    pub id: u64,
+    pub seller: Identity,
+    pub reserve_price: u64,
+    pub end_height: u32,
+}
+
+pub struct BidEvent {
+    pub id: u64,
+    pub bidder: Identity,
+    pub amount: u64,
+}
+
+pub struct AuctionSettledEvent {
+    pub id: u64,
+    pub winner: Option<Identity>,
+    pub price: u64,
+}
+
+abi Auction {
+    /// Auction the forwarded coins for bids in `bid_asset`, open for `duration` blocks.
+    /// Returns the auction id.
+    #[payable]
+    #[storage(read, write)]
+    fn start(bid_asset: AssetId, reserve_price: u64, duration: u32) -> u64;
+
+    /// Bid the forwarded coins. The previous highest bidder is refunded.
+    #[payable]
+    #[storage(read, write)]
+    fn bid(id: u64);
+
+    /// After the end height, send the item to the winner and the winning bid to
+    /// the seller, or return the item to the seller if nobody bid. Anyone may settle.
+    #[storage(read, write)]
+    fn settle(id: u64);
+
+    /// Get an auction by id.
+    #[storage(read)]
+    fn auction(id: u64) -> Option<AuctionInfo>;
+
+    /// Get the number of auctions started.
+    #[storage(read)]
+    fn auction_count() -> u64;
+}
+
+#[storage(read)]
+fn read_auction(id: u64) -> AuctionInfo {
+    let auction = storage.auctions.get(id).try_read();
+    require(auction.is_some(), "Unknown auction");
+    auction.unwrap()
+}
+
+impl Auction for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn start(bid_asset: AssetId, reserve_price: u64, duration: u32) -> u64 {
+        require(msg_amount() > 0, "Nothing to auction");
+        require(duration > 0, "Duration must be positive");
+        let seller = msg_sender().unwrap();
+        let end_height = height() + duration;
+
+        let id = storage.auction_count.read();
+        storage.auctions.insert(id, AuctionInfo {
+            seller,
+            item_asset: msg_asset_id(),
+            item_amount: msg_amount(),
+            bid_asset,
+            reserve_price,
+            end_height,
+            highest_bidder: None,
+            highest_bid: 0,
+            settled: false,
+        });
+        storage.auction_count.write(id + 1);
+
+        log(AuctionStartedEvent {
+            id,
+            seller,
+            reserve_price,
+            end_height,
+        });
+        id
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn bid(id: u64) {
+        let mut auction = read_auction(id);
+        require(height() <= auction.end_height, "Auction ended");
+        require(msg_asset_id() == auction.bid_asset, "Wrong asset type");
+        require(msg_amount() >= auction.reserve_price, "Bid below reserve");
+        require(msg_amount() > auction.highest_bid, "Bid too low");
+
+        let bidder = msg_sender().unwrap();
+        if let Some(previous) = auction.highest_bidder {
+            transfer(previous, auction.bid_asset, auction.highest_bid);
+        }
+
+        auction.highest_bidder = Some(bidder);
+        auction.highest_bid = msg_amount();
+        storage.auctions.insert(id, auction);
+
+        log(BidEvent {
+            id,
+            bidder,
+            amount: msg_amount(),
+        });
+    }
+
+    #[storage(read, write)]
+    fn settle(id: u64) {
+        let mut auction = read_auction(id);
+        require(height() > auction.end_height, "Auction still running");
+        require(!auction.settled, "Auction already settled");
+
+        auction.settled = true;
+        storage.auctions.insert(id, auction);
+
+        match auction.highest_bidder {
+            Some(winner) => {
+                transfer(winner, auction.item_asset, auction.item_amount);
+                transfer(auction.seller, auction.bid_asset, auction.highest_bid);
+            },
+            None => {
+                transfer(auction.seller, auction.item_asset, auction.item_amount);
+            },
+        }
+
+        log(AuctionSettledEvent {
+            id,
+            winner: auction.highest_bidder,
+            price: auction.highest_bid,
+        });
+    }
+
+    #[storage(read)]
+    fn auction(id: u64) -> Option<AuctionInfo> {
+        storage.auctions.get(id).try_read()
+    }
+
+    #[storage(read)]
+    fn auction_count() -> u64 {
+        storage.auction_count.read()
+    }
+}
@@ -0,0 +1,41 @@
+contract;
+
+// First version of a counter meant to run behind the SRC-14 proxy. Its
+// storage lives in the proxy, which never runs the storage initializers, so
+// every read falls back to the default explicitly.
+
+storage {
+    /// The current count.
+    count: u64 = 0,
+}
+
+abi CounterV1 {
+    /// Add `amount` to the count and return the new count.
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+
+    /// Get the current count.
+    #[storage(read)]
+    fn count() -> u64;
+
+    /// Get the implementation version.
+    fn version() -> u64;
+}
+
+impl CounterV1 for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let count = storage.count.try_read().unwrap_or(0) + amount;
+        storage.count.write(count);
+        count
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.try_read().unwrap_or(0)
+    }
+
+    fn version() -> u64 {
+        1
+    }
+}
@@ -0,0 +1,53 @@
+contract;
+
+// Second version of the proxied counter. It keeps the `count` field of
+// version 1 so the slot, and with it the stored count, carries over, and
+// adds decrementing.
+
+storage {
+    /// The current count. Must keep the name used by version 1.
+    count: u64 = 0,
+}
+
+abi CounterV2 {
+    /// Add `amount` to the count and return the new count.
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+
+    /// Subtract `amount` from the count and return the new count.
+    #[storage(read, write)]
+    fn decrement(amount: u64) -> u64;
+
+    /// Get the current count.
+    #[storage(read)]
+    fn count() -> u64;
+
+    /// Get the implementation version.
+    fn version() -> u64;
+}
+
+impl CounterV2 for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let count = storage.count.try_read().unwrap_or(0) + amount;
+        storage.count.write(count);
+        count
+    }
+
+    #[storage(read, write)]
+    fn decrement(amount: u64) -> u64 {
+        let count = storage.count.try_read().unwrap_or(0);
+        require(count >= amount, "Count would go negative");
+        storage.count.write(count - amount);
+        count - amount
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.try_read().unwrap_or(0)
+    }
+
+    fn version() -> u64 {
+        2
+    }
+}
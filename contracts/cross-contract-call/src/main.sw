@@ -32,18 +32,17 @@ abi TokenVault {
     #[storage(read)]
     fn get_deposit(user: Identity) -> u64;
 
-    /// Get total deposits in the vault.
+    /// Get total deposits of `asset_id` in the vault.
     #[storage(read)]
-    fn get_total_deposits() -> u64;
+    fn get_total_deposits(asset_id: AssetId) -> u64;
 
     /// Cross-contract transfer demonstration.
     #[payable]
     #[storage(read, write)]
     fn cross_contract_deposit(user: Identity);
 
-    /// Get the vault's balance of the accepted token.
-    #[storage(read)]
-    fn get_vault_balance() -> u64;
+    /// Get the vault's balance of `asset_id`, accrued fees included.
+    fn get_vault_balance(asset_id: AssetId) -> u64;
 }
 
 configurable {
@@ -51,19 +50,51 @@ configurable {
     ADMIN: Identity = Identity::Address(Address::zero()),
 }
 
+storage {
+    /// Admin set by `accept_admin`; `ADMIN` until the first handover.
+    admin: Option<Identity> = None,
+    /// Admin proposed by the current admin, waiting to accept.
+    pending_admin: Option<Identity> = None,
+}
+
+// Event structs
+pub struct AdminProposedEvent {
+    pub admin: Identity,
+    pub proposed: Identity,
+}
+
+pub struct AdminTransferredEvent {
+    pub previous: Identity,
+    pub admin: Identity,
+}
+
+pub struct RouteEvent {
+    pub user: Identity,
+    pub destination: Identity,
+    pub amount: u64,
+    pub asset_id: AssetId,
+}
+
 abi CrossContractCall {
-    #[payable]  
+    #[payable]
+    #[storage(read)]
     fn deposit(token_vault_contract_id: ContractId, user: Identity);
+
+    /// Route the forwarded coins: base asset goes to the fee sink, anything else into the vault.
+    #[payable]
+    #[storage(read)]
+    fn route(token_vault_contract_id: ContractId, user: Identity, fee_sink: Identity);
 }
 
 impl CrossContractCall for Contract {
     #[payable]
+    #[storage(read)]
     fn deposit(token_vault_contract_id: ContractId, user: Identity) {
         let msg_caller = msg_sender().unwrap();
         let amount = msg_amount();
         let asset_id = msg_asset_id();
         // restrict who can call this function
-        require(msg_caller == ADMIN, "Only admin can deposit");
+        require(msg_caller == current_admin(), "Only admin can deposit");
 
         let token_vault_contract = abi(TokenVault, token_vault_contract_id.into());
 
@@ -75,4 +106,105 @@ impl CrossContractCall for Contract {
                 asset_id: asset_id.into(),
             }(user);
     }
+
+    #[payable]
+    #[storage(read)]
+    fn route(token_vault_contract_id: ContractId, user: Identity, fee_sink: Identity) {
+        let msg_caller = msg_sender().unwrap();
+        let amount = msg_amount();
+        let asset_id = msg_asset_id();
+        // restrict who can call this function
+        require(msg_caller == current_admin(), "Only admin can route");
+
+        if asset_id == AssetId::base() {
+            // Base asset is the fee portion of the split
+            transfer(fee_sink, asset_id, amount);
+
+            log(RouteEvent {
+                user,
+                destination: fee_sink,
+                amount,
+                asset_id,
+            });
+        } else {
+            let token_vault_contract = abi(TokenVault, token_vault_contract_id.into());
+
+            token_vault_contract
+                .cross_contract_deposit {
+                    gas: 1000000,
+                    coins: amount,
+                    asset_id: asset_id.into(),
+                }(user);
+
+            log(RouteEvent {
+                user,
+                destination: Identity::ContractId(token_vault_contract_id),
+                amount,
+                asset_id,
+            });
+        }
+    }
+}
+
+abi TwoStepAdmin {
+    /// Propose `new_admin`; takes effect once they call `accept_admin` (admin only).
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity);
+
+    /// Become admin; only the proposed identity can call this.
+    #[storage(read, write)]
+    fn accept_admin();
+
+    #[storage(read)]
+    fn admin() -> Identity;
+
+    #[storage(read)]
+    fn pending_admin() -> Option<Identity>;
+}
+
+impl TwoStepAdmin for Contract {
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity) {
+        let admin = current_admin();
+        require(msg_sender().unwrap() == admin, "Unauthorized: Only admin can propose admin");
+        storage.pending_admin.write(Some(new_admin));
+
+        log(AdminProposedEvent {
+            admin,
+            proposed: new_admin,
+        });
+    }
+
+    #[storage(read, write)]
+    fn accept_admin() {
+        let sender = msg_sender().unwrap();
+        require(
+            storage.pending_admin.read() == Some(sender),
+            "Unauthorized: Only the proposed admin can accept",
+        );
+        let previous = current_admin();
+        storage.admin.write(Some(sender));
+        storage.pending_admin.write(None);
+
+        log(AdminTransferredEvent {
+            previous,
+            admin: sender,
+        });
+    }
+
+    #[storage(read)]
+    fn admin() -> Identity {
+        current_admin()
+    }
+
+    #[storage(read)]
+    fn pending_admin() -> Option<Identity> {
+        storage.pending_admin.read()
+    }
+}
+
+// The configurable admin until ownership is handed over.
+#[storage(read)]
+fn current_admin() -> Identity {
+    storage.admin.read().unwrap_or(ADMIN)
 }
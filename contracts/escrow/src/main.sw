@@ -0,0 +1,223 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+};
+
+/// Lifecycle of an escrow. Only `Pending` and `Disputed` escrows hold funds.
+pub enum EscrowState {
+    Pending: (),
+    Disputed: (),
+    Released: (),
+    Refunded: (),
+}
+
+impl PartialEq for EscrowState {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (EscrowState::Pending, EscrowState::Pending) => true,
+            (EscrowState::Disputed, EscrowState::Disputed) => true,
+            (EscrowState::Released, EscrowState::Released) => true,
+            (EscrowState::Refunded, EscrowState::Refunded) => true,
+            _ => false,
+        }
+    }
+}
+impl Eq for EscrowState {}
+
+/// Funds deposited by `buyer`, owed to `seller` unless refunded.
+pub struct EscrowInfo {
+    pub buyer: Identity,
+    pub seller: Identity,
+    pub arbiter: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    pub state: EscrowState,
+}
+
+storage {
+    /// Escrows by id.
+    escrows: StorageMap<u64, EscrowInfo> = StorageMap {},
+    /// Number of escrows created; also the next id.
+    escrow_count: u64 = 0,
+}
+
+// Event structs
+pub struct EscrowCreatedEvent {
+    pub id: u64,
+    pub buyer: Identity,
+    pub seller: Identity,
+    pub arbiter: Identity,
+    pub amount: u64,
+}
+
+pub struct EscrowSettledEvent {
+    pub id: u64,
+    pub recipient: Identity,
+    pub amount: u64,
+    pub state: EscrowState,
+}
+
+pub struct EscrowDisputedEvent {
+    pub id: u64,
+    pub by: Identity,
+}
+
+abi Escrow {
+    /// Deposit the forwarded coins for `seller`, with `arbiter` settling disputes.
+    /// The caller is the buyer. Returns the escrow id.
+    #[payable]
+    #[storage(read, write)]
+    fn create(seller: Identity, arbiter: Identity) -> u64;
+
+    /// Pay the seller. Buyer only, while pending.
+    #[storage(read, write)]
+    fn release(id: u64);
+
+    /// Return the funds to the buyer. Seller only, while pending.
+    #[storage(read, write)]
+    fn refund(id: u64);
+
+    /// Freeze the escrow until the arbiter resolves it. Buyer or seller, while pending.
+    #[storage(read, write)]
+    fn dispute(id: u64);
+
+    /// Settle a disputed escrow, paying the seller or refunding the buyer. Arbiter only.
+    #[storage(read, write)]
+    fn resolve(id: u64, pay_seller: bool);
+
+    /// Get an escrow by id.
+    #[storage(read)]
+    fn escrow(id: u64) -> Option<EscrowInfo>;
+
+    /// Get the number of escrows created.
+    #[storage(read)]
+    fn escrow_count() -> u64;
+}
+
+#[storage(read)]
+fn read_escrow(id: u64) -> EscrowInfo {
+    let escrow = storage.escrows.get(id).try_read();
+    require(escrow.is_some(), "Unknown escrow");
+    escrow.unwrap()
+}
+
+#[storage(write)]
+fn settle(id: u64, escrow: EscrowInfo, state: EscrowState) {
+    let recipient = match state {
+        EscrowState::Released => escrow.seller,
+        _ => escrow.buyer,
+    };
+
+    let mut settled = escrow;
+    settled.state = state;
+    storage.escrows.insert(id, settled);
+    transfer(recipient, escrow.asset, escrow.amount);
+
+    log(EscrowSettledEvent {
+        id,
+        recipient,
+        amount: escrow.amount,
+        state,
+    });
+}
+
+impl Escrow for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn create(seller: Identity, arbiter: Identity) -> u64 {
+        require(msg_amount() > 0, "Nothing to escrow");
+        let buyer = msg_sender().unwrap();
+
+        let id = storage.escrow_count.read();
+        storage.escrows.insert(id, EscrowInfo {
+            buyer,
+            seller,
+            arbiter,
+            asset: msg_asset_id(),
+            amount: msg_amount(),
+            state: EscrowState::Pending,
+        });
+        storage.escrow_count.write(id + 1);
+
+        log(EscrowCreatedEvent {
+            id,
+            buyer,
+            seller,
+            arbiter,
+            amount: msg_amount(),
+        });
+        id
+    }
+
+    #[storage(read, write)]
+    fn release(id: u64) {
+        let escrow = read_escrow(id);
+        require(
+            msg_sender().unwrap() == escrow.buyer,
+            "Unauthorized: Only buyer can release",
+        );
+        require(escrow.state == EscrowState::Pending, "Escrow is not pending");
+
+        settle(id, escrow, EscrowState::Released);
+    }
+
+    #[storage(read, write)]
+    fn refund(id: u64) {
+        let escrow = read_escrow(id);
+        require(
+            msg_sender().unwrap() == escrow.seller,
+            "Unauthorized: Only seller can refund",
+        );
+        require(escrow.state == EscrowState::Pending, "Escrow is not pending");
+
+        settle(id, escrow, EscrowState::Refunded);
+    }
+
+    #[storage(read, write)]
+    fn dispute(id: u64) {
+        let mut escrow = read_escrow(id);
+        let sender = msg_sender().unwrap();
+        require(
+            sender == escrow.buyer || sender == escrow.seller,
+            "Unauthorized: Only buyer or seller can dispute",
+        );
+        require(escrow.state == EscrowState::Pending, "Escrow is not pending");
+
+        escrow.state = EscrowState::Disputed;
+        storage.escrows.insert(id, escrow);
+
+        log(EscrowDisputedEvent { id, by: sender });
+    }
+
+    #[storage(read, write)]
+    fn resolve(id: u64, pay_seller: bool) {
+        let escrow = read_escrow(id);
+        require(
+            msg_sender().unwrap() == escrow.arbiter,
+            "Unauthorized: Only arbiter can resolve",
+        );
+        require(escrow.state == EscrowState::Disputed, "Escrow is not disputed");
+
+        let state = if pay_seller {
+            EscrowState::Released
+        } else {
+            EscrowState::Refunded
+        };
+        settle(id, escrow, state);
+    }
+
+    #[storage(read)]
+    fn escrow(id: u64) -> Option<EscrowInfo> {
+        storage.escrows.get(id).try_read()
+    }
+
+    #[storage(read)]
+    fn escrow_count() -> u64 {
+        storage.escrow_count.read()
+    }
+}
@@ -0,0 +1,139 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    b512::B512,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    ecr::ec_recover_address,
+    hash::{Hash, sha256},
+    logging::log,
+};
+
+// Forwards transfers that users signed off chain. Users' coins are held here,
+// credited to them by `deposit_for`; a user signs a TransferIntent and a
+// relayer submits it with `forward`, paying the fee. The user never sends a
+// transaction, so they need no base asset at all. Intents carry the user's
+// next nonce and a block-height deadline, so they cannot be replayed.
+
+/// A transfer of `amount` of `asset` from `from`'s balance here to `to`.
+pub struct TransferIntent {
+    pub from: Address,
+    pub to: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    pub nonce: u64,
+    /// Last block height at which the intent can be forwarded.
+    pub deadline: u32,
+}
+
+storage {
+    /// Balance held for each (owner, asset).
+    balances: StorageMap<(Address, AssetId), u64> = StorageMap {},
+    /// Next intent nonce per owner.
+    nonces: StorageMap<Address, u64> = StorageMap {},
+}
+
+// Event structs
+pub struct CreditedEvent {
+    pub owner: Address,
+    pub asset: AssetId,
+    pub amount: u64,
+}
+
+pub struct ForwardedEvent {
+    pub from: Address,
+    pub to: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    pub nonce: u64,
+    pub relayer: Identity,
+}
+
+abi Forwarder {
+    /// Credit the forwarded coins to `owner`. Anyone may fund anyone.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit_for(owner: Address);
+
+    /// Execute an intent signed by its `from`. Anyone may relay it.
+    /// The signature covers sha256(contract id, from, to, asset, amount, nonce, deadline).
+    #[storage(read, write)]
+    fn forward(intent: TransferIntent, signature: B512);
+
+    /// Get the balance held for `owner` in `asset`.
+    #[storage(read)]
+    fn balance_of(owner: Address, asset: AssetId) -> u64;
+
+    /// Get the nonce the next intent of `owner` must carry.
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64;
+}
+
+impl Forwarder for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit_for(owner: Address) {
+        let asset = msg_asset_id();
+        let amount = msg_amount();
+        require(amount > 0, "Nothing to deposit");
+
+        let balance = storage.balances.get((owner, asset)).try_read().unwrap_or(0);
+        storage.balances.insert((owner, asset), balance + amount);
+
+        log(CreditedEvent {
+            owner,
+            asset,
+            amount,
+        });
+    }
+
+    #[storage(read, write)]
+    fn forward(intent: TransferIntent, signature: B512) {
+        require(height() <= intent.deadline, "Intent expired");
+
+        let digest = sha256((
+            (ContractId::this(), intent.from, intent.to),
+            (intent.asset, intent.amount),
+            (intent.nonce, intent.deadline),
+        ));
+        let signer = ec_recover_address(signature, digest);
+        require(
+            signer.is_ok() && signer.unwrap() == intent.from,
+            "Invalid signature",
+        );
+
+        let nonce = storage.nonces.get(intent.from).try_read().unwrap_or(0);
+        require(intent.nonce == nonce, "Invalid nonce");
+
+        let key = (intent.from, intent.asset);
+        let balance = storage.balances.get(key).try_read().unwrap_or(0);
+        require(balance >= intent.amount, "Insufficient balance");
+
+        // Update the books before moving the coins
+        storage.nonces.insert(intent.from, nonce + 1);
+        storage.balances.insert(key, balance - intent.amount);
+        transfer(intent.to, intent.asset, intent.amount);
+
+        log(ForwardedEvent {
+            from: intent.from,
+            to: intent.to,
+            asset: intent.asset,
+            amount: intent.amount,
+            nonce,
+            relayer: msg_sender().unwrap(),
+        });
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Address, asset: AssetId) -> u64 {
+        storage.balances.get((owner, asset)).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64 {
+        storage.nonces.get(owner).try_read().unwrap_or(0)
+    }
+}
@@ -0,0 +1,227 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+};
+
+// Token-weighted governance over a treasury. Voters lock governance tokens
+// with their vote, one token per unit of weight, and unlock them once voting
+// has closed. A proposal passes when the votes cast reach QUORUM and more
+// weight voted for it than against it; executing it pays out of the treasury.
+
+configurable {
+    /// The token whose holders vote.
+    GOV_ASSET: AssetId = AssetId::zero(),
+    /// Minimum total weight (for + against) for a proposal to pass.
+    QUORUM: u64 = 0,
+    /// Number of blocks a proposal is open for voting.
+    VOTING_PERIOD: u32 = 0,
+}
+
+/// A proposal to pay `amount` of `asset` from the treasury to `recipient`.
+pub struct Proposal {
+    pub proposer: Identity,
+    pub recipient: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    /// Last block height at which votes are accepted.
+    pub end_height: u32,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub executed: bool,
+}
+
+storage {
+    /// Proposals by id.
+    proposals: StorageMap<u64, Proposal> = StorageMap {},
+    /// Number of proposals created; also the next id.
+    proposal_count: u64 = 0,
+    /// Governance tokens locked per (proposal, voter).
+    locked_votes: StorageMap<(u64, Identity), u64> = StorageMap {},
+    /// Treasury balance per asset.
+    treasury: StorageMap<AssetId, u64> = StorageMap {},
+}
+
+// Event structs
+pub struct ProposalCreatedEvent {
+    pub id: u64,
+    pub proposer: Identity,
+    pub end_height: u32,
+}
+
+pub struct VoteEvent {
+    pub id: u64,
+    pub voter: Identity,
+    pub support: bool,
+    pub weight: u64,
+}
+
+pub struct ProposalExecutedEvent {
+    pub id: u64,
+}
+
+abi Governance {
+    /// Add the forwarded coins to the treasury.
+    #[payable]
+    #[storage(read, write)]
+    fn fund();
+
+    /// Propose paying `amount` of `asset` from the treasury to `recipient`. Returns the id.
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset: AssetId, amount: u64) -> u64;
+
+    /// Vote with the forwarded governance tokens as weight. One vote per voter and proposal.
+    #[payable]
+    #[storage(read, write)]
+    fn vote(id: u64, support: bool);
+
+    /// Pay out a proposal that passed, once voting has closed. Anyone may execute.
+    #[storage(read, write)]
+    fn execute(id: u64);
+
+    /// Return the caller's governance tokens locked in a closed proposal.
+    #[storage(read, write)]
+    fn unlock(id: u64) -> u64;
+
+    /// Get a proposal by id.
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<Proposal>;
+
+    /// Get the number of proposals created.
+    #[storage(read)]
+    fn proposal_count() -> u64;
+
+    /// Get the treasury balance of `asset`.
+    #[storage(read)]
+    fn treasury_balance(asset: AssetId) -> u64;
+}
+
+#[storage(read)]
+fn read_proposal(id: u64) -> Proposal {
+    let proposal = storage.proposals.get(id).try_read();
+    require(proposal.is_some(), "Unknown proposal");
+    proposal.unwrap()
+}
+
+impl Governance for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn fund() {
+        let asset = msg_asset_id();
+        let balance = storage.treasury.get(asset).try_read().unwrap_or(0);
+        storage.treasury.insert(asset, balance + msg_amount());
+    }
+
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset: AssetId, amount: u64) -> u64 {
+        let proposer = msg_sender().unwrap();
+        let end_height = height() + VOTING_PERIOD;
+
+        let id = storage.proposal_count.read();
+        storage.proposals.insert(id, Proposal {
+            proposer,
+            recipient,
+            asset,
+            amount,
+            end_height,
+            votes_for: 0,
+            votes_against: 0,
+            executed: false,
+        });
+        storage.proposal_count.write(id + 1);
+
+        log(ProposalCreatedEvent {
+            id,
+            proposer,
+            end_height,
+        });
+        id
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn vote(id: u64, support: bool) {
+        let mut proposal = read_proposal(id);
+        require(height() <= proposal.end_height, "Voting closed");
+        require(msg_asset_id() == GOV_ASSET, "Wrong asset type");
+        require(msg_amount() > 0, "Vote has no weight");
+
+        let voter = msg_sender().unwrap();
+        require(
+            storage.locked_votes.get((id, voter)).try_read().is_none(),
+            "Already voted",
+        );
+        storage.locked_votes.insert((id, voter), msg_amount());
+
+        if support {
+            proposal.votes_for += msg_amount();
+        } else {
+            proposal.votes_against += msg_amount();
+        }
+        storage.proposals.insert(id, proposal);
+
+        log(VoteEvent {
+            id,
+            voter,
+            support,
+            weight: msg_amount(),
+        });
+    }
+
+    #[storage(read, write)]
+    fn execute(id: u64) {
+        let mut proposal = read_proposal(id);
+        require(height() > proposal.end_height, "Voting still open");
+        require(!proposal.executed, "Proposal already executed");
+        require(
+            proposal.votes_for + proposal.votes_against >= QUORUM,
+            "Quorum not reached",
+        );
+        require(proposal.votes_for > proposal.votes_against, "Proposal rejected");
+
+        let balance = storage.treasury.get(proposal.asset).try_read().unwrap_or(0);
+        require(balance >= proposal.amount, "Insufficient treasury funds");
+
+        proposal.executed = true;
+        storage.proposals.insert(id, proposal);
+        storage.treasury.insert(proposal.asset, balance - proposal.amount);
+        transfer(proposal.recipient, proposal.asset, proposal.amount);
+
+        log(ProposalExecutedEvent { id });
+    }
+
+    #[storage(read, write)]
+    fn unlock(id: u64) -> u64 {
+        let proposal = read_proposal(id);
+        require(height() > proposal.end_height, "Voting still open");
+
+        let voter = msg_sender().unwrap();
+        let locked = storage.locked_votes.get((id, voter)).try_read().unwrap_or(0);
+        require(locked > 0, "Nothing to unlock");
+
+        // Keep the entry so the voter cannot vote again on this proposal
+        storage.locked_votes.insert((id, voter), 0);
+        transfer(voter, GOV_ASSET, locked);
+        locked
+    }
+
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<Proposal> {
+        storage.proposals.get(id).try_read()
+    }
+
+    #[storage(read)]
+    fn proposal_count() -> u64 {
+        storage.proposal_count.read()
+    }
+
+    #[storage(read)]
+    fn treasury_balance(asset: AssetId) -> u64 {
+        storage.treasury.get(asset).try_read().unwrap_or(0)
+    }
+}
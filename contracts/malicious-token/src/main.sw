@@ -0,0 +1,94 @@
+contract;
+
+// A malicious token used to show that TokenVault::burn_deposit stays
+// consistent when the token it calls re-enters the vault.
+//
+// `burn_deposit(token, sub_id, amount)` hands the burned coins to whatever
+// contract the caller names as the token. This token deposits its own asset
+// into the vault, then burns it back through `burn_deposit`; its `burn` calls
+// `burn_deposit` again for the same deposit before burning the coins. The vault
+// updates its books before calling out, so a nested burn only succeeds for
+// what is left of the deposit, and otherwise the whole transaction reverts.
+
+use std::{
+    asset::{burn, mint},
+    constants::DEFAULT_SUB_ID,
+};
+
+abi TokenVault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read, write)]
+    fn burn_deposit(token: ContractId, sub_id: SubId, amount: u64);
+}
+
+abi Token {
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64);
+}
+
+abi MaliciousToken {
+    /// Mint `amount` of this token and deposit it into `vault` as this contract.
+    fn deposit(vault: ContractId, amount: u64);
+
+    /// Burn `amount` of this contract's deposit through `vault`, re-entering
+    /// `burn_deposit` from `burn` until `depth` burns have been requested.
+    #[storage(read, write)]
+    fn attack(vault: ContractId, amount: u64, depth: u64);
+
+    /// Coins of this token burned so far.
+    #[storage(read)]
+    fn burned() -> u64;
+}
+
+storage {
+    /// Vault of the attack in progress.
+    vault: ContractId = ContractId::zero(),
+    /// Re-entries still to make from `burn`.
+    reentries: u64 = 0,
+    /// Coins burned so far.
+    burned: u64 = 0,
+}
+
+impl Token for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        let reentries = storage.reentries.read();
+        if reentries > 0 {
+            storage.reentries.write(reentries - 1);
+            abi(TokenVault, storage.vault.read().into())
+                .burn_deposit(ContractId::this(), sub_id, amount);
+        }
+
+        burn(sub_id, amount);
+        storage.burned.write(storage.burned.read() + amount);
+    }
+}
+
+impl MaliciousToken for Contract {
+    fn deposit(vault: ContractId, amount: u64) {
+        mint(DEFAULT_SUB_ID, amount);
+        abi(TokenVault, vault.into())
+            .deposit {
+                coins: amount,
+                asset_id: AssetId::new(ContractId::this(), DEFAULT_SUB_ID).into(),
+            }();
+    }
+
+    #[storage(read, write)]
+    fn attack(vault: ContractId, amount: u64, depth: u64) {
+        storage.vault.write(vault);
+        storage.reentries.write(depth - 1);
+        abi(TokenVault, vault.into())
+            .burn_deposit(ContractId::this(), DEFAULT_SUB_ID, amount);
+    }
+
+    #[storage(read)]
+    fn burned() -> u64 {
+        storage.burned.read()
+    }
+}
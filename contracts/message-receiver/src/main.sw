@@ -0,0 +1,110 @@
+contract;
+
+use std::{
+    bytes::Bytes,
+    inputs::{
+        input_amount,
+        input_count,
+        input_message_data,
+        input_message_data_length,
+        input_message_nonce,
+        input_message_sender,
+        input_type,
+        Input,
+    },
+    logging::log,
+    primitive_conversions::b256::*,
+};
+
+// Processes L1→Fuel deposit messages spent by the calling transaction. A
+// deposit message carries the Fuel address to credit as its 32 bytes of
+// data; the message sender is the L1 address that made the deposit. Each
+// message nonce is only processed once.
+
+/// Length of the data of a deposit message: one Fuel address.
+const DEPOSIT_DATA_LENGTH: u64 = 32;
+
+storage {
+    /// Total deposited amount credited per Fuel recipient.
+    deposits: StorageMap<Address, u64> = StorageMap {},
+    /// Nonces of the messages already processed.
+    processed: StorageMap<b256, bool> = StorageMap {},
+}
+
+// Event structs
+pub struct DepositProcessedEvent {
+    pub l1_sender: Address,
+    pub recipient: Address,
+    pub amount: u64,
+    pub nonce: b256,
+}
+
+abi MessageReceiver {
+    /// Credit every deposit message input of the transaction to the recipient
+    /// in its data. Returns the number of messages processed.
+    #[storage(read, write)]
+    fn process_messages() -> u64;
+
+    /// Get the total amount credited to `recipient`.
+    #[storage(read)]
+    fn deposits_of(recipient: Address) -> u64;
+
+    /// Check whether the message with `nonce` has been processed.
+    #[storage(read)]
+    fn is_processed(nonce: b256) -> bool;
+}
+
+impl MessageReceiver for Contract {
+    #[storage(read, write)]
+    fn process_messages() -> u64 {
+        let mut processed = 0;
+        let mut index = 0;
+        while index < input_count().as_u64() {
+            if is_deposit_message(index) {
+                let nonce = input_message_nonce(index).unwrap();
+                require(
+                    !storage.processed.get(nonce).try_read().unwrap_or(false),
+                    "Message already processed",
+                );
+                storage.processed.insert(nonce, true);
+
+                let data: Bytes = input_message_data(index, 0).unwrap();
+                let recipient = Address::from(b256::try_from(data).unwrap());
+                let amount = input_amount(index).unwrap();
+                let credited = storage.deposits.get(recipient).try_read().unwrap_or(0);
+                storage.deposits.insert(recipient, credited + amount);
+
+                log(DepositProcessedEvent {
+                    l1_sender: input_message_sender(index).unwrap(),
+                    recipient,
+                    amount,
+                    nonce,
+                });
+                processed += 1;
+            }
+            index += 1;
+        }
+
+        require(processed > 0, "No deposit messages");
+        processed
+    }
+
+    #[storage(read)]
+    fn deposits_of(recipient: Address) -> u64 {
+        storage.deposits.get(recipient).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn is_processed(nonce: b256) -> bool {
+        storage.processed.get(nonce).try_read().unwrap_or(false)
+    }
+}
+
+// Message inputs without data are plain base asset deposits, spendable like
+// coins; only those carrying a recipient are processed here.
+fn is_deposit_message(index: u64) -> bool {
+    match input_type(index) {
+        Some(Input::Message) => input_message_data_length(index) == Some(DEPOSIT_DATA_LENGTH),
+        _ => false,
+    }
+}
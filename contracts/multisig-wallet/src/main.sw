@@ -0,0 +1,199 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    context::this_balance,
+    logging::log,
+};
+
+// A contract-based multisig wallet. Any owner proposes a payment out of the
+// contract's balance, owners approve it one by one, and once THRESHOLD owners
+// have approved anyone may execute it. Unlike the multisig predicate, the
+// approvals live in storage, so owners sign separate transactions at their own
+// pace. A proposal executes at most once.
+
+configurable {
+    /// The owners allowed to propose and approve.
+    OWNERS: [Identity; 3] = [
+        Identity::Address(Address::zero()),
+        Identity::Address(Address::zero()),
+        Identity::Address(Address::zero()),
+    ],
+    /// Number of owner approvals a proposal needs to execute.
+    THRESHOLD: u64 = 2,
+}
+
+/// A proposal to pay `amount` of `asset` to `recipient`.
+pub struct Proposal {
+    pub proposer: Identity,
+    pub recipient: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    pub approvals: u64,
+    pub executed: bool,
+}
+
+storage {
+    /// Proposals by id.
+    proposals: StorageMap<u64, Proposal> = StorageMap {},
+    /// Number of proposals created; also the next id.
+    proposal_count: u64 = 0,
+    /// Whether an owner approved a proposal, per (proposal, owner).
+    approved: StorageMap<(u64, Identity), bool> = StorageMap {},
+}
+
+// Event structs
+pub struct ProposalCreatedEvent {
+    pub id: u64,
+    pub proposer: Identity,
+}
+
+pub struct ApprovalEvent {
+    pub id: u64,
+    pub owner: Identity,
+    pub approvals: u64,
+}
+
+pub struct ProposalExecutedEvent {
+    pub id: u64,
+}
+
+abi MultisigWallet {
+    /// Accept the forwarded coins into the wallet. Anyone may fund it.
+    #[payable]
+    fn fund();
+
+    /// Propose paying `amount` of `asset` to `recipient` (owners only). Returns the id.
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset: AssetId, amount: u64) -> u64;
+
+    /// Approve a proposal (owners only, once per owner and proposal).
+    #[storage(read, write)]
+    fn approve(id: u64);
+
+    /// Pay out a proposal approved by at least THRESHOLD owners. Anyone may execute.
+    #[storage(read, write)]
+    fn execute(id: u64);
+
+    /// Get a proposal by id.
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<Proposal>;
+
+    /// Get the number of proposals created.
+    #[storage(read)]
+    fn proposal_count() -> u64;
+
+    /// Whether `owner` approved proposal `id`.
+    #[storage(read)]
+    fn has_approved(id: u64, owner: Identity) -> bool;
+
+    /// Get the wallet's balance of `asset`.
+    fn balance(asset: AssetId) -> u64;
+}
+
+fn is_owner(who: Identity) -> bool {
+    let mut i = 0;
+    while i < 3 {
+        if OWNERS[i] == who {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+fn require_owner() -> Identity {
+    let sender = msg_sender().unwrap();
+    require(is_owner(sender), "Unauthorized: not an owner");
+    sender
+}
+
+#[storage(read)]
+fn read_proposal(id: u64) -> Proposal {
+    let proposal = storage.proposals.get(id).try_read();
+    require(proposal.is_some(), "Unknown proposal");
+    proposal.unwrap()
+}
+
+impl MultisigWallet for Contract {
+    #[payable]
+    fn fund() {}
+
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset: AssetId, amount: u64) -> u64 {
+        let proposer = require_owner();
+
+        let id = storage.proposal_count.read();
+        storage.proposals.insert(id, Proposal {
+            proposer,
+            recipient,
+            asset,
+            amount,
+            approvals: 0,
+            executed: false,
+        });
+        storage.proposal_count.write(id + 1);
+
+        log(ProposalCreatedEvent { id, proposer });
+        id
+    }
+
+    #[storage(read, write)]
+    fn approve(id: u64) {
+        let owner = require_owner();
+        let mut proposal = read_proposal(id);
+        require(!proposal.executed, "Proposal already executed");
+        require(
+            !storage.approved.get((id, owner)).try_read().unwrap_or(false),
+            "Already approved",
+        );
+
+        storage.approved.insert((id, owner), true);
+        proposal.approvals += 1;
+        storage.proposals.insert(id, proposal);
+
+        log(ApprovalEvent {
+            id,
+            owner,
+            approvals: proposal.approvals,
+        });
+    }
+
+    #[storage(read, write)]
+    fn execute(id: u64) {
+        let mut proposal = read_proposal(id);
+        require(!proposal.executed, "Proposal already executed");
+        require(proposal.approvals >= THRESHOLD, "Not enough approvals");
+        require(
+            this_balance(proposal.asset) >= proposal.amount,
+            "Insufficient balance",
+        );
+
+        // Mark as executed before paying out so the proposal cannot run twice
+        proposal.executed = true;
+        storage.proposals.insert(id, proposal);
+        transfer(proposal.recipient, proposal.asset, proposal.amount);
+
+        log(ProposalExecutedEvent { id });
+    }
+
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<Proposal> {
+        storage.proposals.get(id).try_read()
+    }
+
+    #[storage(read)]
+    fn proposal_count() -> u64 {
+        storage.proposal_count.read()
+    }
+
+    #[storage(read)]
+    fn has_approved(id: u64, owner: Identity) -> bool {
+        storage.approved.get((id, owner)).try_read().unwrap_or(false)
+    }
+
+    fn balance(asset: AssetId) -> u64 {
+        this_balance(asset)
+    }
+}
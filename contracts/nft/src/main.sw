@@ -0,0 +1,128 @@
+contract;
+
+use src20::{SRC20, TotalSupplyEvent};
+use src3::SRC3;
+use std::{
+    asset::{
+        burn,
+        mint_to,
+    },
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    hash::Hash,
+    logging::log,
+    string::String,
+};
+
+// Every sub id is a separate one-of-one asset: minting requires an amount of
+// exactly 1 and an unused sub id.
+
+configurable {
+    /// The name of the collection.
+    NAME: str[7] = __to_str_array("MyNFTs_"),
+    /// The symbol of the collection.
+    SYMBOL: str[5] = __to_str_array("MYNFT"),
+    /// The admin of the collection (can mint).
+    ADMIN: Identity = Identity::Address(Address::zero()),
+}
+
+storage {
+    /// Supply per asset: 1 once minted, 0 after burning.
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    /// Whether a sub id has ever been minted, so burnt ids are never reused.
+    minted: StorageMap<SubId, bool> = StorageMap {},
+    /// Number of assets ever minted.
+    total_assets: u64 = 0,
+}
+
+// Event structs
+pub struct NftMintEvent {
+    pub recipient: Identity,
+    pub sub_id: SubId,
+    pub asset_id: AssetId,
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        storage.total_assets.read()
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        storage.total_supply.get(asset).try_read()
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        match storage.total_supply.get(asset).try_read() {
+            Some(_) => Some(String::from_ascii_str(from_str_array(NAME))),
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        match storage.total_supply.get(asset).try_read() {
+            Some(_) => Some(String::from_ascii_str(from_str_array(SYMBOL))),
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        match storage.total_supply.get(asset).try_read() {
+            Some(_) => Some(0u8),
+            None => None,
+        }
+    }
+}
+
+impl SRC3 for Contract {
+    /// Mints the one-of-one asset with `sub_id` to `recipient` (admin only).
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64) {
+        require(
+            msg_sender()
+                .unwrap() == ADMIN,
+            "Unauthorized: Only admin can mint",
+        );
+        require(sub_id.is_some(), "Sub Id required");
+        require(amount == 1, "NFTs are minted one at a time");
+
+        let sub_id = sub_id.unwrap();
+        require(
+            !storage.minted.get(sub_id).try_read().unwrap_or(false),
+            "Sub Id already minted",
+        );
+
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
+        storage.minted.insert(sub_id, true);
+        storage.total_supply.insert(asset_id, 1);
+        storage.total_assets.write(storage.total_assets.read() + 1);
+
+        mint_to(recipient, sub_id, 1);
+
+        log(NftMintEvent {
+            recipient,
+            sub_id,
+            asset_id,
+        });
+        TotalSupplyEvent::new(asset_id, 1, msg_sender().unwrap()).log();
+    }
+
+    /// Burns the forwarded NFT.
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
+        require(amount == 1 && msg_amount() == 1, "Incorrect amount provided");
+        require(msg_asset_id() == asset_id, "Incorrect asset provided");
+
+        storage.total_supply.insert(asset_id, 0);
+        burn(sub_id, 1);
+
+        TotalSupplyEvent::new(asset_id, 0, msg_sender().unwrap()).log();
+    }
+}
@@ -0,0 +1,200 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+};
+
+/// `sell_amount` of `sell_asset` offered for `buy_amount` of `buy_asset`,
+/// fillable in parts at the same price.
+pub struct Order {
+    pub maker: Identity,
+    pub sell_asset: AssetId,
+    pub sell_amount: u64,
+    pub buy_asset: AssetId,
+    pub buy_amount: u64,
+    /// Part of `sell_amount` not yet filled or cancelled.
+    pub remaining: u64,
+    /// Total of `buy_asset` paid in by takers.
+    pub proceeds: u64,
+    /// Part of `proceeds` the maker has not claimed yet.
+    pub unclaimed: u64,
+}
+
+storage {
+    /// Orders by id.
+    orders: StorageMap<u64, Order> = StorageMap {},
+    /// Number of orders posted; also the next id.
+    order_count: u64 = 0,
+}
+
+// Event structs
+pub struct OrderPostedEvent {
+    pub id: u64,
+    pub maker: Identity,
+    pub sell_amount: u64,
+    pub buy_amount: u64,
+}
+
+pub struct OrderFilledEvent {
+    pub id: u64,
+    pub taker: Identity,
+    pub paid: u64,
+    pub received: u64,
+    pub remaining: u64,
+}
+
+pub struct ProceedsClaimedEvent {
+    pub id: u64,
+    pub amount: u64,
+}
+
+pub struct OrderCancelledEvent {
+    pub id: u64,
+    pub refunded: u64,
+}
+
+abi OrderBook {
+    /// Offer the forwarded coins for `buy_amount` of `buy_asset`. Returns the order id.
+    #[payable]
+    #[storage(read, write)]
+    fn post_order(buy_asset: AssetId, buy_amount: u64) -> u64;
+
+    /// Pay the forwarded `buy_asset` coins into an order and receive its sell
+    /// asset at the order's price, rounded down. Returns the amount received.
+    #[payable]
+    #[storage(read, write)]
+    fn fill(id: u64) -> u64;
+
+    /// Send the unclaimed proceeds to the maker. Maker only. Returns the amount.
+    #[storage(read, write)]
+    fn claim_proceeds(id: u64) -> u64;
+
+    /// Return the unfilled remainder to the maker. Maker only.
+    #[storage(read, write)]
+    fn cancel(id: u64);
+
+    /// Get an order by id.
+    #[storage(read)]
+    fn order(id: u64) -> Option<Order>;
+
+    /// Get the number of orders posted.
+    #[storage(read)]
+    fn order_count() -> u64;
+}
+
+#[storage(read)]
+fn read_order(id: u64) -> Order {
+    let order = storage.orders.get(id).try_read();
+    require(order.is_some(), "Unknown order");
+    order.unwrap()
+}
+
+fn mul_div(value: u64, numerator: u64, denominator: u64) -> u64 {
+    let result = value.as_u256() * numerator.as_u256() / denominator.as_u256();
+    u64::try_from(result).unwrap()
+}
+
+impl OrderBook for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn post_order(buy_asset: AssetId, buy_amount: u64) -> u64 {
+        let sell_amount = msg_amount();
+        require(sell_amount > 0, "Nothing to sell");
+        require(buy_amount > 0, "Price must be positive");
+        require(msg_asset_id() != buy_asset, "Cannot trade an asset for itself");
+        let maker = msg_sender().unwrap();
+
+        let id = storage.order_count.read();
+        storage.orders.insert(id, Order {
+            maker,
+            sell_asset: msg_asset_id(),
+            sell_amount,
+            buy_asset,
+            buy_amount,
+            remaining: sell_amount,
+            proceeds: 0,
+            unclaimed: 0,
+        });
+        storage.order_count.write(id + 1);
+
+        log(OrderPostedEvent {
+            id,
+            maker,
+            sell_amount,
+            buy_amount,
+        });
+        id
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn fill(id: u64) -> u64 {
+        let mut order = read_order(id);
+        require(msg_asset_id() == order.buy_asset, "Wrong payment asset");
+        let paid = msg_amount();
+
+        let received = mul_div(paid, order.sell_amount, order.buy_amount);
+        require(received > 0, "Fill too small");
+        require(received <= order.remaining, "Fill exceeds remaining size");
+
+        order.remaining -= received;
+        order.proceeds += paid;
+        order.unclaimed += paid;
+        storage.orders.insert(id, order);
+
+        let taker = msg_sender().unwrap();
+        transfer(taker, order.sell_asset, received);
+
+        log(OrderFilledEvent {
+            id,
+            taker,
+            paid,
+            received,
+            remaining: order.remaining,
+        });
+        received
+    }
+
+    #[storage(read, write)]
+    fn claim_proceeds(id: u64) -> u64 {
+        let mut order = read_order(id);
+        require(msg_sender().unwrap() == order.maker, "Only maker can claim");
+        let amount = order.unclaimed;
+        require(amount > 0, "Nothing to claim");
+
+        order.unclaimed = 0;
+        storage.orders.insert(id, order);
+        transfer(order.maker, order.buy_asset, amount);
+
+        log(ProceedsClaimedEvent { id, amount });
+        amount
+    }
+
+    #[storage(read, write)]
+    fn cancel(id: u64) {
+        let mut order = read_order(id);
+        require(msg_sender().unwrap() == order.maker, "Only maker can cancel");
+        let refunded = order.remaining;
+        require(refunded > 0, "Order already closed");
+
+        order.remaining = 0;
+        storage.orders.insert(id, order);
+        transfer(order.maker, order.sell_asset, refunded);
+
+        log(OrderCancelledEvent { id, refunded });
+    }
+
+    #[storage(read)]
+    fn order(id: u64) -> Option<Order> {
+        storage.orders.get(id).try_read()
+    }
+
+    #[storage(read)]
+    fn order_count() -> u64 {
+        storage.order_count.read()
+    }
+}
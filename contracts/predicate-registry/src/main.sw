@@ -0,0 +1,90 @@
+contract;
+
+use std::{auth::msg_sender, logging::log};
+
+// Registry of predicate accounts, modelled on SRC-12's registry of contracts.
+// SRC-12 validates a child contract by reading its bytecode root on chain; a
+// predicate has no deployed code, so here the predicate proves itself by
+// authorizing its own registration. `msg_sender` is the predicate's address
+// only when every coin input of the transaction belongs to it, and the account
+// predicate only approves transactions its owner signed.
+
+storage {
+    /// Owner of each registered predicate account.
+    owners: StorageMap<Address, Address> = StorageMap {},
+    /// Predicate account of each registered owner.
+    accounts: StorageMap<Address, Address> = StorageMap {},
+    /// Number of registered accounts.
+    registered_count: u64 = 0,
+}
+
+// Event structs
+pub struct AccountRegisteredEvent {
+    pub predicate: Address,
+    pub owner: Address,
+}
+
+abi PredicateRegistry {
+    /// Register the calling predicate as the account of `owner`.
+    #[storage(read, write)]
+    fn register(owner: Address);
+
+    /// Check whether `predicate` is a registered account.
+    #[storage(read)]
+    fn is_valid(predicate: Address) -> bool;
+
+    /// Get the owner of a registered predicate account.
+    #[storage(read)]
+    fn owner_of(predicate: Address) -> Option<Address>;
+
+    /// Get the predicate account registered for `owner`.
+    #[storage(read)]
+    fn account_of(owner: Address) -> Option<Address>;
+
+    /// Get the number of registered accounts.
+    #[storage(read)]
+    fn registered_count() -> u64;
+}
+
+impl PredicateRegistry for Contract {
+    #[storage(read, write)]
+    fn register(owner: Address) {
+        let sender = msg_sender().unwrap();
+        require(sender.is_address(), "Only predicate accounts can register");
+        let predicate = sender.as_address().unwrap();
+        require(
+            storage.owners.get(predicate).try_read().is_none(),
+            "Already registered",
+        );
+        require(
+            storage.accounts.get(owner).try_read().is_none(),
+            "Owner already has an account",
+        );
+
+        storage.owners.insert(predicate, owner);
+        storage.accounts.insert(owner, predicate);
+        storage.registered_count.write(storage.registered_count.read() + 1);
+
+        log(AccountRegisteredEvent { predicate, owner });
+    }
+
+    #[storage(read)]
+    fn is_valid(predicate: Address) -> bool {
+        storage.owners.get(predicate).try_read().is_some()
+    }
+
+    #[storage(read)]
+    fn owner_of(predicate: Address) -> Option<Address> {
+        storage.owners.get(predicate).try_read()
+    }
+
+    #[storage(read)]
+    fn account_of(owner: Address) -> Option<Address> {
+        storage.accounts.get(owner).try_read()
+    }
+
+    #[storage(read)]
+    fn registered_count() -> u64 {
+        storage.registered_count.read()
+    }
+}
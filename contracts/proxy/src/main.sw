@@ -0,0 +1,81 @@
+contract;
+
+use src14::{SRC14, SRC14Extension};
+use src5::State;
+use sway_libs::upgradability::{
+    _proxy_owner,
+    _proxy_target,
+    _set_proxy_owner,
+    _set_proxy_target,
+    only_proxy_owner,
+};
+use std::execution::run_external;
+
+// SRC-14 proxy. Every call that does not match the proxy's own ABI falls
+// through to the target contract, whose code runs against this contract's
+// storage. Upgrading swaps the target; the stored state stays here.
+
+configurable {
+    /// The target set by `initialize_proxy`.
+    INITIAL_TARGET: Option<ContractId> = None,
+    /// The owner set by `initialize_proxy`, allowed to change the target.
+    INITIAL_OWNER: State = State::Uninitialized,
+}
+
+storage {
+    // The SRC-14 slots are fixed so they cannot collide with target storage
+    SRC14 {
+        /// The contract all unknown calls are forwarded to.
+        target in 0x7bb458adc1d118713319a5baa00a2d049dd64d2916477d2688d76970c898cd55: Option<ContractId> = None,
+        /// The owner allowed to upgrade the target.
+        proxy_owner in 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754: State = State::Uninitialized,
+    },
+}
+
+abi ProxyInit {
+    /// Store the configured target and owner. Can only be called once.
+    #[storage(read, write)]
+    fn initialize_proxy();
+}
+
+impl SRC14 for Contract {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        only_proxy_owner();
+        _set_proxy_target(new_target);
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        _proxy_target()
+    }
+}
+
+impl SRC14Extension for Contract {
+    #[storage(read)]
+    fn proxy_owner() -> State {
+        _proxy_owner()
+    }
+}
+
+impl ProxyInit for Contract {
+    #[storage(read, write)]
+    fn initialize_proxy() {
+        require(
+            _proxy_owner() == State::Uninitialized,
+            "Proxy already initialized",
+        );
+        require(INITIAL_TARGET.is_some(), "No initial target");
+
+        storage::SRC14.target.write(INITIAL_TARGET);
+        _set_proxy_owner(INITIAL_OWNER);
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    let target = _proxy_target();
+    require(target.is_some(), "No proxy target");
+    run_external(target.unwrap())
+}
@@ -0,0 +1,67 @@
+contract;
+
+// A malicious vault depositor used to show that TokenVault withdrawals cannot
+// be repeated against the same deposit.
+//
+// Coin transfers on Fuel run no code on the receiving contract, so there is no
+// fallback to hook into while the vault pays out. The attacker instead nests
+// its withdrawals in its own call frames: each frame withdraws and then calls
+// back into the attacker before returning, the shape of a reentrant drain. The
+// vault updates its books before transferring, so the second withdrawal finds
+// the deposit gone and the whole transaction reverts.
+
+use std::{
+    call_frames::msg_asset_id,
+    context::{balance_of, msg_amount},
+};
+
+abi TokenVault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[payable]
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+}
+
+abi ReentrancyAttacker {
+    /// Deposit the forwarded coins into `vault` as this contract.
+    #[payable]
+    fn deposit(vault: ContractId);
+
+    /// Withdraw `amount` of `asset_id` from `vault`, then re-enter for another
+    /// withdrawal until `depth` withdrawals have been made.
+    fn attack(vault: ContractId, asset_id: AssetId, amount: u64, depth: u64);
+
+    /// This contract's balance of `asset_id`.
+    fn balance(asset_id: AssetId) -> u64;
+}
+
+impl ReentrancyAttacker for Contract {
+    #[payable]
+    fn deposit(vault: ContractId) {
+        abi(TokenVault, vault.into())
+            .deposit {
+                coins: msg_amount(),
+                asset_id: msg_asset_id().into(),
+            }();
+    }
+
+    fn attack(vault: ContractId, asset_id: AssetId, amount: u64, depth: u64) {
+        abi(TokenVault, vault.into())
+            .withdraw {
+                coins: 0,
+                asset_id: asset_id.into(),
+            }(amount);
+
+        if depth > 1 {
+            abi(ReentrancyAttacker, ContractId::this().into())
+                .attack(vault, asset_id, amount, depth - 1);
+        }
+    }
+
+    fn balance(asset_id: AssetId) -> u64 {
+        balance_of(ContractId::this(), asset_id)
+    }
+}
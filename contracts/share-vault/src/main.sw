@@ -0,0 +1,182 @@
+contract;
+
+use std::{
+    asset::{
+        burn,
+        mint_to,
+        transfer,
+    },
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    constants::DEFAULT_SUB_ID,
+    context::msg_amount,
+    logging::log,
+    primitive_conversions::u64::*,
+};
+
+// SRC-6 style vault: deposits of the underlying asset mint share tokens and
+// burning shares redeems a proportional part of the underlying. Anything sent
+// through `donate` raises the value of every outstanding share.
+
+configurable {
+    /// The asset accepted by the vault.
+    UNDERLYING: AssetId = AssetId::zero(),
+}
+
+storage {
+    /// Underlying assets managed by the vault.
+    total_assets: u64 = 0,
+    /// Shares in circulation.
+    total_shares: u64 = 0,
+}
+
+// Event structs
+pub struct SharesMintedEvent {
+    pub receiver: Identity,
+    pub assets: u64,
+    pub shares: u64,
+}
+
+pub struct SharesBurnedEvent {
+    pub receiver: Identity,
+    pub assets: u64,
+    pub shares: u64,
+}
+
+abi ShareVault {
+    /// Deposit the underlying asset and mint shares to `receiver`.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(receiver: Identity) -> u64;
+
+    /// Burn the forwarded shares and send the underlying asset to `receiver`.
+    #[payable]
+    #[storage(read, write)]
+    fn withdraw(receiver: Identity) -> u64;
+
+    /// Add underlying assets without minting shares (e.g. yield).
+    #[payable]
+    #[storage(read, write)]
+    fn donate();
+
+    /// Shares minted for depositing `assets` right now.
+    #[storage(read)]
+    fn preview_deposit(assets: u64) -> u64;
+
+    /// Underlying assets returned for burning `shares` right now.
+    #[storage(read)]
+    fn preview_withdraw(shares: u64) -> u64;
+
+    #[storage(read)]
+    fn total_assets() -> u64;
+
+    #[storage(read)]
+    fn total_shares() -> u64;
+
+    /// Asset id of the share token.
+    fn share_asset_id() -> AssetId;
+}
+
+impl ShareVault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(receiver: Identity) -> u64 {
+        require(msg_asset_id() == UNDERLYING, "Wrong asset type");
+        let assets = msg_amount();
+        require(assets > 0, "Zero deposit");
+
+        let shares = convert_to_shares(assets);
+        require(shares > 0, "Deposit too small");
+
+        storage.total_assets.write(storage.total_assets.read() + assets);
+        storage.total_shares.write(storage.total_shares.read() + shares);
+        mint_to(receiver, DEFAULT_SUB_ID, shares);
+
+        log(SharesMintedEvent {
+            receiver,
+            assets,
+            shares,
+        });
+        shares
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn withdraw(receiver: Identity) -> u64 {
+        require(msg_asset_id() == AssetId::default(), "Wrong asset type");
+        let shares = msg_amount();
+        require(shares > 0, "Zero withdrawal");
+
+        let assets = convert_to_assets(shares);
+
+        storage.total_assets.write(storage.total_assets.read() - assets);
+        storage.total_shares.write(storage.total_shares.read() - shares);
+        burn(DEFAULT_SUB_ID, shares);
+        transfer(receiver, UNDERLYING, assets);
+
+        log(SharesBurnedEvent {
+            receiver,
+            assets,
+            shares,
+        });
+        assets
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn donate() {
+        require(msg_asset_id() == UNDERLYING, "Wrong asset type");
+        require(storage.total_shares.read() > 0, "No shares to credit");
+        storage.total_assets.write(storage.total_assets.read() + msg_amount());
+    }
+
+    #[storage(read)]
+    fn preview_deposit(assets: u64) -> u64 {
+        convert_to_shares(assets)
+    }
+
+    #[storage(read)]
+    fn preview_withdraw(shares: u64) -> u64 {
+        convert_to_assets(shares)
+    }
+
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        storage.total_assets.read()
+    }
+
+    #[storage(read)]
+    fn total_shares() -> u64 {
+        storage.total_shares.read()
+    }
+
+    fn share_asset_id() -> AssetId {
+        AssetId::default()
+    }
+}
+
+// Rounds down, so rounding always favours the vault.
+#[storage(read)]
+fn convert_to_shares(assets: u64) -> u64 {
+    let total_assets = storage.total_assets.read();
+    let total_shares = storage.total_shares.read();
+    if total_shares == 0 || total_assets == 0 {
+        return assets;
+    }
+    mul_div(assets, total_shares, total_assets)
+}
+
+#[storage(read)]
+fn convert_to_assets(shares: u64) -> u64 {
+    let total_assets = storage.total_assets.read();
+    let total_shares = storage.total_shares.read();
+    if total_shares == 0 {
+        return 0;
+    }
+    mul_div(shares, total_assets, total_shares)
+}
+
+fn mul_div(value: u64, numerator: u64, denominator: u64) -> u64 {
+    let result = value.as_u256() * numerator.as_u256() / denominator.as_u256();
+    u64::try_from(result).unwrap()
+}
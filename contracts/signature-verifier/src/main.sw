@@ -0,0 +1,111 @@
+contract;
+
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    hash::{Hash, sha256},
+    logging::log,
+};
+
+// Verifies attestations signed off chain. The signer never sends a
+// transaction: they sign the attestation's digest with their wallet key and
+// anyone may submit it. The digest binds the attestation to this contract and
+// to the signer's next nonce, so a signature cannot be replayed here or
+// against another deployment.
+
+/// A signed statement: `signer` gives `subject` a `score`.
+pub struct Attestation {
+    pub signer: Address,
+    pub subject: b256,
+    pub score: u64,
+    pub nonce: u64,
+}
+
+storage {
+    /// Next attestation nonce per signer.
+    nonces: StorageMap<Address, u64> = StorageMap {},
+    /// Latest recorded score per (signer, subject).
+    scores: StorageMap<(Address, b256), u64> = StorageMap {},
+}
+
+// Event structs
+pub struct AttestationRecordedEvent {
+    pub signer: Address,
+    pub subject: b256,
+    pub score: u64,
+    pub nonce: u64,
+}
+
+abi SignatureVerifier {
+    /// The digest signed for `attestation`:
+    /// sha256(contract id, signer, subject, score, nonce).
+    fn digest(attestation: Attestation) -> b256;
+
+    /// Whether `signature` is the attestation signer's signature over its digest.
+    fn verify(attestation: Attestation, signature: B512) -> bool;
+
+    /// Record a signed attestation. Anyone may submit it; its nonce must be the
+    /// signer's next one.
+    #[storage(read, write)]
+    fn submit(attestation: Attestation, signature: B512);
+
+    /// Get the nonce the next attestation of `signer` must carry.
+    #[storage(read)]
+    fn nonce(signer: Address) -> u64;
+
+    /// Get the latest score `signer` recorded for `subject`.
+    #[storage(read)]
+    fn score(signer: Address, subject: b256) -> Option<u64>;
+}
+
+fn digest_of(attestation: Attestation) -> b256 {
+    sha256((
+        (ContractId::this(), attestation.signer, attestation.subject),
+        (attestation.score, attestation.nonce),
+    ))
+}
+
+fn is_signed(attestation: Attestation, signature: B512) -> bool {
+    let signer = ec_recover_address(signature, digest_of(attestation));
+    signer.is_ok() && signer.unwrap() == attestation.signer
+}
+
+impl SignatureVerifier for Contract {
+    fn digest(attestation: Attestation) -> b256 {
+        digest_of(attestation)
+    }
+
+    fn verify(attestation: Attestation, signature: B512) -> bool {
+        is_signed(attestation, signature)
+    }
+
+    #[storage(read, write)]
+    fn submit(attestation: Attestation, signature: B512) {
+        require(is_signed(attestation, signature), "Invalid signature");
+
+        let nonce = storage.nonces.get(attestation.signer).try_read().unwrap_or(0);
+        require(attestation.nonce == nonce, "Invalid nonce");
+
+        storage.nonces.insert(attestation.signer, nonce + 1);
+        storage
+            .scores
+            .insert((attestation.signer, attestation.subject), attestation.score);
+
+        log(AttestationRecordedEvent {
+            signer: attestation.signer,
+            subject: attestation.subject,
+            score: attestation.score,
+            nonce,
+        });
+    }
+
+    #[storage(read)]
+    fn nonce(signer: Address) -> u64 {
+        storage.nonces.get(signer).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn score(signer: Address, subject: b256) -> Option<u64> {
+        storage.scores.get((signer, subject)).try_read()
+    }
+}
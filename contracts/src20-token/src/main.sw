@@ -2,6 +2,8 @@ contract;
 
 use src20::{SetDecimalsEvent, SetNameEvent, SetSymbolEvent, SRC20, TotalSupplyEvent};
 use src3::SRC3;
+use src7::{Metadata, SRC7};
+use sway_libs::asset::metadata::{_metadata, _set_metadata, SetAssetMetadata, StorageMetadata};
 use std::{
     asset::{
         burn,
@@ -9,12 +11,16 @@ use std::{
         transfer
     },
     auth::msg_sender,
+    b512::B512,
+    block::height,
     call_frames::msg_asset_id,
     constants::DEFAULT_SUB_ID,
     context::{
         balance_of,
         msg_amount,
     },
+    ecr::ec_recover_address,
+    hash::{Hash, sha256},
     logging::log,
     string::String,
 };
@@ -33,8 +39,24 @@ configurable {
 }
 
 storage {
-    /// The total supply of the asset minted by this contract.
-    total_supply: u64 = 0,
+    /// Supply per asset; every sub id is a separate asset.
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    /// Number of assets ever minted, counting the default one.
+    total_assets: u64 = 1,
+    /// Whether minting and burning are paused.
+    paused: bool = false,
+    /// Identities that may not receive newly minted tokens.
+    frozen: StorageMap<Identity, bool> = StorageMap {},
+    /// SRC-7 metadata of the asset.
+    metadata: StorageMetadata = StorageMetadata {},
+    /// Admin set by `accept_admin`; `ADMIN` until the first handover.
+    admin: Option<Identity> = None,
+    /// Admin proposed by the current admin, waiting to accept.
+    pending_admin: Option<Identity> = None,
+    /// Amount a spender is approved for, per (owner, spender).
+    allowances: StorageMap<(Identity, Identity), u64> = StorageMap {},
+    /// Next permit nonce per owner.
+    nonces: StorageMap<Address, u64> = StorageMap {},
 }
 
 // Event structs for logging
@@ -49,6 +71,33 @@ pub struct BurnEvent {
     pub asset_id: AssetId,
 }
 
+pub struct PauseEvent {
+    pub paused: bool,
+    pub sender: Identity,
+}
+
+pub struct FreezeEvent {
+    pub identity: Identity,
+    pub frozen: bool,
+}
+
+pub struct AdminProposedEvent {
+    pub admin: Identity,
+    pub proposed: Identity,
+}
+
+pub struct AdminTransferredEvent {
+    pub previous: Identity,
+    pub admin: Identity,
+}
+
+pub struct PermitEvent {
+    pub owner: Identity,
+    pub spender: Identity,
+    pub amount: u64,
+    pub deadline: u32,
+}
+
 pub struct TransferEvent {
     pub from: Identity,
     pub to: Identity,
@@ -60,21 +109,22 @@ pub struct TransferEvent {
 impl SRC20 for Contract {
     #[storage(read)]
     fn total_assets() -> u64 {
-        1
+        storage.total_assets.read()
     }
 
     #[storage(read)]
     fn total_supply(asset: AssetId) -> Option<u64> {
-        if asset == AssetId::default() {
-            Some(storage.total_supply.read())
+        if is_known(asset) {
+            Some(storage.total_supply.get(asset).try_read().unwrap_or(0))
         } else {
             None
         }
     }
 
+    // Every asset of the contract shares the configured name, symbol and decimals.
     #[storage(read)]
     fn name(asset: AssetId) -> Option<String> {
-        if asset == AssetId::default() {
+        if is_known(asset) {
             Some(String::from_ascii_str(from_str_array(NAME)))
         } else {
             None
@@ -83,7 +133,7 @@ impl SRC20 for Contract {
 
     #[storage(read)]
     fn symbol(asset: AssetId) -> Option<String> {
-        if asset == AssetId::default() {
+        if is_known(asset) {
             Some(String::from_ascii_str(from_str_array(SYMBOL)))
         } else {
             None
@@ -92,7 +142,7 @@ impl SRC20 for Contract {
 
     #[storage(read)]
     fn decimals(asset: AssetId) -> Option<u8> {
-        if asset == AssetId::default() {
+        if is_known(asset) {
             Some(DECIMALS)
         } else {
             None
@@ -100,6 +150,32 @@ impl SRC20 for Contract {
     }
 }
 
+impl SRC7 for Contract {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        if is_known(asset) {
+            _metadata(storage.metadata, asset, key)
+        } else {
+            None
+        }
+    }
+}
+
+impl SetAssetMetadata for Contract {
+    /// Sets a metadata entry of the asset (admin only).
+    #[storage(read, write)]
+    fn set_metadata(asset: AssetId, key: String, metadata: Metadata) {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can set metadata",
+        );
+        require(is_known(asset), "Incorrect asset provided");
+
+        _set_metadata(storage.metadata, asset, key, metadata);
+    }
+}
+
 abi EmitSRC20Events {
     fn emit_src20_events();
 }
@@ -119,36 +195,45 @@ impl EmitSRC20Events for Contract {
 }
 
 impl SRC3 for Contract {
-    /// Mints assets to a given identity.
+    /// Mints assets of the given sub id to a given identity.
     #[storage(read, write)]
     fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64) {
+        require(!storage.paused.read(), "Paused");
         // Only the admin can mint assets.
         require(
             msg_sender()
-                .unwrap() == ADMIN,
+                .unwrap() == current_admin(),
             "Unauthorized: Only admin can mint",
         );
+        require(sub_id.is_some(), "Incorrect Sub Id");
         require(
-            sub_id
-                .is_some() && sub_id
-                .unwrap() == DEFAULT_SUB_ID,
-            "Incorrect Sub Id",
+            !storage.frozen.get(recipient).try_read().unwrap_or(false),
+            "Frozen: recipient is frozen",
         );
 
+        let sub_id = sub_id.unwrap();
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
+
+        // The first mint of a sub id creates a new asset.
+        let supply = storage.total_supply.get(asset_id).try_read();
+        if supply.is_none() && asset_id != AssetId::default() {
+            storage.total_assets.write(storage.total_assets.read() + 1);
+        }
+
         // Increment total supply of the asset and mint to the recipient.
-        let new_supply = amount + storage.total_supply.read();
-        storage.total_supply.write(new_supply);
+        let new_supply = amount + supply.unwrap_or(0);
+        storage.total_supply.insert(asset_id, new_supply);
 
-        mint_to(recipient, DEFAULT_SUB_ID, amount);
+        mint_to(recipient, sub_id, amount);
 
         // Log mint event
         log(MintEvent {
             recipient,
             amount,
-            asset_id: AssetId::default(),
+            asset_id,
         });
 
-        TotalSupplyEvent::new(AssetId::default(), new_supply, msg_sender().unwrap())
+        TotalSupplyEvent::new(asset_id, new_supply, msg_sender().unwrap())
             .log();
     }
 
@@ -156,30 +241,292 @@ impl SRC3 for Contract {
     #[payable]
     #[storage(read, write)]
     fn burn(sub_id: SubId, amount: u64) {
-        require(sub_id == DEFAULT_SUB_ID, "Incorrect Sub Id");
+        require(!storage.paused.read(), "Paused");
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
         require(msg_amount() >= amount, "Incorrect amount provided");
-        require(
-            msg_asset_id() == AssetId::default(),
-            "Incorrect asset provided",
-        );
+        require(msg_asset_id() == asset_id, "Incorrect asset provided");
 
         // Decrement total supply of the asset and burn.
-        let new_supply = storage.total_supply.read() - amount;
-        storage.total_supply.write(new_supply);
+        let new_supply = storage.total_supply.get(asset_id).try_read().unwrap_or(0) - amount;
+        storage.total_supply.insert(asset_id, new_supply);
 
-        burn(DEFAULT_SUB_ID, amount);
+        burn(sub_id, amount);
 
         // Log burn event
         log(BurnEvent {
             amount,
-            asset_id: AssetId::default(),
+            asset_id,
         });
 
-        TotalSupplyEvent::new(AssetId::default(), new_supply, msg_sender().unwrap())
+        TotalSupplyEvent::new(asset_id, new_supply, msg_sender().unwrap())
             .log();
     }
 }
 
+abi Pausable {
+    #[storage(read, write)]
+    fn pause();
+
+    #[storage(read, write)]
+    fn unpause();
+
+    #[storage(read)]
+    fn is_paused() -> bool;
+}
+
+impl Pausable for Contract {
+    /// Stops minting and burning until `unpause` is called (admin only).
+    #[storage(read, write)]
+    fn pause() {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can pause",
+        );
+        storage.paused.write(true);
+
+        log(PauseEvent {
+            paused: true,
+            sender: msg_sender().unwrap(),
+        });
+    }
+
+    /// Resumes minting and burning (admin only).
+    #[storage(read, write)]
+    fn unpause() {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can unpause",
+        );
+        storage.paused.write(false);
+
+        log(PauseEvent {
+            paused: false,
+            sender: msg_sender().unwrap(),
+        });
+    }
+
+    #[storage(read)]
+    fn is_paused() -> bool {
+        storage.paused.read()
+    }
+}
+
+abi Freezable {
+    #[storage(read, write)]
+    fn freeze(identity: Identity);
+
+    #[storage(read, write)]
+    fn unfreeze(identity: Identity);
+
+    #[storage(read)]
+    fn is_frozen(identity: Identity) -> bool;
+}
+
+impl Freezable for Contract {
+    /// Blocks `identity` from receiving mints (admin only).
+    #[storage(read, write)]
+    fn freeze(identity: Identity) {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can freeze",
+        );
+        storage.frozen.insert(identity, true);
+
+        log(FreezeEvent {
+            identity,
+            frozen: true,
+        });
+    }
+
+    /// Lifts a freeze on `identity` (admin only).
+    #[storage(read, write)]
+    fn unfreeze(identity: Identity) {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can unfreeze",
+        );
+        storage.frozen.insert(identity, false);
+
+        log(FreezeEvent {
+            identity,
+            frozen: false,
+        });
+    }
+
+    #[storage(read)]
+    fn is_frozen(identity: Identity) -> bool {
+        storage.frozen.get(identity).try_read().unwrap_or(false)
+    }
+}
+
+abi TwoStepAdmin {
+    /// Propose `new_admin`; takes effect once they call `accept_admin` (admin only).
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity);
+
+    /// Become admin; only the proposed identity can call this.
+    #[storage(read, write)]
+    fn accept_admin();
+
+    #[storage(read)]
+    fn admin() -> Identity;
+
+    #[storage(read)]
+    fn pending_admin() -> Option<Identity>;
+}
+
+impl TwoStepAdmin for Contract {
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity) {
+        let admin = current_admin();
+        require(msg_sender().unwrap() == admin, "Unauthorized: Only admin can propose admin");
+        storage.pending_admin.write(Some(new_admin));
+
+        log(AdminProposedEvent {
+            admin,
+            proposed: new_admin,
+        });
+    }
+
+    #[storage(read, write)]
+    fn accept_admin() {
+        let sender = msg_sender().unwrap();
+        require(
+            storage.pending_admin.read() == Some(sender),
+            "Unauthorized: Only the proposed admin can accept",
+        );
+        let previous = current_admin();
+        storage.admin.write(Some(sender));
+        storage.pending_admin.write(None);
+
+        log(AdminTransferredEvent {
+            previous,
+            admin: sender,
+        });
+    }
+
+    #[storage(read)]
+    fn admin() -> Identity {
+        current_admin()
+    }
+
+    #[storage(read)]
+    fn pending_admin() -> Option<Identity> {
+        storage.pending_admin.read()
+    }
+}
+
+// Native assets live in UTXOs, so the token cannot pull an owner's coins itself.
+// Approvals are recorded here for contracts and off-chain services that honour
+// them; a permit lets the owner approve without a transaction of their own, by
+// signing off chain and having anyone (a relayer) submit the signature.
+abi Permit {
+    /// Approve `spender` for `amount` on behalf of `owner` with a signed permit.
+    /// The signature covers sha256(contract id, owner, spender, amount, deadline, nonce)
+    /// and is only valid until block `deadline` and for the owner's current nonce.
+    #[storage(read, write)]
+    fn permit(owner: Address, spender: Identity, amount: u64, deadline: u32, signature: B512);
+
+    /// Get the nonce the next permit of `owner` must be signed with.
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64;
+
+    /// Approve `spender` for `amount` of the caller's tokens, paying the fee.
+    #[storage(write)]
+    fn approve(spender: Identity, amount: u64);
+
+    /// Get the amount `spender` is approved for by `owner`.
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u64;
+}
+
+impl Permit for Contract {
+    #[storage(read, write)]
+    fn permit(owner: Address, spender: Identity, amount: u64, deadline: u32, signature: B512) {
+        require(height() <= deadline, "Permit expired");
+
+        let nonce = storage.nonces.get(owner).try_read().unwrap_or(0);
+        let digest = sha256(((ContractId::this(), owner, spender), (amount, deadline, nonce)));
+        let signer = ec_recover_address(signature, digest);
+        require(
+            signer.is_ok() && signer.unwrap() == owner,
+            "Invalid permit signature",
+        );
+
+        storage.nonces.insert(owner, nonce + 1);
+        storage.allowances.insert((Identity::Address(owner), spender), amount);
+
+        log(PermitEvent {
+            owner: Identity::Address(owner),
+            spender,
+            amount,
+            deadline,
+        });
+    }
+
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64 {
+        storage.nonces.get(owner).try_read().unwrap_or(0)
+    }
+
+    #[storage(write)]
+    fn approve(spender: Identity, amount: u64) {
+        storage.allowances.insert((msg_sender().unwrap(), spender), amount);
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u64 {
+        storage.allowances.get((owner, spender)).try_read().unwrap_or(0)
+    }
+}
+
+// The default asset, or one that has been minted under another sub id.
+#[storage(read)]
+fn is_known(asset: AssetId) -> bool {
+    asset == AssetId::default() || storage.total_supply.get(asset).try_read().is_some()
+}
+
+// The configurable admin until ownership is handed over.
+#[storage(read)]
+fn current_admin() -> Identity {
+    storage.admin.read().unwrap_or(ADMIN)
+}
+
+abi AssetRegistry {
+    #[storage(read, write)]
+    fn register(sub_id: SubId, name: String, symbol: String, decimals: u8, logo_uri: String) -> AssetId;
+}
+
+abi RegisterAsset {
+    #[storage(read)]
+    fn register_with(registry: ContractId, logo_uri: String) -> AssetId;
+}
+
+impl RegisterAsset for Contract {
+    /// Registers this token's name, symbol and decimals with an asset registry (admin only).
+    #[storage(read)]
+    fn register_with(registry: ContractId, logo_uri: String) -> AssetId {
+        require(
+            msg_sender()
+                .unwrap() == current_admin(),
+            "Unauthorized: Only admin can register",
+        );
+
+        let registry_contract = abi(AssetRegistry, registry.into());
+        registry_contract.register(
+            DEFAULT_SUB_ID,
+            String::from_ascii_str(from_str_array(NAME)),
+            String::from_ascii_str(from_str_array(SYMBOL)),
+            DECIMALS,
+            logo_uri,
+        )
+    }
+}
+
 // Additional ABI for cross-contract interaction demonstrations
 abi TokenInteraction {
     #[storage(read)]
@@ -193,6 +540,8 @@ abi TokenInteraction {
 
     #[storage(read)]
     fn get_asset_id() -> AssetId;
+
+    fn get_asset_id_for(sub_id: SubId) -> AssetId;
 }
 
 impl TokenInteraction for Contract {
@@ -229,4 +578,9 @@ impl TokenInteraction for Contract {
     fn get_asset_id() -> AssetId {
         AssetId::default()
     }
+
+    /// Get the AssetId minted under `sub_id`.
+    fn get_asset_id_for(sub_id: SubId) -> AssetId {
+        AssetId::new(ContractId::this(), sub_id)
+    }
 }
@@ -0,0 +1,220 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+    primitive_conversions::u64::*,
+};
+
+// Rewards accrue at REWARD_PER_BLOCK and are shared among stakers in
+// proportion to their stake. `reward_per_share` accumulates the reward earned
+// by one staked unit (scaled by PRECISION) since deployment; each staker
+// remembers the accumulator value their rewards were last settled at.
+
+configurable {
+    /// The asset users stake.
+    STAKE_ASSET: AssetId = AssetId::zero(),
+    /// The asset rewards are paid in.
+    REWARD_ASSET: AssetId = AssetId::zero(),
+    /// Reward shared among all stakers per block.
+    REWARD_PER_BLOCK: u64 = 0,
+}
+
+/// Fixed-point scale of `reward_per_share` (1e18).
+const PRECISION: u256 = 0x0de0b6b3a7640000u256;
+
+pub struct StakerInfo {
+    pub staked: u64,
+    /// `reward_per_share` when this staker's rewards were last settled.
+    pub reward_per_share_paid: u256,
+    /// Settled rewards not yet claimed.
+    pub unclaimed: u64,
+}
+
+storage {
+    stakers: StorageMap<Identity, StakerInfo> = StorageMap {},
+    total_staked: u64 = 0,
+    reward_per_share: u256 = 0,
+    last_update: u32 = 0,
+    /// Reward tokens funded and not yet claimed.
+    reward_reserve: u64 = 0,
+}
+
+// Event structs
+pub struct StakeEvent {
+    pub staker: Identity,
+    pub amount: u64,
+}
+
+pub struct UnstakeEvent {
+    pub staker: Identity,
+    pub amount: u64,
+}
+
+pub struct RewardClaimedEvent {
+    pub staker: Identity,
+    pub amount: u64,
+}
+
+abi Staking {
+    /// Add the forwarded reward tokens to the reserve rewards are paid from.
+    #[payable]
+    #[storage(read, write)]
+    fn fund_rewards();
+
+    /// Stake the forwarded tokens.
+    #[payable]
+    #[storage(read, write)]
+    fn stake();
+
+    /// Return `amount` of the caller's stake. Accrued rewards stay claimable.
+    #[storage(read, write)]
+    fn unstake(amount: u64);
+
+    /// Transfer the caller's accrued rewards and return the amount.
+    #[storage(read, write)]
+    fn claim() -> u64;
+
+    /// Get the rewards `staker` could claim now.
+    #[storage(read)]
+    fn pending_rewards(staker: Identity) -> u64;
+
+    /// Get the amount staked by `staker`.
+    #[storage(read)]
+    fn staked_of(staker: Identity) -> u64;
+
+    /// Get the amount staked by everyone.
+    #[storage(read)]
+    fn total_staked() -> u64;
+
+    /// Get the reward tokens funded and not yet claimed.
+    #[storage(read)]
+    fn reward_reserve() -> u64;
+}
+
+// The accumulator as of the current block
+#[storage(read)]
+fn current_reward_per_share() -> u256 {
+    let reward_per_share = storage.reward_per_share.read();
+    let total_staked = storage.total_staked.read();
+    let last_update = storage.last_update.read();
+    let now = height();
+    if total_staked == 0 || now <= last_update {
+        return reward_per_share;
+    }
+
+    let blocks = (now - last_update).as_u64();
+    let reward = blocks.as_u256() * REWARD_PER_BLOCK.as_u256();
+    reward_per_share + reward * PRECISION / total_staked.as_u256()
+}
+
+#[storage(read)]
+fn read_staker(staker: Identity) -> StakerInfo {
+    storage.stakers.get(staker).try_read().unwrap_or(StakerInfo {
+        staked: 0,
+        reward_per_share_paid: 0,
+        unclaimed: 0,
+    })
+}
+
+fn earned(info: StakerInfo, reward_per_share: u256) -> u64 {
+    let accrued = info.staked.as_u256() * (reward_per_share - info.reward_per_share_paid) / PRECISION;
+    info.unclaimed + u64::try_from(accrued).unwrap()
+}
+
+// Brings the accumulator and `staker`'s settled rewards up to the current block
+#[storage(read, write)]
+fn settle(staker: Identity) -> StakerInfo {
+    let reward_per_share = current_reward_per_share();
+    storage.reward_per_share.write(reward_per_share);
+    storage.last_update.write(height());
+
+    let mut info = read_staker(staker);
+    info.unclaimed = earned(info, reward_per_share);
+    info.reward_per_share_paid = reward_per_share;
+    info
+}
+
+impl Staking for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn fund_rewards() {
+        require(msg_asset_id() == REWARD_ASSET, "Wrong asset type");
+        storage.reward_reserve.write(storage.reward_reserve.read() + msg_amount());
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn stake() {
+        require(msg_asset_id() == STAKE_ASSET, "Wrong asset type");
+        require(msg_amount() > 0, "Nothing to stake");
+        let staker = msg_sender().unwrap();
+
+        let mut info = settle(staker);
+        info.staked += msg_amount();
+        storage.stakers.insert(staker, info);
+        storage.total_staked.write(storage.total_staked.read() + msg_amount());
+
+        log(StakeEvent {
+            staker,
+            amount: msg_amount(),
+        });
+    }
+
+    #[storage(read, write)]
+    fn unstake(amount: u64) {
+        let staker = msg_sender().unwrap();
+        let mut info = settle(staker);
+        require(info.staked >= amount, "Insufficient stake");
+
+        info.staked -= amount;
+        storage.stakers.insert(staker, info);
+        storage.total_staked.write(storage.total_staked.read() - amount);
+        transfer(staker, STAKE_ASSET, amount);
+
+        log(UnstakeEvent { staker, amount });
+    }
+
+    #[storage(read, write)]
+    fn claim() -> u64 {
+        let staker = msg_sender().unwrap();
+        let mut info = settle(staker);
+        let amount = info.unclaimed;
+        require(amount > 0, "No rewards to claim");
+
+        let reserve = storage.reward_reserve.read();
+        require(reserve >= amount, "Insufficient reward funds");
+
+        info.unclaimed = 0;
+        storage.stakers.insert(staker, info);
+        storage.reward_reserve.write(reserve - amount);
+        transfer(staker, REWARD_ASSET, amount);
+
+        log(RewardClaimedEvent { staker, amount });
+        amount
+    }
+
+    #[storage(read)]
+    fn pending_rewards(staker: Identity) -> u64 {
+        earned(read_staker(staker), current_reward_per_share())
+    }
+
+    #[storage(read)]
+    fn staked_of(staker: Identity) -> u64 {
+        read_staker(staker).staked
+    }
+
+    #[storage(read)]
+    fn total_staked() -> u64 {
+        storage.total_staked.read()
+    }
+
+    #[storage(read)]
+    fn reward_reserve() -> u64 {
+        storage.reward_reserve.read()
+    }
+}
@@ -0,0 +1,176 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    b512::B512,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    ecr::ec_recover_address,
+    hash::{Hash, sha256},
+    logging::log,
+};
+
+// Native assets on Fuel are held in UTXOs, so a contract cannot move tokens
+// out of a user's wallet. Owners deposit into this contract instead, and
+// approved spenders move tokens out of the owner's deposited balance.
+
+configurable {
+    /// The asset managed by this contract.
+    ASSET: AssetId = AssetId::zero(),
+}
+
+storage {
+    /// Deposited balance per owner.
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    /// Remaining allowance per (owner, spender).
+    allowances: StorageMap<(Identity, Identity), u64> = StorageMap {},
+    /// Next permit nonce per owner.
+    nonces: StorageMap<Address, u64> = StorageMap {},
+}
+
+// Event structs
+pub struct ApprovalEvent {
+    pub owner: Identity,
+    pub spender: Identity,
+    pub amount: u64,
+}
+
+pub struct TransferFromEvent {
+    pub owner: Identity,
+    pub spender: Identity,
+    pub recipient: Identity,
+    pub amount: u64,
+}
+
+abi TokenAllowance {
+    /// Deposit tokens to be spent by approved spenders.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    /// Withdraw the caller's own deposited tokens.
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+
+    /// Set the amount `spender` may move out of the caller's balance.
+    #[storage(write)]
+    fn approve(spender: Identity, amount: u64);
+
+    /// Set an allowance from an approval `owner` signed off-chain.
+    ///
+    /// The signature covers sha256(contract id, owner, spender, amount, deadline, nonce)
+    /// and is only valid until block `deadline` and for the owner's current nonce.
+    #[storage(read, write)]
+    fn permit(owner: Address, spender: Identity, amount: u64, deadline: u32, signature: B512);
+
+    /// Get the nonce the next permit of `owner` must be signed with.
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64;
+
+    /// Move `amount` from `owner`'s balance to `recipient`, spending allowance.
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, amount: u64);
+
+    /// Get the remaining allowance of `spender` over `owner`'s balance.
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u64;
+
+    /// Get the deposited balance of `owner`.
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64;
+}
+
+impl TokenAllowance for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        require(msg_asset_id() == ASSET, "Wrong asset type");
+        let sender = msg_sender().unwrap();
+
+        let current = storage.balances.get(sender).try_read().unwrap_or(0);
+        storage.balances.insert(sender, current + msg_amount());
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64) {
+        let sender = msg_sender().unwrap();
+        let current = storage.balances.get(sender).try_read().unwrap_or(0);
+
+        require(current >= amount, "Insufficient balance");
+
+        storage.balances.insert(sender, current - amount);
+        transfer(sender, ASSET, amount);
+    }
+
+    #[storage(write)]
+    fn approve(spender: Identity, amount: u64) {
+        let owner = msg_sender().unwrap();
+        storage.allowances.insert((owner, spender), amount);
+
+        log(ApprovalEvent {
+            owner,
+            spender,
+            amount,
+        });
+    }
+
+    #[storage(read, write)]
+    fn permit(owner: Address, spender: Identity, amount: u64, deadline: u32, signature: B512) {
+        require(height() <= deadline, "Permit expired");
+
+        let nonce = storage.nonces.get(owner).try_read().unwrap_or(0);
+        let digest = sha256(((ContractId::this(), owner, spender), (amount, deadline, nonce)));
+        let signer = ec_recover_address(signature, digest);
+        require(
+            signer.is_ok() && signer.unwrap() == owner,
+            "Invalid permit signature",
+        );
+
+        storage.nonces.insert(owner, nonce + 1);
+        storage.allowances.insert((Identity::Address(owner), spender), amount);
+
+        log(ApprovalEvent {
+            owner: Identity::Address(owner),
+            spender,
+            amount,
+        });
+    }
+
+    #[storage(read)]
+    fn nonce(owner: Address) -> u64 {
+        storage.nonces.get(owner).try_read().unwrap_or(0)
+    }
+
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, amount: u64) {
+        let spender = msg_sender().unwrap();
+        let allowance = storage.allowances.get((owner, spender)).try_read().unwrap_or(0);
+        require(allowance >= amount, "Insufficient allowance");
+
+        let balance = storage.balances.get(owner).try_read().unwrap_or(0);
+        require(balance >= amount, "Insufficient balance");
+
+        storage.allowances.insert((owner, spender), allowance - amount);
+        storage.balances.insert(owner, balance - amount);
+        transfer(recipient, ASSET, amount);
+
+        log(TransferFromEvent {
+            owner,
+            spender,
+            recipient,
+            amount,
+        });
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity) -> u64 {
+        storage.allowances.get((owner, spender)).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64 {
+        storage.balances.get(owner).try_read().unwrap_or(0)
+    }
+}
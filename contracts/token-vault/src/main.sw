@@ -1,7 +1,7 @@
 contract;
 
 use std::{
-    asset::transfer,
+    asset::{burn, mint_to, transfer},
     call_frames::{msg_asset_id},
     context::{msg_amount, balance_of},
     storage::storage_api::{read, write},
@@ -15,13 +15,26 @@ configurable {
     ADMIN: Identity = Identity::Address(Address::zero()),
     /// The contract ID of the cross-contract call contract.
     CROSS_CONTRACT_CALL: ContractId = ContractId::zero(),
+    /// Fee kept on every withdrawal, in basis points of the withdrawn amount.
+    WITHDRAWAL_FEE_BPS: u64 = 0,
 }
 
 storage {
-    /// Mapping of user deposits.
+    /// Mapping of user deposits, summed over every asset. Informational only:
+    /// amounts of unrelated assets are added up regardless of their decimals.
     deposits: StorageMap<Identity, u64> = StorageMap {},
-    /// Total amount deposited in the vault.
-    total_deposits: u64 = 0,
+    /// Mapping of user deposits per asset.
+    asset_deposits: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+    /// Total amount deposited in the vault per asset, claim deposits included.
+    total_deposits: StorageMap<AssetId, u64> = StorageMap {},
+    /// Admin set by `accept_admin`; `ADMIN` until the first handover.
+    admin: Option<Identity> = None,
+    /// Admin proposed by the current admin, waiting to accept.
+    pending_admin: Option<Identity> = None,
+    /// Withdrawal fees per asset, waiting for the admin to collect.
+    fees: StorageMap<AssetId, u64> = StorageMap {},
+    /// Claim tokens in circulation per deposited asset.
+    claims: StorageMap<AssetId, u64> = StorageMap {},
 }
 
 // Event structs
@@ -31,10 +44,41 @@ pub struct DepositEvent {
     pub asset_id: AssetId,
 }
 
+pub struct AdminProposedEvent {
+    pub admin: Identity,
+    pub proposed: Identity,
+}
+
+pub struct AdminTransferredEvent {
+    pub previous: Identity,
+    pub admin: Identity,
+}
+
 pub struct WithdrawEvent {
     pub user: Identity,
+    /// Amount taken off the deposit; the user receives `amount - fee`.
+    pub amount: u64,
+    pub asset_id: AssetId,
+    pub fee: u64,
+}
+
+pub struct FeesCollectedEvent {
+    pub admin: Identity,
+    pub asset_id: AssetId,
     pub amount: u64,
+}
+
+pub struct DepositBurnedEvent {
+    pub user: Identity,
     pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+// The burn half of the token's SRC3 interface.
+abi Token {
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64);
 }
 
 abi TokenVault {
@@ -43,27 +87,68 @@ abi TokenVault {
     #[storage(read, write)]
     fn deposit();
     
-    /// Withdraw tokens from the vault.
+    /// Withdraw tokens from the vault, minus the withdrawal fee.
     #[payable]
     #[storage(read, write)]
     fn withdraw(amount: u64);
     
-    /// Get the deposit amount for a user.
+    /// Deposit tokens and receive the same amount of the asset's claim token.
+    /// The deposit belongs to whoever holds the claim tokens.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit_for_claim();
+    
+    /// Return claim tokens of `asset_id` to withdraw that much of it, minus the
+    /// withdrawal fee. The claim tokens are burned.
+    #[payable]
+    #[storage(read, write)]
+    fn withdraw_with_claim(asset_id: AssetId);
+    
+    /// Burn `amount` of the caller's deposit of the asset `token` mints under
+    /// `sub_id`, by sending it to the token's `burn`. Burned tokens count
+    /// towards neither the deposit nor the total.
+    #[storage(read, write)]
+    fn burn_deposit(token: ContractId, sub_id: SubId, amount: u64);
+    
+    /// Get the claim token minted for deposits of `asset_id`.
+    fn claim_asset_id(asset_id: AssetId) -> AssetId;
+    
+    /// Get the claim tokens of `asset_id` in circulation.
+    #[storage(read)]
+    fn get_outstanding_claims(asset_id: AssetId) -> u64;
+    
+    /// Send the accumulated withdrawal fees of an asset to the admin (admin only).
+    #[storage(read, write)]
+    fn collect_fees(asset_id: AssetId) -> u64;
+    
+    /// Get the withdrawal fees of an asset not collected yet.
+    #[storage(read)]
+    fn get_accrued_fees(asset_id: AssetId) -> u64;
+    
+    /// Get the withdrawal fee in basis points.
+    fn get_withdrawal_fee_bps() -> u64;
+    
+    /// Get the deposit amount for a user, summed over every asset. Only
+    /// meaningful for a single asset, or assets with the same decimals; use
+    /// `get_deposit_for_asset` for accounting.
     #[storage(read)]
     fn get_deposit(user: Identity) -> u64;
     
-    /// Get total deposits in the vault.
+    /// Get the deposit amount for a user in a single asset.
     #[storage(read)]
-    fn get_total_deposits() -> u64;
+    fn get_deposit_for_asset(user: Identity, asset_id: AssetId) -> u64;
+    
+    /// Get total deposits of `asset_id` in the vault.
+    #[storage(read)]
+    fn get_total_deposits(asset_id: AssetId) -> u64;
     
     /// Cross-contract transfer demonstration.
     #[payable]
     #[storage(read, write)]
     fn cross_contract_deposit(user: Identity);
     
-    /// Get the vault's balance of the accepted token.
-    #[storage(read)]
-    fn get_vault_balance() -> u64;
+    /// Get the vault's balance of `asset_id`, accrued fees included.
+    fn get_vault_balance(asset_id: AssetId) -> u64;
 }
 
 impl TokenVault for Contract {
@@ -81,10 +166,11 @@ impl TokenVault for Contract {
         // Update user's deposit balance
         let current_deposit = storage.deposits.get(sender).try_read().unwrap_or(0);
         storage.deposits.insert(sender, current_deposit + amount);
+        let current_asset_deposit = storage.asset_deposits.get((sender, asset_id)).try_read().unwrap_or(0);
+        storage.asset_deposits.insert((sender, asset_id), current_asset_deposit + amount);
         
         // Update total deposits
-        let new_total = storage.total_deposits.read() + amount;
-        storage.total_deposits.write(new_total);
+        add_to_total(asset_id, amount);
         
         // Log deposit event
         log(DepositEvent {
@@ -99,38 +185,143 @@ impl TokenVault for Contract {
     #[storage(read, write)]
     fn withdraw(amount: u64) {
         let sender = msg_sender().unwrap();
-        let current_deposit = storage.deposits.get(sender).try_read().unwrap_or(0);
+        // The asset to withdraw is selected by the call's asset id.
+        let asset_id = msg_asset_id();
+        let current_asset_deposit = storage.asset_deposits.get((sender, asset_id)).try_read().unwrap_or(0);
         
-        require(current_deposit >= amount, "Insufficient balance");
+        require(current_asset_deposit >= amount, "Insufficient balance");
         
         // Update user's deposit balance
+        storage.asset_deposits.insert((sender, asset_id), current_asset_deposit - amount);
+        let current_deposit = storage.deposits.get(sender).try_read().unwrap_or(0);
         storage.deposits.insert(sender, current_deposit - amount);
         
         // Update total deposits
-        let new_total = storage.total_deposits.read() - amount;
-        storage.total_deposits.write(new_total);
+        subtract_from_total(asset_id, amount);
+        
+        pay_out(sender, asset_id, amount);
+    }
+    
+    #[payable]
+    #[storage(read, write)]
+    fn deposit_for_claim() {
+        let amount = msg_amount();
+        let asset_id = msg_asset_id();
+        let sender = msg_sender().unwrap();
+        require(amount > 0, "Nothing deposited");
+        
+        // The claim tokens stand in for a per-user deposit
+        let outstanding = storage.claims.get(asset_id).try_read().unwrap_or(0);
+        storage.claims.insert(asset_id, outstanding + amount);
+        add_to_total(asset_id, amount);
+        
+        mint_to(sender, asset_id.bits(), amount);
+        
+        log(DepositEvent {
+            user: sender,
+            amount,
+            asset_id,
+        });
+    }
+    
+    #[payable]
+    #[storage(read, write)]
+    fn withdraw_with_claim(asset_id: AssetId) {
+        let amount = msg_amount();
+        require(amount > 0, "No claim tokens sent");
+        require(msg_asset_id() == claim_asset(asset_id), "Wrong claim token");
+        
+        // Claim tokens are only minted against deposits, so they are backed
+        let outstanding = storage.claims.get(asset_id).try_read().unwrap_or(0);
+        storage.claims.insert(asset_id, outstanding - amount);
+        subtract_from_total(asset_id, amount);
+        
+        burn(asset_id.bits(), amount);
+        
+        pay_out(msg_sender().unwrap(), asset_id, amount);
+    }
+    
+    #[storage(read, write)]
+    fn burn_deposit(token: ContractId, sub_id: SubId, amount: u64) {
+        let sender = msg_sender().unwrap();
+        let asset_id = AssetId::new(token, sub_id);
+        require(amount > 0, "Nothing to burn");
+        let current_asset_deposit = storage.asset_deposits.get((sender, asset_id)).try_read().unwrap_or(0);
+        
+        require(current_asset_deposit >= amount, "Insufficient balance");
         
-        // Transfer tokens back to user
-        transfer(sender, msg_asset_id(), amount);
+        // Update the books before handing the coins to the token
+        storage.asset_deposits.insert((sender, asset_id), current_asset_deposit - amount);
+        let current_deposit = storage.deposits.get(sender).try_read().unwrap_or(0);
+        storage.deposits.insert(sender, current_deposit - amount);
+        subtract_from_total(asset_id, amount);
+        
+        abi(Token, token.into())
+            .burn {
+                coins: amount,
+                asset_id: asset_id.into(),
+            }(sub_id, amount);
         
-        // Log withdrawal event
-        log(WithdrawEvent {
+        log(DepositBurnedEvent {
             user: sender,
+            asset_id,
+            amount,
+        });
+    }
+    
+    fn claim_asset_id(asset_id: AssetId) -> AssetId {
+        claim_asset(asset_id)
+    }
+    
+    #[storage(read)]
+    fn get_outstanding_claims(asset_id: AssetId) -> u64 {
+        storage.claims.get(asset_id).try_read().unwrap_or(0)
+    }
+    
+    #[storage(read, write)]
+    fn collect_fees(asset_id: AssetId) -> u64 {
+        let admin = current_admin();
+        require(msg_sender().unwrap() == admin, "Unauthorized: Only admin can collect fees");
+        
+        let amount = storage.fees.get(asset_id).try_read().unwrap_or(0);
+        require(amount > 0, "No fees to collect");
+        
+        storage.fees.insert(asset_id, 0);
+        transfer(admin, asset_id, amount);
+        
+        log(FeesCollectedEvent {
+            admin,
+            asset_id,
             amount,
-            asset_id: msg_asset_id(),
         });
+        amount
     }
     
-    /// Get the deposit amount for a user.
+    #[storage(read)]
+    fn get_accrued_fees(asset_id: AssetId) -> u64 {
+        storage.fees.get(asset_id).try_read().unwrap_or(0)
+    }
+    
+    fn get_withdrawal_fee_bps() -> u64 {
+        WITHDRAWAL_FEE_BPS
+    }
+    
+    /// Get the deposit amount for a user, summed over every asset.
     #[storage(read)]
     fn get_deposit(user: Identity) -> u64 {
         storage.deposits.get(user).try_read().unwrap_or(0)
     }
     
-    /// Get total deposits in the vault.
+    /// Get the deposit amount for a user in a single asset.
     #[storage(read)]
-    fn get_total_deposits() -> u64 {
-        storage.total_deposits.read()
+    fn get_deposit_for_asset(user: Identity, asset_id: AssetId) -> u64 {
+        storage.asset_deposits.get((user, asset_id)).try_read().unwrap_or(0)
+    }
+    
+    /// Get total deposits of an asset in the vault.
+    #[storage(read)]
+    fn get_total_deposits(asset_id: AssetId) -> u64 {
+        storage.total_deposits.get(asset_id).try_read().unwrap_or(0)
     }
     
     /// Cross-contract transfer demonstration.
@@ -146,9 +337,10 @@ impl TokenVault for Contract {
         // For demonstration, we'll just update the deposit
         let current_deposit = storage.deposits.get(user).try_read().unwrap_or(0);
         storage.deposits.insert(user, current_deposit + amount);
+        let current_asset_deposit = storage.asset_deposits.get((user, asset_id)).try_read().unwrap_or(0);
+        storage.asset_deposits.insert((user, asset_id), current_asset_deposit + amount);
         
-        let new_total = storage.total_deposits.read() + amount;
-        storage.total_deposits.write(new_total);
+        add_to_total(asset_id, amount);
         
        // Log deposit event
         log(DepositEvent {
@@ -158,9 +350,117 @@ impl TokenVault for Contract {
         });
     }
     
-    /// Get the vault's balance of the accepted token.
+    /// Get the vault's balance of an asset.
+    fn get_vault_balance(asset_id: AssetId) -> u64 {
+        balance_of(ContractId::this(), asset_id)
+    }
+}
+
+abi TwoStepAdmin {
+    /// Propose `new_admin`; takes effect once they call `accept_admin` (admin only).
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity);
+
+    /// Become admin; only the proposed identity can call this.
+    #[storage(read, write)]
+    fn accept_admin();
+
+    #[storage(read)]
+    fn admin() -> Identity;
+
     #[storage(read)]
-    fn get_vault_balance() -> u64 {
-        balance_of(ContractId::this(), msg_asset_id())
+    fn pending_admin() -> Option<Identity>;
+}
+
+impl TwoStepAdmin for Contract {
+    #[storage(read, write)]
+    fn propose_admin(new_admin: Identity) {
+        let admin = current_admin();
+        require(msg_sender().unwrap() == admin, "Unauthorized: Only admin can propose admin");
+        storage.pending_admin.write(Some(new_admin));
+
+        log(AdminProposedEvent {
+            admin,
+            proposed: new_admin,
+        });
+    }
+
+    #[storage(read, write)]
+    fn accept_admin() {
+        let sender = msg_sender().unwrap();
+        require(
+            storage.pending_admin.read() == Some(sender),
+            "Unauthorized: Only the proposed admin can accept",
+        );
+        let previous = current_admin();
+        storage.admin.write(Some(sender));
+        storage.pending_admin.write(None);
+
+        log(AdminTransferredEvent {
+            previous,
+            admin: sender,
+        });
+    }
+
+    #[storage(read)]
+    fn admin() -> Identity {
+        current_admin()
+    }
+
+    #[storage(read)]
+    fn pending_admin() -> Option<Identity> {
+        storage.pending_admin.read()
     }
-}
\ No newline at end of file
+}
+
+// Sends a withdrawal of `amount` to `user`, keeping the fee for the admin.
+#[storage(read, write)]
+fn pay_out(user: Identity, asset_id: AssetId, amount: u64) {
+    let fee = withdrawal_fee(amount);
+    if fee > 0 {
+        let accrued = storage.fees.get(asset_id).try_read().unwrap_or(0);
+        storage.fees.insert(asset_id, accrued + fee);
+    }
+    
+    if amount > fee {
+        transfer(user, asset_id, amount - fee);
+    }
+    
+    log(WithdrawEvent {
+        user,
+        amount,
+        asset_id,
+        fee,
+    });
+}
+
+// Adds `amount` to the total deposits of `asset_id`.
+#[storage(read, write)]
+fn add_to_total(asset_id: AssetId, amount: u64) {
+    let total = storage.total_deposits.get(asset_id).try_read().unwrap_or(0);
+    storage.total_deposits.insert(asset_id, total + amount);
+}
+
+// Takes `amount` off the total deposits of `asset_id`.
+#[storage(read, write)]
+fn subtract_from_total(asset_id: AssetId, amount: u64) {
+    let total = storage.total_deposits.get(asset_id).try_read().unwrap_or(0);
+    storage.total_deposits.insert(asset_id, total - amount);
+}
+
+// The claim token of `asset_id`, minted under the deposited asset id as sub id.
+fn claim_asset(asset_id: AssetId) -> AssetId {
+    AssetId::new(ContractId::this(), asset_id.bits())
+}
+
+// The fee on withdrawing `amount`, rounded down. Split so that large amounts
+// cannot overflow.
+fn withdrawal_fee(amount: u64) -> u64 {
+    amount / 10_000 * WITHDRAWAL_FEE_BPS + amount % 10_000 * WITHDRAWAL_FEE_BPS / 10_000
+}
+
+// The configurable admin until ownership is handed over.
+#[storage(read)]
+fn current_admin() -> Identity {
+    storage.admin.read().unwrap_or(ADMIN)
+}
@@ -0,0 +1,154 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    block::height,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    logging::log,
+    primitive_conversions::u64::*,
+};
+
+configurable {
+    /// The asset being vested.
+    ASSET: AssetId = AssetId::zero(),
+}
+
+/// Tokens released to a beneficiary linearly over `duration` blocks from
+/// `start`, with nothing claimable before `start + cliff`.
+pub struct VestingSchedule {
+    pub total: u64,
+    pub claimed: u64,
+    pub start: u32,
+    pub cliff: u32,
+    pub duration: u32,
+}
+
+storage {
+    /// Vesting schedule per beneficiary.
+    schedules: StorageMap<Identity, VestingSchedule> = StorageMap {},
+}
+
+// Event structs
+pub struct ScheduleCreatedEvent {
+    pub beneficiary: Identity,
+    pub total: u64,
+    pub start: u32,
+    pub cliff: u32,
+    pub duration: u32,
+}
+
+pub struct ClaimEvent {
+    pub beneficiary: Identity,
+    pub amount: u64,
+}
+
+abi Vesting {
+    /// Lock the forwarded tokens for `beneficiary`, vesting from the current block.
+    #[payable]
+    #[storage(read, write)]
+    fn create_schedule(beneficiary: Identity, cliff: u32, duration: u32);
+
+    /// Transfer everything vested and not yet claimed to the caller.
+    #[storage(read, write)]
+    fn claim() -> u64;
+
+    /// Get the schedule of `beneficiary`.
+    #[storage(read)]
+    fn schedule(beneficiary: Identity) -> Option<VestingSchedule>;
+
+    /// Get the amount of `beneficiary`'s tokens vested so far, claimed or not.
+    #[storage(read)]
+    fn vested_amount(beneficiary: Identity) -> u64;
+
+    /// Get the amount `beneficiary` could claim now.
+    #[storage(read)]
+    fn claimable(beneficiary: Identity) -> u64;
+}
+
+fn vested(schedule: VestingSchedule, now: u32) -> u64 {
+    if now < schedule.start + schedule.cliff {
+        return 0;
+    }
+    let elapsed = now - schedule.start;
+    if elapsed >= schedule.duration {
+        return schedule.total;
+    }
+    let vested = schedule.total.as_u256() * elapsed.as_u64().as_u256() / schedule.duration.as_u64().as_u256();
+    u64::try_from(vested).unwrap()
+}
+
+impl Vesting for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn create_schedule(beneficiary: Identity, cliff: u32, duration: u32) {
+        require(msg_asset_id() == ASSET, "Wrong asset type");
+        require(msg_amount() > 0, "Nothing to vest");
+        require(duration > 0, "Duration must be positive");
+        require(cliff <= duration, "Cliff exceeds duration");
+        require(
+            storage.schedules.get(beneficiary).try_read().is_none(),
+            "Schedule already exists",
+        );
+
+        let start = height();
+        storage.schedules.insert(beneficiary, VestingSchedule {
+            total: msg_amount(),
+            claimed: 0,
+            start,
+            cliff,
+            duration,
+        });
+
+        log(ScheduleCreatedEvent {
+            beneficiary,
+            total: msg_amount(),
+            start,
+            cliff,
+            duration,
+        });
+    }
+
+    #[storage(read, write)]
+    fn claim() -> u64 {
+        let beneficiary = msg_sender().unwrap();
+        let schedule = storage.schedules.get(beneficiary).try_read();
+        require(schedule.is_some(), "No vesting schedule");
+        let mut schedule = schedule.unwrap();
+
+        let amount = vested(schedule, height()) - schedule.claimed;
+        require(amount > 0, "Nothing vested to claim");
+
+        schedule.claimed += amount;
+        storage.schedules.insert(beneficiary, schedule);
+        transfer(beneficiary, ASSET, amount);
+
+        log(ClaimEvent {
+            beneficiary,
+            amount,
+        });
+        amount
+    }
+
+    #[storage(read)]
+    fn schedule(beneficiary: Identity) -> Option<VestingSchedule> {
+        storage.schedules.get(beneficiary).try_read()
+    }
+
+    #[storage(read)]
+    fn vested_amount(beneficiary: Identity) -> u64 {
+        match storage.schedules.get(beneficiary).try_read() {
+            Some(schedule) => vested(schedule, height()),
+            None => 0,
+        }
+    }
+
+    #[storage(read)]
+    fn claimable(beneficiary: Identity) -> u64 {
+        match storage.schedules.get(beneficiary).try_read() {
+            Some(schedule) => vested(schedule, height()) - schedule.claimed,
+            None => 0,
+        }
+    }
+}
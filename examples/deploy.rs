@@ -0,0 +1,202 @@
+//! Deploys Src20Token, CrossContractCall and TokenVault and records them in the
+//! deployments registry (`deployments/<network>.json`).
+//!
+//! ```bash
+//! # Against a running node (flags or env vars)
+//! FUEL_NODE_URL=127.0.0.1:4000 PRIVATE_KEY=0x... cargo run --example deploy
+//! cargo run --example deploy -- --network testnet --node-url https://testnet.fuel.network/v1/graphql --private-key 0x...
+//!
+//! # A recoverable account derived from a mnemonic instead of a private key
+//! FUEL_MNEMONIC="..." cargo run --example deploy -- --node-url ... --account 0
+//!
+//! # A fresh wallet funded by the testnet faucet first (key printed for recovery)
+//! cargo run --example deploy -- --network testnet --node-url https://testnet.fuel.network/v1/graphql --faucet
+//!
+//! # Signing with an AWS KMS key (the key never leaves KMS)
+//! cargo run --example deploy --features aws-kms -- --node-url ... --kms-key-id <KEY_ID>
+//!
+//! # Without a node URL an in-process local node is launched (handy for a dry run)
+//! cargo run --example deploy
+//!
+//! # Record the bytecode hashes of a reviewed build, then refuse to deploy anything else
+//! cargo run --example deploy -- --record-bytecode bytecode-hashes.json
+//! cargo run --example deploy -- --node-url ... --verify-bytecode bytecode-hashes.json
+//! ```
+
+use std::str::FromStr;
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner, crypto::SecretKey, prelude::*,
+};
+use rosetta_stone::{
+    deployments::{deploy_suite, Deployments},
+    faucet::{fund_from_faucet, FaucetConfig},
+    integrity::{BytecodeManifest, SUITE_ARTIFACTS},
+    keys::{account_derivation_path, signer_from_mnemonic, MNEMONIC_ENV},
+    retry::{connect_with_retry, RetryPolicy},
+};
+
+#[derive(Debug, Default)]
+struct Args {
+    network: String,
+    node_url: Option<String>,
+    private_key: Option<String>,
+    mnemonic: Option<String>,
+    account: u32,
+    #[cfg(feature = "aws-kms")]
+    kms_key_id: Option<String>,
+    faucet: bool,
+    faucet_url: Option<String>,
+    verify_bytecode: Option<String>,
+    record_bytecode: Option<String>,
+    out: Option<String>,
+}
+
+// Flags take precedence over FUEL_NODE_URL / PRIVATE_KEY; a private key over FUEL_MNEMONIC
+fn parse_args() -> Args {
+    let mut args = Args {
+        network: "local".to_string(),
+        node_url: std::env::var("FUEL_NODE_URL").ok(),
+        private_key: std::env::var("PRIVATE_KEY").ok(),
+        mnemonic: std::env::var(MNEMONIC_ENV).ok(),
+        account: 0,
+        #[cfg(feature = "aws-kms")]
+        kms_key_id: std::env::var(rosetta_stone::kms::KMS_KEY_ID_ENV).ok(),
+        faucet: false,
+        faucet_url: None,
+        verify_bytecode: None,
+        record_bytecode: None,
+        out: None,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--network" => args.network = iter.next().unwrap_or(args.network),
+            "--node-url" => args.node_url = iter.next(),
+            "--private-key" => args.private_key = iter.next(),
+            "--account" => {
+                args.account = iter.next().and_then(|n| n.parse().ok()).unwrap_or_else(|| {
+                    eprintln!("--account needs a number");
+                    std::process::exit(2);
+                })
+            }
+            #[cfg(feature = "aws-kms")]
+            "--kms-key-id" => args.kms_key_id = iter.next(),
+            "--faucet" => args.faucet = true,
+            "--faucet-url" => {
+                args.faucet = true;
+                args.faucet_url = iter.next();
+            }
+            "--verify-bytecode" => args.verify_bytecode = iter.next(),
+            "--record-bytecode" => args.record_bytecode = iter.next(),
+            "--out" => args.out = iter.next(),
+            "-h" | "--help" => {
+                println!(
+                    "Usage: deploy [--network NAME] [--node-url URL] [--private-key HEX | --account N | --kms-key-id ID] [--faucet [--faucet-url URL]] [--verify-bytecode FILE | --record-bytecode FILE] [--out FILE]"
+                );
+                std::process::exit(0);
+            }
+            other => {
+                eprintln!("Unknown argument: {}", other);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    args
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = parse_args();
+
+    if let Some(path) = &args.record_bytecode {
+        BytecodeManifest::for_artifacts(&SUITE_ARTIFACTS)?.save(path)?;
+        println!("✅ Bytecode hashes written to {}", path);
+        return Ok(());
+    }
+    // Checked before connecting so a stale artifact never reaches a node
+    if let Some(path) = &args.verify_bytecode {
+        BytecodeManifest::load(path)?.verify_all(&SUITE_ARTIFACTS)?;
+        println!("✅ Bytecode matches {}", path);
+    }
+
+    #[cfg(feature = "aws-kms")]
+    if let (Some(url), Some(key_id)) = (&args.node_url, &args.kms_key_id) {
+        let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+        let signer = rosetta_stone::kms::aws_kms_signer(key_id).await?;
+        return deploy_and_record(&Wallet::new(signer, provider), &args).await;
+    }
+
+    let wallet = match (&args.node_url, &args.private_key, &args.mnemonic) {
+        (Some(url), Some(key), _) => {
+            let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+            let secret_key = SecretKey::from_str(key)?;
+            Wallet::new(PrivateKeySigner::new(secret_key), provider)
+        }
+        (Some(url), None, Some(phrase)) => {
+            let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+            let signer = signer_from_mnemonic(phrase, &account_derivation_path(args.account))?;
+            Wallet::new(signer, provider)
+        }
+        // A fresh wallet, funded below
+        (Some(url), None, None) if args.faucet => {
+            let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+            let secret_key = SecretKey::random(&mut rand::thread_rng());
+            println!("Generated deployer key (keep it to recover the funds): {}", secret_key);
+            Wallet::new(PrivateKeySigner::new(secret_key), provider)
+        }
+        (Some(_), None, None) => {
+            return Err(
+                "A private key (--private-key or PRIVATE_KEY) or FUEL_MNEMONIC is required".into(),
+            );
+        }
+        _ => {
+            println!("No node URL given, launching a local node...");
+            launch_provider_and_get_wallet().await?
+        }
+    };
+
+    if args.faucet && args.node_url.is_some() {
+        let mut config = FaucetConfig::default();
+        if let Some(url) = &args.faucet_url {
+            config = config.with_url(url);
+        }
+        println!("Requesting funds for {} from {}...", wallet.address(), config.url);
+        let balance = fund_from_faucet(&wallet, &config).await?;
+        println!("Funded, base asset balance: {}", balance);
+    }
+
+    deploy_and_record(&wallet, &args).await
+}
+
+// Deploys the suite with any signer and writes the deployments file
+async fn deploy_and_record<A: Account + Clone>(wallet: &A, args: &Args) -> Result<()> {
+    println!(
+        "Deploying from {} to {}",
+        wallet.address(),
+        wallet.try_provider()?.url()
+    );
+
+    let deployments = deploy_suite(wallet, &args.network, "MYTOKEN", "TOKEN", 9).await?;
+
+    println!("Src20Token:        {}", deployments.src20_token);
+    println!("  asset id:        {}", deployments.asset_id);
+    println!("CrossContractCall: {}", deployments.cross_contract_call);
+    println!("TokenVault:        {}", deployments.token_vault);
+
+    let path = match &args.out {
+        Some(out) => {
+            deployments.save(out)?;
+            out.into()
+        }
+        None => deployments.save_for_network()?,
+    };
+    println!("✅ Deployments written to {}", path.display());
+
+    // Sanity check: the registry round-trips
+    assert_eq!(Deployments::load(&path)?, deployments);
+
+    Ok(())
+}
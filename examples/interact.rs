@@ -0,0 +1,133 @@
+//! Walks through the whole flow against an existing deployment: mint, transfer,
+//! vault deposit, and balance printouts.
+//!
+//! ```bash
+//! cargo run --example deploy -- --node-url 127.0.0.1:4000 --private-key 0x...
+//! PRIVATE_KEY=0x... cargo run --example interact -- --network local
+//! FUEL_MNEMONIC="..." cargo run --example interact -- --network testnet --account 0
+//! ```
+//!
+//! The private key (or mnemonic account) must be the deployer's, since only the
+//! admin can mint.
+
+use std::str::FromStr;
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    crypto::SecretKey,
+    prelude::*,
+    types::Identity,
+};
+use rosetta_stone::{
+    deployments::Deployments,
+    keys::{account_derivation_path, signer_from_mnemonic, MNEMONIC_ENV},
+    retry::{connect_with_retry, RetryPolicy},
+    SUB_ID, TOKEN_AMOUNT,
+};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut network = "local".to_string();
+    let mut deployments_path = None;
+    let mut private_key = std::env::var("PRIVATE_KEY").ok();
+    let mnemonic = std::env::var(MNEMONIC_ENV).ok();
+    let mut account = 0;
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--network" => network = iter.next().unwrap_or(network),
+            "--deployments" => deployments_path = iter.next(),
+            "--private-key" => private_key = iter.next(),
+            "--account" => account = iter.next().and_then(|n| n.parse().ok()).unwrap_or(account),
+            other => {
+                eprintln!("Unknown argument: {}", other);
+                eprintln!(
+                    "Usage: interact [--network NAME | --deployments FILE] [--private-key HEX | --account N]"
+                );
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let deployments = match deployments_path {
+        Some(path) => Deployments::load(path)?,
+        None => Deployments::load_for_network(&network)?.ok_or_else(|| {
+            Error::Other(format!(
+                "no deployment for '{}', run `cargo run --example deploy -- --network {}` first",
+                network, network
+            ))
+        })?,
+    };
+    let admin_signer = match (private_key, mnemonic) {
+        (Some(key), _) => PrivateKeySigner::new(SecretKey::from_str(&key)?),
+        (None, Some(phrase)) => signer_from_mnemonic(&phrase, &account_derivation_path(account))?,
+        (None, None) => {
+            return Err(
+                "A private key (--private-key or PRIVATE_KEY) or FUEL_MNEMONIC is required".into(),
+            )
+        }
+    };
+
+    let provider = connect_with_retry(&deployments.node_url, &RetryPolicy::default()).await?;
+    let admin_wallet = Wallet::new(admin_signer, provider.clone());
+    // A fresh wallet to receive the transfer
+    let friend_wallet = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        provider.clone(),
+    );
+
+    let suite = deployments.connect(&admin_wallet)?;
+    let (token, vault, asset_id) = (suite.token, suite.vault, suite.asset_id);
+
+    println!("Admin:  {}", admin_wallet.address());
+    println!("Friend: {}", friend_wallet.address());
+
+    // 1. Mint
+    println!("Minting {} tokens to admin...", TOKEN_AMOUNT);
+    token
+        .methods()
+        .mint(Identity::Address(admin_wallet.address().into()), Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    // 2. Transfer
+    let transfer_amount = 100_000;
+    println!("Transferring {} tokens to friend...", transfer_amount);
+    admin_wallet
+        .transfer(friend_wallet.address(), transfer_amount, asset_id, TxPolicies::default())
+        .await?;
+
+    // 3. Vault deposit
+    let deposit_amount = 200_000;
+    println!("Depositing {} tokens into the vault...", deposit_amount);
+    vault
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(deposit_amount)
+                .with_asset_id(asset_id),
+        )?
+        .call()
+        .await?;
+
+    // 4. Balances
+    let total_supply = token.methods().total_supply(asset_id).call().await?.value;
+    let vault_deposit = vault
+        .methods()
+        .get_deposit(Identity::Address(admin_wallet.address().into()))
+        .call()
+        .await?
+        .value;
+
+    println!("Balances:");
+    println!("  Admin wallet:  {}", admin_wallet.get_asset_balance(&asset_id).await?);
+    println!("  Friend wallet: {}", friend_wallet.get_asset_balance(&asset_id).await?);
+    println!("  Admin vault deposit: {}", vault_deposit);
+    println!("  Total supply: {:?}", total_supply);
+
+    println!("✅ Interaction complete");
+    Ok(())
+}
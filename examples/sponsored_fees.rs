@@ -0,0 +1,64 @@
+//! Sends tokens from a wallet that holds no base asset, with the fee paid by a
+//! gas-sponsorship predicate. Runs against an in-process node:
+//!
+//! ```bash
+//! cargo run --example sponsored_fees
+//! ```
+//!
+//! The sponsor predicate only lets its coins pay fees up to `MAX_FEE`; the
+//! change must come back to it, so the same funding sponsors many transfers.
+
+use fuels::{accounts::signers::private_key::PrivateKeySigner, prelude::*};
+use rosetta_stone::{
+    artifacts,
+    fees::fee_from_status,
+    predicates::{fund_predicate, sponsored_transfer},
+    HarnessConfig, SponsorPredicateConfigurables, TestHarness,
+};
+
+const MAX_FEE: u64 = 100_000;
+const AMOUNT: u64 = 1_000;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+
+    let configurables = SponsorPredicateConfigurables::default().with_MAX_FEE(MAX_FEE)?;
+    let sponsor = Predicate::load_from(artifacts::SPONSOR_PREDICATE_BIN)?
+        .with_provider(harness.provider.clone())
+        .with_configurables(configurables);
+    fund_predicate(&harness.admin, &sponsor, 1_000_000).await?;
+    println!("Sponsor predicate: {}", sponsor.address());
+
+    // A fresh wallet: tokens, but not a single coin of the base asset
+    let user = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        harness.provider.clone(),
+    );
+    harness.mint_to(&user, AMOUNT).await?;
+
+    let sponsor_before = sponsor.get_asset_balance(&base_asset_id).await?;
+    let recipient = harness.user(0);
+    let status = sponsored_transfer(
+        &sponsor,
+        &user,
+        recipient.address().into(),
+        harness.asset_id,
+        AMOUNT,
+        TxPolicies::default(),
+    )
+    .await?;
+
+    println!("Transferred {} tokens to {}", AMOUNT, recipient.address());
+    println!("  fee:               {}", fee_from_status(&status)?);
+    println!(
+        "  sponsor balance:   {} -> {}",
+        sponsor_before,
+        sponsor.get_asset_balance(&base_asset_id).await?
+    );
+    println!("  user base balance: {}", user.get_asset_balance(&base_asset_id).await?);
+    println!("  recipient tokens:  {}", harness.token_balance(recipient).await?);
+
+    Ok(())
+}
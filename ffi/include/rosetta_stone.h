@@ -0,0 +1,177 @@
+#ifndef ROSETTA_STONE_H
+#define ROSETTA_STONE_H
+
+/* Generated by cbindgen from ffi/src/lib.rs; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* Size of a buffer that holds a `0x`-prefixed 32-byte hex id and its NUL. */
+#define ROSETTA_HEX_ID_LEN 67
+
+/**
+ * Outcome of every call.
+ */
+typedef enum RosettaStatus {
+  ROSETTA_STATUS_OK = 0,
+  /**
+   * A required pointer was null.
+   */
+  ROSETTA_STATUS_NULL_ARGUMENT = 1,
+  /**
+   * A string was not UTF-8, or not a valid key, address, name or buffer.
+   */
+  ROSETTA_STATUS_INVALID_ARGUMENT = 2,
+  /**
+   * The node could not be reached or rejected the operation.
+   */
+  ROSETTA_STATUS_FAILED = 3,
+  /**
+   * The library panicked; the handles passed to the call should be freed.
+   */
+  ROSETTA_STATUS_PANICKED = 4,
+} RosettaStatus;
+
+/**
+ * A wallet on a node and the runtime its calls run on.
+ */
+typedef struct RosettaClient RosettaClient;
+
+/**
+ * A deployed SRC-20 token and the asset it mints under the default sub id.
+ */
+typedef struct RosettaToken RosettaToken;
+
+/**
+ * The message of the last failed call on this thread, or an empty string.
+ *
+ * The pointer stays valid until the next call on the same thread.
+ */
+const char *rosetta_last_error(void);
+
+/**
+ * Connects to the node at `node_url` with the wallet of `private_key`.
+ *
+ * # Safety
+ *
+ * `node_url` and `private_key` must be NUL-terminated strings and `out` a
+ * valid pointer; free the client with [`rosetta_client_free`].
+ */
+enum RosettaStatus rosetta_client_connect(const char *node_url,
+                                          const char *private_key,
+                                          struct RosettaClient **out);
+
+/**
+ * Launches a local node with one funded wallet, or honors `FUEL_NODE_URL`
+ * like [`launch_wallets`], and connects to it.
+ *
+ * An in-process node runs on the client's runtime until the client is freed.
+ *
+ * # Safety
+ *
+ * `out` must be a valid pointer; free the client with [`rosetta_client_free`].
+ */
+enum RosettaStatus rosetta_client_launch_local(struct RosettaClient **out);
+
+/**
+ * Writes the client's address into `out`, a buffer of `len` bytes.
+ *
+ * # Safety
+ *
+ * `client` must come from this library and `out` must hold `len` bytes,
+ * at least `ROSETTA_HEX_ID_LEN` (67).
+ */
+enum RosettaStatus rosetta_client_address(const struct RosettaClient *client,
+                                          char *out,
+                                          size_t len);
+
+/**
+ * Frees a client and stops its runtime. Null is ignored.
+ *
+ * # Safety
+ *
+ * `client` must come from this library and not be used afterwards.
+ */
+void rosetta_client_free(struct RosettaClient *client);
+
+/**
+ * Deploys an SRC-20 token with the client's wallet as admin.
+ *
+ * `name` has exactly 7 and `symbol` exactly 5 ASCII characters. The bytecode
+ * is read from [`artifacts::SRC20_TOKEN_BIN`](rosetta_stone::artifacts::SRC20_TOKEN_BIN),
+ * relative to the working directory.
+ *
+ * # Safety
+ *
+ * `client` must come from this library, `name` and `symbol` must be
+ * NUL-terminated strings and `out` a valid pointer; free the token with
+ * [`rosetta_token_free`].
+ */
+enum RosettaStatus rosetta_deploy_token(const struct RosettaClient *client,
+                                        const char *name,
+                                        const char *symbol,
+                                        uint8_t decimals,
+                                        struct RosettaToken **out);
+
+/**
+ * Writes the id of the asset the token mints into `out`, a buffer of `len` bytes.
+ *
+ * # Safety
+ *
+ * `token` must come from this library and `out` must hold `len` bytes, at
+ * least `ROSETTA_HEX_ID_LEN` (67).
+ */
+enum RosettaStatus rosetta_token_asset_id(const struct RosettaToken *token, char *out, size_t len);
+
+/**
+ * Frees a token handle. Null is ignored.
+ *
+ * # Safety
+ *
+ * `token` must come from this library and not be used afterwards.
+ */
+void rosetta_token_free(struct RosettaToken *token);
+
+/**
+ * Mints `amount` of the token to `recipient`; the client must be the token's admin.
+ *
+ * # Safety
+ *
+ * `client` and `token` must come from this library and `recipient` must be
+ * a NUL-terminated string.
+ */
+enum RosettaStatus rosetta_mint(const struct RosettaClient *client,
+                                const struct RosettaToken *token,
+                                const char *recipient,
+                                uint64_t amount);
+
+/**
+ * Transfers `amount` of the token from the client's wallet to `recipient`.
+ *
+ * # Safety
+ *
+ * `client` and `token` must come from this library and `recipient` must be
+ * a NUL-terminated string.
+ */
+enum RosettaStatus rosetta_transfer(const struct RosettaClient *client,
+                                    const struct RosettaToken *token,
+                                    const char *recipient,
+                                    uint64_t amount);
+
+/**
+ * Writes the token balance of `owner` into `out`.
+ *
+ * # Safety
+ *
+ * `client` and `token` must come from this library, `owner` must be a
+ * NUL-terminated string and `out` a valid pointer.
+ */
+enum RosettaStatus rosetta_balance(const struct RosettaClient *client,
+                                   const struct RosettaToken *token,
+                                   const char *owner,
+                                   uint64_t *out);
+
+#endif  /* ROSETTA_STONE_H */
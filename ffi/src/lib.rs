@@ -0,0 +1,375 @@
+//! C ABI over the `rosetta_stone` token flows.
+//!
+//! Non-Rust backends deploy an SRC-20 token, mint, transfer and read balances
+//! through opaque handles and integer status codes. This crate only builds a
+//! cdylib, `librosetta_stone_ffi`; its header, `include/rosetta_stone.h`, is
+//! generated from this file with cbindgen, run from `ffi/`:
+//!
+//! ```text
+//! cbindgen --quiet --config cbindgen.toml --output include/rosetta_stone.h
+//! ```
+//!
+//! Every call blocks on the client's own tokio runtime. A failed call returns
+//! a non-zero [`RosettaStatus`] and leaves a message for [`rosetta_last_error`].
+//! Strings are NUL-terminated UTF-8; keys, addresses and ids are `0x` hex.
+//! `tests/ffi/smoke.c` shows the whole flow.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    fmt::{Display, LowerHex},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+    str::FromStr,
+};
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    crypto::SecretKey,
+    prelude::*,
+    tx::ContractIdExt,
+    types::{Address, Identity, SubAssetId},
+};
+use tokio::runtime::Runtime;
+
+use rosetta_stone::{deploy_src20_token, launch_wallets, Src20Token, TestWallet, SUB_ID};
+
+/// Outcome of every call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosettaStatus {
+    Ok = 0,
+    /// A required pointer was null.
+    NullArgument = 1,
+    /// A string was not UTF-8, or not a valid key, address, name or buffer.
+    InvalidArgument = 2,
+    /// The node could not be reached or rejected the operation.
+    Failed = 3,
+    /// The library panicked; the handles passed to the call should be freed.
+    Panicked = 4,
+}
+
+/// A wallet on a node and the runtime its calls run on.
+pub struct RosettaClient {
+    runtime: Runtime,
+    wallet: TestWallet,
+}
+
+/// A deployed SRC-20 token and the asset it mints under the default sub id.
+pub struct RosettaToken {
+    contract: Src20Token<TestWallet>,
+    asset_id: AssetId,
+}
+
+type FfiResult<T> = std::result::Result<T, (RosettaStatus, String)>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// The message of the last failed call on this thread, or an empty string.
+///
+/// The pointer stays valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn rosetta_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
+
+/// Connects to the node at `node_url` with the wallet of `private_key`.
+///
+/// # Safety
+///
+/// `node_url` and `private_key` must be NUL-terminated strings and `out` a
+/// valid pointer; free the client with [`rosetta_client_free`].
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_client_connect(
+    node_url: *const c_char,
+    private_key: *const c_char,
+    out: *mut *mut RosettaClient,
+) -> RosettaStatus {
+    run(|| {
+        let node_url = str_arg(node_url, "node_url")?;
+        let secret_key = SecretKey::from_str(str_arg(private_key, "private_key")?)
+            .map_err(|e| invalid(format!("private_key: {}", e)))?;
+        // Checked before connecting, so a bad call costs no runtime
+        out_arg(out)?;
+        let runtime = new_runtime()?;
+        let provider = runtime.block_on(Provider::connect(node_url)).map_err(failed)?;
+        let wallet = Wallet::new(PrivateKeySigner::new(secret_key), provider);
+        write_out(out, Box::into_raw(Box::new(RosettaClient { runtime, wallet })))
+    })
+}
+
+/// Launches a local node with one funded wallet, or honors `FUEL_NODE_URL`
+/// like [`launch_wallets`], and connects to it.
+///
+/// An in-process node runs on the client's runtime until the client is freed.
+///
+/// # Safety
+///
+/// `out` must be a valid pointer; free the client with [`rosetta_client_free`].
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_client_launch_local(
+    out: *mut *mut RosettaClient,
+) -> RosettaStatus {
+    run(|| {
+        // Checked before launching, so a bad call never starts a node
+        out_arg(out)?;
+        let runtime = new_runtime()?;
+        let config = WalletsConfig::new(Some(1), Some(1), Some(1_000_000_000));
+        let mut wallets = runtime.block_on(launch_wallets(config)).map_err(failed)?;
+        let wallet = wallets.pop().expect("one wallet was requested");
+        write_out(out, Box::into_raw(Box::new(RosettaClient { runtime, wallet })))
+    })
+}
+
+/// Writes the client's address into `out`, a buffer of `len` bytes.
+///
+/// # Safety
+///
+/// `client` must come from this library and `out` must hold `len` bytes,
+/// at least `ROSETTA_HEX_ID_LEN` (67).
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_client_address(
+    client: *const RosettaClient,
+    out: *mut c_char,
+    len: usize,
+) -> RosettaStatus {
+    run(|| {
+        let client = ref_arg(client, "client")?;
+        write_hex(client.wallet.address(), out, len)
+    })
+}
+
+/// Frees a client and stops its runtime. Null is ignored.
+///
+/// # Safety
+///
+/// `client` must come from this library and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_client_free(client: *mut RosettaClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
+
+/// Deploys an SRC-20 token with the client's wallet as admin.
+///
+/// `name` has exactly 7 and `symbol` exactly 5 ASCII characters. The bytecode
+/// is read from [`artifacts::SRC20_TOKEN_BIN`](rosetta_stone::artifacts::SRC20_TOKEN_BIN),
+/// relative to the working directory.
+///
+/// # Safety
+///
+/// `client` must come from this library, `name` and `symbol` must be
+/// NUL-terminated strings and `out` a valid pointer; free the token with
+/// [`rosetta_token_free`].
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_deploy_token(
+    client: *const RosettaClient,
+    name: *const c_char,
+    symbol: *const c_char,
+    decimals: u8,
+    out: *mut *mut RosettaToken,
+) -> RosettaStatus {
+    run(|| {
+        // Checked before deploying, so a bad call neither deploys nor leaks the token
+        out_arg(out)?;
+        let client = ref_arg(client, "client")?;
+        let (name, symbol) = (str_arg(name, "name")?, str_arg(symbol, "symbol")?);
+        let contract = client
+            .runtime
+            .block_on(deploy_src20_token(client.wallet.clone(), name, symbol, decimals))
+            .map_err(failed)?;
+        let asset_id = contract.contract_id().asset_id(&SubAssetId::from(SUB_ID.0));
+        write_out(out, Box::into_raw(Box::new(RosettaToken { contract, asset_id })))
+    })
+}
+
+/// Writes the id of the asset the token mints into `out`, a buffer of `len` bytes.
+///
+/// # Safety
+///
+/// `token` must come from this library and `out` must hold `len` bytes, at
+/// least `ROSETTA_HEX_ID_LEN` (67).
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_token_asset_id(
+    token: *const RosettaToken,
+    out: *mut c_char,
+    len: usize,
+) -> RosettaStatus {
+    run(|| {
+        let token = ref_arg(token, "token")?;
+        write_hex(token.asset_id, out, len)
+    })
+}
+
+/// Frees a token handle. Null is ignored.
+///
+/// # Safety
+///
+/// `token` must come from this library and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_token_free(token: *mut RosettaToken) {
+    if !token.is_null() {
+        drop(Box::from_raw(token));
+    }
+}
+
+/// Mints `amount` of the token to `recipient`; the client must be the token's admin.
+///
+/// # Safety
+///
+/// `client` and `token` must come from this library and `recipient` must be
+/// a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_mint(
+    client: *const RosettaClient,
+    token: *const RosettaToken,
+    recipient: *const c_char,
+    amount: u64,
+) -> RosettaStatus {
+    run(|| {
+        let client = ref_arg(client, "client")?;
+        let token = ref_arg(token, "token")?;
+        let recipient = address_arg(recipient, "recipient")?;
+        let call = token
+            .contract
+            .clone()
+            .with_account(client.wallet.clone())
+            .methods()
+            .mint(Identity::Address(recipient), Some(SUB_ID), amount)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+        client.runtime.block_on(call.call()).map_err(failed)?;
+        Ok(())
+    })
+}
+
+/// Transfers `amount` of the token from the client's wallet to `recipient`.
+///
+/// # Safety
+///
+/// `client` and `token` must come from this library and `recipient` must be
+/// a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_transfer(
+    client: *const RosettaClient,
+    token: *const RosettaToken,
+    recipient: *const c_char,
+    amount: u64,
+) -> RosettaStatus {
+    run(|| {
+        let client = ref_arg(client, "client")?;
+        let token = ref_arg(token, "token")?;
+        let recipient = address_arg(recipient, "recipient")?;
+        let transfer =
+            client
+                .wallet
+                .transfer(recipient, amount, token.asset_id, TxPolicies::default());
+        client.runtime.block_on(transfer).map_err(failed)?;
+        Ok(())
+    })
+}
+
+/// Writes the token balance of `owner` into `out`.
+///
+/// # Safety
+///
+/// `client` and `token` must come from this library, `owner` must be a
+/// NUL-terminated string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rosetta_balance(
+    client: *const RosettaClient,
+    token: *const RosettaToken,
+    owner: *const c_char,
+    out: *mut u64,
+) -> RosettaStatus {
+    run(|| {
+        let client = ref_arg(client, "client")?;
+        let token = ref_arg(token, "token")?;
+        let owner = address_arg(owner, "owner")?;
+        let balance = client
+            .runtime
+            .block_on(client.wallet.provider().get_asset_balance(&owner, &token.asset_id))
+            .map_err(failed)?;
+        let balance = u64::try_from(balance)
+            .map_err(|_| failed(format!("balance {} does not fit in a u64", balance)))?;
+        write_out(out, balance)
+    })
+}
+
+// Runs `call`, turning its error or panic into a status and the last error message
+fn run(call: impl FnOnce() -> FfiResult<()>) -> RosettaStatus {
+    let (status, message) = match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(())) => return RosettaStatus::Ok,
+        Ok(Err(error)) => error,
+        Err(_) => (RosettaStatus::Panicked, "rosetta_stone panicked".to_string()),
+    };
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+    status
+}
+
+fn new_runtime() -> FfiResult<Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(failed)
+}
+
+fn invalid(message: impl Display) -> (RosettaStatus, String) {
+    (RosettaStatus::InvalidArgument, message.to_string())
+}
+
+fn failed(error: impl Display) -> (RosettaStatus, String) {
+    (RosettaStatus::Failed, error.to_string())
+}
+
+fn null(name: &str) -> (RosettaStatus, String) {
+    (RosettaStatus::NullArgument, format!("{} is null", name))
+}
+
+unsafe fn ref_arg<'a, T>(value: *const T, name: &str) -> FfiResult<&'a T> {
+    value.as_ref().ok_or_else(|| null(name))
+}
+
+unsafe fn str_arg<'a>(value: *const c_char, name: &str) -> FfiResult<&'a str> {
+    if value.is_null() {
+        return Err(null(name));
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| invalid(format!("{} is not UTF-8", name)))
+}
+
+unsafe fn address_arg(value: *const c_char, name: &str) -> FfiResult<Address> {
+    let value = str_arg(value, name)?;
+    Address::from_str(value).map_err(|e| invalid(format!("{} {:?}: {}", name, value, e)))
+}
+
+fn out_arg<T>(out: *mut T) -> FfiResult<()> {
+    if out.is_null() {
+        return Err(null("out"));
+    }
+    Ok(())
+}
+
+unsafe fn write_out<T>(out: *mut T, value: T) -> FfiResult<()> {
+    out_arg(out)?;
+    ptr::write(out, value);
+    Ok(())
+}
+
+// Writes `id` as `0x` hex and a NUL into the `len` bytes at `out`
+unsafe fn write_hex(id: impl LowerHex, out: *mut c_char, len: usize) -> FfiResult<()> {
+    if out.is_null() {
+        return Err(null("out"));
+    }
+    let hex = CString::new(format!("{:#x}", id)).map_err(failed)?;
+    let bytes = hex.as_bytes_with_nul();
+    if bytes.len() > len {
+        return Err(invalid(format!("out holds {} bytes, {} needed", len, bytes.len())));
+    }
+    ptr::copy_nonoverlapping(bytes.as_ptr().cast::<c_char>(), out, bytes.len());
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// FFI Tests
+// 
+// This module contains tests for the C ABI of the rosetta-stone-ffi crate including:
+// - Compiling a C program against include/rosetta_stone.h and the crate's cdylib
+// - Deploying, minting, transferring and reading balances from C
+// - Status codes and messages for a bad address and a null handle
+// - The checked-in header matching what cbindgen generates from src/lib.rs
+//
+// Run with: cargo test -p rosetta-stone-ffi --test ffi -- --nocapture
+// Needs a C compiler, `cc` or the one named by `CC`.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Cargo builds the cdylib into the same directory as this test binary
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().expect("test binary path");
+    exe.parent().expect("test binary directory").to_path_buf()
+}
+
+// Test that include/rosetta_stone.h is up to date with src/lib.rs
+#[test]
+fn test_header_matches_source() {
+    println!("Testing the checked-in header against cbindgen...");
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(manifest_dir.join("cbindgen.toml"))
+        .expect("failed to read cbindgen.toml");
+    let generated = Path::new(env!("CARGO_TARGET_TMPDIR")).join("rosetta_stone.h");
+    cbindgen::Builder::new()
+        .with_crate(manifest_dir)
+        .with_config(config)
+        .generate()
+        .expect("cbindgen failed on src/lib.rs")
+        .write_to_file(&generated);
+
+    let committed = std::fs::read_to_string(manifest_dir.join("include/rosetta_stone.h"))
+        .expect("failed to read include/rosetta_stone.h");
+    let generated = std::fs::read_to_string(&generated).expect("failed to read generated header");
+    assert!(
+        committed == generated,
+        "include/rosetta_stone.h is stale; regenerate it from ffi/ with \
+         `cbindgen --quiet --config cbindgen.toml --output include/rosetta_stone.h`"
+    );
+    println!("✅ Header matches what cbindgen generates");
+}
+
+// Test the whole token flow from tests/ffi/smoke.c
+#[test]
+fn test_c_program_drives_token_flow() {
+    println!("Testing the C ABI from a C program...");
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi_smoke");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let compiled = Command::new(&compiler)
+        .arg(manifest_dir.join("tests/ffi/smoke.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(library_dir())
+        .arg("-lrosetta_stone_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(compiled.success(), "{} could not build tests/ffi/smoke.c", compiler);
+    println!("✅ Smoke program built against include/rosetta_stone.h");
+
+    // Contract bytecode paths are relative to the rosetta_stone manifest directory
+    let workspace_dir = manifest_dir.parent().expect("ffi/ sits in the workspace root");
+    let output = Command::new(&program)
+        .current_dir(workspace_dir)
+        .env("LD_LIBRARY_PATH", library_dir())
+        .env("DYLD_LIBRARY_PATH", library_dir())
+        .output()
+        .expect("failed to run the smoke program");
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success(), "smoke program failed: {}", output.status);
+    println!("✅ Deploy, mint, transfer and balances driven from C");
+}
@@ -0,0 +1,68 @@
+// FFI Smoke Test
+//
+// Drives the C ABI the way a non-Rust backend would: a local node, a token
+// deployment, a mint, a transfer and both balances, then the errors for a bad
+// address and a null handle. Built and run by ffi/tests/ffi.rs.
+
+#include <stdint.h>
+#include <stdio.h>
+
+#include "rosetta_stone.h"
+
+#define CHECK(call)                                                                \
+    do {                                                                           \
+        RosettaStatus status = (call);                                             \
+        if (status != ROSETTA_STATUS_OK) {                                         \
+            fprintf(stderr, "%s failed with %d: %s\n", #call, status,              \
+                    rosetta_last_error());                                         \
+            return 1;                                                              \
+        }                                                                          \
+    } while (0)
+
+static const char *RECIPIENT =
+    "0x0101010101010101010101010101010101010101010101010101010101010101";
+
+int main(void) {
+    RosettaClient *client = NULL;
+    RosettaToken *token = NULL;
+    char admin[ROSETTA_HEX_ID_LEN];
+    char asset_id[ROSETTA_HEX_ID_LEN];
+    uint64_t balance = 0;
+
+    printf("Testing the C ABI...\n");
+
+    CHECK(rosetta_client_launch_local(&client));
+    CHECK(rosetta_client_address(client, admin, sizeof admin));
+    CHECK(rosetta_deploy_token(client, "CTOKEN1", "CTKN1", 9, &token));
+    CHECK(rosetta_token_asset_id(token, asset_id, sizeof asset_id));
+    printf("✅ Token deployed by %s, asset %s\n", admin, asset_id);
+
+    CHECK(rosetta_mint(client, token, admin, 1000));
+    CHECK(rosetta_transfer(client, token, RECIPIENT, 400));
+    CHECK(rosetta_balance(client, token, admin, &balance));
+    if (balance != 600) {
+        fprintf(stderr, "admin balance is %llu, expected 600\n", (unsigned long long)balance);
+        return 1;
+    }
+    CHECK(rosetta_balance(client, token, RECIPIENT, &balance));
+    if (balance != 400) {
+        fprintf(stderr, "recipient balance is %llu, expected 400\n", (unsigned long long)balance);
+        return 1;
+    }
+    printf("✅ Minted 1000, transferred 400, balances 600 and 400\n");
+
+    if (rosetta_mint(client, token, "not an address", 1) != ROSETTA_STATUS_INVALID_ARGUMENT) {
+        fprintf(stderr, "a bad recipient was accepted\n");
+        return 1;
+    }
+    printf("❌ Bad recipient rejected: %s\n", rosetta_last_error());
+    if (rosetta_balance(client, NULL, admin, &balance) != ROSETTA_STATUS_NULL_ARGUMENT) {
+        fprintf(stderr, "a null token was accepted\n");
+        return 1;
+    }
+    printf("❌ Null token rejected: %s\n", rosetta_last_error());
+
+    rosetta_token_free(token);
+    rosetta_client_free(client);
+    return 0;
+}
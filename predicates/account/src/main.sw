@@ -0,0 +1,21 @@
+predicate;
+
+use std::{b512::B512, ecr::ec_recover_address, tx::{tx_id, tx_witness_data}};
+
+configurable {
+    /// The key that controls the account.
+    OWNER: Address = Address::zero(),
+}
+
+// A predicate used as a user account: its address holds the funds, and it
+// approves any transaction the owner signed. When every coin input of a
+// transaction belongs to the predicate, contracts see it as `msg_sender`, so
+// it can pay for and authorize contract calls like a wallet.
+fn main() -> bool {
+    // The owner's signature over the transaction id must be the first witness
+    let signature = tx_witness_data::<B512>(0).unwrap();
+    match ec_recover_address(signature, tx_id()) {
+        Ok(address) => address == OWNER,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,40 @@
+predicate;
+
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    hash::{Hash, sha256},
+    tx::{
+        tx_id,
+        tx_maturity,
+        tx_witness_data,
+    },
+};
+
+configurable {
+    /// sha256 of the secret that unlocks the claim path.
+    HASH: b256 = b256::zero(),
+    /// May spend by revealing the preimage of HASH.
+    RECIPIENT: Address = Address::zero(),
+    /// May spend once the transaction maturity reaches TIMEOUT.
+    REFUND: Address = Address::zero(),
+    /// First block height at which the refund path opens.
+    TIMEOUT: u32 = 0,
+}
+
+// The spender's signature over the transaction id must be the first witness.
+// The claim path reveals `preimage` in the predicate data, which lets the
+// other side of a swap read it from the chain and claim its own lock.
+fn main(preimage: b256) -> bool {
+    let signer = match ec_recover_address(tx_witness_data::<B512>(0).unwrap(), tx_id()) {
+        Ok(address) => address,
+        Err(_) => return false,
+    };
+
+    if signer == RECIPIENT && sha256(preimage) == HASH {
+        return true;
+    }
+
+    // Refunds rely on the node refusing transactions before their maturity
+    signer == REFUND && tx_maturity().unwrap_or(0) >= TIMEOUT
+}
@@ -0,0 +1,76 @@
+predicate;
+
+use std::{
+    auth::predicate_address,
+    outputs::{
+        Output,
+        output_amount,
+        output_asset_id,
+        output_asset_to,
+        output_count,
+        output_type,
+    },
+    tx::tx_script_length,
+};
+
+configurable {
+    /// The asset the predicate pays out.
+    ASSET: AssetId = AssetId::zero(),
+    /// Largest payout a single transaction may take.
+    MAX_PAYOUT: u64 = 0,
+}
+
+// A faucet-style predicate: any transaction may take up to MAX_PAYOUT, but it
+// must name the recipient and amount in the predicate data, and its first
+// output must pay exactly that. The data is chosen by the spender when the
+// transaction is built; the predicate's address does not depend on it.
+//
+// Everything beyond the payout must come back to the predicate as change, so
+// no other output may carry ASSET, and scripts are refused because they could
+// forward the coins elsewhere.
+fn main(recipient: Address, amount: u64) -> bool {
+    if amount == 0 || amount > MAX_PAYOUT {
+        return false;
+    }
+
+    if tx_script_length() != Some(0) {
+        return false;
+    }
+
+    match output_type(0) {
+        Some(Output::Coin) => {
+            if output_asset_to(0) != Some(recipient)
+                || output_amount(0) != Some(amount)
+                || output_asset_id(0) != Some(ASSET)
+            {
+                return false;
+            }
+        },
+        _ => return false,
+    }
+
+    let mut change_returned = false;
+    let count = output_count().as_u64();
+    let mut i = 1;
+    while i < count {
+        match output_type(i) {
+            Some(Output::Coin) => {
+                if output_asset_id(i) == Some(ASSET) {
+                    return false;
+                }
+            },
+            Some(Output::Change) => {
+                if output_asset_id(i) == Some(ASSET) {
+                    if output_asset_to(i) != predicate_address() {
+                        return false;
+                    }
+                    change_returned = true;
+                }
+            },
+            _ => {},
+        }
+        i += 1;
+    }
+
+    change_returned
+}
@@ -0,0 +1,66 @@
+predicate;
+
+use std::{
+    auth::predicate_address,
+    outputs::{
+        Output,
+        output_asset_id,
+        output_asset_to,
+        output_count,
+        output_type,
+    },
+    tx::{
+        tx_max_fee,
+        tx_script_length,
+    },
+};
+
+configurable {
+    /// Highest max fee the sponsor covers for a single transaction.
+    MAX_FEE: u64 = 0,
+}
+
+// A gas sponsor: its base-asset coins may be added to anyone's transfer to pay
+// the fee. Whatever the fee does not use must come back to the predicate as
+// change, so the transaction may not pay the base asset out to anyone, and
+// scripts are refused because they could forward the coins elsewhere.
+fn main() -> bool {
+    if tx_script_length() != Some(0) {
+        return false;
+    }
+
+    match tx_max_fee() {
+        Some(max_fee) => {
+            if max_fee > MAX_FEE {
+                return false;
+            }
+        },
+        None => return false,
+    }
+
+    let base_asset = AssetId::base();
+    let mut change_returned = false;
+    let count = output_count().as_u64();
+    let mut i = 0;
+    while i < count {
+        match output_type(i) {
+            Some(Output::Coin) => {
+                if output_asset_id(i) == Some(base_asset) {
+                    return false;
+                }
+            },
+            Some(Output::Change) => {
+                if output_asset_id(i) == Some(base_asset) {
+                    if output_asset_to(i) != predicate_address() {
+                        return false;
+                    }
+                    change_returned = true;
+                }
+            },
+            _ => {},
+        }
+        i += 1;
+    }
+
+    change_returned
+}
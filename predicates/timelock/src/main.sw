@@ -0,0 +1,35 @@
+predicate;
+
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    tx::{
+        tx_id,
+        tx_maturity,
+        tx_witness_data,
+    },
+};
+
+configurable {
+    /// The only address that may spend the locked coins.
+    OWNER: Address = Address::zero(),
+    /// First block height at which the coins can be spent.
+    UNLOCK_HEIGHT: u32 = 0,
+}
+
+// Predicates cannot read the current block height. Instead the spending
+// transaction must set its maturity to at least UNLOCK_HEIGHT, and the node
+// refuses to include a transaction before its maturity height.
+fn main() -> bool {
+    let maturity = tx_maturity().unwrap_or(0);
+    if maturity < UNLOCK_HEIGHT {
+        return false;
+    }
+
+    // The owner's signature over the transaction id must be the first witness
+    let signature = tx_witness_data::<B512>(0).unwrap();
+    match ec_recover_address(signature, tx_id()) {
+        Ok(address) => address == OWNER,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,31 @@
+script;
+
+use std::{
+    asset::transfer,
+    logging::log,
+};
+
+/// Logged once per recipient paid.
+pub struct AirdropTransfer {
+    pub recipient: Identity,
+    pub amount: u64,
+}
+
+/// Pays `amounts[i]` of `asset_id` to `recipients[i]` and returns the number of transfers made.
+///
+/// The batch size is chosen by the caller; every recipient needs one variable
+/// output, so a batch must fit in the transaction's output limit.
+fn main(asset_id: AssetId, recipients: Vec<Identity>, amounts: Vec<u64>) -> u64 {
+    require(recipients.len() == amounts.len(), "Recipients and amounts differ in length");
+
+    let mut i = 0;
+    while i < recipients.len() {
+        let recipient = recipients.get(i).unwrap();
+        let amount = amounts.get(i).unwrap();
+        transfer(recipient, asset_id, amount);
+        log(AirdropTransfer { recipient, amount });
+        i += 1;
+    }
+
+    i
+}
@@ -0,0 +1,36 @@
+script;
+
+use std::{
+    asset::transfer,
+    logging::log,
+};
+
+/// One party's side of a swap: `owner` gives `amount` of `asset`.
+pub struct SwapLeg {
+    pub owner: Address,
+    pub asset: AssetId,
+    pub amount: u64,
+}
+
+/// Logged once the two legs have been exchanged.
+pub struct SwapExecuted {
+    pub a: SwapLeg,
+    pub b: SwapLeg,
+}
+
+/// Pays `a`'s coins to `b.owner` and `b`'s coins to `a.owner`.
+///
+/// The coins are the transaction's own inputs, signed for by both owners. If
+/// either side's inputs are missing or short, its transfer panics and the
+/// whole transaction reverts, so neither party can receive without paying.
+/// Each transfer needs one variable output.
+fn main(a: SwapLeg, b: SwapLeg) -> bool {
+    require(a.owner != b.owner, "Cannot swap with oneself");
+    require(a.asset != b.asset, "Legs must be different assets");
+
+    transfer(Identity::Address(b.owner), a.asset, a.amount);
+    transfer(Identity::Address(a.owner), b.asset, b.amount);
+
+    log(SwapExecuted { a, b });
+    true
+}
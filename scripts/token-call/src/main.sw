@@ -0,0 +1,19 @@
+script;
+
+use src20::SRC20;
+use src3::SRC3;
+use std::constants::DEFAULT_SUB_ID;
+
+/// Mints `amount` of `token` to `recipient` through the token's SRC3 ABI and
+/// returns the total supply read back through its SRC20 ABI.
+///
+/// The script calls the contract directly, so the transaction needs the token
+/// as a contract input, and a variable output for the minted coins. The token
+/// only lets its admin mint; the script's caller is the owner of its coin inputs.
+fn main(token: ContractId, recipient: Identity, amount: u64) -> u64 {
+    let src3 = abi(SRC3, token.bits());
+    src3.mint(recipient, Some(DEFAULT_SUB_ID), amount);
+
+    let src20 = abi(SRC20, token.bits());
+    src20.total_supply(AssetId::new(token, DEFAULT_SUB_ID)).unwrap_or(0)
+}
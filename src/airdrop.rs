@@ -0,0 +1,226 @@
+//! CSV-driven airdrops through the `airdrop` script.
+//!
+//! Recipients and amounts are read from a CSV file with one `address,amount`
+//! row per recipient. The rows are split into batches small enough to fit the
+//! chain's output limit, each batch is paid by one script transaction, and the
+//! report records which transaction paid each recipient or why it failed,
+//! with the failed transaction's id and block in the reason.
+
+use std::{fs, path::Path, str::FromStr};
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::TxId,
+    types::{transaction::Transaction, tx_status::TxStatus, Address, AssetId, Identity},
+};
+
+use crate::{
+    artifacts,
+    error::{ErrorContext, ResultExt, RosettaError, RosettaResult},
+    script_tx::build_script_tx,
+    Airdrop,
+};
+
+/// Outputs every batch needs besides one variable output per recipient:
+/// change for the airdropped asset and change for the base asset.
+pub const RESERVED_OUTPUTS: usize = 2;
+
+/// One row of the recipients file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AirdropEntry {
+    pub recipient: Address,
+    pub amount: u64,
+    /// 1-based line number in the source file, for error messages.
+    pub line: usize,
+}
+
+/// What happened to one recipient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AirdropStatus {
+    Paid { tx_id: TxId },
+    Failed { reason: String },
+}
+
+/// Outcome of one recipient's payment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirdropResult {
+    pub entry: AirdropEntry,
+    pub status: AirdropStatus,
+}
+
+/// Per-recipient outcome of a whole airdrop, in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AirdropReport {
+    pub results: Vec<AirdropResult>,
+    /// Number of transactions submitted.
+    pub batches: usize,
+}
+
+impl AirdropReport {
+    pub fn paid(&self) -> impl Iterator<Item = &AirdropResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.status, AirdropStatus::Paid { .. }))
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &AirdropResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.status, AirdropStatus::Failed { .. }))
+    }
+
+    /// Total amount delivered to recipients.
+    pub fn total_paid(&self) -> u64 {
+        self.paid().map(|result| result.entry.amount).sum()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+/// Parses `address,amount` rows.
+///
+/// Blank lines and lines starting with `#` are skipped, as is a first row
+/// whose amount column is not a number (a header).
+pub fn parse_csv(contents: &str) -> Result<Vec<AirdropEntry>> {
+    let mut entries = Vec::new();
+    for (index, raw) in contents.lines().enumerate() {
+        let line = index + 1;
+        let row = raw.trim();
+        if row.is_empty() || row.starts_with('#') {
+            continue;
+        }
+
+        let mut columns = row.split(',').map(str::trim);
+        let (Some(recipient), Some(amount), None) = (columns.next(), columns.next(), columns.next())
+        else {
+            return Err(Error::Other(format!(
+                "line {}: expected `address,amount`, got `{}`",
+                line, row
+            )));
+        };
+
+        let amount = match amount.parse::<u64>() {
+            Ok(amount) => amount,
+            Err(_) if entries.is_empty() && Address::from_str(recipient).is_err() => continue,
+            Err(_) => {
+                return Err(Error::Other(format!("line {}: invalid amount `{}`", line, amount)))
+            }
+        };
+        if amount == 0 {
+            return Err(Error::Other(format!("line {}: amount must be positive", line)));
+        }
+        let recipient = Address::from_str(recipient).map_err(|_| {
+            Error::Other(format!("line {}: invalid address `{}`", line, recipient))
+        })?;
+
+        entries.push(AirdropEntry {
+            recipient,
+            amount,
+            line,
+        });
+    }
+    Ok(entries)
+}
+
+/// Reads and parses a recipients file.
+pub fn load_csv(path: impl AsRef<Path>) -> Result<Vec<AirdropEntry>> {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .map_err(|err| Error::Other(format!("failed to read {}: {}", path.display(), err)))?;
+    parse_csv(&contents)
+}
+
+/// The largest batch a single transaction can pay on this chain.
+pub async fn max_batch_size(provider: &Provider) -> Result<usize> {
+    let max_outputs = provider
+        .consensus_parameters()
+        .await?
+        .tx_params()
+        .max_outputs() as usize;
+    max_outputs
+        .checked_sub(RESERVED_OUTPUTS)
+        .filter(|size| *size > 0)
+        .ok_or_else(|| Error::Other(format!("max outputs {} is too small", max_outputs)))
+}
+
+/// Pays every entry, at most `batch_size` recipients per transaction.
+///
+/// `batch_size` is capped at [`max_batch_size`]; pass `None` to use the cap.
+/// A failed batch marks its recipients as failed and the remaining batches
+/// still run, so the report always covers every entry.
+pub async fn run_airdrop(
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+    entries: &[AirdropEntry],
+    batch_size: Option<usize>,
+) -> Result<AirdropReport> {
+    let provider = wallet.try_provider()?.clone();
+    let max = max_batch_size(&provider).await?;
+    let batch_size = batch_size.unwrap_or(max).clamp(1, max);
+
+    let mut report = AirdropReport::default();
+    for batch in entries.chunks(batch_size) {
+        report.batches += 1;
+        let status = match send_batch(wallet, &provider, asset_id, batch).await {
+            Ok(tx_id) => AirdropStatus::Paid { tx_id },
+            Err(err) => AirdropStatus::Failed {
+                reason: err.to_string(),
+            },
+        };
+        report
+            .results
+            .extend(batch.iter().map(|entry| AirdropResult {
+                entry: *entry,
+                status: status.clone(),
+            }));
+    }
+    Ok(report)
+}
+
+async fn send_batch(
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    provider: &Provider,
+    asset_id: AssetId,
+    batch: &[AirdropEntry],
+) -> RosettaResult<TxId> {
+    let recipients: Vec<Identity> = batch
+        .iter()
+        .map(|entry| Identity::Address(entry.recipient))
+        .collect();
+    let amounts: Vec<u64> = batch.iter().map(|entry| entry.amount).collect();
+    let total: u128 = amounts.iter().map(|amount| *amount as u128).sum();
+
+    let script_call = Airdrop::new(wallet.clone(), artifacts::AIRDROP_BIN)
+        .main(asset_id, recipients, amounts)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(batch.len()));
+
+    // The script spends the wallet's coins, so they have to be inputs of the
+    // transaction, with the unspent remainder returned as change.
+    let tx = build_script_tx(&script_call, wallet, &[(asset_id, total)]).await?;
+
+    let chain_id = provider.consensus_parameters().await?.chain_id();
+    let tx_id = tx.id(chain_id);
+    let context = ErrorContext::default().with_tx_id(tx_id);
+    let status = provider
+        .send_transaction_and_await_commit(tx)
+        .await
+        .context(&context)?;
+    let context = context.with_committed_block(provider).await;
+    if !matches!(status, TxStatus::Success { .. }) {
+        return Err(RosettaError::TransactionFailed(format!("{:?}", status)).with_context(context));
+    }
+
+    let paid = script_call.get_response(status).context(&context)?.value;
+    if paid != batch.len() as u64 {
+        return Err(RosettaError::TransactionFailed(format!(
+            "script paid {} of {} recipients",
+            paid,
+            batch.len()
+        ))
+        .with_context(context));
+    }
+    Ok(tx_id)
+}
@@ -0,0 +1,138 @@
+//! Allocation statistics for batch operations (feature `alloc-profiling`).
+//!
+//! Install [`CountingAllocator`] as the global allocator of a test or binary,
+//! then wrap a batch in [`profile`] to get peak memory and allocation counts:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+//!
+//! let (result, stats) = profile(&ALLOCATOR, || async { build_batch().await }).await;
+//! println!("{}", stats.per_thousand(1_000));
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A wrapper around the system allocator that counts allocations and tracks peak usage.
+pub struct CountingAllocator {
+    allocations: AtomicUsize,
+    allocated_bytes: AtomicUsize,
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            allocated_bytes: AtomicUsize::new(0),
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Counters since the last [`CountingAllocator::reset`].
+    pub fn snapshot(&self) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            allocated_bytes: self.allocated_bytes.load(Ordering::Relaxed),
+            peak_bytes: self.peak.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Zeroes the counters and restarts peak tracking from the current live size.
+    pub fn reset(&self) {
+        self.allocations.store(0, Ordering::Relaxed);
+        self.allocated_bytes.store(0, Ordering::Relaxed);
+        self.peak
+            .store(self.current.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    fn track_alloc(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.allocated_bytes.fetch_add(size, Ordering::Relaxed);
+        let current = self.current.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+            self.track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocation counters for one profiled section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations (reallocations count as one).
+    pub allocations: usize,
+    /// Total bytes requested.
+    pub allocated_bytes: usize,
+    /// Highest live heap size observed, in bytes.
+    pub peak_bytes: usize,
+}
+
+impl AllocStats {
+    /// Scales allocation counts to a batch of 1,000 operations; peak is left as is.
+    pub fn per_thousand(&self, operations: usize) -> AllocStats {
+        let operations = operations.max(1);
+        AllocStats {
+            allocations: self.allocations * 1_000 / operations,
+            allocated_bytes: self.allocated_bytes * 1_000 / operations,
+            peak_bytes: self.peak_bytes,
+        }
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {:.1} MiB allocated, {:.1} MiB peak",
+            self.allocations,
+            self.allocated_bytes as f64 / (1024.0 * 1024.0),
+            self.peak_bytes as f64 / (1024.0 * 1024.0)
+        )
+    }
+}
+
+/// Runs `f` and returns its output with the allocation stats it caused.
+pub async fn profile<F, Fut, T>(allocator: &CountingAllocator, f: F) -> (T, AllocStats)
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T>,
+{
+    allocator.reset();
+    let output = f().await;
+    (output, allocator.snapshot())
+}
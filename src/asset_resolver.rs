@@ -0,0 +1,135 @@
+//! Human-readable names for asset ids.
+//!
+//! [`AssetResolver`] looks asset ids up in the AssetRegistry contract, caches
+//! the answers, and renders balances as `1.5 TOKEN` instead of a raw amount
+//! next to a 32-byte id. Unregistered assets fall back to a shortened id.
+//!
+//! [`src20_metadata`] skips the registry and asks an SRC-20 contract directly
+//! for the name, symbol, decimals and supply of its assets.
+
+use std::collections::HashMap;
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::ContractIdExt,
+    types::{AssetId, Bits256, SubAssetId},
+};
+
+pub use crate::units::format_units;
+use crate::{AssetInfo, AssetRegistry, Src20Token};
+
+/// Resolves asset ids to registered metadata.
+pub struct AssetResolver {
+    registry: Option<AssetRegistry<Wallet<Unlocked<PrivateKeySigner>>>>,
+    cache: HashMap<AssetId, Option<AssetInfo>>,
+}
+
+impl AssetResolver {
+    pub fn new(registry: AssetRegistry<Wallet<Unlocked<PrivateKeySigner>>>) -> Self {
+        Self {
+            registry: Some(registry),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// A resolver without a registry; only assets added with [`AssetResolver::with_known`] resolve.
+    pub fn offline() -> Self {
+        Self {
+            registry: None,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Adds metadata for an asset that is not in the registry, such as the base asset.
+    pub fn with_known(mut self, asset_id: AssetId, info: AssetInfo) -> Self {
+        self.cache.insert(asset_id, Some(info));
+        self
+    }
+
+    /// Registered metadata of `asset_id`, if any.
+    pub async fn resolve(&mut self, asset_id: AssetId) -> Result<Option<AssetInfo>> {
+        if let Some(info) = self.cache.get(&asset_id) {
+            return Ok(info.clone());
+        }
+
+        let info = match &self.registry {
+            Some(registry) => registry.methods().asset_info(asset_id).call().await?.value,
+            None => None,
+        };
+        self.cache.insert(asset_id, info.clone());
+        Ok(info)
+    }
+
+    /// The asset's symbol, or its shortened id when unregistered.
+    pub async fn label(&mut self, asset_id: AssetId) -> Result<String> {
+        Ok(match self.resolve(asset_id).await? {
+            Some(info) => info.symbol,
+            None => short_id(&asset_id),
+        })
+    }
+
+    /// `amount` scaled by the asset's decimals and followed by its label.
+    pub async fn format_amount(&mut self, asset_id: AssetId, amount: u128) -> Result<String> {
+        Ok(match self.resolve(asset_id).await? {
+            Some(info) => format!("{} {}", format_units(amount, info.decimals), info.symbol),
+            None => format!("{} {}", amount, short_id(&asset_id)),
+        })
+    }
+}
+
+/// SRC-20 metadata of one asset, as reported by the contract that mints it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Src20Metadata {
+    pub sub_id: Bits256,
+    pub asset_id: AssetId,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    pub total_supply: u64,
+}
+
+/// Reads the SRC-20 metadata of the assets `token` mints under `sub_ids`.
+///
+/// Only the SRC-20 methods are called, so `token` can be bound to any SRC-20
+/// contract. Sub ids the contract reports no supply for are skipped.
+pub async fn src20_metadata<A: Account + Clone>(
+    token: &Src20Token<A>,
+    sub_ids: &[Bits256],
+) -> Result<Vec<Src20Metadata>> {
+    let mut assets = Vec::with_capacity(sub_ids.len());
+    for sub_id in sub_ids {
+        let asset_id = token.contract_id().asset_id(&SubAssetId::from(sub_id.0));
+        let methods = token.methods();
+        let Some(total_supply) = methods.total_supply(asset_id).call().await?.value else {
+            continue;
+        };
+
+        assets.push(Src20Metadata {
+            sub_id: *sub_id,
+            asset_id,
+            name: methods.name(asset_id).call().await?.value,
+            symbol: methods.symbol(asset_id).call().await?.value,
+            decimals: methods.decimals(asset_id).call().await?.value,
+            total_supply,
+        });
+    }
+    Ok(assets)
+}
+
+/// Metadata of the base asset, which is not minted by a registered contract.
+pub fn base_asset_info() -> AssetInfo {
+    AssetInfo {
+        name: "Ether".to_string(),
+        symbol: "ETH".to_string(),
+        decimals: 9,
+        logo_uri: String::new(),
+    }
+}
+
+/// `0x1234…abcd`
+pub fn short_id(asset_id: &AssetId) -> String {
+    let hex = asset_id.to_string();
+    let hex = hex.trim_start_matches("0x");
+    format!("0x{}…{}", &hex[..4], &hex[hex.len() - 4..])
+}
@@ -0,0 +1,71 @@
+//! Attestations signed off chain and verified by the SignatureVerifier contract.
+//!
+//! The signer builds an [`Attestation`] with their current nonce and signs its
+//! digest with the wallet key, without sending a transaction. Anyone holding
+//! the signature can submit it; the contract recovers the signer from the
+//! signature and only records the attestation if it matches.
+//!
+//! ```ignore
+//! let attestation = prepare_attestation(&verifier, signer.address(), subject, 7).await?;
+//! let signature = sign_attestation(&signer_key, &attestation, &verifier.contract_id()).await?;
+//! verifier.methods().submit(attestation, signature).call().await?;
+//! ```
+
+use fuels::{
+    crypto::Message,
+    prelude::*,
+    types::{Address, Bits256, ContractId, B512},
+};
+
+use crate::{Attestation, SignatureVerifier};
+
+/// The bytes the contract hashes, laid out as Sway's `Hash` writes them:
+/// contract id, signer and subject, then score and nonce as big-endian integers.
+pub fn encode_attestation(attestation: &Attestation, contract_id: &ContractId) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(32 + 32 + 32 + 8 + 8);
+    bytes.extend_from_slice(contract_id.as_ref());
+    bytes.extend_from_slice(attestation.signer.as_ref());
+    bytes.extend_from_slice(&attestation.subject.0);
+    bytes.extend_from_slice(&attestation.score.to_be_bytes());
+    bytes.extend_from_slice(&attestation.nonce.to_be_bytes());
+    bytes
+}
+
+/// The message signed for the contract at `contract_id`; its bytes are the
+/// digest the contract's `digest` returns.
+pub fn attestation_message(attestation: &Attestation, contract_id: &ContractId) -> Message {
+    Message::new(encode_attestation(attestation, contract_id))
+}
+
+/// Signs `attestation` with `signer`, which must be its signer.
+pub async fn sign_attestation(
+    signer: &impl Signer,
+    attestation: &Attestation,
+    contract_id: &ContractId,
+) -> Result<B512> {
+    if signer.address() != attestation.signer {
+        return Err(Error::Other(format!(
+            "attestation signer {} does not match signer {}",
+            attestation.signer,
+            signer.address()
+        )));
+    }
+    let signature = signer.sign(attestation_message(attestation, contract_id)).await?;
+    B512::try_from(signature.as_ref())
+}
+
+/// Builds an attestation of `signer` using the nonce currently stored in the contract.
+pub async fn prepare_attestation<A: Account>(
+    verifier: &SignatureVerifier<A>,
+    signer: Address,
+    subject: Bits256,
+    score: u64,
+) -> Result<Attestation> {
+    let nonce = verifier.methods().nonce(signer).call().await?.value;
+    Ok(Attestation {
+        signer,
+        subject,
+        score,
+        nonce,
+    })
+}
@@ -0,0 +1,118 @@
+//! Balance-change assertions for tests.
+//!
+//! Instead of reading every balance before an operation and computing the
+//! expected values by hand afterwards, take a [`BalanceSnapshot`] of the
+//! (address, asset) pairs involved and assert the signed deltas once the
+//! operation is done. [`assert_balance_change!`](crate::assert_balance_change)
+//! wraps both steps around a future:
+//!
+//! ```ignore
+//! let response = assert_balance_change!(
+//!     &harness.provider,
+//!     [
+//!         (user.address(), harness.asset_id) => -100,
+//!         (recipient.address(), harness.asset_id) => 100,
+//!     ],
+//!     user.transfer(recipient.address(), 100, harness.asset_id, TxPolicies::default())
+//! )?;
+//! ```
+//!
+//! Base asset deltas include the fees the holder paid; see [`crate::fees`] to
+//! get them exactly.
+
+use std::fmt::Write;
+
+use fuels::{prelude::*, types::Address};
+
+/// Balances of a set of (address, asset) pairs at one point in time.
+#[derive(Debug, Clone)]
+pub struct BalanceSnapshot {
+    provider: Provider,
+    entries: Vec<(Address, AssetId, u128)>,
+}
+
+impl BalanceSnapshot {
+    /// Reads the current balance of every pair in `holders`.
+    pub async fn take(provider: &Provider, holders: &[(Address, AssetId)]) -> Result<Self> {
+        let mut entries = Vec::with_capacity(holders.len());
+        for (address, asset_id) in holders {
+            let balance = provider.get_asset_balance(address, asset_id).await?;
+            entries.push((*address, *asset_id, balance));
+        }
+
+        Ok(Self {
+            provider: provider.clone(),
+            entries,
+        })
+    }
+
+    /// The balances as they were when the snapshot was taken, in `holders` order.
+    pub fn balances(&self) -> Vec<u128> {
+        self.entries.iter().map(|(_, _, balance)| *balance).collect()
+    }
+
+    /// How much each balance changed since the snapshot, in `holders` order.
+    pub async fn deltas(&self) -> Result<Vec<i128>> {
+        let mut deltas = Vec::with_capacity(self.entries.len());
+        for (address, asset_id, before) in &self.entries {
+            let after = self.provider.get_asset_balance(address, asset_id).await?;
+            deltas.push(after as i128 - *before as i128);
+        }
+        Ok(deltas)
+    }
+
+    /// Panics unless the balances changed by exactly `expected`, in `holders`
+    /// order, listing every pair that differs.
+    pub async fn assert_deltas(&self, expected: &[i128]) -> Result<()> {
+        assert_eq!(
+            expected.len(),
+            self.entries.len(),
+            "expected {} balance changes for {} holders",
+            expected.len(),
+            self.entries.len()
+        );
+
+        let deltas = self.deltas().await?;
+        let mut mismatches = String::new();
+        for ((address, asset_id, _), (expected, actual)) in
+            self.entries.iter().zip(expected.iter().zip(&deltas))
+        {
+            if expected != actual {
+                let _ = writeln!(
+                    mismatches,
+                    "  {} / {}: expected {:+}, changed by {:+}",
+                    address, asset_id, expected, actual
+                );
+            }
+        }
+        assert!(mismatches.is_empty(), "unexpected balance changes:\n{}", mismatches);
+
+        Ok(())
+    }
+}
+
+/// Snapshots the balances of the listed (address, asset) pairs, awaits `$operation`
+/// and asserts that each balance changed by the given signed amount.
+///
+/// Evaluates to the output of `$operation`. Reading the balances uses `?`, so
+/// the calling function must return a `Result` whose error converts from the
+/// SDK's.
+#[macro_export]
+macro_rules! assert_balance_change {
+    (
+        $provider:expr,
+        [$(($address:expr, $asset_id:expr) => $delta:expr),+ $(,)?],
+        $operation:expr $(,)?
+    ) => {{
+        let snapshot = $crate::balances::BalanceSnapshot::take(
+            $provider,
+            &[$(($address, $asset_id)),+],
+        )
+        .await?;
+        let output = $operation.await;
+        snapshot
+            .assert_deltas(&[$(::std::primitive::i128::from($delta)),+])
+            .await?;
+        output
+    }};
+}
@@ -0,0 +1,112 @@
+//! Benchmark reporting in the github-action-benchmark format.
+//!
+//! Benchmarks record measurements into a [`BenchReport`], which is written as a
+//! JSON array of `{ "name", "unit", "value" }` entries (the `customSmallerIsBetter`
+//! tool of github-action-benchmark), so results can be tracked with standard dashboards.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Environment variable overriding where [`BenchReport::write_default`] writes.
+pub const BENCH_OUTPUT_ENV: &str = "ROSETTA_BENCH_OUTPUT";
+/// Default output path, relative to the package root.
+pub const DEFAULT_BENCH_OUTPUT: &str = "target/bench.json";
+
+/// A single measurement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchEntry {
+    pub name: String,
+    pub unit: String,
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub extra: Option<String>,
+}
+
+/// A collection of measurements from one benchmark run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BenchReport {
+    entries: Vec<BenchEntry>,
+}
+
+impl BenchReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an arbitrary value with its unit.
+    pub fn record(&mut self, name: impl Into<String>, unit: impl Into<String>, value: f64) {
+        self.entries.push(BenchEntry {
+            name: name.into(),
+            unit: unit.into(),
+            value,
+            extra: None,
+        });
+    }
+
+    /// Records a duration in milliseconds.
+    pub fn record_duration(&mut self, name: impl Into<String>, duration: Duration) {
+        self.record(name, "ms", duration.as_secs_f64() * 1_000.0);
+    }
+
+    /// Records the mean duration of one operation out of `iterations` in milliseconds,
+    /// noting the total in `extra`.
+    pub fn record_per_op(&mut self, name: impl Into<String>, total: Duration, iterations: u64) {
+        let per_op_ms = total.as_secs_f64() * 1_000.0 / iterations.max(1) as f64;
+        self.entries.push(BenchEntry {
+            name: name.into(),
+            unit: "ms/op".to_string(),
+            value: per_op_ms,
+            extra: Some(format!("{} iterations in {:?}", iterations, total)),
+        });
+    }
+
+    pub fn entries(&self) -> &[BenchEntry] {
+        &self.entries
+    }
+
+    /// Appends every entry of `other`.
+    pub fn merge(&mut self, other: BenchReport) {
+        self.entries.extend(other.entries);
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Writes the report to `path`, creating parent directories.
+    ///
+    /// An existing report at `path` is merged in, so several benchmark binaries
+    /// can contribute to the same file.
+    pub fn write(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut report = match std::fs::read_to_string(path) {
+            Ok(existing) => serde_json::from_str(&existing).unwrap_or_default(),
+            Err(_) => BenchReport::new(),
+        };
+        // Newer measurements replace older ones with the same name
+        report
+            .entries
+            .retain(|old| !self.entries.iter().any(|new| new.name == old.name));
+        report.merge(self.clone());
+
+        std::fs::write(path, report.to_json()?)
+    }
+
+    /// Writes to `$ROSETTA_BENCH_OUTPUT`, or `target/bench.json` if unset.
+    pub fn write_default(&self) -> std::io::Result<PathBuf> {
+        let path = std::env::var(BENCH_OUTPUT_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_BENCH_OUTPUT));
+        self.write(&path)?;
+        Ok(path)
+    }
+}
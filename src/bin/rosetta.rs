@@ -0,0 +1,451 @@
+//! `rosetta`: a command line front end for the contract suite.
+//!
+//! ```bash
+//! rosetta --network local --keyfile admin.key deploy
+//! rosetta --network local --keyfile admin.key mint <RECIPIENT> 1000
+//! rosetta --network local --keyfile user.key transfer <RECIPIENT> 500
+//! rosetta --network local --keyfile user.key vault deposit 500
+//! rosetta --network local --keyfile user.key vault withdraw 200
+//! rosetta --network local --keyfile user.key --dry-run vault withdraw 200
+//! rosetta --network local --keyfile user.key balance
+//! rosetta --network local --keyfile user.key metadata <CONTRACT_ID> --sub-id <SUB_ID>
+//! rosetta --network local --keyfile user.key request --amount 500 --memo "order 42"
+//! rosetta --network local --keyfile payer.key pay fuelpay:...
+//! rosetta --network testnet --mnemonic-file seed.txt --account 1 balance
+//! rosetta --network testnet --keystore deployer.json --password-env DEPLOYER_PASSWORD deploy
+//! ```
+//!
+//! A failed command prints the error with the contract, method, transaction id
+//! and block height it happened at, where known, and exits with status 1.
+
+use std::{path::PathBuf, str::FromStr};
+
+use clap::{Parser, Subcommand};
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    crypto::SecretKey,
+    prelude::*,
+    types::{Address, AssetId, Bits256, Bytes32, ContractId, Identity},
+};
+use rosetta_stone::{
+    asset_resolver::{base_asset_info, format_units, src20_metadata, AssetResolver},
+    deployments::{deploy_suite, Deployments},
+    error::{call_with_context, RosettaResult},
+    keys::{
+        account_derivation_path, signer_from_keystore_env, signer_from_mnemonic,
+        KEYSTORE_PASSWORD_ENV,
+    },
+    latency::{timed_wallet, LatencyBreakdown},
+    network::NetworkProfile,
+    payment_request::{unix_now, PaymentRequest},
+    simulation::{simulate_then_call_timed, CallOutcome},
+    Src20Token, SUB_ID,
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "rosetta", about = "Interact with the Rosetta Stone contract suite")]
+struct Cli {
+    /// Network profile: `local`, `devnet`, `testnet` or a path to a JSON profile.
+    #[arg(long, env = "ROSETTA_NETWORK", default_value = "local")]
+    network: String,
+
+    #[command(flatten)]
+    key: KeySource,
+
+    #[command(flatten)]
+    key_options: KeyOptions,
+
+    /// Deployments file written by `deploy` and read by every other command.
+    /// Defaults to `deployments/<network>.json`.
+    #[arg(long)]
+    deployments: Option<PathBuf>,
+
+    /// Print extra detail such as transaction ids and the latency of submitted calls.
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Simulate `mint` and `vault` calls and print their logs instead of submitting them.
+    #[arg(long)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Where the signing key comes from; exactly one source is required.
+#[derive(Debug, clap::Args)]
+#[group(required = true, multiple = false)]
+struct KeySource {
+    /// File containing the hex-encoded private key used to sign.
+    #[arg(long, env = "ROSETTA_KEYFILE")]
+    keyfile: Option<PathBuf>,
+
+    /// File containing a BIP-39 mnemonic phrase to derive the signing key from.
+    #[arg(long, env = "ROSETTA_MNEMONIC_FILE")]
+    mnemonic_file: Option<PathBuf>,
+
+    /// Encrypted JSON keystore holding the signing key.
+    #[arg(long, env = "ROSETTA_KEYSTORE")]
+    keystore: Option<PathBuf>,
+}
+
+/// Options of the mnemonic and keystore key sources.
+#[derive(Debug, clap::Args)]
+struct KeyOptions {
+    /// Account of the mnemonic to sign with.
+    #[arg(long, default_value_t = 0, conflicts_with = "derivation_path")]
+    account: u32,
+
+    /// Full derivation path, overriding `--account`.
+    #[arg(long)]
+    derivation_path: Option<String>,
+
+    /// Environment variable holding the password of `--keystore`.
+    #[arg(long, default_value = KEYSTORE_PASSWORD_ENV)]
+    password_env: String,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Deploy Src20Token, CrossContractCall and TokenVault.
+    Deploy {
+        #[arg(long, default_value = "MYTOKEN")]
+        name: String,
+        #[arg(long, default_value = "TOKEN")]
+        symbol: String,
+        #[arg(long, default_value_t = 9)]
+        decimals: u8,
+    },
+    /// Mint tokens to a recipient address (admin only).
+    Mint { recipient: String, amount: u64 },
+    /// Transfer tokens to a recipient address.
+    Transfer { recipient: String, amount: u64 },
+    /// Vault operations.
+    #[command(subcommand)]
+    Vault(VaultCommand),
+    /// Print every asset balance and the vault deposit of the signer.
+    Balance,
+    /// Print the registered name, symbol and decimals of an asset id.
+    Resolve { asset_id: String },
+    /// Print the name, symbol, decimals and supply of an SRC-20 contract's assets.
+    Metadata {
+        contract_id: String,
+        /// Sub id to look up besides the default one; repeatable.
+        #[arg(long = "sub-id")]
+        sub_ids: Vec<String>,
+    },
+    /// Create a payment request for the token, payable to the signer by default.
+    Request {
+        #[arg(long)]
+        amount: u64,
+        /// Recipient address; defaults to the signer.
+        #[arg(long)]
+        recipient: Option<String>,
+        #[arg(long)]
+        memo: Option<String>,
+        /// Seconds until the request expires.
+        #[arg(long)]
+        expires_in: Option<u64>,
+    },
+    /// Decode and check a payment request without paying it.
+    Verify { request: String },
+    /// Pay a payment request with the token.
+    Pay { request: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum VaultCommand {
+    /// Deposit tokens into the vault.
+    Deposit { amount: u64 },
+    /// Withdraw tokens from the vault.
+    Withdraw { amount: u64 },
+}
+
+fn load_wallet(
+    key: &KeySource,
+    options: &KeyOptions,
+    provider: Provider,
+) -> Result<Wallet<Unlocked<PrivateKeySigner>>> {
+    let signer = match (&key.keyfile, &key.mnemonic_file, &key.keystore) {
+        (Some(keyfile), _, _) => {
+            let key = std::fs::read_to_string(keyfile)?;
+            PrivateKeySigner::new(SecretKey::from_str(key.trim())?)
+        }
+        (None, None, Some(keystore)) => signer_from_keystore_env(keystore, &options.password_env)?,
+        (None, Some(mnemonic_file), _) => {
+            let phrase = std::fs::read_to_string(mnemonic_file)?;
+            let path = options
+                .derivation_path
+                .clone()
+                .unwrap_or_else(|| account_derivation_path(options.account));
+            signer_from_mnemonic(&phrase, &path)?
+        }
+        (None, None, None) => return Err(Error::Other("no signing key given".to_string())),
+    };
+    Ok(Wallet::new(signer, provider))
+}
+
+fn parse_address(value: &str) -> Result<Address> {
+    Address::from_str(value).map_err(|_| Error::Other(format!("invalid address: {}", value)))
+}
+
+fn parse_sub_id(value: &str) -> Result<Bits256> {
+    Bits256::from_hex_str(value).map_err(|_| Error::Other(format!("invalid sub id: {}", value)))
+}
+
+// Prints the SRC-20 metadata of the default and the given sub ids of `contract_id`
+async fn print_src20_metadata(
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    contract_id: &str,
+    sub_ids: &[String],
+) -> RosettaResult<()> {
+    let contract_id = ContractId::from_str(contract_id)
+        .map_err(|_| Error::Other(format!("invalid contract id: {}", contract_id)))?;
+    let mut lookup = vec![SUB_ID];
+    for sub_id in sub_ids {
+        let sub_id = parse_sub_id(sub_id)?;
+        if !lookup.contains(&sub_id) {
+            lookup.push(sub_id);
+        }
+    }
+
+    let token = Src20Token::new(contract_id, wallet.clone());
+    let total_assets = call_with_context(token.methods().total_assets()).await?.value;
+    let assets = src20_metadata(&token, &lookup).await?;
+
+    println!("Contract:    {}", contract_id);
+    println!("Assets:      {}", total_assets);
+    for asset in &assets {
+        let (decimals, supply) = match asset.decimals {
+            Some(decimals) => (
+                decimals.to_string(),
+                format_units(u128::from(asset.total_supply), decimals),
+            ),
+            None => ("-".to_string(), asset.total_supply.to_string()),
+        };
+        println!();
+        println!("Sub id:      {}", Bytes32::from(asset.sub_id.0));
+        println!("Asset:       {}", asset.asset_id);
+        println!("Name:        {}", asset.name.as_deref().unwrap_or("-"));
+        println!("Symbol:      {}", asset.symbol.as_deref().unwrap_or("-"));
+        println!("Decimals:    {}", decimals);
+        println!("Supply:      {}", supply);
+    }
+    if assets.len() < lookup.len() {
+        println!();
+        println!("{} of the sub ids have no supply", lookup.len() - assets.len());
+    }
+    Ok(())
+}
+
+// Prints the result of a mint or vault call; `done` describes a submitted one
+fn report_call(
+    (outcome, breakdown): &(CallOutcome<()>, Option<LatencyBreakdown>),
+    done: &str,
+    verbose: bool,
+) {
+    let response = outcome.response();
+    if outcome.is_dry_run() {
+        println!("🔎 Dry run succeeded, nothing was submitted");
+        for log in response.decode_logs().filter_succeeded() {
+            println!("Log:         {}", log);
+        }
+        println!("Gas used:    {}", response.tx_status.total_gas);
+        return;
+    }
+
+    println!("✅ {}", done);
+    if verbose {
+        println!("Transaction: {:?}", response.tx_id);
+        if let Some(breakdown) = breakdown {
+            println!("Latency:     {}", breakdown);
+        }
+    }
+}
+
+fn print_payment_request(request: &PaymentRequest) {
+    println!("Recipient:   {}", request.recipient);
+    println!("Asset:       {}", request.asset_id);
+    println!("Amount:      {}", request.amount);
+    if let Some(memo) = &request.memo {
+        println!("Memo:        {}", memo);
+    }
+    if let Some(expires_at) = request.expires_at {
+        println!("Expires at:  {}", expires_at);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = run(cli).await {
+        eprintln!("❌ {}", error);
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> RosettaResult<()> {
+    let profile = NetworkProfile::resolve(&cli.network)?;
+    let provider = profile.connect().await?;
+    let wallet = load_wallet(&cli.key, &cli.key_options, provider)?;
+
+    if cli.verbose {
+        println!("Network: {} ({})", profile.name, profile.node_url);
+        println!("Signer:  {}", wallet.address());
+    }
+
+    let deployments_path = cli
+        .deployments
+        .clone()
+        .unwrap_or_else(|| Deployments::path_for(&profile.name));
+
+    if cli.dry_run && !matches!(cli.command, Command::Mint { .. } | Command::Vault(_)) {
+        return Err(Error::Other("--dry-run only applies to mint and vault".to_string()).into());
+    }
+
+    if let Command::Deploy { name, symbol, decimals } = &cli.command {
+        let deployments = deploy_suite(&wallet, &profile.name, name, symbol, *decimals).await?;
+        deployments.save(&deployments_path)?;
+        println!("✅ Deployments written to {}", deployments_path.display());
+        return Ok(());
+    }
+
+    // Works on any contract, so it needs no deployments file
+    if let Command::Metadata { contract_id, sub_ids } = &cli.command {
+        return print_src20_metadata(&wallet, contract_id, sub_ids).await;
+    }
+
+    let deployments = Deployments::load(&deployments_path)?;
+    let suite = deployments.connect(&wallet)?;
+    // Calls are signed through a timed signer so --verbose can break down their latency
+    let timed = timed_wallet(&wallet)?;
+    let (token, vault, asset_id) = (
+        suite.token.with_account(timed.clone()),
+        suite.vault.with_account(timed.clone()),
+        suite.asset_id,
+    );
+
+    let base_asset_id = *wallet
+        .try_provider()?
+        .consensus_parameters()
+        .await?
+        .base_asset_id();
+    let mut resolver = match suite.asset_registry {
+        Some(registry) => AssetResolver::new(registry),
+        None => AssetResolver::offline(),
+    }
+    .with_known(base_asset_id, base_asset_info());
+
+    match cli.command {
+        Command::Deploy { .. } | Command::Metadata { .. } => unreachable!("handled above"),
+        Command::Mint { recipient, amount } => {
+            let recipient = Identity::Address(parse_address(&recipient)?);
+            let call = token
+                .methods()
+                .mint(recipient, Some(SUB_ID), amount)
+                .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+            let outcome = simulate_then_call_timed(call, &timed, cli.dry_run).await?;
+            report_call(&outcome, &format!("Minted {} tokens", amount), cli.verbose);
+        }
+        Command::Transfer { recipient, amount } => {
+            let recipient = parse_address(&recipient)?;
+            let response = wallet
+                .transfer(recipient, amount, asset_id, TxPolicies::default())
+                .await?;
+            println!("✅ Transferred {} tokens", amount);
+            if cli.verbose {
+                println!("Transaction: {:?}", response.tx_id);
+            }
+        }
+        Command::Vault(VaultCommand::Deposit { amount }) => {
+            let call = vault.methods().deposit().call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(asset_id),
+            )?;
+            let outcome = simulate_then_call_timed(call, &timed, cli.dry_run).await?;
+            report_call(&outcome, &format!("Deposited {} tokens", amount), cli.verbose);
+        }
+        Command::Vault(VaultCommand::Withdraw { amount }) => {
+            let call = vault
+                .methods()
+                .withdraw(amount)
+                .call_params(CallParameters::default().with_asset_id(asset_id))?
+                .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+            let outcome = simulate_then_call_timed(call, &timed, cli.dry_run).await?;
+            report_call(&outcome, &format!("Withdrew {} tokens", amount), cli.verbose);
+        }
+        Command::Balance => {
+            let deposit = call_with_context(
+                vault
+                    .methods()
+                    .get_deposit_for_asset(Identity::Address(wallet.address().into()), asset_id),
+            )
+            .await?
+            .value;
+
+            println!("Address:     {}", wallet.address());
+            for (id, amount) in wallet.get_balances().await? {
+                let id = AssetId::from_str(&id)
+                    .map_err(|_| Error::Other(format!("invalid asset id from node: {}", id)))?;
+                println!("Wallet:      {}", resolver.format_amount(id, amount).await?);
+            }
+            println!(
+                "Vault:       {}",
+                resolver.format_amount(asset_id, deposit as u128).await?
+            );
+        }
+        Command::Resolve { asset_id } => {
+            let asset_id = AssetId::from_str(&asset_id)
+                .map_err(|_| Error::Other(format!("invalid asset id: {}", asset_id)))?;
+            match resolver.resolve(asset_id).await? {
+                Some(info) => {
+                    println!("Name:        {}", info.name);
+                    println!("Symbol:      {}", info.symbol);
+                    println!("Decimals:    {}", info.decimals);
+                    if !info.logo_uri.is_empty() {
+                        println!("Logo:        {}", info.logo_uri);
+                    }
+                }
+                None => println!("{} is not registered", asset_id),
+            }
+        }
+        Command::Request {
+            amount,
+            recipient,
+            memo,
+            expires_in,
+        } => {
+            let recipient = match recipient {
+                Some(recipient) => parse_address(&recipient)?,
+                None => wallet.address().into(),
+            };
+            let mut request = PaymentRequest::new(recipient, asset_id, amount);
+            if let Some(memo) = memo {
+                request = request.with_memo(memo);
+            }
+            if let Some(seconds) = expires_in {
+                request = request.expires_in(seconds);
+            }
+            println!("{}", request.encode()?);
+        }
+        Command::Verify { request } => {
+            let request = PaymentRequest::decode(&request)?;
+            print_payment_request(&request);
+            request.verify(asset_id, unix_now())?;
+            println!("✅ Payment request is valid");
+        }
+        Command::Pay { request } => {
+            let request = PaymentRequest::decode(&request)?;
+            print_payment_request(&request);
+            let tx_id = request.pay(&wallet, asset_id).await?;
+            println!("✅ Paid {} tokens", request.amount);
+            if cli.verbose {
+                println!("Transaction: {:?}", tx_id);
+            }
+        }
+    }
+
+    Ok(())
+}
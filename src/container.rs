@@ -0,0 +1,145 @@
+//! fuel-core nodes in Docker containers (feature `docker`).
+//!
+//! [`NodeMode::Container`](crate::NodeMode::Container) runs the harness against
+//! a released fuel-core image instead of the in-process node, so the same suite
+//! can be checked against several fuel-core versions:
+//!
+//! ```bash
+//! ROSETTA_FUEL_CORE_VERSION=v0.44.0 cargo test --features docker
+//! ```
+//!
+//! The genesis (chain config and the wallets' coins) is written as a snapshot
+//! directory and mounted into the container. The node runs with an in-memory
+//! database and debug mode on, so `produce_blocks` works as it does locally.
+//! Of the [`NodeConfig`] settings, block production and the starting gas
+//! price are passed on as fuel-core flags. Docker must be running.
+
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use fuel_core_chain_config::{ChainConfig, SnapshotWriter, StateConfig};
+use fuels::prelude::*;
+use testcontainers::{
+    core::{IntoContainerPort, Mount},
+    runners::AsyncRunner,
+    GenericImage, ImageExt,
+};
+
+use crate::retry::{connect_with_retry, RetryPolicy};
+
+/// The fuel-core image containers are started from.
+pub const FUEL_CORE_IMAGE: &str = "ghcr.io/fuellabs/fuel-core";
+/// Release the container tests fall back to when `ROSETTA_FUEL_CORE_VERSION` is unset.
+pub const DEFAULT_FUEL_CORE_VERSION: &str = "v0.44.0";
+
+const GRAPHQL_PORT: u16 = 4000;
+const SNAPSHOT_MOUNT: &str = "/snapshot";
+
+static NEXT_SNAPSHOT: AtomicUsize = AtomicUsize::new(0);
+
+/// Starts fuel-core `version` in a container from the given genesis and connects to it.
+///
+/// The container is removed when the runtime that started it shuts down.
+pub async fn start_container_node(
+    version: &str,
+    node_config: &NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+) -> Result<Provider> {
+    let snapshot_dir = write_snapshot(chain_config, state_config)?;
+
+    let container = GenericImage::new(FUEL_CORE_IMAGE, version)
+        .with_exposed_port(GRAPHQL_PORT.tcp())
+        .with_mount(Mount::bind_mount(
+            snapshot_dir.to_string_lossy().into_owned(),
+            SNAPSHOT_MOUNT,
+        ))
+        .with_cmd(node_args(node_config))
+        .start()
+        .await
+        .map_err(|e| container_error(version, e))?;
+
+    let host = container.get_host().await.map_err(|e| container_error(version, e))?;
+    let port = container
+        .get_host_port_ipv4(GRAPHQL_PORT)
+        .await
+        .map_err(|e| container_error(version, e))?;
+
+    // The GraphQL server comes up a little after the container starts
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        max_backoff: Duration::from_secs(2),
+        ..RetryPolicy::default()
+    };
+    let provider = connect_with_retry(&format!("{}:{}", host, port), &policy).await?;
+    std::fs::remove_dir_all(&snapshot_dir).ok();
+
+    // Dropping the container stops it, so it lives as long as the runtime
+    tokio::spawn(async move {
+        let _container = container;
+        std::future::pending::<()>().await
+    });
+
+    Ok(provider)
+}
+
+// The `fuel-core run` command line for `node_config`
+fn node_args(node_config: &NodeConfig) -> Vec<String> {
+    let mut args = [
+        "./fuel-core",
+        "run",
+        "--ip",
+        "0.0.0.0",
+        "--port",
+        "4000",
+        "--db-type",
+        "in-memory",
+        "--snapshot",
+        SNAPSHOT_MOUNT,
+        "--debug",
+    ]
+    .map(str::to_string)
+    .to_vec();
+
+    let block_production = match node_config.block_production {
+        Trigger::Instant => ["--poa-instant".to_string(), "true".to_string()],
+        Trigger::Never => ["--poa-instant".to_string(), "false".to_string()],
+        Trigger::Interval { block_time } => {
+            ["--poa-interval-period".to_string(), format!("{}ms", block_time.as_millis())]
+        }
+        Trigger::Open { period } => {
+            ["--poa-open-period".to_string(), format!("{}ms", period.as_millis())]
+        }
+    };
+    args.extend(block_production);
+    args.extend([
+        "--starting-gas-price".to_string(),
+        node_config.starting_gas_price.to_string(),
+    ]);
+
+    args
+}
+
+// Writes the genesis as a fuel-core snapshot directory under the temp dir
+fn write_snapshot(chain_config: ChainConfig, state_config: StateConfig) -> Result<PathBuf> {
+    let snapshot_dir = std::env::temp_dir().join(format!(
+        "rosetta-fuel-core-{}-{}",
+        std::process::id(),
+        NEXT_SNAPSHOT.fetch_add(1, Ordering::SeqCst)
+    ));
+    std::fs::create_dir_all(&snapshot_dir).map_err(|e| {
+        Error::Other(format!("failed to create {}: {}", snapshot_dir.display(), e))
+    })?;
+    SnapshotWriter::json(&snapshot_dir)
+        .write_state_config(state_config, &chain_config)
+        .map_err(|e| Error::Other(format!("failed to write the genesis snapshot: {}", e)))?;
+
+    Ok(snapshot_dir)
+}
+
+fn container_error(version: &str, error: impl std::fmt::Display) -> Error {
+    Error::Other(format!("fuel-core {} container: {}", version, error))
+}
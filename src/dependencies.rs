@@ -0,0 +1,58 @@
+//! Finding the contracts a call depends on.
+//!
+//! A call into a contract that calls other contracts must carry those as
+//! inputs, or the node reverts it with `ContractNotInInputs`. Instead of
+//! hand-listing them with `with_contract_ids`, [`with_dependencies`] lets the
+//! SDK estimate them: it dry-runs the call, adds every contract the run
+//! reports missing and repeats until no new one turns up.
+//!
+//! ```ignore
+//! let call = router.methods().deposit(vault_id, user).call_params(params)?;
+//! let response = with_dependencies(call).await?.call().await?;
+//! ```
+//!
+//! Each round is a dry run, so listing the ids by hand stays cheaper where
+//! they are known up front.
+
+use std::fmt::Debug;
+
+use fuels::{
+    core::traits::{Parameterize, Tokenizable},
+    prelude::*,
+    programs::calls::ContractCall,
+    types::ContractId,
+};
+
+/// Rounds of estimation before giving up; each round finds at least one
+/// contract, so this bounds the depth of nested calls.
+pub const MAX_DEPENDENCY_ROUNDS: usize = 10;
+
+/// Adds every external contract `call` needs to its inputs.
+///
+/// A call that reverts for another reason is returned as is, so submitting it
+/// reports the real revert.
+pub async fn with_dependencies<A, T>(
+    mut call: CallHandler<A, ContractCall, T>,
+) -> Result<CallHandler<A, ContractCall, T>>
+where
+    A: Account,
+    T: Tokenizable + Parameterize + Debug,
+{
+    for _ in 0..MAX_DEPENDENCY_ROUNDS {
+        let listed = call.call.external_contracts.len();
+        call = call.determine_missing_contracts().await?;
+        if call.call.external_contracts.len() == listed {
+            return Ok(call);
+        }
+    }
+
+    Err(Error::Other(format!(
+        "call still misses contracts after {} rounds of estimation",
+        MAX_DEPENDENCY_ROUNDS
+    )))
+}
+
+/// The external contracts `call` carries as inputs, listed or estimated.
+pub fn dependencies_of<A, T>(call: &CallHandler<A, ContractCall, T>) -> &[ContractId] {
+    &call.call.external_contracts
+}
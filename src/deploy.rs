@@ -0,0 +1,847 @@
+//! Deployment helpers for the contracts in this repository.
+//!
+//! Every `deploy_*` helper has a `deploy_*_with_salt` twin. A contract's id is
+//! derived from its bytecode (with configurables applied), its salt and its
+//! initial storage, so [`predict_contract_id`] can compute the address of a
+//! salted deployment before it happens, without a node.
+//!
+//! Contracts too big for a single transaction are deployed through blobs and a
+//! loader, see [`deploy_large_contract`].
+//!
+//! The helpers of the core suite (token, router, vault and asset registry)
+//! accept any [`Account`], so a remote signer such as the AWS KMS one (see
+//! `crate::kms`) can deploy them; the others take a private-key wallet.
+//!
+//! Deploys go through the wallet's provider; one created with
+//! [`connect_with_retry`](crate::retry::connect_with_retry) retries transient
+//! RPC failures during deployment too.
+//!
+//! Every deployed contract id is logged through `tracing` at info level; the
+//! helpers print nothing.
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    core::Configurables,
+    prelude::*,
+    types::{ContractId, Identity, Salt, SizedAsciiString},
+};
+
+use crate::{
+    artifacts,
+    error::{call_with_context, RosettaError, RosettaResult},
+    AssetRegistry, Auction, CounterV1, CounterV2, CrossContractCall, CrossContractCallConfigurables,
+    Escrow, Forwarder, Governance, GovernanceConfigurables, LargeContract, MessageReceiver,
+    MultisigWallet, MultisigWalletConfigurables, Nft, NftConfigurables, OrderBook,
+    PredicateRegistry, Proxy, ProxyConfigurables, ShareVault, ShareVaultConfigurables,
+    SignatureVerifier, Src20Token, Src20TokenConfigurables, Staking, StakingConfigurables, State,
+    TokenAllowance, TokenAllowanceConfigurables, TokenVault, TokenVaultConfigurables, Vesting,
+    VestingConfigurables,
+};
+
+/// Deploys the SRC20 token contract with the given wallet as admin and the given metadata.
+///
+/// `name` must be exactly 7 ASCII characters and `symbol` exactly 5, matching
+/// the `str[7]`/`str[5]` configurables of the contract.
+pub async fn deploy_src20_token<A: Account + Clone>(
+    wallet: A,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+) -> RosettaResult<Src20Token<A>> {
+    deploy_src20_token_with_salt(wallet, name, symbol, decimals, Salt::default()).await
+}
+
+/// Same as [`deploy_src20_token`], deployed with the given salt.
+pub async fn deploy_src20_token_with_salt<A: Account + Clone>(
+    wallet: A,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    salt: Salt,
+) -> RosettaResult<Src20Token<A>> {
+    // Convert name and symbol to SizedAsciiString for contract configurables.
+    let name_bytes: SizedAsciiString<7> = name.try_into()?;
+    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
+
+    // Set up contract configurables (name, symbol, decimals, admin).
+    let configurables = Src20TokenConfigurables::default()
+        .with_NAME(name_bytes)?
+        .with_SYMBOL(symbol_bytes)?
+        .with_DECIMALS(decimals)?
+        .with_ADMIN(Identity::Address(wallet.address().into()))?;
+
+    // Deploy the contract to the node.
+    let deploy_response = Contract::load_from(
+        artifacts::SRC20_TOKEN_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Src20Token", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, name, symbol, "Src20Token deployed");
+    Ok(Src20Token::new(contract_id, wallet))
+}
+
+/// Deploys the CrossContractCall (router) contract with the given wallet as admin.
+pub async fn deploy_cross_contract_call<A: Account + Clone>(
+    admin_wallet: A,
+) -> RosettaResult<CrossContractCall<A>> {
+    deploy_cross_contract_call_with_salt(admin_wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_cross_contract_call`], deployed with the given salt.
+pub async fn deploy_cross_contract_call_with_salt<A: Account + Clone>(
+    admin_wallet: A,
+    salt: Salt,
+) -> RosettaResult<CrossContractCall<A>> {
+    // Set up contract configurables (admin).
+    let configurables = CrossContractCallConfigurables::default()
+        .with_ADMIN(Identity::Address(admin_wallet.address().into()))?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::CROSS_CONTRACT_CALL_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&admin_wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("CrossContractCall", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "CrossContractCall deployed");
+
+    Ok(CrossContractCall::new(contract_id, admin_wallet))
+}
+
+/// Basis points in a whole; a vault withdrawal fee can be at most this.
+pub const MAX_FEE_BPS: u64 = 10_000;
+
+/// Deploys the TokenVault contract, linking it to the given CrossContractCall contract.
+pub async fn deploy_token_vault<A: Account + Clone>(
+    wallet: A,
+    cross_contract_call_contract_instance: &CrossContractCall<A>,
+) -> RosettaResult<TokenVault<A>> {
+    deploy_token_vault_with_salt(
+        wallet,
+        cross_contract_call_contract_instance,
+        Salt::default(),
+    )
+    .await
+}
+
+/// Same as [`deploy_token_vault`], deployed with the given salt.
+pub async fn deploy_token_vault_with_salt<A: Account + Clone>(
+    wallet: A,
+    cross_contract_call_contract_instance: &CrossContractCall<A>,
+    salt: Salt,
+) -> RosettaResult<TokenVault<A>> {
+    deploy_token_vault_with_config(wallet, cross_contract_call_contract_instance, 0, salt).await
+}
+
+/// Same as [`deploy_token_vault`], keeping `fee_bps` basis points of every
+/// withdrawal for the admin to collect.
+pub async fn deploy_token_vault_with_fee<A: Account + Clone>(
+    wallet: A,
+    cross_contract_call_contract_instance: &CrossContractCall<A>,
+    fee_bps: u64,
+) -> RosettaResult<TokenVault<A>> {
+    deploy_token_vault_with_config(
+        wallet,
+        cross_contract_call_contract_instance,
+        fee_bps,
+        Salt::default(),
+    )
+    .await
+}
+
+async fn deploy_token_vault_with_config<A: Account + Clone>(
+    wallet: A,
+    cross_contract_call_contract_instance: &CrossContractCall<A>,
+    fee_bps: u64,
+    salt: Salt,
+) -> RosettaResult<TokenVault<A>> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(Error::Other(format!(
+            "withdrawal fee of {fee_bps} bps exceeds {MAX_FEE_BPS} bps"
+        ))
+        .into());
+    }
+
+    // Set up contract configurables (cross-contract caller, admin, fee).
+    let configurables = TokenVaultConfigurables::default()
+        .with_CROSS_CONTRACT_CALL(ContractId::from(
+            cross_contract_call_contract_instance.contract_id(),
+        ))?
+        .with_ADMIN(Identity::Address(wallet.address().into()))?
+        .with_WITHDRAWAL_FEE_BPS(fee_bps)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::TOKEN_VAULT_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("TokenVault", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "TokenVault deployed");
+
+    Ok(TokenVault::new(contract_id, wallet))
+}
+
+/// Deploys the TokenAllowance contract for the given asset.
+pub async fn deploy_token_allowance(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+) -> RosettaResult<TokenAllowance<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_token_allowance_with_salt(wallet, asset_id, Salt::default()).await
+}
+
+/// Same as [`deploy_token_allowance`], deployed with the given salt.
+pub async fn deploy_token_allowance_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+    salt: Salt,
+) -> RosettaResult<TokenAllowance<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (managed asset).
+    let configurables = TokenAllowanceConfigurables::default().with_ASSET(asset_id)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::TOKEN_ALLOWANCE_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("TokenAllowance", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "TokenAllowance deployed");
+
+    Ok(TokenAllowance::new(contract_id, wallet))
+}
+
+/// Deploys the ShareVault contract over the given underlying asset.
+pub async fn deploy_share_vault(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    underlying: AssetId,
+) -> RosettaResult<ShareVault<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_share_vault_with_salt(wallet, underlying, Salt::default()).await
+}
+
+/// Same as [`deploy_share_vault`], deployed with the given salt.
+pub async fn deploy_share_vault_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    underlying: AssetId,
+    salt: Salt,
+) -> RosettaResult<ShareVault<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (underlying asset).
+    let configurables = ShareVaultConfigurables::default().with_UNDERLYING(underlying)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::SHARE_VAULT_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("ShareVault", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "ShareVault deployed");
+
+    Ok(ShareVault::new(contract_id, wallet))
+}
+
+/// Deploys the AssetRegistry contract.
+pub async fn deploy_asset_registry<A: Account + Clone>(
+    wallet: A,
+) -> RosettaResult<AssetRegistry<A>> {
+    deploy_asset_registry_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_asset_registry`], deployed with the given salt.
+pub async fn deploy_asset_registry_with_salt<A: Account + Clone>(
+    wallet: A,
+    salt: Salt,
+) -> RosettaResult<AssetRegistry<A>> {
+    let deploy_response = Contract::load_from(
+        artifacts::ASSET_REGISTRY_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("AssetRegistry", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "AssetRegistry deployed");
+
+    Ok(AssetRegistry::new(contract_id, wallet))
+}
+
+/// Deploys the Nft collection contract with the given wallet as admin.
+///
+/// `name` must be exactly 7 ASCII characters and `symbol` exactly 5.
+pub async fn deploy_nft(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    name: &str,
+    symbol: &str,
+) -> RosettaResult<Nft<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_nft_with_salt(wallet, name, symbol, Salt::default()).await
+}
+
+/// Same as [`deploy_nft`], deployed with the given salt.
+pub async fn deploy_nft_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    name: &str,
+    symbol: &str,
+    salt: Salt,
+) -> RosettaResult<Nft<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let name_bytes: SizedAsciiString<7> = name.try_into()?;
+    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
+
+    // Set up contract configurables (name, symbol, admin).
+    let configurables = NftConfigurables::default()
+        .with_NAME(name_bytes)?
+        .with_SYMBOL(symbol_bytes)?
+        .with_ADMIN(Identity::Address(wallet.address().into()))?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::NFT_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Nft", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, name, symbol, "Nft deployed");
+
+    Ok(Nft::new(contract_id, wallet))
+}
+
+/// Deploys the Vesting contract for the given asset.
+pub async fn deploy_vesting(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+) -> RosettaResult<Vesting<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_vesting_with_salt(wallet, asset_id, Salt::default()).await
+}
+
+/// Same as [`deploy_vesting`], deployed with the given salt.
+pub async fn deploy_vesting_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+    salt: Salt,
+) -> RosettaResult<Vesting<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (vested asset).
+    let configurables = VestingConfigurables::default().with_ASSET(asset_id)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::VESTING_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Vesting", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Vesting deployed");
+
+    Ok(Vesting::new(contract_id, wallet))
+}
+
+/// Deploys the Escrow contract.
+pub async fn deploy_escrow(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<Escrow<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_escrow_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_escrow`], deployed with the given salt.
+pub async fn deploy_escrow_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<Escrow<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::ESCROW_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Escrow", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Escrow deployed");
+
+    Ok(Escrow::new(contract_id, wallet))
+}
+
+/// Deploys the Auction contract.
+pub async fn deploy_auction(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<Auction<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_auction_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_auction`], deployed with the given salt.
+pub async fn deploy_auction_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<Auction<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::AUCTION_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Auction", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Auction deployed");
+
+    Ok(Auction::new(contract_id, wallet))
+}
+
+/// Deploys the Staking contract paying `reward_per_block` of `reward_asset`
+/// to stakers of `stake_asset`.
+pub async fn deploy_staking(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    stake_asset: AssetId,
+    reward_asset: AssetId,
+    reward_per_block: u64,
+) -> RosettaResult<Staking<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_staking_with_salt(
+        wallet,
+        stake_asset,
+        reward_asset,
+        reward_per_block,
+        Salt::default(),
+    )
+    .await
+}
+
+/// Same as [`deploy_staking`], deployed with the given salt.
+pub async fn deploy_staking_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    stake_asset: AssetId,
+    reward_asset: AssetId,
+    reward_per_block: u64,
+    salt: Salt,
+) -> RosettaResult<Staking<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (staked asset, reward asset, emission rate).
+    let configurables = StakingConfigurables::default()
+        .with_STAKE_ASSET(stake_asset)?
+        .with_REWARD_ASSET(reward_asset)?
+        .with_REWARD_PER_BLOCK(reward_per_block)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::STAKING_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Staking", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Staking deployed");
+
+    Ok(Staking::new(contract_id, wallet))
+}
+
+/// Deploys the Governance contract voted on with `gov_asset`.
+pub async fn deploy_governance(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    gov_asset: AssetId,
+    quorum: u64,
+    voting_period: u32,
+) -> RosettaResult<Governance<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_governance_with_salt(wallet, gov_asset, quorum, voting_period, Salt::default()).await
+}
+
+/// Same as [`deploy_governance`], deployed with the given salt.
+pub async fn deploy_governance_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    gov_asset: AssetId,
+    quorum: u64,
+    voting_period: u32,
+    salt: Salt,
+) -> RosettaResult<Governance<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (voting token, quorum, voting period).
+    let configurables = GovernanceConfigurables::default()
+        .with_GOV_ASSET(gov_asset)?
+        .with_QUORUM(quorum)?
+        .with_VOTING_PERIOD(voting_period)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::GOVERNANCE_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Governance", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Governance deployed");
+
+    Ok(Governance::new(contract_id, wallet))
+}
+
+/// Deploys the MultisigWallet contract owned by `owners`, executing proposals
+/// approved by `threshold` of them.
+pub async fn deploy_multisig_wallet(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    owners: [Identity; 3],
+    threshold: u64,
+) -> RosettaResult<MultisigWallet<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_multisig_wallet_with_salt(wallet, owners, threshold, Salt::default()).await
+}
+
+/// Same as [`deploy_multisig_wallet`], deployed with the given salt.
+pub async fn deploy_multisig_wallet_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    owners: [Identity; 3],
+    threshold: u64,
+    salt: Salt,
+) -> RosettaResult<MultisigWallet<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (owners, approval threshold).
+    let configurables = MultisigWalletConfigurables::default()
+        .with_OWNERS(owners)?
+        .with_THRESHOLD(threshold)?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::MULTISIG_WALLET_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("MultisigWallet", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "MultisigWallet deployed");
+
+    Ok(MultisigWallet::new(contract_id, wallet))
+}
+
+/// Deploys version 1 of the proxied counter.
+pub async fn deploy_counter_v1(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<CounterV1<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_counter_v1_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_counter_v1`], deployed with the given salt.
+pub async fn deploy_counter_v1_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<CounterV1<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::COUNTER_V1_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("CounterV1", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "CounterV1 deployed");
+
+    Ok(CounterV1::new(contract_id, wallet))
+}
+
+/// Deploys version 2 of the proxied counter.
+pub async fn deploy_counter_v2(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<CounterV2<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_counter_v2_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_counter_v2`], deployed with the given salt.
+pub async fn deploy_counter_v2_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<CounterV2<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::COUNTER_V2_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("CounterV2", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "CounterV2 deployed");
+
+    Ok(CounterV2::new(contract_id, wallet))
+}
+
+/// Deploys an SRC-14 proxy forwarding to `target`, owned by the given wallet,
+/// and initializes it.
+///
+/// Calls through the proxy must list the current target with
+/// `with_contract_ids`, as the node only sees the proxy in the call.
+pub async fn deploy_proxy(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    target: ContractId,
+) -> RosettaResult<Proxy<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_proxy_with_salt(wallet, target, Salt::default()).await
+}
+
+/// Same as [`deploy_proxy`], deployed with the given salt.
+pub async fn deploy_proxy_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    target: ContractId,
+    salt: Salt,
+) -> RosettaResult<Proxy<Wallet<Unlocked<PrivateKeySigner>>>> {
+    // Set up contract configurables (initial target and owner).
+    let configurables = ProxyConfigurables::default()
+        .with_INITIAL_TARGET(Some(target))?
+        .with_INITIAL_OWNER(State::Initialized(Identity::Address(wallet.address().into())))?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::PROXY_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Proxy", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Proxy deployed");
+
+    let proxy = Proxy::new(contract_id, wallet);
+    call_with_context(proxy.methods().initialize_proxy()).await?;
+
+    Ok(proxy)
+}
+
+/// Deploys the MessageReceiver contract.
+pub async fn deploy_message_receiver(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<MessageReceiver<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_message_receiver_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_message_receiver`], deployed with the given salt.
+pub async fn deploy_message_receiver_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<MessageReceiver<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::MESSAGE_RECEIVER_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("MessageReceiver", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "MessageReceiver deployed");
+
+    Ok(MessageReceiver::new(contract_id, wallet))
+}
+
+/// Deploys the OrderBook contract.
+pub async fn deploy_order_book<A: Account + Clone>(wallet: A) -> RosettaResult<OrderBook<A>> {
+    deploy_order_book_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_order_book`], deployed with the given salt.
+pub async fn deploy_order_book_with_salt<A: Account + Clone>(
+    wallet: A,
+    salt: Salt,
+) -> RosettaResult<OrderBook<A>> {
+    let deploy_response = Contract::load_from(
+        artifacts::ORDER_BOOK_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("OrderBook", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "OrderBook deployed");
+
+    Ok(OrderBook::new(contract_id, wallet))
+}
+
+/// Deploys the PredicateRegistry contract.
+pub async fn deploy_predicate_registry(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+) -> RosettaResult<PredicateRegistry<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_predicate_registry_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_predicate_registry`], deployed with the given salt.
+pub async fn deploy_predicate_registry_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    salt: Salt,
+) -> RosettaResult<PredicateRegistry<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::PREDICATE_REGISTRY_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("PredicateRegistry", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "PredicateRegistry deployed");
+
+    Ok(PredicateRegistry::new(contract_id, wallet))
+}
+
+/// Deploys the SignatureVerifier contract.
+pub async fn deploy_signature_verifier<A: Account + Clone>(
+    wallet: A,
+) -> RosettaResult<SignatureVerifier<A>> {
+    deploy_signature_verifier_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_signature_verifier`], deployed with the given salt.
+pub async fn deploy_signature_verifier_with_salt<A: Account + Clone>(
+    wallet: A,
+    salt: Salt,
+) -> RosettaResult<SignatureVerifier<A>> {
+    let deploy_response = Contract::load_from(
+        artifacts::SIGNATURE_VERIFIER_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("SignatureVerifier", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "SignatureVerifier deployed");
+
+    Ok(SignatureVerifier::new(contract_id, wallet))
+}
+
+/// Deploys the Forwarder contract.
+pub async fn deploy_forwarder<A: Account + Clone>(wallet: A) -> RosettaResult<Forwarder<A>> {
+    deploy_forwarder_with_salt(wallet, Salt::default()).await
+}
+
+/// Same as [`deploy_forwarder`], deployed with the given salt.
+pub async fn deploy_forwarder_with_salt<A: Account + Clone>(
+    wallet: A,
+    salt: Salt,
+) -> RosettaResult<Forwarder<A>> {
+    let deploy_response = Contract::load_from(
+        artifacts::FORWARDER_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .map_err(|e| RosettaError::deploy_failed("Forwarder", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, "Forwarder deployed");
+
+    Ok(Forwarder::new(contract_id, wallet))
+}
+
+/// Words per blob used by [`deploy_large_contract`]. A blob transaction must
+/// stay under the transaction size limit, so this is kept well below it.
+pub const DEFAULT_BLOB_WORDS: usize = 8_192;
+
+/// Deploys the LargeContract through blobs and a loader.
+///
+/// The bytecode is split into blobs of at most `max_words_per_blob` words,
+/// each uploaded in its own transaction. The deployed contract is a small
+/// loader that pulls the blobs in when called, so neither the blobs nor the
+/// loader run into the contract size limit. Blob ids are content hashes, so
+/// blobs already on chain are not uploaded twice.
+pub async fn deploy_large_contract(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    max_words_per_blob: usize,
+) -> RosettaResult<LargeContract<Wallet<Unlocked<PrivateKeySigner>>>> {
+    deploy_large_contract_with_salt(wallet, max_words_per_blob, Salt::default()).await
+}
+
+/// Same as [`deploy_large_contract`], deployed with the given salt.
+pub async fn deploy_large_contract_with_salt(
+    wallet: Wallet<Unlocked<PrivateKeySigner>>,
+    max_words_per_blob: usize,
+    salt: Salt,
+) -> RosettaResult<LargeContract<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let contract = Contract::load_from(
+        artifacts::LARGE_CONTRACT_BIN,
+        LoadConfiguration::default().with_salt(salt),
+    )?
+    .convert_to_loader(max_words_per_blob)?;
+    let blob_count = contract.blobs().len();
+
+    let deploy_response = contract
+        .deploy(&wallet, TxPolicies::default())
+        .await
+        .map_err(|e| RosettaError::deploy_failed("LargeContract", e))?;
+
+    let contract_id = deploy_response.contract_id;
+
+    tracing::info!(%contract_id, blob_count, "LargeContract deployed as a loader");
+
+    Ok(LargeContract::new(contract_id, wallet))
+}
+
+/// Predicts the ContractId that `binary_filepath` deploys to with the given
+/// configurables and salt, without a node.
+///
+/// Pass the same configurables the deploy helper sets, e.g. the admin address
+/// of the deploying wallet; `Configurables::default()` for contracts without any.
+pub fn predict_contract_id(
+    binary_filepath: &str,
+    configurables: impl Into<Configurables>,
+    salt: Salt,
+) -> Result<ContractId> {
+    let contract = Contract::load_from(
+        binary_filepath,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt(salt),
+    )?;
+
+    Ok(contract.contract_id())
+}
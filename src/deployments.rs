@@ -0,0 +1,180 @@
+//! The deployments registry: `deployments/<network>.json`.
+//!
+//! After deploying, contract ids, the token asset id and the configurables used
+//! are persisted per network, so examples, the CLI and follow-up tests can
+//! reuse an existing deployment instead of redeploying on every run.
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{AssetId, ContractId},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    deploy_asset_registry, deploy_cross_contract_call, deploy_src20_token, deploy_token_vault,
+    error::{call_with_context, RosettaResult},
+    AssetRegistry, CrossContractCall, Src20Token, TokenVault,
+};
+
+/// Directory holding one registry file per network.
+pub const DEPLOYMENTS_DIR: &str = "deployments";
+
+/// Configurables the suite was deployed with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedConfigurables {
+    pub token_name: String,
+    pub token_symbol: String,
+    pub token_decimals: u8,
+    /// Admin address of every contract.
+    pub admin: String,
+}
+
+/// Contract ids and asset ids of one deployment of the suite.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployments {
+    pub network: String,
+    pub node_url: String,
+    pub src20_token: String,
+    pub asset_id: String,
+    pub cross_contract_call: String,
+    pub token_vault: String,
+    /// Missing in registries written before the asset registry existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_registry: Option<String>,
+    pub configurables: DeployedConfigurables,
+}
+
+/// Typed contract handles for a loaded deployment.
+pub struct DeployedSuite {
+    pub token: Src20Token<Wallet<Unlocked<PrivateKeySigner>>>,
+    pub vault: TokenVault<Wallet<Unlocked<PrivateKeySigner>>>,
+    pub cross_contract_call: CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>,
+    pub asset_registry: Option<AssetRegistry<Wallet<Unlocked<PrivateKeySigner>>>>,
+    pub asset_id: AssetId,
+}
+
+impl Deployments {
+    /// `deployments/<network>.json`
+    pub fn path_for(network: &str) -> PathBuf {
+        PathBuf::from(DEPLOYMENTS_DIR).join(format!("{}.json", network))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|e| Error::Other(e.to_string()))
+    }
+
+    /// Loads `deployments/<network>.json`, or `None` if the network has no deployment yet.
+    pub fn load_for_network(network: &str) -> Result<Option<Self>> {
+        let path = Self::path_for(network);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::load(path).map(Some)
+    }
+
+    /// Writes to `path`, creating parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| Error::Other(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Writes to `deployments/<network>.json` and returns the path.
+    pub fn save_for_network(&self) -> Result<PathBuf> {
+        let path = Self::path_for(&self.network);
+        self.save(&path)?;
+        Ok(path)
+    }
+
+    pub fn src20_token_id(&self) -> Result<ContractId> {
+        parse_id(&self.src20_token)
+    }
+
+    pub fn asset_id(&self) -> Result<AssetId> {
+        parse_id(&self.asset_id)
+    }
+
+    pub fn cross_contract_call_id(&self) -> Result<ContractId> {
+        parse_id(&self.cross_contract_call)
+    }
+
+    pub fn token_vault_id(&self) -> Result<ContractId> {
+        parse_id(&self.token_vault)
+    }
+
+    pub fn asset_registry_id(&self) -> Result<Option<ContractId>> {
+        self.asset_registry.as_deref().map(parse_id).transpose()
+    }
+
+    /// Binds the recorded contracts to `wallet`.
+    pub fn connect(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Result<DeployedSuite> {
+        Ok(DeployedSuite {
+            token: Src20Token::new(self.src20_token_id()?, wallet.clone()),
+            vault: TokenVault::new(self.token_vault_id()?, wallet.clone()),
+            cross_contract_call: CrossContractCall::new(self.cross_contract_call_id()?, wallet.clone()),
+            asset_registry: self
+                .asset_registry_id()?
+                .map(|contract_id| AssetRegistry::new(contract_id, wallet.clone())),
+            asset_id: self.asset_id()?,
+        })
+    }
+}
+
+/// Deploys Src20Token, CrossContractCall, TokenVault and AssetRegistry with
+/// `wallet` as admin, registers the token's metadata, and returns the
+/// deployments entry describing them.
+///
+/// A failed deployment is a [`RosettaError::DeployFailed`](crate::RosettaError::DeployFailed);
+/// a failed call afterwards carries the contract, method and transaction.
+pub async fn deploy_suite<A: Account + Clone>(
+    wallet: &A,
+    network: &str,
+    token_name: &str,
+    token_symbol: &str,
+    token_decimals: u8,
+) -> RosettaResult<Deployments> {
+    let token = deploy_src20_token(wallet.clone(), token_name, token_symbol, token_decimals).await?;
+    let cross_contract_call = deploy_cross_contract_call(wallet.clone()).await?;
+    let vault = deploy_token_vault(wallet.clone(), &cross_contract_call).await?;
+    let asset_id = call_with_context(token.methods().get_asset_id()).await?.value;
+
+    let asset_registry = deploy_asset_registry(wallet.clone()).await?;
+    call_with_context(
+        token
+            .methods()
+            .register_with(asset_registry.contract_id().clone(), String::new())
+            .with_contract_ids(&[asset_registry.contract_id().clone()]),
+    )
+    .await?;
+
+    Ok(Deployments {
+        network: network.to_string(),
+        node_url: wallet.try_provider()?.url().to_string(),
+        src20_token: token.contract_id().to_string(),
+        asset_id: asset_id.to_string(),
+        cross_contract_call: cross_contract_call.contract_id().to_string(),
+        token_vault: vault.contract_id().to_string(),
+        asset_registry: Some(asset_registry.contract_id().to_string()),
+        configurables: DeployedConfigurables {
+            token_name: token_name.to_string(),
+            token_symbol: token_symbol.to_string(),
+            token_decimals,
+            admin: wallet.address().to_string(),
+        },
+    })
+}
+
+fn parse_id<T: FromStr>(value: &str) -> Result<T> {
+    T::from_str(value).map_err(|_| Error::Other(format!("invalid id in deployments file: {}", value)))
+}
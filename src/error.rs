@@ -0,0 +1,289 @@
+//! A typed error for the failures tests and helpers care to tell apart.
+//!
+//! The SDK reports most failures as strings inside [`fuels::types::errors::Error`].
+//! [`RosettaError`] keeps the kinds callers match on (an underfunded wallet, a
+//! failed deployment, a revert with its reason) as variants, and wraps every
+//! other SDK error unchanged. It converts both ways, so `?` works in functions
+//! returning either error type.
+//!
+//! ```ignore
+//! match RosettaError::from(call_error) {
+//!     RosettaError::RevertDecoded { reason, .. } => assert!(reason.contains("Unauthorized")),
+//!     other => panic!("unexpected failure: {}", other),
+//! }
+//! ```
+//!
+//! Any error can carry an [`ErrorContext`] saying where it happened: the
+//! contract and method called, the transaction id and the block it landed in.
+//! [`call_with_context`] submits a contract call and attaches all four when it
+//! fails; [`ResultExt::context`] attaches a context by hand. The variant
+//! accessors look through the context, so a revert is still a revert.
+//!
+//! The library's call helpers (deployment, simulation, latency, airdrop,
+//! planner, manual calls) return [`RosettaResult`] with a context attached,
+//! and the `rosetta` CLI prints it with the error.
+
+use std::fmt::{self, Debug};
+
+use fuels::{
+    core::traits::{Parameterize, Tokenizable},
+    prelude::*,
+    programs::{calls::ContractCall, responses::CallResponse},
+    tx::TxId,
+    types::{errors::transaction::Reason, AssetId, ContractId},
+};
+
+use crate::{queries::transaction_by_id, revert::RevertReason};
+
+/// The SDK's error type.
+pub use fuels::types::errors::Error as SdkError;
+
+/// Result of the helpers that return a [`RosettaError`].
+pub type RosettaResult<T> = std::result::Result<T, RosettaError>;
+
+/// Where a failure happened; each part is known only for some failures.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub contract_id: Option<ContractId>,
+    /// Name of the contract method called.
+    pub method: Option<String>,
+    pub tx_id: Option<TxId>,
+    /// Block the failed transaction was committed in.
+    pub block_height: Option<u32>,
+}
+
+impl ErrorContext {
+    /// The contract and method of `call`.
+    pub fn for_call(call: &ContractCall) -> Self {
+        // The selector is the method name behind its u64 length
+        let method = call
+            .encoded_selector
+            .get(8..)
+            .and_then(|name| std::str::from_utf8(name).ok())
+            .map(str::to_string);
+        ErrorContext {
+            contract_id: Some(call.contract_id),
+            method,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_tx_id(mut self, tx_id: TxId) -> Self {
+        self.tx_id = Some(tx_id);
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u32) -> Self {
+        self.block_height = Some(block_height);
+        self
+    }
+
+    /// Adds the block the context's transaction was committed in, if the node
+    /// knows the transaction; otherwise the context is returned unchanged.
+    pub async fn with_committed_block(mut self, provider: &Provider) -> Self {
+        if let Some(tx_id) = &self.tx_id {
+            let summary = transaction_by_id(provider, tx_id).await.ok().flatten();
+            if let Some(block_height) = summary.and_then(|summary| summary.block_height) {
+                self.block_height = Some(block_height);
+            }
+        }
+        self
+    }
+
+    // Fills the parts missing here from `other`
+    fn merge(self, other: ErrorContext) -> Self {
+        ErrorContext {
+            contract_id: self.contract_id.or(other.contract_id),
+            method: self.method.or(other.method),
+            tx_id: self.tx_id.or(other.tx_id),
+            block_height: self.block_height.or(other.block_height),
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(contract_id) = &self.contract_id {
+            parts.push(format!("contract {}", contract_id));
+        }
+        if let Some(method) = &self.method {
+            parts.push(format!("method {}", method));
+        }
+        if let Some(tx_id) = &self.tx_id {
+            parts.push(format!("tx {}", tx_id));
+        }
+        if let Some(block_height) = self.block_height {
+            parts.push(format!("block {}", block_height));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RosettaError {
+    /// A wallet holds less of an asset than an operation needs.
+    #[error("insufficient balance of {asset_id}: need {needed}, have {available}")]
+    InsufficientBalance {
+        asset_id: AssetId,
+        needed: u128,
+        available: u128,
+    },
+    /// Deploying a contract failed.
+    #[error("deploying {contract} failed: {source}")]
+    DeployFailed {
+        contract: &'static str,
+        #[source]
+        source: SdkError,
+    },
+    /// A transaction reverted; `reason` is the require message or panic the
+    /// SDK decoded from the receipts, `decoded` what it means.
+    #[error("transaction reverted: {reason}")]
+    RevertDecoded {
+        reason: String,
+        revert_id: Option<u64>,
+        decoded: RevertReason,
+    },
+    /// A contract binary does not hash to the value its bytecode manifest
+    /// records, see [`crate::integrity`].
+    #[error("bytecode of {artifact} does not match its manifest: expected {expected}, is {actual}")]
+    BytecodeMismatch {
+        artifact: String,
+        expected: String,
+        actual: String,
+    },
+    /// A transaction was committed with a failure status.
+    #[error("transaction failed: {0}")]
+    TransactionFailed(String),
+    /// A transaction has no final status yet.
+    #[error("transaction still pending")]
+    TransactionPending,
+    /// Any other SDK error.
+    #[error(transparent)]
+    Sdk(SdkError),
+    /// Another error and where it happened.
+    #[error("{source} ({context})")]
+    WithContext {
+        context: ErrorContext,
+        #[source]
+        source: Box<RosettaError>,
+    },
+}
+
+impl RosettaError {
+    pub fn deploy_failed(contract: &'static str, source: SdkError) -> Self {
+        RosettaError::DeployFailed { contract, source }
+    }
+
+    /// Attaches `context`, keeping any parts already attached.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            RosettaError::WithContext { context: inner, source } => RosettaError::WithContext {
+                context: inner.merge(context),
+                source,
+            },
+            other => RosettaError::WithContext {
+                context,
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// Where the error happened, if a context was attached.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            RosettaError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// The error without its context.
+    pub fn kind(&self) -> &RosettaError {
+        match self {
+            RosettaError::WithContext { source, .. } => source.kind(),
+            other => other,
+        }
+    }
+
+    /// The revert reason as described by the SDK, if this is a revert.
+    pub fn revert_reason(&self) -> Option<&str> {
+        match self.kind() {
+            RosettaError::RevertDecoded { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// The decoded revert reason, if this is a revert.
+    pub fn decoded_revert(&self) -> Option<&RevertReason> {
+        match self.kind() {
+            RosettaError::RevertDecoded { decoded, .. } => Some(decoded),
+            _ => None,
+        }
+    }
+}
+
+// Reverts are pulled out of the SDK error so they can be matched on
+impl From<SdkError> for RosettaError {
+    fn from(error: SdkError) -> Self {
+        match error {
+            SdkError::Transaction(Reason::Failure {
+                reason, revert_id, ..
+            }) => RosettaError::RevertDecoded {
+                decoded: RevertReason::decode(&reason, revert_id),
+                reason,
+                revert_id,
+            },
+            other => RosettaError::Sdk(other),
+        }
+    }
+}
+
+impl From<RosettaError> for SdkError {
+    fn from(error: RosettaError) -> Self {
+        match error {
+            RosettaError::Sdk(error) => error,
+            other => SdkError::Other(other.to_string()),
+        }
+    }
+}
+
+/// Attaches an [`ErrorContext`] to the error of a result.
+pub trait ResultExt<T> {
+    fn context(self, context: &ErrorContext) -> RosettaResult<T>;
+}
+
+impl<T, E: Into<RosettaError>> ResultExt<T> for std::result::Result<T, E> {
+    fn context(self, context: &ErrorContext) -> RosettaResult<T> {
+        self.map_err(|error| error.into().with_context(context.clone()))
+    }
+}
+
+/// Submits `call` like `CallHandler::call`, attaching its contract id, method,
+/// transaction id and, once committed, block height to any error.
+pub async fn call_with_context<A, T>(
+    call: CallHandler<A, ContractCall, T>,
+) -> RosettaResult<CallResponse<T>>
+where
+    A: Account,
+    T: Tokenizable + Parameterize + Debug,
+{
+    let mut context = ErrorContext::for_call(&call.call);
+    let provider = call.account.try_provider().context(&context)?.clone();
+
+    let chain_id = provider.consensus_parameters().await.context(&context)?.chain_id();
+    let tx = call.build_tx().await.context(&context)?;
+    let tx_id = tx.id(chain_id);
+    context = context.with_tx_id(tx_id);
+
+    let status = provider.send_transaction_and_await_commit(tx).await.context(&context)?;
+    match call.get_response(status) {
+        Ok(mut response) => {
+            response.tx_id = Some(tx_id);
+            Ok(response)
+        }
+        Err(error) => {
+            let context = context.with_committed_block(&provider).await;
+            Err(RosettaError::from(error).with_context(context))
+        }
+    }
+}
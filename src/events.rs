@@ -0,0 +1,212 @@
+//! Typed decoding of the events logged by the token and vault contracts.
+//!
+//! The event structs are the ones abigen generates from the contract ABIs, so
+//! they always match what the contracts log. Instead of printing
+//! `decode_logs().results`, tests pull out the events they expect:
+//!
+//! ```ignore
+//! let response = token.methods().mint(recipient, Some(SUB_ID), 100).call().await?;
+//! let mint: MintEvent = expect_single_event(&response)?;
+//! assert_eq!(mint.amount, 100);
+//! ```
+//!
+//! [`ContractEvent::decode_all`] tries every event type on every log. The
+//! indexer decodes many receipts with the same few log ids, so it uses an
+//! [`EventDecoder`] instead: it learns which event type each log id carries
+//! once and then decodes logs straight from the borrowed receipt data.
+
+use std::collections::HashMap;
+
+use fuels::{
+    core::{
+        codec::{ABIDecoder, LogDecoder},
+        traits::{Parameterize, Tokenizable},
+    },
+    prelude::*,
+    programs::responses::CallResponse,
+    tx::Receipt,
+    types::ContractId,
+};
+
+pub use crate::{BurnEvent, DepositEvent, MintEvent, WithdrawEvent};
+
+/// Decodes every log of type `T` emitted by the call, in emission order.
+pub fn decode_events_of<T, D>(response: &CallResponse<D>) -> Result<Vec<T>>
+where
+    T: Tokenizable + Parameterize + 'static,
+{
+    response.decode_logs_with_type::<T>()
+}
+
+/// Decodes the only log of type `T` emitted by the call.
+///
+/// Fails if the call emitted none or more than one, so a test asserting on
+/// the event also asserts it was logged exactly once.
+pub fn expect_single_event<T, D>(response: &CallResponse<D>) -> Result<T>
+where
+    T: Tokenizable + Parameterize + 'static,
+{
+    let mut events = decode_events_of::<T, D>(response)?;
+    match events.len() {
+        1 => Ok(events.remove(0)),
+        count => Err(Error::Other(format!(
+            "expected one {} event, found {}",
+            std::any::type_name::<T>(),
+            count
+        ))),
+    }
+}
+
+/// One of the token or vault events.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractEvent {
+    Mint(MintEvent),
+    Burn(BurnEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+}
+
+impl ContractEvent {
+    /// Decodes the token and vault events in `receipts`, in emission order.
+    ///
+    /// `decoder` must know the logs of the emitting contracts, e.g. the
+    /// `log_decoder()` of the token merged with that of the vault. Logs of
+    /// other types are skipped.
+    pub fn decode_all(decoder: &LogDecoder, receipts: &[Receipt]) -> Result<Vec<ContractEvent>> {
+        let mut events = vec![];
+        for receipt in receipts {
+            if !matches!(receipt, Receipt::LogData { .. }) {
+                continue;
+            }
+            // Decoding one receipt at a time keeps the events in order
+            let receipt = std::slice::from_ref(receipt);
+            if let Some(event) = decoder.decode_logs_with_type::<MintEvent>(receipt)?.pop() {
+                events.push(ContractEvent::Mint(event));
+            } else if let Some(event) = decoder.decode_logs_with_type::<BurnEvent>(receipt)?.pop() {
+                events.push(ContractEvent::Burn(event));
+            } else if let Some(event) =
+                decoder.decode_logs_with_type::<DepositEvent>(receipt)?.pop()
+            {
+                events.push(ContractEvent::Deposit(event));
+            } else if let Some(event) =
+                decoder.decode_logs_with_type::<WithdrawEvent>(receipt)?.pop()
+            {
+                events.push(ContractEvent::Withdraw(event));
+            }
+        }
+        Ok(events)
+    }
+
+    fn kind(&self) -> EventKind {
+        match self {
+            ContractEvent::Mint(_) => EventKind::Mint,
+            ContractEvent::Burn(_) => EventKind::Burn,
+            ContractEvent::Deposit(_) => EventKind::Deposit,
+            ContractEvent::Withdraw(_) => EventKind::Withdraw,
+        }
+    }
+
+    /// Amount of asset minted, burned, deposited or withdrawn.
+    pub fn amount(&self) -> u64 {
+        match self {
+            ContractEvent::Mint(event) => event.amount,
+            ContractEvent::Burn(event) => event.amount,
+            ContractEvent::Deposit(event) => event.amount,
+            ContractEvent::Withdraw(event) => event.amount,
+        }
+    }
+
+    /// The asset the event is about.
+    pub fn asset_id(&self) -> AssetId {
+        match self {
+            ContractEvent::Mint(event) => event.asset_id,
+            ContractEvent::Burn(event) => event.asset_id,
+            ContractEvent::Deposit(event) => event.asset_id,
+            ContractEvent::Withdraw(event) => event.asset_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventKind {
+    Mint,
+    Burn,
+    Deposit,
+    Withdraw,
+}
+
+/// Decodes the events of a fixed set of contracts from receipts.
+///
+/// The first log with a given contract and log id is decoded through the
+/// `LogDecoder` to find out which event, if any, it carries. Later logs with
+/// the same id are decoded directly from the receipt's data, without the
+/// per-type lookups and copies of `LogDecoder::decode_logs_with_type`.
+#[derive(Debug, Clone)]
+pub struct EventDecoder {
+    decoder: LogDecoder,
+    contracts: Vec<ContractId>,
+    kinds: HashMap<(ContractId, u64), Option<EventKind>>,
+}
+
+impl EventDecoder {
+    /// `decoder` must know the log types of every contract in `contracts`.
+    pub fn new(decoder: LogDecoder, contracts: Vec<ContractId>) -> Self {
+        Self {
+            decoder,
+            contracts,
+            kinds: HashMap::new(),
+        }
+    }
+
+    pub fn contracts(&self) -> &[ContractId] {
+        &self.contracts
+    }
+
+    /// Decodes the events the watched contracts logged in `receipts`, in emission order.
+    pub fn decode(&mut self, receipts: &[Receipt]) -> Result<Vec<(ContractId, ContractEvent)>> {
+        let mut events = vec![];
+        for receipt in receipts {
+            let Receipt::LogData {
+                id,
+                rb,
+                data: Some(data),
+                ..
+            } = receipt
+            else {
+                continue;
+            };
+            if !self.contracts.contains(id) {
+                continue;
+            }
+
+            let event = match self.kinds.get(&(*id, *rb)) {
+                Some(Some(kind)) => decode_kind(*kind, &data[..])?,
+                Some(None) => continue,
+                None => {
+                    let receipt = std::slice::from_ref(receipt);
+                    let event = ContractEvent::decode_all(&self.decoder, receipt)?.pop();
+                    self.kinds.insert((*id, *rb), event.as_ref().map(ContractEvent::kind));
+                    match event {
+                        Some(event) => event,
+                        None => continue,
+                    }
+                }
+            };
+            events.push((*id, event));
+        }
+        Ok(events)
+    }
+}
+
+fn decode_kind(kind: EventKind, data: &[u8]) -> Result<ContractEvent> {
+    Ok(match kind {
+        EventKind::Mint => ContractEvent::Mint(decode_log(data)?),
+        EventKind::Burn => ContractEvent::Burn(decode_log(data)?),
+        EventKind::Deposit => ContractEvent::Deposit(decode_log(data)?),
+        EventKind::Withdraw => ContractEvent::Withdraw(decode_log(data)?),
+    })
+}
+
+fn decode_log<T: Tokenizable + Parameterize>(data: &[u8]) -> Result<T> {
+    T::from_token(ABIDecoder::default().decode(&T::param_type(), data)?)
+}
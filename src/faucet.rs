@@ -0,0 +1,144 @@
+//! Funding fresh wallets from a testnet faucet.
+//!
+//! [`request_funds`] asks a faucet to dispense base asset to an address, and
+//! [`wait_for_balance`] polls the node until it shows up, so an example can
+//! start from a wallet nobody funded by hand:
+//!
+//! ```ignore
+//! let funded = fund_from_faucet(&wallet, &FaucetConfig::default()).await?;
+//! println!("{} received {}", wallet.address(), funded);
+//! ```
+//!
+//! The faucet is a plain HTTP service (`POST <url>/dispense` with the address
+//! as JSON). Faucets behind a captcha, such as the public testnet one when it
+//! is under load, reject unattended requests; fund the wallet in a browser then.
+//!
+//! Funds that do not arrive in time are a [`RosettaError::InsufficientBalance`]
+//! with the last block the balance was read at attached.
+
+use std::time::{Duration, Instant};
+
+use fuels::{prelude::*, types::Address};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorContext, RosettaError, RosettaResult};
+
+/// URL of the public testnet faucet.
+pub const TESTNET_FAUCET_URL: &str = "https://faucet-testnet.fuel.network";
+/// Environment variable overriding the faucet URL.
+pub const FAUCET_URL_ENV: &str = "ROSETTA_FAUCET_URL";
+
+/// Where to request funds and how long to wait for them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaucetConfig {
+    pub url: String,
+    /// Delay between two balance checks.
+    pub poll_interval: Duration,
+    /// How long to wait for the funds before giving up.
+    pub timeout: Duration,
+}
+
+impl Default for FaucetConfig {
+    fn default() -> Self {
+        Self {
+            url: std::env::var(FAUCET_URL_ENV).unwrap_or_else(|_| TESTNET_FAUCET_URL.to_string()),
+            poll_interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+impl FaucetConfig {
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    pub fn with_polling(mut self, poll_interval: Duration, timeout: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Body of a dispense request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DispenseRequest {
+    pub address: String,
+    /// Left empty; only faucets without a captcha accept the request.
+    #[serde(default)]
+    pub captcha: String,
+}
+
+/// Asks the faucet at `faucet_url` to send base asset to `address`.
+pub async fn request_funds(faucet_url: &str, address: Address) -> Result<()> {
+    let url = format!("{}/dispense", faucet_url.trim_end_matches('/'));
+    let request = DispenseRequest {
+        address: address.to_string(),
+        captcha: String::new(),
+    };
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| Error::Provider(format!("faucet request to {} failed: {}", url, e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(Error::Other(format!(
+            "faucet at {} refused to dispense ({}): {}",
+            url, status, body
+        )));
+    }
+    Ok(())
+}
+
+/// Polls the base asset balance of `wallet` until it reaches `min_balance`,
+/// returning the balance, or fails after `timeout`.
+pub async fn wait_for_balance(
+    wallet: &impl ViewOnlyAccount,
+    min_balance: u128,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> RosettaResult<u128> {
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        let block_height = provider.latest_block_height().await?;
+        let balance = wallet.get_asset_balance(&base_asset_id).await?;
+        if balance >= min_balance {
+            return Ok(balance);
+        }
+        if Instant::now() >= deadline {
+            return Err(RosettaError::InsufficientBalance {
+                asset_id: base_asset_id,
+                needed: min_balance,
+                available: balance,
+            }
+            .with_context(ErrorContext::default().with_block_height(block_height)));
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Requests funds for `wallet` and waits until its base asset balance grows,
+/// returning the new balance.
+pub async fn fund_from_faucet(
+    wallet: &impl ViewOnlyAccount,
+    config: &FaucetConfig,
+) -> RosettaResult<u128> {
+    let base_asset_id = *wallet
+        .try_provider()?
+        .consensus_parameters()
+        .await?
+        .base_asset_id();
+    let before = wallet.get_asset_balance(&base_asset_id).await?;
+
+    request_funds(&config.url, wallet.address()).await?;
+    wait_for_balance(wallet, before + 1, config.poll_interval, config.timeout).await
+}
@@ -0,0 +1,63 @@
+//! Exact transaction fees for tests.
+//!
+//! The node reports the fee it charged in the final transaction status, so
+//! tests can assert balance changes exactly instead of reserving a few units
+//! "for gas" or comparing with inequalities.
+
+use std::{fmt::Debug, ops::RangeBounds};
+
+use fuels::{
+    prelude::*,
+    programs::responses::CallResponse,
+    tx::TxId,
+    types::{
+        tx_response::TxResponse,
+        tx_status::{Failure, Success, TxStatus},
+    },
+};
+
+/// Responses that carry the fee charged for their transaction.
+pub trait FeePaid {
+    fn fee_paid(&self) -> u64;
+}
+
+impl<T> FeePaid for CallResponse<T> {
+    fn fee_paid(&self) -> u64 {
+        self.tx_status.total_fee
+    }
+}
+
+impl FeePaid for TxResponse {
+    fn fee_paid(&self) -> u64 {
+        self.tx_status.total_fee
+    }
+}
+
+/// Fee charged for a committed transaction, whether it succeeded or reverted.
+pub fn fee_from_status(status: &TxStatus) -> Result<u64> {
+    match status {
+        TxStatus::Success(Success { total_fee, .. })
+        | TxStatus::Failure(Failure { total_fee, .. }) => Ok(*total_fee),
+        other => Err(Error::Other(format!(
+            "transaction has no final fee yet: {:?}",
+            other
+        ))),
+    }
+}
+
+/// Looks up the fee charged for `tx_id`.
+pub async fn fee_of(provider: &Provider, tx_id: &TxId) -> Result<u64> {
+    let status = provider.tx_status(tx_id).await?;
+    fee_from_status(&status)
+}
+
+/// Panics unless the fee charged for `response` lies in `expected`.
+pub fn assert_fee_paid(response: &impl FeePaid, expected: impl RangeBounds<u64> + Debug) {
+    let fee = response.fee_paid();
+    assert!(
+        expected.contains(&fee),
+        "fee paid {} is outside the expected range {:?}",
+        fee,
+        expected
+    );
+}
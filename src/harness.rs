@@ -0,0 +1,641 @@
+//! A test harness that boots a local node and deploys the full contract suite.
+//!
+//! Setting `FUEL_NODE_URL` switches every harness (and [`launch_wallets`]) to an
+//! existing fuel-core/devnet/testnet endpoint instead of launching a local node.
+//! Funded keys are then read from `FUEL_WALLET_KEYS` (comma-separated hex
+//! private keys) or derived from the `FUEL_MNEMONIC` phrase (see
+//! [`crate::keys`]), so the same suite runs against a live network; transient
+//! RPC failures are retried (see [`crate::retry`]). Setting
+//! `ROSETTA_SHARED_NODE=1` instead takes wallets from one node shared by every
+//! test of the binary (see [`crate::shared_node`]), and setting
+//! `ROSETTA_FUEL_CORE_VERSION` to a release tag (e.g. `v0.44.0`) runs every
+//! local node in a fuel-core container of that version (feature `docker`, see
+//! `crate::container`).
+//!
+//! A local node can also start from a custom [`ChainConfig`] (e.g. another
+//! base asset) and extra genesis state via [`HarnessConfig::with_chain_config`]
+//! and [`HarnessConfig::with_genesis_state`], and a harness can be saved with
+//! [`TestHarness::snapshot`] and relaunched with [`TestHarness::restore`].
+//! The node itself is tuned with [`HarnessConfig::with_block_time`],
+//! [`HarnessConfig::with_starting_gas_price`] and
+//! [`HarnessConfig::with_max_tx_size`], or a full [`NodeConfig`].
+//!
+//! ```ignore
+//! let harness = TestHarness::new(HarnessConfig::default()).await?;
+//! harness.mint_to(harness.user(0), TOKEN_AMOUNT).await?;
+//! ```
+
+use std::{str::FromStr, time::Duration};
+
+use fuel_core_chain_config::{ChainConfig, CoinConfig, StateConfig};
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    crypto::SecretKey,
+    prelude::*,
+    test_helpers::FuelService,
+    tx::ContractIdExt,
+    types::{Address, Bits256, ContractId, Identity, SubAssetId},
+};
+
+use crate::{
+    deploy_cross_contract_call, deploy_src20_token, deploy_token_vault,
+    keys::{wallets_from_mnemonic, MNEMONIC_ENV},
+    retry::{connect_with_retry, RetryPolicy},
+    shared_node::{shared_wallets, SHARED_NODE_ENV},
+    snapshot::{capture_state, HarnessSnapshot},
+    CrossContractCall, Src20Token, TokenVault, SUB_ID,
+};
+
+/// Environment variable holding the URL of an external node.
+pub const NODE_URL_ENV: &str = "FUEL_NODE_URL";
+/// Environment variable holding comma-separated funded private keys for the external node.
+pub const WALLET_KEYS_ENV: &str = "FUEL_WALLET_KEYS";
+/// Environment variable holding the fuel-core release tag to run in a container.
+pub const FUEL_CORE_VERSION_ENV: &str = "ROSETTA_FUEL_CORE_VERSION";
+
+/// A private-key wallet, as handed out by the harness and [`launch_wallets`].
+pub type TestWallet = Wallet<Unlocked<PrivateKeySigner>>;
+
+/// Where the harness gets its node from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeMode {
+    /// Launch an in-process fuel-core node with freshly funded wallets.
+    Local,
+    /// Connect to an existing node and use pre-funded keys, or the accounts
+    /// derived from `mnemonic` when it is set.
+    External {
+        url: String,
+        wallet_keys: Vec<String>,
+        mnemonic: Option<String>,
+    },
+    /// Take wallets from the in-process node shared by every test of the binary.
+    Shared,
+    /// Launch fuel-core `version` (an image tag such as `v0.44.0`) in a Docker
+    /// container with freshly funded wallets; requires the `docker` feature.
+    Container { version: String },
+}
+
+impl NodeMode {
+    /// `External` when `FUEL_NODE_URL` is set (with keys from `FUEL_WALLET_KEYS`
+    /// or accounts of the `FUEL_MNEMONIC` phrase), `Container` when
+    /// `ROSETTA_FUEL_CORE_VERSION` is set, `Shared` when `ROSETTA_SHARED_NODE`
+    /// is set to `1` or `true`, `Local` otherwise.
+    pub fn from_env() -> Self {
+        match std::env::var(NODE_URL_ENV) {
+            Ok(url) if !url.is_empty() => {
+                let wallet_keys = std::env::var(WALLET_KEYS_ENV)
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                let mnemonic = std::env::var(MNEMONIC_ENV).ok().filter(|m| !m.trim().is_empty());
+                NodeMode::External {
+                    url,
+                    wallet_keys,
+                    mnemonic,
+                }
+            }
+            _ => match std::env::var(FUEL_CORE_VERSION_ENV) {
+                Ok(version) if !version.trim().is_empty() => NodeMode::Container {
+                    version: version.trim().to_string(),
+                },
+                _ => match std::env::var(SHARED_NODE_ENV).as_deref() {
+                    Ok("1") | Ok("true") => NodeMode::Shared,
+                    _ => NodeMode::Local,
+                },
+            },
+        }
+    }
+}
+
+/// Drop-in replacement for `launch_custom_provider_and_get_wallets` that honors
+/// `FUEL_NODE_URL`: against an external node the first `num_wallets` keys from
+/// `FUEL_WALLET_KEYS` (or accounts of `FUEL_MNEMONIC`) are used and the coin
+/// settings are ignored. The coin settings are also ignored on the shared node,
+/// whose wallets are funded up front.
+pub async fn launch_wallets(config: WalletsConfig) -> Result<Vec<TestWallet>> {
+    launch_wallets_with(config, &NodeMode::from_env()).await
+}
+
+/// Like [`launch_wallets`] with an explicit [`NodeMode`].
+pub async fn launch_wallets_with(
+    config: WalletsConfig,
+    mode: &NodeMode,
+) -> Result<Vec<TestWallet>> {
+    match mode {
+        NodeMode::Local => launch_custom_provider_and_get_wallets(config, None, None).await,
+        NodeMode::Shared => shared_wallets(config.num_wallets() as usize).await,
+        NodeMode::Container { .. } => {
+            launch_funded(
+                mode,
+                NodeConfig::default(),
+                ChainConfig::local_testnet(),
+                StateConfig::default(),
+                config.num_wallets(),
+                config.assets(),
+            )
+            .await
+        }
+        NodeMode::External {
+            url,
+            mnemonic: Some(phrase),
+            ..
+        } => {
+            let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+            wallets_from_mnemonic(phrase, config.num_wallets() as usize, provider)
+        }
+        NodeMode::External {
+            url, wallet_keys, ..
+        } => {
+            let needed = config.num_wallets() as usize;
+            if wallet_keys.len() < needed {
+                return Err(Error::Other(format!(
+                    "{} needs {} funded keys in {} or a {} phrase, found {} keys",
+                    url,
+                    needed,
+                    WALLET_KEYS_ENV,
+                    MNEMONIC_ENV,
+                    wallet_keys.len()
+                )));
+            }
+
+            let provider = connect_with_retry(url, &RetryPolicy::default()).await?;
+            wallet_keys
+                .iter()
+                .take(needed)
+                .map(|key| {
+                    let secret_key = SecretKey::from_str(key)?;
+                    Ok(Wallet::new(PrivateKeySigner::new(secret_key), provider.clone()))
+                })
+                .collect()
+        }
+    }
+}
+
+/// Settings for [`TestHarness::new`].
+#[derive(Debug, Clone)]
+pub struct HarnessConfig {
+    /// Number of user wallets created next to the admin wallet.
+    pub num_users: u64,
+    /// Number of base asset coins given to every wallet.
+    pub coins_per_wallet: u64,
+    /// Amount of every base asset coin.
+    pub amount_per_coin: u64,
+    /// Token name, exactly 7 ASCII characters.
+    pub token_name: String,
+    /// Token symbol, exactly 5 ASCII characters.
+    pub token_symbol: String,
+    /// Token decimals.
+    pub token_decimals: u8,
+    /// Local node, container or external endpoint; defaults to [`NodeMode::from_env`].
+    pub node: NodeMode,
+    /// Chain configuration of the local node, e.g. a custom base asset or
+    /// consensus parameters. The wallets are funded in its base asset.
+    pub chain_config: Option<ChainConfig>,
+    /// Genesis coins, messages and contracts of the local node, on top of the
+    /// coins funding the wallets.
+    pub genesis_state: Option<StateConfig>,
+    /// Block production, starting gas price and other settings of the local
+    /// node; instant blocks when unset.
+    pub node_config: Option<NodeConfig>,
+    /// Transaction size limit in bytes, applied on top of the chain config.
+    pub max_tx_size: Option<u64>,
+}
+
+impl Default for HarnessConfig {
+    fn default() -> Self {
+        Self {
+            num_users: 2,
+            coins_per_wallet: 2,
+            amount_per_coin: 1_000_000_000,
+            token_name: "MYTOKEN".to_string(),
+            token_symbol: "TOKEN".to_string(),
+            token_decimals: 9,
+            node: NodeMode::from_env(),
+            chain_config: None,
+            genesis_state: None,
+            node_config: None,
+            max_tx_size: None,
+        }
+    }
+}
+
+impl HarnessConfig {
+    pub fn with_num_users(mut self, num_users: u64) -> Self {
+        self.num_users = num_users;
+        self
+    }
+
+    pub fn with_coins(mut self, coins_per_wallet: u64, amount_per_coin: u64) -> Self {
+        self.coins_per_wallet = coins_per_wallet;
+        self.amount_per_coin = amount_per_coin;
+        self
+    }
+
+    pub fn with_node(mut self, node: NodeMode) -> Self {
+        self.node = node;
+        self
+    }
+
+    pub fn with_token(mut self, name: &str, symbol: &str, decimals: u8) -> Self {
+        self.token_name = name.to_string();
+        self.token_symbol = symbol.to_string();
+        self.token_decimals = decimals;
+        self
+    }
+
+    /// Launches the local node with `chain_config`; requires [`NodeMode::Local`]
+    /// or [`NodeMode::Container`].
+    pub fn with_chain_config(mut self, chain_config: ChainConfig) -> Self {
+        self.chain_config = Some(chain_config);
+        self
+    }
+
+    /// Adds `genesis_state` to the local node's genesis; requires [`NodeMode::Local`]
+    /// or [`NodeMode::Container`].
+    pub fn with_genesis_state(mut self, genesis_state: StateConfig) -> Self {
+        self.genesis_state = Some(genesis_state);
+        self
+    }
+
+    /// Launches the local node with `node_config`; requires [`NodeMode::Local`]
+    /// or [`NodeMode::Container`].
+    pub fn with_node_config(mut self, node_config: NodeConfig) -> Self {
+        self.node_config = Some(node_config);
+        self
+    }
+
+    /// Produces a block every `block_time` instead of one per transaction, so
+    /// every transaction waits for the next block to be committed.
+    pub fn with_block_time(mut self, block_time: Duration) -> Self {
+        self.node_config_mut().block_production = Trigger::Interval { block_time };
+        self
+    }
+
+    /// Starts the node's execution gas price at `gas_price`.
+    pub fn with_starting_gas_price(mut self, gas_price: u64) -> Self {
+        self.node_config_mut().starting_gas_price = gas_price;
+        self
+    }
+
+    /// Limits transactions to `max_size` bytes, overriding the transaction
+    /// parameters of the chain config.
+    pub fn with_max_tx_size(mut self, max_size: u64) -> Self {
+        self.max_tx_size = Some(max_size);
+        self
+    }
+
+    fn node_config_mut(&mut self) -> &mut NodeConfig {
+        self.node_config.get_or_insert_with(NodeConfig::default)
+    }
+
+    fn has_custom_node(&self) -> bool {
+        self.chain_config.is_some()
+            || self.genesis_state.is_some()
+            || self.node_config.is_some()
+            || self.max_tx_size.is_some()
+    }
+}
+
+/// A running local node with funded wallets and the deployed contract suite.
+///
+/// All contract handles are bound to the admin wallet; use [`TestHarness::token_as`]
+/// and [`TestHarness::vault_as`] to call them from another wallet.
+pub struct TestHarness {
+    pub provider: Provider,
+    pub admin: TestWallet,
+    pub users: Vec<TestWallet>,
+    pub token: Src20Token<TestWallet>,
+    pub vault: TokenVault<TestWallet>,
+    pub cross_contract_call: CrossContractCall<TestWallet>,
+    /// Asset id of the token minted by `token`.
+    pub asset_id: AssetId,
+}
+
+impl TestHarness {
+    /// Launches a local node (or connects to the external one), creates the
+    /// admin and user wallets and deploys Src20Token, CrossContractCall and TokenVault.
+    pub async fn new(config: HarnessConfig) -> Result<Self> {
+        let mut wallets = if config.has_custom_node() {
+            if !matches!(config.node, NodeMode::Local | NodeMode::Container { .. }) {
+                return Err(Error::Other(
+                    "a custom chain config, genesis state or node config needs a local node \
+                     or a container"
+                        .to_string(),
+                ));
+            }
+            launch_with_genesis(&config).await?
+        } else {
+            let wallets_config = WalletsConfig::new(
+                Some(config.num_users + 1),
+                Some(config.coins_per_wallet),
+                Some(config.amount_per_coin),
+            );
+            launch_wallets_with(wallets_config, &config.node).await?
+        };
+
+        let admin = wallets.pop().unwrap();
+        let users = wallets;
+        let provider = admin.try_provider()?.clone();
+
+        Self::deploy_suite(provider, admin, users, &config).await
+    }
+
+    // Deploys the contract suite with `admin` and assembles the harness
+    pub(crate) async fn deploy_suite(
+        provider: Provider,
+        admin: TestWallet,
+        users: Vec<TestWallet>,
+        config: &HarnessConfig,
+    ) -> Result<Self> {
+        let token = deploy_src20_token(
+            admin.clone(),
+            &config.token_name,
+            &config.token_symbol,
+            config.token_decimals,
+        )
+        .await?;
+
+        let cross_contract_call = deploy_cross_contract_call(admin.clone()).await?;
+        let vault = deploy_token_vault(admin.clone(), &cross_contract_call).await?;
+
+        let asset_id = token.methods().get_asset_id().call().await?.value;
+
+        Ok(Self {
+            provider,
+            admin,
+            users,
+            token,
+            vault,
+            cross_contract_call,
+            asset_id,
+        })
+    }
+
+    /// The `index`-th user wallet.
+    pub fn user(&self, index: usize) -> &TestWallet {
+        &self.users[index]
+    }
+
+    /// The token contract bound to `wallet`.
+    pub fn token_as(&self, wallet: &TestWallet) -> Src20Token<TestWallet> {
+        self.token.clone().with_account(wallet.clone())
+    }
+
+    /// The vault contract bound to `wallet`.
+    pub fn vault_as(&self, wallet: &TestWallet) -> TokenVault<TestWallet> {
+        self.vault.clone().with_account(wallet.clone())
+    }
+
+    /// Mints `amount` of the harness token to `wallet`.
+    pub async fn mint_to(&self, wallet: &TestWallet, amount: u64) -> Result<()> {
+        self.mint_sub_id_to(wallet, SUB_ID, amount).await?;
+
+        Ok(())
+    }
+
+    /// Mints `amount` of the token's `sub_id` asset to `wallet` and returns its asset id.
+    pub async fn mint_sub_id_to(
+        &self,
+        wallet: &TestWallet,
+        sub_id: Bits256,
+        amount: u64,
+    ) -> Result<AssetId> {
+        self.token
+            .methods()
+            .mint(Identity::Address(wallet.address().into()), Some(sub_id), amount)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+
+        Ok(self.asset_id_for(sub_id))
+    }
+
+    /// The asset id the token mints under `sub_id`, computed without a call.
+    pub fn asset_id_for(&self, sub_id: Bits256) -> AssetId {
+        self.token.contract_id().asset_id(&SubAssetId::from(sub_id.0))
+    }
+
+    /// Balance of the harness token held by `wallet`.
+    pub async fn token_balance(&self, wallet: &TestWallet) -> Result<u128> {
+        wallet.get_asset_balance(&self.asset_id).await
+    }
+
+    /// Captures the coins of the harness wallets and the state of the suite's
+    /// contracts; see [`crate::snapshot`].
+    pub async fn snapshot(&self) -> Result<HarnessSnapshot> {
+        self.snapshot_with(&[], &[]).await
+    }
+
+    /// Like [`TestHarness::snapshot`], also capturing the coins of `owners`
+    /// (e.g. predicates) and contracts deployed after the suite.
+    ///
+    /// Restoring re-derives the wallets from their indexed keys, so the
+    /// harness must run on its own local node.
+    pub async fn snapshot_with(
+        &self,
+        owners: &[Address],
+        contracts: &[ContractId],
+    ) -> Result<HarnessSnapshot> {
+        let wallets = self.users.iter().chain(std::iter::once(&self.admin));
+        for (index, wallet) in wallets.enumerate() {
+            if indexed_signer(index as u64)?.address() != wallet.address() {
+                return Err(Error::Other(
+                    "only a harness on its own local node can be snapshotted".to_string(),
+                ));
+            }
+        }
+
+        let admin = self.admin.address();
+        let users: Vec<Address> = self.users.iter().map(|user| user.address()).collect();
+        let suite = [
+            self.token.contract_id(),
+            self.vault.contract_id(),
+            self.cross_contract_call.contract_id(),
+        ];
+
+        let mut all_owners = users.clone();
+        all_owners.push(admin);
+        all_owners.extend_from_slice(owners);
+        let mut all_contracts = suite.to_vec();
+        all_contracts.extend_from_slice(contracts);
+
+        let chain_config = ChainConfig {
+            consensus_parameters: self.provider.consensus_parameters().await?,
+            ..ChainConfig::local_testnet()
+        };
+        let state = capture_state(&self.provider, &all_owners, &all_contracts).await?;
+
+        Ok(HarnessSnapshot {
+            chain_config,
+            state,
+            admin,
+            users,
+            token: suite[0],
+            vault: suite[1],
+            cross_contract_call: suite[2],
+            asset_id: self.asset_id,
+        })
+    }
+
+    /// Launches a local node from `snapshot` and reattaches the harness to it,
+    /// without deploying anything.
+    pub async fn restore(snapshot: &HarnessSnapshot) -> Result<Self> {
+        let provider = start_local_node(
+            NodeConfig::default(),
+            snapshot.chain_config.clone(),
+            snapshot.state.clone(),
+        )
+        .await?;
+
+        let mut wallets = (0..snapshot.users.len() as u64 + 1)
+            .map(|index| Ok(Wallet::new(indexed_signer(index)?, provider.clone())))
+            .collect::<Result<Vec<_>>>()?;
+        let admin = wallets.pop().unwrap();
+        if admin.address() != snapshot.admin {
+            return Err(Error::Other(format!(
+                "snapshot admin {} does not match the indexed admin key",
+                snapshot.admin
+            )));
+        }
+
+        let cross_contract_call =
+            CrossContractCall::new(snapshot.cross_contract_call, admin.clone());
+        Ok(Self {
+            token: Src20Token::new(snapshot.token, admin.clone()),
+            vault: TokenVault::new(snapshot.vault, admin.clone()),
+            cross_contract_call,
+            provider,
+            admin,
+            users: wallets,
+            asset_id: snapshot.asset_id,
+        })
+    }
+}
+
+/// A signer whose key is derived from `index`, like the SDK's own test wallets.
+///
+/// Keys only need to be distinct on one node, so every in-process node can
+/// reuse the same indices.
+pub(crate) fn indexed_signer(index: u64) -> Result<PrivateKeySigner> {
+    let mut key = [0u8; 32];
+    key[24..].copy_from_slice(&(index + 1).to_be_bytes());
+    let secret_key = SecretKey::try_from(key.as_slice())
+        .map_err(|e| Error::Other(format!("invalid key for index {}: {}", index, e)))?;
+    Ok(PrivateKeySigner::new(secret_key))
+}
+
+// Launches a node for `config.node` with the configured node settings, chain
+// and genesis state, funding the admin and user wallets in the chain's base asset
+async fn launch_with_genesis(config: &HarnessConfig) -> Result<Vec<TestWallet>> {
+    let mut chain_config = config.chain_config.clone().unwrap_or_else(ChainConfig::local_testnet);
+    if let Some(max_size) = config.max_tx_size {
+        let consensus_parameters = &mut chain_config.consensus_parameters;
+        let tx_params = consensus_parameters.tx_params().with_max_size(max_size);
+        consensus_parameters.set_tx_params(tx_params);
+    }
+    let base_asset = AssetConfig {
+        id: *chain_config.consensus_parameters.base_asset_id(),
+        num_coins: config.coins_per_wallet,
+        coin_amount: config.amount_per_coin,
+    };
+    let state_config = config.genesis_state.clone().unwrap_or_default();
+
+    launch_funded(
+        &config.node,
+        config.node_config.clone().unwrap_or_default(),
+        chain_config,
+        state_config,
+        config.num_users + 1,
+        &[base_asset],
+    )
+    .await
+}
+
+// Starts a node for `mode` with `num_wallets` indexed wallets funded with
+// `assets` on top of `state_config`
+async fn launch_funded(
+    mode: &NodeMode,
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    mut state_config: StateConfig,
+    num_wallets: u64,
+    assets: &[AssetConfig],
+) -> Result<Vec<TestWallet>> {
+    let signers = (0..num_wallets)
+        .map(indexed_signer)
+        .collect::<Result<Vec<_>>>()?;
+
+    for signer in &signers {
+        for asset in assets {
+            let coins = setup_single_asset_coins(
+                signer.address(),
+                asset.id,
+                asset.num_coins,
+                asset.coin_amount,
+            );
+            state_config.coins.extend(coins.into_iter().map(|coin| CoinConfig {
+                tx_id: *coin.utxo_id.tx_id(),
+                output_index: coin.utxo_id.output_index(),
+                owner: coin.owner,
+                amount: coin.amount,
+                asset_id: coin.asset_id,
+                ..Default::default()
+            }));
+        }
+    }
+
+    let provider = start_node(mode, node_config, chain_config, state_config).await?;
+
+    Ok(signers
+        .into_iter()
+        .map(|signer| Wallet::new(signer, provider.clone()))
+        .collect())
+}
+
+// Starts the node for `mode` from the given settings and genesis: a container
+// for `Container`, an in-process node otherwise
+async fn start_node(
+    mode: &NodeMode,
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+) -> Result<Provider> {
+    match mode {
+        #[cfg(feature = "docker")]
+        NodeMode::Container { version } => {
+            crate::container::start_container_node(
+                version,
+                &node_config,
+                chain_config,
+                state_config,
+            )
+            .await
+        }
+        #[cfg(not(feature = "docker"))]
+        NodeMode::Container { version } => Err(Error::Other(format!(
+            "running fuel-core {} in a container needs the `docker` feature",
+            version
+        ))),
+        _ => start_local_node(node_config, chain_config, state_config).await,
+    }
+}
+
+// Starts an in-process node from the given genesis and connects to it
+async fn start_local_node(
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+) -> Result<Provider> {
+    let node = FuelService::start(node_config, chain_config, state_config).await?;
+    let provider = Provider::connect(node.bound_address().to_string()).await?;
+    // The node stops when its service is dropped, so it lives as long as the runtime
+    tokio::spawn(async move {
+        let _node = node;
+        std::future::pending::<()>().await
+    });
+
+    Ok(provider)
+}
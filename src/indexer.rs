@@ -0,0 +1,764 @@
+//! A background task that streams token and vault events as blocks arrive.
+//!
+//! The indexer polls the node for new transactions in batches: one paginated
+//! `transactions` query returns up to [`IndexerConfig::batch_size`]
+//! transactions with their block heights and receipts, across as many blocks
+//! as they span, instead of one query per block plus one per transaction.
+//! When the indexer is behind, e.g. catching up from genesis, the blocks it
+//! has to fetch are split into ranges of [`IndexerConfig::range_size`] blocks
+//! that up to [`IndexerConfig::range_workers`] workers fetch concurrently; the
+//! ranges are handed on in height order, whichever finishes first. With
+//! [`IndexerConfig::confirmations`] set, the follower stays that many blocks
+//! behind the head of the chain, so a block is indexed only once it is that deep.
+//!
+//! The logs of the watched contracts in every successful transaction are
+//! decoded into [`ContractEvent`]s and sent over a channel. Logs of
+//! other contracts are ignored even when they share a log type, as are the
+//! logs of reverted transactions. Receipts are decoded in place by an
+//! [`EventDecoder`], so the data of a log is never copied before decoding.
+//!
+//! Fetching, decoding and delivering run as three tasks of an
+//! [`IndexerPipeline`] (follower → decoder → writer) joined by channels of
+//! [`IndexerConfig::channel_capacity`] batches. When the [`EventSink`] at the
+//! end is slow, the channels fill up and the follower stops fetching until it
+//! catches up, so a slow consumer never makes the indexer buffer the chain in
+//! memory. [`IndexerPipeline::shutdown`] stops fetching and lets the decoder
+//! and writer drain what is in flight. [`Indexer`] is the pipeline with a
+//! bounded channel as its sink.
+//!
+//! [`wallet_balance_stream`] follows the same transactions for one wallet and
+//! asset instead, reporting the [`BalanceChange`] each one makes and who it
+//! was with.
+//!
+//! ```ignore
+//! let config = IndexerConfig::default();
+//! let mut indexer = Indexer::for_token_and_vault(&harness.token, &harness.vault, config).await?;
+//! harness.mint_to(harness.user(0), 100).await?;
+//! let event = indexer.next_event_within(Duration::from_secs(5)).await?;
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use fuels::{
+    client::{PageDirection, PaginationRequest},
+    core::codec::LogDecoder,
+    prelude::*,
+    tx::{Output, Receipt, TxId},
+    types::{
+        transaction::{Transaction, TransactionType},
+        transaction_response::TransactionResponse,
+        tx_status::{Failure, Success, TxStatus},
+        Address, ChainId, ContractId, Identity,
+    },
+};
+use tokio::{
+    sync::mpsc::{channel, Receiver, Sender, WeakSender},
+    task::JoinHandle,
+};
+
+use crate::{
+    events::{ContractEvent, EventDecoder},
+    queries::tx_id_of,
+    Src20Token, TokenVault,
+};
+
+/// A decoded event with the block and transaction it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedEvent {
+    pub block_height: u32,
+    pub tx_id: TxId,
+    pub contract_id: ContractId,
+    pub event: ContractEvent,
+}
+
+/// Where the indexer starts, how often it polls and how much it fetches and buffers.
+#[derive(Debug, Clone)]
+pub struct IndexerConfig {
+    /// Delay between polls once the indexer has caught up with the chain.
+    pub poll_interval: Duration,
+    /// First block to index; `None` starts after the latest block at spawn time.
+    pub from_height: Option<u32>,
+    /// Transactions fetched, with their receipts, per query.
+    pub batch_size: i32,
+    /// Batches each pipeline stage may get ahead of the next one.
+    pub channel_capacity: usize,
+    /// Blocks fetched by one range worker.
+    pub range_size: u32,
+    /// Ranges fetched at the same time while catching up.
+    pub range_workers: usize,
+    /// Blocks that must be built on top of a block before it is indexed.
+    pub confirmations: u32,
+}
+
+impl Default for IndexerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(100),
+            from_height: None,
+            batch_size: 100,
+            channel_capacity: 16,
+            range_size: 500,
+            range_workers: 4,
+            confirmations: 0,
+        }
+    }
+}
+
+impl IndexerConfig {
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_from_height(mut self, from_height: u32) -> Self {
+        self.from_height = Some(from_height);
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: i32) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = channel_capacity;
+        self
+    }
+
+    pub fn with_range_size(mut self, range_size: u32) -> Self {
+        self.range_size = range_size;
+        self
+    }
+
+    pub fn with_range_workers(mut self, range_workers: usize) -> Self {
+        self.range_workers = range_workers;
+        self
+    }
+
+    /// Indexes a block only once `confirmations` blocks follow it, i.e. the
+    /// follower stays `confirmations` blocks behind the head of the chain.
+    pub fn with_confirmations(mut self, confirmations: u32) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+}
+
+/// The last stage of an [`IndexerPipeline`], e.g. a store writer.
+#[async_trait]
+pub trait EventSink: Send + 'static {
+    /// Writes the events of one transaction. The pipeline hands over the next
+    /// batch only once this returns; an error stops the pipeline.
+    async fn write(&mut self, events: Vec<IndexedEvent>) -> Result<()>;
+}
+
+// The sink of an `Indexer`: a full channel makes the writer wait
+#[async_trait]
+impl EventSink for Sender<IndexedEvent> {
+    async fn write(&mut self, events: Vec<IndexedEvent>) -> Result<()> {
+        for event in events {
+            self.send(event)
+                .await
+                .map_err(|_| Error::Other("event receiver dropped".to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Items waiting in the channels between the stages of an [`IndexerPipeline`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Buffered {
+    /// Pages of transactions fetched but not yet taken by the decoder.
+    pub pages: usize,
+    /// Batches of events decoded but not yet taken by the writer.
+    pub batches: usize,
+}
+
+/// Handle to the follower, decoder and writer tasks.
+///
+/// Dropping it stops them at once, losing what is still in flight;
+/// [`shutdown`](Self::shutdown) lets the decoder and writer finish first.
+pub struct IndexerPipeline {
+    follower: JoinHandle<()>,
+    /// The decoder and the writer.
+    stages: Vec<JoinHandle<()>>,
+    pages: WeakSender<Vec<TransactionResponse>>,
+    batches: WeakSender<Vec<IndexedEvent>>,
+}
+
+impl IndexerPipeline {
+    /// Spawns a pipeline decoding the logs of `contracts` with `decoder` into `sink`.
+    ///
+    /// `decoder` must know the log types of every watched contract, e.g. the
+    /// merged `log_decoder()`s of their abigen instances.
+    pub async fn spawn(
+        provider: Provider,
+        contracts: Vec<ContractId>,
+        decoder: LogDecoder,
+        config: IndexerConfig,
+        sink: impl EventSink,
+    ) -> Result<Self> {
+        let next_height = match config.from_height {
+            Some(height) => height,
+            None => provider.latest_block_height().await? + 1,
+        };
+        let chain_id = provider.consensus_parameters().await?.chain_id();
+
+        let (pages, pages_rx) = channel(config.channel_capacity);
+        let (batches, batches_rx) = channel(config.channel_capacity);
+        let (weak_pages, weak_batches) = (pages.downgrade(), batches.downgrade());
+        let decoder = EventDecoder::new(decoder, contracts);
+
+        Ok(Self {
+            follower: tokio::spawn(follow(provider, config, next_height, pages)),
+            stages: vec![
+                tokio::spawn(decode(chain_id, decoder, pages_rx, batches)),
+                tokio::spawn(write(sink, batches_rx)),
+            ],
+            pages: weak_pages,
+            batches: weak_batches,
+        })
+    }
+
+    /// Whether every stage has stopped, e.g. after the sink failed.
+    pub fn is_finished(&self) -> bool {
+        self.follower.is_finished() && self.stages.iter().all(JoinHandle::is_finished)
+    }
+
+    /// What the channels hold right now; each holds at most
+    /// [`IndexerConfig::channel_capacity`] items.
+    pub fn buffered(&self) -> Buffered {
+        Buffered {
+            pages: in_channel(&self.pages),
+            batches: in_channel(&self.batches),
+        }
+    }
+
+    /// Stops fetching and waits until the decoder and the writer have passed
+    /// everything already fetched on to the sink.
+    ///
+    /// The follower is stopped between two pages, so no page is half sent.
+    /// If the sink fails meanwhile, the rest is dropped as usual.
+    pub async fn shutdown(mut self) {
+        self.follower.abort();
+        for stage in std::mem::take(&mut self.stages) {
+            // A stage only ends with an error if it panicked, which the
+            // pipeline cannot recover from anyway
+            if let Err(error) = stage.await {
+                tracing::warn!(%error, "indexer stage failed during shutdown");
+            }
+        }
+    }
+}
+
+// Items in a channel: its bound minus the free slots, zero once it is closed
+fn in_channel<T>(sender: &WeakSender<T>) -> usize {
+    sender
+        .upgrade()
+        .map(|sender| sender.max_capacity() - sender.capacity())
+        .unwrap_or(0)
+}
+
+impl Drop for IndexerPipeline {
+    fn drop(&mut self) {
+        self.follower.abort();
+        for stage in &self.stages {
+            stage.abort();
+        }
+    }
+}
+
+/// A running indexer whose events are received through a bounded channel.
+/// Dropping it stops the pipeline.
+pub struct Indexer {
+    events: Receiver<IndexedEvent>,
+    _pipeline: IndexerPipeline,
+}
+
+impl Indexer {
+    /// Spawns an indexer decoding the logs of `contracts` with `decoder`.
+    ///
+    /// `decoder` must know the log types of every watched contract, e.g. the
+    /// merged `log_decoder()`s of their abigen instances.
+    pub async fn spawn(
+        provider: Provider,
+        contracts: Vec<ContractId>,
+        decoder: LogDecoder,
+        config: IndexerConfig,
+    ) -> Result<Self> {
+        let (sender, events) = channel(config.channel_capacity);
+        let pipeline = IndexerPipeline::spawn(provider, contracts, decoder, config, sender).await?;
+
+        Ok(Self {
+            events,
+            _pipeline: pipeline,
+        })
+    }
+
+    /// Spawns an indexer over the logs of a token and a vault.
+    pub async fn for_token_and_vault<A: Account + Clone>(
+        token: &Src20Token<A>,
+        vault: &TokenVault<A>,
+        config: IndexerConfig,
+    ) -> Result<Self> {
+        let provider = token.account().try_provider()?.clone();
+
+        let mut decoder = token.log_decoder();
+        decoder.merge(vault.log_decoder());
+        let contracts = vec![token.contract_id().clone(), vault.contract_id().clone()];
+
+        Self::spawn(provider, contracts, decoder, config).await
+    }
+
+    /// Waits for the next event; `None` once the indexer has stopped.
+    pub async fn next_event(&mut self) -> Option<IndexedEvent> {
+        self.events.recv().await
+    }
+
+    /// Waits at most `timeout` for the next event.
+    pub async fn next_event_within(&mut self, timeout: Duration) -> Result<IndexedEvent> {
+        match tokio::time::timeout(timeout, self.next_event()).await {
+            Ok(Some(event)) => Ok(event),
+            Ok(None) => Err(Error::Other("indexer stopped".to_string())),
+            Err(_) => Err(Error::Other(format!("no event within {:?}", timeout))),
+        }
+    }
+
+    /// Events already received and not yet consumed, without waiting.
+    pub fn drain(&mut self) -> Vec<IndexedEvent> {
+        let mut events = vec![];
+        while let Ok(event) = self.events.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+}
+
+/// A change of a wallet's balance of one asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub block_height: u32,
+    /// The transaction that moved the asset in or out of the wallet.
+    pub tx_id: TxId,
+    /// Who the asset came from or went to: for an incoming change the first
+    /// other owner of a coin input or the contract that transferred it, for
+    /// an outgoing one the first other recipient of a coin output. `None`
+    /// when the transaction names no one else, e.g. when it only paid a fee.
+    pub counterparty: Option<Identity>,
+    pub previous: u128,
+    pub balance: u128,
+}
+
+impl BalanceChange {
+    /// How much the balance went up (positive) or down (negative).
+    pub fn delta(&self) -> i128 {
+        self.balance as i128 - self.previous as i128
+    }
+}
+
+/// A running [`wallet_balance_stream`]. Dropping it stops its tasks.
+pub struct BalanceStream {
+    changes: Receiver<BalanceChange>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl BalanceStream {
+    /// Waits for the next change; `None` once the stream has stopped.
+    pub async fn next_change(&mut self) -> Option<BalanceChange> {
+        self.changes.recv().await
+    }
+
+    /// Waits at most `timeout` for the next change.
+    pub async fn next_change_within(&mut self, timeout: Duration) -> Result<BalanceChange> {
+        match tokio::time::timeout(timeout, self.next_change()).await {
+            Ok(Some(change)) => Ok(change),
+            Ok(None) => Err(Error::Other("balance stream stopped".to_string())),
+            Err(_) => Err(Error::Other(format!("no balance change within {:?}", timeout))),
+        }
+    }
+}
+
+impl Drop for BalanceStream {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+/// Streams the changes of `address`'s balance of `asset_id` from the next block on.
+///
+/// The follower of the indexer pipeline fetches every new transaction, and
+/// each change is computed from the transaction alone: the wallet's coins of
+/// the asset it spends, the coin and change outputs it creates for the wallet
+/// and the contract transfers to the wallet in its receipts. The balance is
+/// read once at the start and then only moved by those deltas, so every
+/// change matches its own transaction even while the stream lags behind the
+/// chain. `config.from_height` is ignored.
+pub async fn wallet_balance_stream(
+    provider: Provider,
+    address: Address,
+    asset_id: AssetId,
+    config: IndexerConfig,
+) -> Result<BalanceStream> {
+    let consensus_parameters = provider.consensus_parameters().await?;
+    let chain_id = consensus_parameters.chain_id();
+    let watch = WatchedBalance {
+        address,
+        asset_id,
+        base_asset_id: *consensus_parameters.base_asset_id(),
+    };
+
+    // The starting balance must be the one after exactly the blocks the
+    // stream skips, so read it again if a block lands in between
+    let (next_height, balance) = loop {
+        let height = provider.latest_block_height().await?;
+        let balance = provider.get_asset_balance(&address, &asset_id).await?;
+        if provider.latest_block_height().await? == height {
+            break (height + 1, balance);
+        }
+    };
+
+    let (pages, pages_rx) = channel(config.channel_capacity);
+    let (sender, changes) = channel(config.channel_capacity);
+    let tasks = vec![
+        tokio::spawn(follow(provider, config, next_height, pages)),
+        tokio::spawn(track_balance(chain_id, watch, balance, pages_rx, sender)),
+    ];
+
+    Ok(BalanceStream { changes, tasks })
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WatchedBalance {
+    address: Address,
+    asset_id: AssetId,
+    base_asset_id: AssetId,
+}
+
+// How one transaction moved the watched asset in and out of the wallet
+#[derive(Debug, Default)]
+struct Movement {
+    spent: u128,
+    received: u128,
+    sender: Option<Identity>,
+    recipient: Option<Identity>,
+}
+
+impl Movement {
+    fn counterparty(&self) -> Option<Identity> {
+        if self.received > self.spent {
+            self.sender
+        } else {
+            self.recipient
+        }
+    }
+}
+
+impl WatchedBalance {
+    // What the transaction spent from and paid to the wallet; `None` if it
+    // did not touch the watched asset of the wallet
+    fn movement_in(&self, response: &TransactionResponse) -> Option<Movement> {
+        // A reverted transaction still spends its inputs, but only creates its
+        // change outputs, which hold everything but the fee
+        let succeeded = matches!(response.status, TxStatus::Success(_));
+        let receipts = match &response.status {
+            TxStatus::Success(Success { receipts, .. }) => receipts.as_slice(),
+            TxStatus::Failure(Failure { receipts, .. }) => receipts.as_slice(),
+            _ => return None,
+        };
+        let (inputs, outputs) = match &response.transaction {
+            TransactionType::Script(tx) => (tx.inputs(), tx.outputs()),
+            TransactionType::Create(tx) => (tx.inputs(), tx.outputs()),
+            TransactionType::Upgrade(tx) => (tx.inputs(), tx.outputs()),
+            TransactionType::Upload(tx) => (tx.inputs(), tx.outputs()),
+            TransactionType::Blob(tx) => (tx.inputs(), tx.outputs()),
+            // Mint transactions only pay the block producer
+            _ => return None,
+        };
+
+        let mut movement = Movement::default();
+        let mut touched = false;
+        for input in inputs {
+            if input.asset_id(&self.base_asset_id) != Some(&self.asset_id) {
+                continue;
+            }
+            match input.input_owner() {
+                Some(owner) if *owner == self.address => {
+                    movement.spent += u128::from(input.amount().unwrap_or_default());
+                    touched = true;
+                }
+                Some(owner) => {
+                    movement.sender.get_or_insert(Identity::Address(*owner));
+                }
+                None => {}
+            }
+        }
+
+        // The block holds the executed transaction, so change outputs carry
+        // what was returned. Variable outputs are left to the receipts below,
+        // which also name the contract that filled them.
+        for output in outputs {
+            let (to, amount, asset_id, is_change) = match output {
+                Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                } => (to, amount, asset_id, false),
+                Output::Change {
+                    to,
+                    amount,
+                    asset_id,
+                } => (to, amount, asset_id, true),
+                _ => continue,
+            };
+            if *asset_id != self.asset_id || !(succeeded || is_change) {
+                continue;
+            }
+            if *to == self.address {
+                movement.received += u128::from(*amount);
+                touched = true;
+            } else if !is_change {
+                movement.recipient.get_or_insert(Identity::Address(*to));
+            }
+        }
+
+        if succeeded {
+            for receipt in receipts {
+                if let Receipt::TransferOut {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } = receipt
+                {
+                    if *to == self.address && *asset_id == self.asset_id {
+                        movement.received += u128::from(*amount);
+                        movement.sender.get_or_insert(Identity::ContractId(*id));
+                        touched = true;
+                    }
+                }
+            }
+        }
+
+        // Only the wallet's own coins and transfers count; a sender or
+        // recipient alone means the transaction was between other wallets
+        touched.then_some(movement)
+    }
+}
+
+// Applies the movement of every transaction touching the wallet to the
+// balance and sends the changes
+async fn track_balance(
+    chain_id: ChainId,
+    watch: WatchedBalance,
+    mut balance: u128,
+    mut pages: Receiver<Vec<TransactionResponse>>,
+    changes: Sender<BalanceChange>,
+) {
+    loop {
+        let page = tokio::select! {
+            page = pages.recv() => page,
+            _ = changes.closed() => None,
+        };
+        let Some(page) = page else {
+            return;
+        };
+
+        for response in page {
+            let Some(movement) = watch.movement_in(&response) else {
+                continue;
+            };
+            if movement.spent == movement.received {
+                continue;
+            }
+
+            let current = (balance + movement.received).saturating_sub(movement.spent);
+            let change = BalanceChange {
+                block_height: response.block_height.map(u32::from).unwrap_or_default(),
+                tx_id: tx_id_of(&response, &chain_id),
+                counterparty: movement.counterparty(),
+                previous: balance,
+                balance: current,
+            };
+            balance = current;
+            if changes.send(change).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// The pagination cursor of the last transaction below `height`; `None` when
+// there is none, so that pagination starts at the first transaction
+async fn cursor_before(provider: &Provider, height: u32) -> Result<Option<String>> {
+    for below in (1..height).rev() {
+        let block = provider
+            .block_by_height(below.into())
+            .await?
+            .ok_or_else(|| Error::Other(format!("block {} not found", below)))?;
+        if let Some(tx_id) = block.transactions.last() {
+            return Ok(Some(format!("{}#{}", below, tx_id)));
+        }
+    }
+    Ok(None)
+}
+
+// Follower: fetches the transactions of every block from `next_height` on
+// until the decoder goes away. Provider errors are retried on the next poll
+// rather than ending the stream.
+async fn follow(
+    provider: Provider,
+    config: IndexerConfig,
+    mut next_height: u32,
+    pages: Sender<Vec<TransactionResponse>>,
+) {
+    while !pages.is_closed() {
+        match fetch_up_to_latest(&provider, &config, &mut next_height, &pages).await {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(error) => tracing::warn!(%error, next_height, "indexer poll failed"),
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    }
+}
+
+// Fetches every block from `next_height` up to the latest one with enough
+// confirmations, split into ranges fetched by up to `range_workers` workers at
+// once. Ranges are sent in height order, each once the decoder took the one
+// before, and `next_height` moves past every range sent. Returns `false` if
+// the decoder is gone.
+async fn fetch_up_to_latest(
+    provider: &Provider,
+    config: &IndexerConfig,
+    next_height: &mut u32,
+    pages: &Sender<Vec<TransactionResponse>>,
+) -> Result<bool> {
+    let head = provider.latest_block_height().await?;
+    let Some(latest) = head.checked_sub(config.confirmations) else {
+        return Ok(true);
+    };
+    if *next_height > latest {
+        return Ok(true);
+    }
+
+    let range_size = config.range_size.max(1);
+    let ranges = (*next_height..=latest)
+        .step_by(range_size as usize)
+        .map(|start| (start, start.saturating_add(range_size - 1).min(latest)));
+
+    // `buffered` runs the workers concurrently but yields their results in order
+    let mut fetched = stream::iter(ranges)
+        .map(|(start, end)| async move {
+            fetch_range(provider, config.batch_size, start, end)
+                .await
+                .map(|page| (end, page))
+        })
+        .buffered(config.range_workers.max(1));
+
+    while let Some(result) = fetched.next().await {
+        let (end, mut page) = result?;
+        // Pages come back in cursor order; the sort only guards the decoder's
+        // height order against a node that returns them otherwise
+        page.sort_by_key(|response| response.block_height.map(u32::from));
+        if !page.is_empty() && pages.send(page).await.is_err() {
+            return Ok(false);
+        }
+        *next_height = end + 1;
+    }
+    Ok(true)
+}
+
+// Fetches the transactions of blocks `start..=end`, `batch_size` per query
+async fn fetch_range(
+    provider: &Provider,
+    batch_size: i32,
+    start: u32,
+    end: u32,
+) -> Result<Vec<TransactionResponse>> {
+    let mut cursor = cursor_before(provider, start).await?;
+    let mut transactions = vec![];
+    loop {
+        let page = provider
+            .get_transactions(PaginationRequest {
+                cursor: cursor.clone(),
+                results: batch_size,
+                direction: PageDirection::Forward,
+            })
+            .await?;
+
+        for response in page.results {
+            if response.block_height.map(u32::from).unwrap_or_default() > end {
+                return Ok(transactions);
+            }
+            transactions.push(response);
+        }
+
+        match page.cursor {
+            Some(next) if page.has_next_page => cursor = Some(next),
+            _ => return Ok(transactions),
+        }
+    }
+}
+
+// Decoder: turns pages of transactions into the events of each one
+async fn decode(
+    chain_id: ChainId,
+    mut decoder: EventDecoder,
+    mut pages: Receiver<Vec<TransactionResponse>>,
+    batches: Sender<Vec<IndexedEvent>>,
+) {
+    loop {
+        // Stop as soon as the writer is gone, even while no pages arrive
+        let page = tokio::select! {
+            page = pages.recv() => page,
+            _ = batches.closed() => None,
+        };
+        let Some(page) = page else {
+            return;
+        };
+
+        for response in page {
+            let TxStatus::Success(Success { receipts, .. }) = &response.status else {
+                continue;
+            };
+            // The follower already moved past the page, so it cannot be retried
+            let events = match decoder.decode(receipts) {
+                Ok(events) if events.is_empty() => continue,
+                Ok(events) => events,
+                Err(error) => {
+                    tracing::warn!(%error, "skipping transaction with undecodable logs");
+                    continue;
+                }
+            };
+
+            // Responses carry no ids; only transactions with events are hashed for one
+            let tx_id = tx_id_of(&response, &chain_id);
+            let block_height = response.block_height.map(u32::from).unwrap_or_default();
+            let batch = events
+                .into_iter()
+                .map(|(contract_id, event)| IndexedEvent {
+                    block_height,
+                    tx_id,
+                    contract_id,
+                    event,
+                })
+                .collect();
+            if batches.send(batch).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Writer: hands each batch to the sink, stopping at its first error
+async fn write(mut sink: impl EventSink, mut batches: Receiver<Vec<IndexedEvent>>) {
+    while let Some(batch) = batches.recv().await {
+        if let Err(error) = sink.write(batch).await {
+            tracing::warn!(%error, "indexer sink failed, stopping");
+            return;
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Bytecode integrity checks before deploying.
+//!
+//! A bytecode manifest maps artifact paths (see [`crate::artifacts`]) to the
+//! SHA-256 of the binary at that path. Recording one after a reviewed build and
+//! verifying it before a deploy catches artifacts that are stale or were
+//! rebuilt from different sources, instead of deploying them silently.
+//!
+//! ```ignore
+//! // After `forc build`
+//! BytecodeManifest::for_artifacts(&SUITE_ARTIFACTS)?.save(BYTECODE_MANIFEST)?;
+//!
+//! // Before deploying
+//! BytecodeManifest::load(BYTECODE_MANIFEST)?.verify_all(&SUITE_ARTIFACTS)?;
+//! ```
+
+use std::{collections::BTreeMap, path::Path};
+
+use fuels::{crypto::Hasher, prelude::*, types::Bytes32};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    artifacts,
+    error::{RosettaError, RosettaResult},
+};
+
+/// Default location of the manifest, next to `Forc.toml`.
+pub const BYTECODE_MANIFEST: &str = "bytecode-hashes.json";
+
+/// The binaries deployed by [`deploy_suite`](crate::deployments::deploy_suite).
+pub const SUITE_ARTIFACTS: [&str; 4] = [
+    artifacts::SRC20_TOKEN_BIN,
+    artifacts::CROSS_CONTRACT_CALL_BIN,
+    artifacts::TOKEN_VAULT_BIN,
+    artifacts::ASSET_REGISTRY_BIN,
+];
+
+/// Expected bytecode hashes, keyed by artifact path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BytecodeManifest {
+    pub hashes: BTreeMap<String, String>,
+}
+
+/// SHA-256 of the binary at `path`.
+pub fn bytecode_hash(path: impl AsRef<Path>) -> Result<Bytes32> {
+    let bytecode = std::fs::read(path)?;
+    Ok(Hasher::hash(bytecode))
+}
+
+impl BytecodeManifest {
+    /// A manifest of the current hashes of `artifacts`.
+    pub fn for_artifacts(artifacts: &[&str]) -> Result<Self> {
+        let mut manifest = Self::default();
+        for artifact in artifacts {
+            manifest.record(artifact)?;
+        }
+        Ok(manifest)
+    }
+
+    /// Records the current hash of `artifact`, replacing any previous one.
+    pub fn record(&mut self, artifact: &str) -> Result<Bytes32> {
+        let hash = bytecode_hash(artifact)?;
+        self.hashes.insert(artifact.to_string(), to_hex(&hash));
+        Ok(hash)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|e| Error::Other(e.to_string()))
+    }
+
+    /// Writes to `path`, creating parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| Error::Other(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Checks that `artifact` hashes to the value recorded for it.
+    ///
+    /// Fails with [`RosettaError::BytecodeMismatch`] if the binary changed, and
+    /// with a plain error if the manifest has no entry for it.
+    pub fn verify(&self, artifact: &str) -> RosettaResult<()> {
+        let expected = self.hashes.get(artifact).ok_or_else(|| {
+            Error::Other(format!("no hash for {} in the bytecode manifest", artifact))
+        })?;
+        let actual = to_hex(&bytecode_hash(artifact)?);
+
+        if !expected.eq_ignore_ascii_case(&actual) {
+            return Err(RosettaError::BytecodeMismatch {
+                artifact: artifact.to_string(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// [`verify`](Self::verify) for each of `artifacts`, stopping at the first failure.
+    pub fn verify_all(&self, artifacts: &[&str]) -> RosettaResult<()> {
+        artifacts.iter().try_for_each(|artifact| self.verify(artifact))
+    }
+}
+
+// 0x-prefixed lowercase hex
+fn to_hex(hash: &Bytes32) -> String {
+    let hex: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", hex)
+}
@@ -0,0 +1,78 @@
+//! Signing keys from sources other than a raw private key.
+//!
+//! A BIP-39 mnemonic plus a derivation path gives deterministic, recoverable
+//! accounts: the same phrase always yields the same addresses, so testnet
+//! deployments can be repeated and their funds recovered in any Fuel wallet.
+//! Account `n` uses the path `m/44'/1179993420'/n'/0/0`, the layout of the
+//! official wallets; account 0 is [`DEFAULT_DERIVATION_PATH`].
+//!
+//! A JSON keystore (the Web3 Secret Storage format written by `forc-wallet` and
+//! most Ethereum tooling) keeps a private key encrypted at rest; see
+//! [`signer_from_keystore`].
+//!
+//! ```ignore
+//! let phrase = std::env::var(MNEMONIC_ENV)?;
+//! let wallets = wallets_from_mnemonic(&phrase, 3, provider)?;
+//! ```
+
+use std::path::Path;
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    crypto::SecretKey,
+    prelude::*,
+};
+
+pub use fuels::accounts::signers::derivation::DEFAULT_DERIVATION_PATH;
+
+/// Environment variable holding a mnemonic phrase for the harness, CLI and examples.
+pub const MNEMONIC_ENV: &str = "FUEL_MNEMONIC";
+
+/// Default environment variable holding the password of a keystore.
+pub const KEYSTORE_PASSWORD_ENV: &str = "ROSETTA_KEYSTORE_PASSWORD";
+
+/// Derivation path of account `index`.
+pub fn account_derivation_path(index: u32) -> String {
+    format!("m/44'/1179993420'/{}'/0/0", index)
+}
+
+/// Derives the signer at `path` from a BIP-39 mnemonic `phrase`.
+pub fn signer_from_mnemonic(phrase: &str, path: &str) -> Result<PrivateKeySigner> {
+    let secret_key = SecretKey::new_from_mnemonic_phrase_with_path(phrase.trim(), path)
+        .map_err(|e| Error::Other(format!("cannot derive key at {}: {}", path, e)))?;
+    Ok(PrivateKeySigner::new(secret_key))
+}
+
+/// Derives the wallets of accounts `0..count` from `phrase`.
+pub fn wallets_from_mnemonic(
+    phrase: &str,
+    count: usize,
+    provider: Provider,
+) -> Result<Vec<Wallet<Unlocked<PrivateKeySigner>>>> {
+    (0..count as u32)
+        .map(|index| {
+            let signer = signer_from_mnemonic(phrase, &account_derivation_path(index))?;
+            Ok(Wallet::new(signer, provider.clone()))
+        })
+        .collect()
+}
+
+/// Decrypts the JSON keystore at `path` with `password`.
+pub fn signer_from_keystore(path: impl AsRef<Path>, password: &str) -> Result<PrivateKeySigner> {
+    let path = path.as_ref();
+    let key = eth_keystore::decrypt_key(path, password)
+        .map_err(|e| Error::Other(format!("cannot decrypt keystore {}: {}", path.display(), e)))?;
+    let secret_key = SecretKey::try_from(key.as_slice())
+        .map_err(|e| Error::Other(format!("invalid key in keystore {}: {}", path.display(), e)))?;
+    Ok(PrivateKeySigner::new(secret_key))
+}
+
+/// Decrypts the JSON keystore at `path` with the password in `password_env`.
+pub fn signer_from_keystore_env(
+    path: impl AsRef<Path>,
+    password_env: &str,
+) -> Result<PrivateKeySigner> {
+    let password = std::env::var(password_env)
+        .map_err(|_| Error::Other(format!("keystore password not set: {}", password_env)))?;
+    signer_from_keystore(path, &password)
+}
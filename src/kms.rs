@@ -0,0 +1,32 @@
+//! A remote signer backed by an AWS KMS key (feature `aws-kms`).
+//!
+//! The private key never leaves KMS: transactions are hashed locally and KMS
+//! signs the hash. The key must be an asymmetric `ECC_SECG_P256K1` signing key.
+//! Credentials and region come from the standard AWS configuration chain
+//! (environment, profile, instance role), and `AWS_ENDPOINT_URL` points the
+//! client at a local KMS emulator.
+//!
+//! ```ignore
+//! let signer = aws_kms_signer(&std::env::var(KMS_KEY_ID_ENV)?).await?;
+//! let wallet = Wallet::new(signer, provider);
+//! let deployments = deploy_suite(&wallet, "testnet", "MYTOKEN", "TOKEN", 9).await?;
+//! ```
+
+use fuels::{
+    accounts::signers::kms::aws::{
+        aws_config::{self, BehaviorVersion},
+        aws_sdk_kms::Client,
+        AwsKmsSigner,
+    },
+    prelude::*,
+};
+
+/// Environment variable holding the id or ARN of the KMS signing key.
+pub const KMS_KEY_ID_ENV: &str = "ROSETTA_KMS_KEY_ID";
+
+/// Connects to KMS with the default AWS configuration and loads the signer of `key_id`.
+pub async fn aws_kms_signer(key_id: &str) -> Result<AwsKmsSigner> {
+    let config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+    let client = Client::new(&config);
+    AwsKmsSigner::new(key_id, &client).await
+}
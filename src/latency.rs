@@ -0,0 +1,256 @@
+//! Latency breakdown for transaction submission: build vs sign vs submit vs confirm.
+//!
+//! Each phase runs inside a `tracing` span and is reported to the `metrics`
+//! facade as a histogram (`rosetta.tx.<phase>_seconds`), so users can tell
+//! whether slowness comes from the SDK, their own code, or the node.
+//!
+//! Signing happens inside the SDK's `build()`, so it is measured by wrapping
+//! the wallet's signer in a [`TimedSigner`] and subtracted from the build time.
+//! Confirmation is polled for at most [`DEFAULT_CONFIRM_TIMEOUT`], or the
+//! timeout given to [`call_with_breakdown_within`].
+
+use std::{
+    fmt,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    core::traits::{Parameterize, Tokenizable},
+    crypto::{Message, Signature},
+    prelude::*,
+    programs::{calls::ContractCall, responses::CallResponse},
+    types::{
+        errors::transaction::Reason,
+        tx_status::{SqueezedOut, TxStatus},
+    },
+};
+use tracing::Instrument;
+
+use crate::{
+    bench_report::BenchReport,
+    error::{ErrorContext, ResultExt, RosettaError, RosettaResult},
+};
+
+/// How long [`call_with_breakdown`] waits for a submitted transaction to be included.
+pub const DEFAULT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time spent in each phase of a single transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyBreakdown {
+    /// Coin selection, fee adjustment, gas estimation and encoding.
+    pub build: Duration,
+    /// Producing witness signatures.
+    pub sign: Duration,
+    /// Handing the transaction to the node.
+    pub submit: Duration,
+    /// Waiting for the node to include the transaction in a block.
+    pub confirm: Duration,
+}
+
+impl LatencyBreakdown {
+    pub fn total(&self) -> Duration {
+        self.build + self.sign + self.submit + self.confirm
+    }
+
+    /// Records every phase into `report` as `<prefix>_<phase>`.
+    pub fn record_into(&self, report: &mut BenchReport, prefix: &str) {
+        report.record_duration(format!("{}_build", prefix), self.build);
+        report.record_duration(format!("{}_sign", prefix), self.sign);
+        report.record_duration(format!("{}_submit", prefix), self.submit);
+        report.record_duration(format!("{}_confirm", prefix), self.confirm);
+    }
+
+    /// Sum of several breakdowns, e.g. over a batch.
+    pub fn sum<'a>(breakdowns: impl IntoIterator<Item = &'a LatencyBreakdown>) -> Self {
+        breakdowns
+            .into_iter()
+            .fold(Self::default(), |acc, b| LatencyBreakdown {
+                build: acc.build + b.build,
+                sign: acc.sign + b.sign,
+                submit: acc.submit + b.submit,
+                confirm: acc.confirm + b.confirm,
+            })
+    }
+
+    fn emit_metrics(&self) {
+        metrics::histogram!("rosetta.tx.build_seconds").record(self.build.as_secs_f64());
+        metrics::histogram!("rosetta.tx.sign_seconds").record(self.sign.as_secs_f64());
+        metrics::histogram!("rosetta.tx.submit_seconds").record(self.submit.as_secs_f64());
+        metrics::histogram!("rosetta.tx.confirm_seconds").record(self.confirm.as_secs_f64());
+    }
+}
+
+impl fmt::Display for LatencyBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "build {:?} | sign {:?} | submit {:?} | confirm {:?} | total {:?}",
+            self.build,
+            self.sign,
+            self.submit,
+            self.confirm,
+            self.total()
+        )
+    }
+}
+
+/// A signer that accumulates the time spent signing.
+#[derive(Debug, Clone)]
+pub struct TimedSigner<S> {
+    inner: S,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl<S> TimedSigner<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Returns the accumulated signing time and resets it.
+    pub fn take_elapsed(&self) -> Duration {
+        std::mem::take(&mut *self.elapsed.lock().unwrap())
+    }
+}
+
+#[async_trait]
+impl<S: Signer + Send + Sync> Signer for TimedSigner<S> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let start = Instant::now();
+        let signature = self.inner.sign(message).await;
+        *self.elapsed.lock().unwrap() += start.elapsed();
+        signature
+    }
+
+    fn address(&self) -> Address {
+        self.inner.address()
+    }
+}
+
+/// A wallet whose signing time is measured.
+pub type TimedWallet = Wallet<Unlocked<TimedSigner<PrivateKeySigner>>>;
+
+/// Wraps the signer of `wallet` in a [`TimedSigner`].
+pub fn timed_wallet(wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Result<TimedWallet> {
+    Ok(Wallet::new(
+        TimedSigner::new(wallet.signer().clone()),
+        wallet.try_provider()?.clone(),
+    ))
+}
+
+// Whether the transaction still waits for its block. A preconfirmation is
+// only the block producer's promise, so it keeps waiting either way; a
+// squeezed-out transaction never gets a block, which the poll reports as a
+// failure rather than a confirmation.
+fn is_pending(status: &TxStatus) -> bool {
+    match status {
+        TxStatus::Submitted
+        | TxStatus::PreConfirmationSuccess(_)
+        | TxStatus::PreConfirmationFailure(_) => true,
+        TxStatus::Success(_) | TxStatus::Failure(_) | TxStatus::SqueezedOut(_) => false,
+    }
+}
+
+/// Runs a contract call phase by phase and returns its response with the latency breakdown.
+///
+/// The call must be made through a [`TimedWallet`] (see [`timed_wallet`]).
+pub async fn call_with_breakdown<T>(
+    call: CallHandler<TimedWallet, ContractCall, T>,
+    wallet: &TimedWallet,
+) -> RosettaResult<(CallResponse<T>, LatencyBreakdown)>
+where
+    T: Tokenizable + Parameterize + Debug,
+{
+    call_with_breakdown_within(call, wallet, DEFAULT_CONFIRM_TIMEOUT).await
+}
+
+/// [`call_with_breakdown`], failing when the transaction is still pending after
+/// `confirm_timeout`.
+///
+/// Errors carry the contract and method of the call, and its transaction id
+/// and block once it was submitted.
+pub async fn call_with_breakdown_within<T>(
+    call: CallHandler<TimedWallet, ContractCall, T>,
+    wallet: &TimedWallet,
+    confirm_timeout: Duration,
+) -> RosettaResult<(CallResponse<T>, LatencyBreakdown)>
+where
+    T: Tokenizable + Parameterize + Debug,
+{
+    let mut context = ErrorContext::for_call(&call.call);
+    let provider = wallet.try_provider().context(&context)?.clone();
+    let signer = wallet.signer();
+    signer.take_elapsed();
+
+    // Build (includes signing, which is separated out below)
+    let build_start = Instant::now();
+    let tx = async {
+        let tb = call.transaction_builder().await?;
+        tb.build(&provider).await
+    }
+    .instrument(tracing::info_span!("tx_build"))
+    .await
+    .context(&context)?;
+    let build_and_sign = build_start.elapsed();
+    let sign = signer.take_elapsed();
+
+    // Submit
+    let submit_start = Instant::now();
+    let tx_id = provider
+        .send_transaction(tx)
+        .instrument(tracing::info_span!("tx_submit"))
+        .await
+        .context(&context)?;
+    let submit = submit_start.elapsed();
+    context = context.with_tx_id(tx_id);
+
+    // Confirm
+    let confirm_start = Instant::now();
+    let poll = async {
+        loop {
+            let status = provider.tx_status(&tx_id).await?;
+            if let TxStatus::SqueezedOut(SqueezedOut { reason }) = status {
+                return Err(Error::Transaction(Reason::SqueezedOut(reason)));
+            }
+            if !is_pending(&status) {
+                return Ok::<_, Error>(status);
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    };
+    let status = tokio::time::timeout(confirm_timeout, poll)
+        .instrument(tracing::info_span!("tx_confirm", tx_id = %tx_id))
+        .await
+        .map_err(|_| {
+            Error::Other(format!("{} not confirmed within {:?}", tx_id, confirm_timeout))
+        })
+        .context(&context)?
+        .context(&context)?;
+    let confirm = confirm_start.elapsed();
+
+    let breakdown = LatencyBreakdown {
+        build: build_and_sign.saturating_sub(sign),
+        sign,
+        submit,
+        confirm,
+    };
+    breakdown.emit_metrics();
+    tracing::info!(tx_id = %tx_id, %breakdown, "transaction latency");
+
+    match call.get_response(status) {
+        Ok(mut response) => {
+            response.tx_id = Some(tx_id);
+            Ok((response, breakdown))
+        }
+        Err(error) => {
+            let context = context.with_committed_block(&provider).await;
+            Err(RosettaError::from(error).with_context(context))
+        }
+    }
+}
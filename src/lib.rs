@@ -0,0 +1,354 @@
+//! Rosetta Stone
+//!
+//! Reusable building blocks for Rust + Sway integration testing on Fuel:
+//! the abigen bindings for every contract, script and predicate in this
+//! repository, shared constants, and deployment helpers. Tests, examples and
+//! downstream users depend on this crate instead of copy-pasting setup code.
+//!
+//! The bindings and the modules built on them sit behind the default `abigen`
+//! feature. Without it the crate needs neither the proc macros nor `forc`, and
+//! the `no-abigen` feature adds [`manual`] token calls encoded by hand. Both
+//! build on `native`, the SDK's provider with tokio and the in-process node;
+//! the `wasm` feature alone builds the read-only subset for the browser.
+
+#[cfg(feature = "abigen")]
+use fuels::prelude::*;
+use fuels::types::Bits256;
+
+#[cfg(feature = "abigen")]
+pub mod airdrop;
+#[cfg(feature = "alloc-profiling")]
+pub mod alloc_profiling;
+#[cfg(feature = "abigen")]
+pub mod asset_resolver;
+#[cfg(feature = "abigen")]
+pub mod attestation;
+#[cfg(feature = "native")]
+pub mod balances;
+pub mod bench_report;
+#[cfg(feature = "docker")]
+pub mod container;
+#[cfg(feature = "native")]
+pub mod dependencies;
+#[cfg(feature = "abigen")]
+pub mod deploy;
+#[cfg(feature = "abigen")]
+pub mod deployments;
+#[cfg(feature = "native")]
+pub mod error;
+#[cfg(feature = "abigen")]
+pub mod events;
+#[cfg(feature = "native")]
+pub mod faucet;
+#[cfg(feature = "native")]
+pub mod fees;
+#[cfg(feature = "abigen")]
+pub mod harness;
+#[cfg(feature = "abigen")]
+pub mod indexer;
+#[cfg(feature = "abigen")]
+pub mod integrity;
+#[cfg(feature = "native")]
+pub mod keys;
+#[cfg(feature = "aws-kms")]
+pub mod kms;
+#[cfg(feature = "native")]
+pub mod latency;
+#[cfg(feature = "no-abigen")]
+pub mod manual;
+#[cfg(feature = "native")]
+pub mod mock_signer;
+#[cfg(feature = "native")]
+pub mod network;
+#[cfg(feature = "native")]
+pub mod payment_request;
+#[cfg(feature = "abigen")]
+pub mod permit;
+#[cfg(feature = "abigen")]
+pub mod planner;
+#[cfg(feature = "native")]
+pub mod policies;
+#[cfg(feature = "abigen")]
+pub mod predicates;
+#[cfg(feature = "native")]
+pub mod queries;
+#[cfg(feature = "abigen")]
+pub mod relayer;
+#[cfg(feature = "native")]
+pub mod retry;
+#[cfg(feature = "native")]
+pub mod revert;
+#[cfg(feature = "abigen")]
+pub mod routing;
+#[cfg(feature = "abigen")]
+pub mod scenarios;
+#[cfg(feature = "native")]
+pub mod script_tx;
+#[cfg(feature = "abigen")]
+pub mod shared_node;
+#[cfg(feature = "native")]
+pub mod simulation;
+#[cfg(feature = "native")]
+pub mod snapshot;
+#[cfg(feature = "abigen")]
+pub mod sweep;
+#[cfg(feature = "abigen")]
+pub mod swap;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "abigen")]
+pub use deploy::{
+    deploy_asset_registry, deploy_asset_registry_with_salt, deploy_auction,
+    deploy_auction_with_salt, deploy_counter_v1, deploy_counter_v1_with_salt, deploy_counter_v2,
+    deploy_counter_v2_with_salt, deploy_cross_contract_call, deploy_cross_contract_call_with_salt,
+    deploy_escrow, deploy_escrow_with_salt, deploy_forwarder, deploy_forwarder_with_salt,
+    deploy_governance, deploy_governance_with_salt,
+    deploy_large_contract, deploy_large_contract_with_salt, deploy_message_receiver,
+    deploy_message_receiver_with_salt, deploy_multisig_wallet, deploy_multisig_wallet_with_salt,
+    deploy_nft, deploy_nft_with_salt, deploy_order_book, deploy_order_book_with_salt,
+    deploy_predicate_registry,
+    deploy_predicate_registry_with_salt, deploy_proxy, deploy_proxy_with_salt, deploy_share_vault,
+    deploy_share_vault_with_salt, deploy_signature_verifier, deploy_signature_verifier_with_salt,
+    deploy_src20_token, deploy_src20_token_with_salt, deploy_staking, deploy_staking_with_salt,
+    deploy_token_allowance, deploy_token_allowance_with_salt,
+    deploy_token_vault, deploy_token_vault_with_fee, deploy_token_vault_with_salt, deploy_vesting,
+    deploy_vesting_with_salt, predict_contract_id, DEFAULT_BLOB_WORDS, MAX_FEE_BPS,
+};
+#[cfg(feature = "native")]
+pub use error::{ErrorContext, RosettaError, RosettaResult};
+#[cfg(feature = "abigen")]
+pub use events::{decode_events_of, expect_single_event, ContractEvent, EventDecoder};
+#[cfg(feature = "abigen")]
+pub use harness::{launch_wallets, HarnessConfig, NodeMode, TestHarness, TestWallet};
+#[cfg(feature = "abigen")]
+pub use indexer::{
+    wallet_balance_stream, BalanceChange, BalanceStream, Buffered, EventSink, IndexedEvent,
+    Indexer, IndexerConfig, IndexerPipeline,
+};
+#[cfg(feature = "native")]
+pub use revert::RevertReason;
+#[cfg(feature = "abigen")]
+pub use routing::route_token_and_base_asset;
+
+// Load abi from json
+#[cfg(feature = "abigen")]
+abigen!(
+    Contract(
+        name = "Src20Token",
+        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
+    ),
+    Contract(
+        name = "TokenVault",
+        abi = "contracts/token-vault/out/debug/token_vault-abi.json",
+    ),
+    Contract(
+        name = "CrossContractCall",
+        abi = "contracts/cross-contract-call/out/debug/cross_contract_call-abi.json",
+    ),
+    Contract(
+        name = "TokenAllowance",
+        abi = "contracts/token-allowance/out/debug/token_allowance-abi.json",
+    ),
+    Contract(
+        name = "ShareVault",
+        abi = "contracts/share-vault/out/debug/share_vault-abi.json",
+    ),
+    Contract(
+        name = "AssetRegistry",
+        abi = "contracts/asset-registry/out/debug/asset_registry-abi.json",
+    ),
+    Contract(
+        name = "LargeContract",
+        abi = "contracts/large-contract/out/debug/large_contract-abi.json",
+    ),
+    Contract(
+        name = "MessageReceiver",
+        abi = "contracts/message-receiver/out/debug/message_receiver-abi.json",
+    ),
+    Contract(
+        name = "Nft",
+        abi = "contracts/nft/out/debug/nft-abi.json",
+    ),
+    Contract(
+        name = "Auction",
+        abi = "contracts/auction/out/debug/auction-abi.json",
+    ),
+    Contract(
+        name = "CounterV1",
+        abi = "contracts/counter-v1/out/debug/counter_v1-abi.json",
+    ),
+    Contract(
+        name = "CounterV2",
+        abi = "contracts/counter-v2/out/debug/counter_v2-abi.json",
+    ),
+    Contract(
+        name = "Escrow",
+        abi = "contracts/escrow/out/debug/escrow-abi.json",
+    ),
+    Contract(
+        name = "Forwarder",
+        abi = "contracts/forwarder/out/debug/forwarder-abi.json",
+    ),
+    Contract(
+        name = "Governance",
+        abi = "contracts/governance/out/debug/governance-abi.json",
+    ),
+    Contract(
+        name = "MaliciousToken",
+        abi = "contracts/malicious-token/out/debug/malicious_token-abi.json",
+    ),
+    Contract(
+        name = "MultisigWallet",
+        abi = "contracts/multisig-wallet/out/debug/multisig_wallet-abi.json",
+    ),
+    Contract(
+        name = "OrderBook",
+        abi = "contracts/order-book/out/debug/order_book-abi.json",
+    ),
+    Contract(
+        name = "PredicateRegistry",
+        abi = "contracts/predicate-registry/out/debug/predicate_registry-abi.json",
+    ),
+    Contract(
+        name = "Proxy",
+        abi = "contracts/proxy/out/debug/proxy-abi.json",
+    ),
+    Contract(
+        name = "ReentrancyAttacker",
+        abi = "contracts/reentrancy-attacker/out/debug/reentrancy_attacker-abi.json",
+    ),
+    Contract(
+        name = "SignatureVerifier",
+        abi = "contracts/signature-verifier/out/debug/signature_verifier-abi.json",
+    ),
+    Contract(
+        name = "Staking",
+        abi = "contracts/staking/out/debug/staking-abi.json",
+    ),
+    Contract(
+        name = "Vesting",
+        abi = "contracts/vesting/out/debug/vesting-abi.json",
+    ),
+    Script(
+        name = "Airdrop",
+        abi = "scripts/airdrop/out/debug/airdrop-abi.json",
+    ),
+    Script(
+        name = "AtomicSwap",
+        abi = "scripts/atomic-swap/out/debug/atomic_swap-abi.json",
+    ),
+    Script(
+        name = "MultiAssetTransfer",
+        abi = "scripts/multi-asset-transfer/out/debug/multi_asset_transfer-abi.json",
+    ),
+    Script(
+        name = "TokenCall",
+        abi = "scripts/token-call/out/debug/token_call-abi.json",
+    ),
+    Predicate(
+        name = "AccountPredicate",
+        abi = "predicates/account/out/debug/account_predicate-abi.json",
+    ),
+    Predicate(
+        name = "MultiSigPredicate",
+        abi = "predicates/multi-sig/out/debug/multi_sig_predicate-abi.json",
+    ),
+    Predicate(
+        name = "HtlcPredicate",
+        abi = "predicates/htlc/out/debug/htlc_predicate-abi.json",
+    ),
+    Predicate(
+        name = "PayoutPredicate",
+        abi = "predicates/payout/out/debug/payout_predicate-abi.json",
+    ),
+    Predicate(
+        name = "SponsorPredicate",
+        abi = "predicates/sponsor/out/debug/sponsor_predicate-abi.json",
+    ),
+    Predicate(
+        name = "TimelockPredicate",
+        abi = "predicates/timelock/out/debug/timelock_predicate-abi.json",
+    ),
+);
+
+/// Default amount minted by the example flows.
+pub const TOKEN_AMOUNT: u64 = 1_000_000;
+/// Raw bytes of the sub id of the SRC20 token's default asset.
+pub const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
+/// The sub id of the SRC20 token's default asset; other sub ids mint separate assets.
+pub const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
+
+/// Compiled artifacts produced by `forc build`.
+///
+/// The paths point into `out/debug`, or `out/release` when the crate is built
+/// with `ROSETTA_BUILD_PROFILE=release` (read by `build.rs`, which also builds
+/// that profile), e.g. to benchmark gas against optimized bytecode:
+///
+/// ```text
+/// ROSETTA_BUILD_PROFILE=release cargo bench
+/// ```
+///
+/// The `abigen!` bindings always read the debug ABIs; both profiles share an ABI.
+pub mod artifacts {
+    /// The `forc` build profile the artifact paths point into, `debug` or `release`.
+    pub const BUILD_PROFILE: &str = env!("ROSETTA_BUILD_PROFILE");
+
+    // The path of `$file` in the output directory of the Sway project at `$project`
+    macro_rules! artifact {
+        ($project:literal, $file:literal) => {
+            concat!($project, "/out/", env!("ROSETTA_BUILD_PROFILE"), "/", $file)
+        };
+    }
+
+    pub const SRC20_TOKEN_BIN: &str = artifact!("contracts/src20-token", "src20_token.bin");
+    pub const TOKEN_VAULT_BIN: &str = artifact!("contracts/token-vault", "token_vault.bin");
+    pub const CROSS_CONTRACT_CALL_BIN: &str =
+        artifact!("contracts/cross-contract-call", "cross_contract_call.bin");
+    pub const TOKEN_ALLOWANCE_BIN: &str =
+        artifact!("contracts/token-allowance", "token_allowance.bin");
+    pub const SHARE_VAULT_BIN: &str = artifact!("contracts/share-vault", "share_vault.bin");
+    pub const ASSET_REGISTRY_BIN: &str =
+        artifact!("contracts/asset-registry", "asset_registry.bin");
+    pub const LARGE_CONTRACT_BIN: &str =
+        artifact!("contracts/large-contract", "large_contract.bin");
+    pub const MESSAGE_RECEIVER_BIN: &str =
+        artifact!("contracts/message-receiver", "message_receiver.bin");
+    pub const NFT_BIN: &str = artifact!("contracts/nft", "nft.bin");
+    pub const AUCTION_BIN: &str = artifact!("contracts/auction", "auction.bin");
+    pub const COUNTER_V1_BIN: &str = artifact!("contracts/counter-v1", "counter_v1.bin");
+    pub const COUNTER_V2_BIN: &str = artifact!("contracts/counter-v2", "counter_v2.bin");
+    pub const ESCROW_BIN: &str = artifact!("contracts/escrow", "escrow.bin");
+    pub const FORWARDER_BIN: &str = artifact!("contracts/forwarder", "forwarder.bin");
+    pub const GOVERNANCE_BIN: &str = artifact!("contracts/governance", "governance.bin");
+    pub const MALICIOUS_TOKEN_BIN: &str =
+        artifact!("contracts/malicious-token", "malicious_token.bin");
+    pub const MULTISIG_WALLET_BIN: &str =
+        artifact!("contracts/multisig-wallet", "multisig_wallet.bin");
+    pub const ORDER_BOOK_BIN: &str = artifact!("contracts/order-book", "order_book.bin");
+    pub const PREDICATE_REGISTRY_BIN: &str =
+        artifact!("contracts/predicate-registry", "predicate_registry.bin");
+    pub const PROXY_BIN: &str = artifact!("contracts/proxy", "proxy.bin");
+    pub const REENTRANCY_ATTACKER_BIN: &str =
+        artifact!("contracts/reentrancy-attacker", "reentrancy_attacker.bin");
+    pub const SIGNATURE_VERIFIER_BIN: &str =
+        artifact!("contracts/signature-verifier", "signature_verifier.bin");
+    pub const STAKING_BIN: &str = artifact!("contracts/staking", "staking.bin");
+    pub const VESTING_BIN: &str = artifact!("contracts/vesting", "vesting.bin");
+    pub const AIRDROP_BIN: &str = artifact!("scripts/airdrop", "airdrop.bin");
+    pub const ATOMIC_SWAP_BIN: &str = artifact!("scripts/atomic-swap", "atomic_swap.bin");
+    pub const MULTI_ASSET_TRANSFER_BIN: &str =
+        artifact!("scripts/multi-asset-transfer", "multi_asset_transfer.bin");
+    pub const TOKEN_CALL_BIN: &str = artifact!("scripts/token-call", "token_call.bin");
+    pub const ACCOUNT_PREDICATE_BIN: &str =
+        artifact!("predicates/account", "account_predicate.bin");
+    pub const MULTI_SIG_PREDICATE_BIN: &str =
+        artifact!("predicates/multi-sig", "multi_sig_predicate.bin");
+    pub const HTLC_PREDICATE_BIN: &str = artifact!("predicates/htlc", "htlc_predicate.bin");
+    pub const PAYOUT_PREDICATE_BIN: &str = artifact!("predicates/payout", "payout_predicate.bin");
+    pub const SPONSOR_PREDICATE_BIN: &str =
+        artifact!("predicates/sponsor", "sponsor_predicate.bin");
+    pub const TIMELOCK_PREDICATE_BIN: &str =
+        artifact!("predicates/timelock", "timelock_predicate.bin");
+}
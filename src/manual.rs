@@ -0,0 +1,99 @@
+//! Token calls without the `abigen!` proc macros (feature `no-abigen`).
+//!
+//! Function selectors and arguments are encoded by hand with
+//! [`CallHandler::new_contract_call`], for build-constrained environments
+//! (e.g. wasm tooling) where proc macros are not available. Build the crate
+//! without the generated bindings, and so without `forc`, with:
+//!
+//! ```text
+//! cargo check --lib --no-default-features --features no-abigen
+//! ```
+//!
+//! `tests/no_abigen_operations.rs` checks these against the generated
+//! `Src20Token` methods. Like the generated calls submitted through
+//! [`call_with_context`], a failure carries the contract, method and transaction.
+
+use fuels::{
+    core::{
+        codec::{encode_fn_selector, EncoderConfig, LogDecoder},
+        traits::Tokenizable,
+    },
+    prelude::*,
+    programs::calls::{CallHandler, ContractCall},
+    types::{Bits256, ContractId, Identity},
+};
+
+use crate::error::{call_with_context, RosettaResult};
+
+// No log types are known without the ABI
+fn log_decoder() -> LogDecoder {
+    LogDecoder::new(Default::default(), Default::default())
+}
+
+/// Encodes the token's `mint(recipient, sub_id, amount)` by hand and submits it.
+pub async fn mint<A: Account + Clone>(
+    contract_id: ContractId,
+    account: &A,
+    recipient: Identity,
+    sub_id: Option<Bits256>,
+    amount: u64,
+) -> RosettaResult<()> {
+    let args = [
+        recipient.into_token(),
+        sub_id.into_token(),
+        amount.into_token(),
+    ];
+
+    let call = CallHandler::<_, ContractCall, ()>::new_contract_call(
+        contract_id,
+        account.clone(),
+        encode_fn_selector("mint"),
+        &args,
+        log_decoder(),
+        false,
+        EncoderConfig::default(),
+    )
+    .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+    call_with_context(call).await?;
+
+    Ok(())
+}
+
+/// Encodes the token's `get_asset_id()` by hand and decodes the returned [`AssetId`].
+pub async fn get_asset_id<A: Account + Clone>(
+    contract_id: ContractId,
+    account: &A,
+) -> RosettaResult<AssetId> {
+    let call = CallHandler::<_, ContractCall, AssetId>::new_contract_call(
+        contract_id,
+        account.clone(),
+        encode_fn_selector("get_asset_id"),
+        &[],
+        log_decoder(),
+        false,
+        EncoderConfig::default(),
+    );
+    let response = call_with_context(call).await?;
+
+    Ok(response.value)
+}
+
+/// Encodes the token's `total_supply(asset)` by hand and decodes the returned `Option<u64>`.
+pub async fn total_supply<A: Account + Clone>(
+    contract_id: ContractId,
+    account: &A,
+    asset_id: AssetId,
+) -> RosettaResult<Option<u64>> {
+    let call = CallHandler::<_, ContractCall, Option<u64>>::new_contract_call(
+        contract_id,
+        account.clone(),
+        encode_fn_selector("total_supply"),
+        &[asset_id.into_token()],
+        log_decoder(),
+        false,
+        EncoderConfig::default(),
+    );
+    let response = call_with_context(call).await?;
+
+    Ok(response.value)
+}
@@ -0,0 +1,79 @@
+//! A deterministic signer for reproducible test vectors.
+//!
+//! [`MockSigner`] signs with a fixed key derived from a seed byte and records
+//! every message it signs. Fuel signatures are deterministic (RFC 6979), so
+//! the same key, coins and policies always produce the same transaction id and
+//! the same witness, and tests can assert on them byte for byte. A change in
+//! an SDK upgrade then shows up as a fixture mismatch rather than a silent
+//! encoding difference.
+//!
+//! ```ignore
+//! let signer = MockSigner::fixed(1);
+//! let wallet = Wallet::new(signer.clone(), provider);
+//! // ... build and sign a transaction ...
+//! let (message, signature) = signer.signed().pop().unwrap();
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    core::traits::Signer,
+    crypto::{Message, SecretKey, Signature},
+    prelude::*,
+    types::Address,
+};
+
+/// A signer with a fixed key that records what it signs.
+///
+/// Clones share the record, so a clone handed to a wallet still reports to
+/// the original.
+#[derive(Debug, Clone)]
+pub struct MockSigner {
+    secret_key: SecretKey,
+    inner: PrivateKeySigner,
+    signed: Arc<Mutex<Vec<(Message, Signature)>>>,
+}
+
+impl MockSigner {
+    /// The signer whose secret key is `seed` repeated 32 times.
+    pub fn fixed(seed: u8) -> Self {
+        let secret_key = SecretKey::try_from([seed; 32].as_slice())
+            .expect("a repeated non-zero byte is a valid secret key");
+        Self::from_secret_key(secret_key)
+    }
+
+    pub fn from_secret_key(secret_key: SecretKey) -> Self {
+        Self {
+            secret_key,
+            inner: PrivateKeySigner::new(secret_key),
+            signed: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn secret_key(&self) -> SecretKey {
+        self.secret_key
+    }
+
+    /// Every message signed so far with its signature, oldest first.
+    pub fn signed(&self) -> Vec<(Message, Signature)> {
+        self.signed.lock().expect("signing record poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl Signer for MockSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let signature = self.inner.sign(message).await?;
+        self.signed
+            .lock()
+            .expect("signing record poisoned")
+            .push((message, signature));
+        Ok(signature)
+    }
+
+    fn address(&self) -> Address {
+        self.inner.address()
+    }
+}
@@ -0,0 +1,59 @@
+//! Network profiles used by the CLI and example binaries.
+
+use std::path::Path;
+
+use fuels::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::retry::{connect_with_retry, RetryPolicy};
+
+/// A named node endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkProfile {
+    pub name: String,
+    pub node_url: String,
+}
+
+impl NetworkProfile {
+    pub fn local() -> Self {
+        Self {
+            name: "local".to_string(),
+            node_url: "127.0.0.1:4000".to_string(),
+        }
+    }
+
+    pub fn devnet() -> Self {
+        Self {
+            name: "devnet".to_string(),
+            node_url: "https://devnet.fuel.network/v1/graphql".to_string(),
+        }
+    }
+
+    pub fn testnet() -> Self {
+        Self {
+            name: "testnet".to_string(),
+            node_url: "https://testnet.fuel.network/v1/graphql".to_string(),
+        }
+    }
+
+    /// Resolves a built-in profile name (`local`, `devnet`, `testnet`) or a
+    /// path to a JSON profile file.
+    pub fn resolve(name_or_path: &str) -> Result<Self> {
+        match name_or_path {
+            "local" => Ok(Self::local()),
+            "devnet" => Ok(Self::devnet()),
+            "testnet" => Ok(Self::testnet()),
+            path => Self::load(path),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path.as_ref())?;
+        serde_json::from_str(&json).map_err(|e| Error::Other(e.to_string()))
+    }
+
+    /// Connects to the profile's node, retrying transient failures.
+    pub async fn connect(&self) -> Result<Provider> {
+        connect_with_retry(&self.node_url, &RetryPolicy::default()).await
+    }
+}
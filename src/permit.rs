@@ -0,0 +1,184 @@
+//! Off-chain signed approvals for the TokenAllowance contract and the token.
+//!
+//! A token holder signs a [`Permit`] without sending a transaction; the
+//! spender submits it with `permit` and can spend it with `transfer_from` in
+//! the same transaction. Permits carry a block-height deadline and the owner's
+//! current nonce, so they expire and cannot be replayed.
+//!
+//! The SRC-20 token takes the same permits against its own nonces: build one
+//! with [`prepare_token_permit`], and any wallet can relay the signature with
+//! [`submit_token_permit`] and pay the fee. Since each permit consumes the
+//! owner's nonce, permits land in the order they were signed, and an owner can
+//! cancel a pending one by submitting a newer permit first.
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    crypto::{Message, Signature},
+    prelude::*,
+    programs::{calls::CallHandler, responses::CallResponse},
+    types::{Address, ContractId, Identity, B512},
+};
+
+use crate::{Src20Token, TokenAllowance};
+
+/// An approval of `amount` for `spender` over `owner`'s balance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permit {
+    pub owner: Address,
+    pub spender: Identity,
+    pub amount: u64,
+    /// Last block height at which the permit can be submitted.
+    pub deadline: u32,
+    pub nonce: u64,
+}
+
+/// A permit with the owner's signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedPermit {
+    pub permit: Permit,
+    pub signature: Signature,
+}
+
+impl Permit {
+    /// The bytes the contract hashes, laid out as Sway's `Hash` writes them:
+    /// contract id, owner, spender (prefixed with 0 for an address or 1 for a
+    /// contract), then amount, deadline and nonce as big-endian integers.
+    pub fn encode(&self, contract_id: &ContractId) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 + 32 + 33 + 8 + 4 + 8);
+        bytes.extend_from_slice(contract_id.as_ref());
+        bytes.extend_from_slice(self.owner.as_ref());
+        encode_identity(&mut bytes, &self.spender);
+        bytes.extend_from_slice(&self.amount.to_be_bytes());
+        bytes.extend_from_slice(&self.deadline.to_be_bytes());
+        bytes.extend_from_slice(&self.nonce.to_be_bytes());
+        bytes
+    }
+
+    /// The message the owner signs for the contract at `contract_id`.
+    pub fn message(&self, contract_id: &ContractId) -> Message {
+        Message::new(self.encode(contract_id))
+    }
+
+    /// Signs the permit with `signer`, which must be the owner.
+    pub async fn sign(self, signer: &impl Signer, contract_id: &ContractId) -> Result<SignedPermit> {
+        if signer.address() != self.owner {
+            return Err(Error::Other(format!(
+                "permit owner {} does not match signer {}",
+                self.owner,
+                signer.address()
+            )));
+        }
+        let signature = signer.sign(self.message(contract_id)).await?;
+        Ok(SignedPermit {
+            permit: self,
+            signature,
+        })
+    }
+}
+
+// An identity as Sway's `Hash` writes it: 0 for an address or 1 for a contract, then the id
+pub(crate) fn encode_identity(bytes: &mut Vec<u8>, identity: &Identity) {
+    match identity {
+        Identity::Address(address) => {
+            bytes.push(0);
+            bytes.extend_from_slice(address.as_ref());
+        }
+        Identity::ContractId(contract_id) => {
+            bytes.push(1);
+            bytes.extend_from_slice(contract_id.as_ref());
+        }
+    }
+}
+
+impl SignedPermit {
+    /// The signature in the form the contract takes it.
+    pub fn signature_b512(&self) -> Result<B512> {
+        B512::try_from(self.signature.as_ref())
+    }
+}
+
+/// Builds a permit for `owner` using the nonce currently stored in the contract.
+pub async fn prepare_permit(
+    allowance: &TokenAllowance<Wallet<Unlocked<PrivateKeySigner>>>,
+    owner: Address,
+    spender: Identity,
+    amount: u64,
+    deadline: u32,
+) -> Result<Permit> {
+    let nonce = allowance.methods().nonce(owner).call().await?.value;
+    Ok(Permit {
+        owner,
+        spender,
+        amount,
+        deadline,
+        nonce,
+    })
+}
+
+/// Submits `signed` and spends `amount` of it to `recipient` in one transaction.
+///
+/// `allowance` must be bound to the spender's wallet.
+pub async fn permit_and_transfer_from(
+    allowance: &TokenAllowance<Wallet<Unlocked<PrivateKeySigner>>>,
+    signed: &SignedPermit,
+    recipient: Identity,
+    amount: u64,
+) -> Result<CallResponse<((), ())>> {
+    let permit = signed.permit;
+    let permit_call = allowance.methods().permit(
+        permit.owner,
+        permit.spender,
+        permit.amount,
+        permit.deadline,
+        signed.signature_b512()?,
+    );
+    let transfer_call = allowance.methods().transfer_from(
+        Identity::Address(permit.owner),
+        recipient,
+        amount,
+    );
+
+    CallHandler::new_multi_call(allowance.account().clone())
+        .add_call(permit_call)
+        .add_call(transfer_call)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call::<((), ())>()
+        .await
+}
+
+/// Builds a permit for `owner` using the nonce currently stored in the token.
+pub async fn prepare_token_permit<A: Account>(
+    token: &Src20Token<A>,
+    owner: Address,
+    spender: Identity,
+    amount: u64,
+    deadline: u32,
+) -> Result<Permit> {
+    let nonce = token.methods().nonce(owner).call().await?.value;
+    Ok(Permit {
+        owner,
+        spender,
+        amount,
+        deadline,
+        nonce,
+    })
+}
+
+/// Submits `signed` to the token, paid for by the wallet `token` is bound to.
+pub async fn submit_token_permit<A: Account>(
+    token: &Src20Token<A>,
+    signed: &SignedPermit,
+) -> Result<CallResponse<()>> {
+    let permit = signed.permit;
+    token
+        .methods()
+        .permit(
+            permit.owner,
+            permit.spender,
+            permit.amount,
+            permit.deadline,
+            signed.signature_b512()?,
+        )
+        .call()
+        .await
+}
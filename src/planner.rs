@@ -0,0 +1,271 @@
+//! Plans and executes the token movements that bring harness users to a target state.
+//!
+//! Targets say how many tokens a user should end up holding in their wallet
+//! and/or in the vault. The planner reads current balances, works out the
+//! vault deposits and withdrawals, covers wallet shortfalls with transfers from
+//! users that hold more than their target, and mints only what is still missing.
+//! Any leftover surplus is sent to the admin wallet.
+//!
+//! A failed vault call while observing or executing carries the contract,
+//! method and transaction it happened in (see [`crate::ErrorContext`]).
+
+use std::{collections::BTreeMap, fmt};
+
+use fuels::{
+    prelude::*,
+    types::{Address, Identity},
+};
+
+use crate::{
+    error::{call_with_context, RosettaResult},
+    TestHarness,
+};
+
+/// Desired end state for one user. `None` leaves that balance unconstrained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Target {
+    pub user: usize,
+    pub wallet: Option<u64>,
+    pub vault: Option<u64>,
+}
+
+impl Target {
+    pub fn new(user: usize) -> Self {
+        Self {
+            user,
+            ..Self::default()
+        }
+    }
+
+    pub fn wallet(mut self, amount: u64) -> Self {
+        self.wallet = Some(amount);
+        self
+    }
+
+    pub fn vault(mut self, amount: u64) -> Self {
+        self.vault = Some(amount);
+        self
+    }
+}
+
+/// Token balances of one user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UserBalances {
+    pub wallet: u64,
+    pub vault: u64,
+}
+
+/// Who receives surplus tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Account {
+    Admin,
+    User(usize),
+}
+
+/// One token movement, executed in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Withdraw { user: usize, amount: u64 },
+    Transfer { from: usize, to: Account, amount: u64 },
+    Mint { to: usize, amount: u64 },
+    Deposit { user: usize, amount: u64 },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Withdraw { user, amount } => write!(f, "user {} withdraws {}", user, amount),
+            Step::Transfer { from, to, amount } => match to {
+                Account::Admin => write!(f, "user {} sends {} to admin", from, amount),
+                Account::User(to) => write!(f, "user {} sends {} to user {}", from, amount, to),
+            },
+            Step::Mint { to, amount } => write!(f, "mint {} to user {}", amount, to),
+            Step::Deposit { user, amount } => write!(f, "user {} deposits {}", user, amount),
+        }
+    }
+}
+
+/// The steps to run and the balances they are expected to produce.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Plan {
+    pub steps: Vec<Step>,
+    pub expected: BTreeMap<usize, UserBalances>,
+}
+
+/// Computes a plan from known balances.
+///
+/// `current` must contain every user named in `targets`.
+pub fn plan(current: &BTreeMap<usize, UserBalances>, targets: &[Target]) -> Result<Plan> {
+    let mut withdrawals = Vec::new();
+    let mut deposits = Vec::new();
+    // (user, amount) held beyond, or missing from, the wallet target plus any deposit.
+    let mut surpluses = Vec::new();
+    let mut deficits = Vec::new();
+    let mut expected = BTreeMap::new();
+
+    for target in targets {
+        let balances = current
+            .get(&target.user)
+            .copied()
+            .ok_or_else(|| Error::Other(format!("no balances for user {}", target.user)))?;
+
+        let vault_target = target.vault.unwrap_or(balances.vault);
+        let mut wallet = balances.wallet;
+        let mut needed = 0;
+        if vault_target < balances.vault {
+            let amount = balances.vault - vault_target;
+            withdrawals.push(Step::Withdraw { user: target.user, amount });
+            wallet += amount;
+        } else if vault_target > balances.vault {
+            let amount = vault_target - balances.vault;
+            deposits.push(Step::Deposit { user: target.user, amount });
+            needed += amount;
+        }
+
+        // Without a wallet target the user keeps whatever is left after depositing.
+        let wallet_target = target.wallet.unwrap_or(wallet.saturating_sub(needed));
+        needed += wallet_target;
+        if wallet > needed {
+            surpluses.push((target.user, wallet - needed));
+        } else if wallet < needed {
+            deficits.push((target.user, needed - wallet));
+        }
+
+        expected.insert(
+            target.user,
+            UserBalances {
+                wallet: wallet_target,
+                vault: vault_target,
+            },
+        );
+    }
+
+    // Cover deficits from surpluses first, then mint the rest.
+    let mut transfers = Vec::new();
+    let mut mints = Vec::new();
+    let mut surpluses = surpluses.into_iter();
+    let mut remaining_surplus = None;
+    for (user, mut deficit) in deficits {
+        while deficit > 0 {
+            let Some((from, available)) = remaining_surplus.take().or_else(|| surpluses.next())
+            else {
+                break;
+            };
+            let amount = deficit.min(available);
+            transfers.push(Step::Transfer {
+                from,
+                to: Account::User(user),
+                amount,
+            });
+            deficit -= amount;
+            if available > amount {
+                remaining_surplus = Some((from, available - amount));
+            }
+        }
+        if deficit > 0 {
+            mints.push(Step::Mint { to: user, amount: deficit });
+        }
+    }
+    for (from, amount) in remaining_surplus.into_iter().chain(surpluses) {
+        transfers.push(Step::Transfer {
+            from,
+            to: Account::Admin,
+            amount,
+        });
+    }
+
+    let steps = withdrawals
+        .into_iter()
+        .chain(transfers)
+        .chain(mints)
+        .chain(deposits)
+        .collect();
+
+    Ok(Plan { steps, expected })
+}
+
+/// Reads the wallet and vault balances of `users`.
+pub async fn observe(
+    harness: &TestHarness,
+    users: &[usize],
+) -> RosettaResult<BTreeMap<usize, UserBalances>> {
+    let mut balances = BTreeMap::new();
+    for &user in users {
+        let wallet = harness.user(user);
+        let vault = call_with_context(
+            harness
+                .vault
+                .methods()
+                .get_deposit_for_asset(Identity::Address(wallet.address().into()), harness.asset_id),
+        )
+        .await?
+        .value;
+        let wallet_balance = harness.token_balance(wallet).await?;
+        let wallet_balance = u64::try_from(wallet_balance).map_err(|_| {
+            Error::Other(format!(
+                "balance {} of user {} does not fit in a u64",
+                wallet_balance, user
+            ))
+        })?;
+        balances.insert(
+            user,
+            UserBalances {
+                wallet: wallet_balance,
+                vault,
+            },
+        );
+    }
+    Ok(balances)
+}
+
+/// Observes the current balances of the targeted users and plans against them.
+pub async fn plan_for(harness: &TestHarness, targets: &[Target]) -> RosettaResult<Plan> {
+    let users: Vec<usize> = targets.iter().map(|target| target.user).collect();
+    let current = observe(harness, &users).await?;
+    Ok(plan(&current, targets)?)
+}
+
+/// Runs every step of `plan` with the harness handles.
+pub async fn execute(harness: &TestHarness, plan: &Plan) -> RosettaResult<()> {
+    for step in &plan.steps {
+        match *step {
+            Step::Withdraw { user, amount } => {
+                call_with_context(
+                    harness
+                        .vault_as(harness.user(user))
+                        .methods()
+                        .withdraw(amount)
+                        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+                        .with_variable_output_policy(VariableOutputPolicy::Exactly(1)),
+                )
+                .await?;
+            }
+            Step::Transfer { from, to, amount } => {
+                let recipient: Address = match to {
+                    Account::Admin => harness.admin.address().into(),
+                    Account::User(to) => harness.user(to).address().into(),
+                };
+                harness
+                    .user(from)
+                    .transfer(recipient, amount, harness.asset_id, TxPolicies::default())
+                    .await?;
+            }
+            Step::Mint { to, amount } => harness.mint_to(harness.user(to), amount).await?,
+            Step::Deposit { user, amount } => {
+                call_with_context(
+                    harness
+                        .vault_as(harness.user(user))
+                        .methods()
+                        .deposit()
+                        .call_params(
+                            CallParameters::default()
+                                .with_amount(amount)
+                                .with_asset_id(harness.asset_id),
+                        )?,
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Preset transaction policies.
+//!
+//! Tests and examples pick one of these instead of spelling out gas limits and
+//! fee caps inline. Every preset is a plain [`TxPolicies`], so it can still be
+//! adjusted with the usual `with_*` methods.
+//!
+//! ```ignore
+//! token.methods().mint(recipient, Some(SUB_ID), amount)
+//!     .with_tx_policies(policies::fast())
+//!     .call()
+//!     .await?;
+//! ```
+
+use fuels::prelude::*;
+
+/// Tip paid by [`fast`] to be included ahead of untipped transactions.
+pub const FAST_TIP: u64 = 1_000;
+/// Fee cap of [`cheap`].
+pub const CHEAP_MAX_FEE: u64 = 500_000;
+/// Script gas limit of [`script_heavy`].
+pub const SCRIPT_HEAVY_GAS_LIMIT: u64 = 2_000_000;
+/// Fee cap of [`script_heavy`].
+pub const SCRIPT_HEAVY_MAX_FEE: u64 = 1_000_000;
+
+/// Tips the block producer so the transaction is prioritized in the pool.
+pub fn fast() -> TxPolicies {
+    TxPolicies::default().with_tip(FAST_TIP)
+}
+
+/// No tip and a capped fee; the transaction is rejected rather than paying more.
+pub fn cheap() -> TxPolicies {
+    TxPolicies::default().with_tip(0).with_max_fee(CHEAP_MAX_FEE)
+}
+
+/// A fixed, generous gas limit for scripts that loop over many inputs or calls,
+/// where estimation is slow or unavailable (e.g. hand-built transactions).
+pub fn script_heavy() -> TxPolicies {
+    TxPolicies::default()
+        .with_script_gas_limit(SCRIPT_HEAVY_GAS_LIMIT)
+        .with_max_fee(SCRIPT_HEAVY_MAX_FEE)
+}
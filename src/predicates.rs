@@ -0,0 +1,207 @@
+//! Funding and spending predicates.
+//!
+//! A predicate owns coins like a wallet but has no key: spending them takes a
+//! transaction whose predicate inputs evaluate to true, which for the signature
+//! predicates in this repository means carrying the right witnesses. These
+//! helpers assemble those transactions in the base asset:
+//!
+//! ```ignore
+//! fund_predicate(&wallets[0], &predicate, 500_000).await?;
+//! let status =
+//!     spend_from_predicate(&predicate, &[&wallets[0], &wallets[1]], recipient, 300_000).await?;
+//! ```
+//!
+//! [`multisig_predicate`] configures the multi-sig predicate for three signers
+//! and a threshold, [`funded_multisig`] launches such signers with a funded
+//! predicate for tests, and [`base_balance`] reads what an account holds of the
+//! chain's base asset. [`spend_asset_from_predicate`] spends any other asset
+//! the same way, with the fee still paid in the base asset.
+//!
+//! The sponsor predicate works the other way round: it holds base asset only
+//! to pay fees, and [`sponsored_transfer`] adds its coins to a user's token
+//! transfer so that the user needs no base asset at all.
+
+use fuels::{
+    prelude::*,
+    types::{
+        output::Output, transaction_builders::ScriptTransactionBuilder, tx_response::TxResponse,
+        tx_status::TxStatus, Address,
+    },
+};
+
+use crate::{artifacts, launch_wallets, MultiSigPredicateConfigurables, TestWallet};
+
+/// The multi-sig predicate over `signers`, spendable with the signatures of
+/// the first `required` of them (see [`witness_order`]).
+///
+/// The predicate is connected to the first signer's provider.
+pub fn multisig_predicate<A: ViewOnlyAccount>(
+    signers: [&A; 3],
+    required: u64,
+) -> Result<Predicate> {
+    let provider = signers[0].try_provider()?.clone();
+    let configurables = MultiSigPredicateConfigurables::default()
+        .with_SIGNERS(signers.map(|signer| signer.address().into()))?
+        .with_REQUIRED_SIGNATURES(required)?;
+
+    Ok(Predicate::load_from(artifacts::MULTI_SIG_PREDICATE_BIN)?
+        .with_provider(provider)
+        .with_configurables(configurables))
+}
+
+/// Launches three signers and a recipient on a fresh node, with the signers'
+/// `required`-of-3 [`multisig_predicate`] funded with `amount` of the base
+/// asset by the first signer.
+pub async fn funded_multisig(
+    required: u64,
+    amount: u64,
+) -> Result<(Vec<TestWallet>, TestWallet, Predicate)> {
+    let mut signers =
+        launch_wallets(WalletsConfig::new(Some(4), Some(1), Some(1_000_000))).await?;
+    let recipient = signers.pop().expect("four wallets were requested");
+
+    let predicate = multisig_predicate([&signers[0], &signers[1], &signers[2]], required)?;
+    fund_predicate(&signers[0], &predicate, amount).await?;
+    Ok((signers, recipient, predicate))
+}
+
+/// Balance of the chain's base asset held by `account`.
+pub async fn base_balance(account: &impl ViewOnlyAccount) -> Result<u128> {
+    let base_asset_id = *account.try_provider()?.consensus_parameters().await?.base_asset_id();
+    account.get_asset_balance(&base_asset_id).await
+}
+
+/// Transfers `amount` of the base asset from `wallet` to `predicate`.
+pub async fn fund_predicate(
+    wallet: &impl Account,
+    predicate: &Predicate,
+    amount: u64,
+) -> Result<TxResponse> {
+    let base_asset_id = *wallet.try_provider()?.consensus_parameters().await?.base_asset_id();
+    wallet
+        .transfer(predicate.address(), amount, base_asset_id, TxPolicies::default())
+        .await
+}
+
+/// Sends `amount` of the base asset from `predicate` to `to`, signed by `signers`.
+///
+/// The predicate pays the fee out of its change, so every signer only adds a
+/// witness, in the order given (see [`witness_order`]). Returns the committed
+/// status; a transaction the predicate rejects, or one that fails on chain, is
+/// an error.
+pub async fn spend_from_predicate<A: Account>(
+    predicate: &Predicate,
+    signers: &[&A],
+    to: Address,
+    amount: u64,
+) -> Result<TxStatus> {
+    let base_asset_id = *predicate
+        .try_provider()?
+        .consensus_parameters()
+        .await?
+        .base_asset_id();
+    spend_asset_from_predicate(predicate, signers, to, base_asset_id, amount).await
+}
+
+/// [`spend_from_predicate`] for any asset the predicate holds.
+///
+/// The fee is still paid in the base asset, so the predicate needs some of it
+/// besides `asset`.
+pub async fn spend_asset_from_predicate<A: Account>(
+    predicate: &Predicate,
+    signers: &[&A],
+    to: Address,
+    asset_id: AssetId,
+    amount: u64,
+) -> Result<TxStatus> {
+    let provider = predicate.try_provider()?;
+
+    let inputs = predicate
+        .get_asset_inputs_for_amount(asset_id, u128::from(amount), None)
+        .await?;
+    let outputs = predicate.get_asset_outputs_for_amount(to, asset_id, amount);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+
+    predicate.adjust_for_fee(&mut tb, 0).await?;
+    for signer in signers {
+        signer.add_witnesses(&mut tb)?;
+    }
+
+    let tx = tb.build(provider).await?;
+    let status = provider.send_transaction_and_await_commit(tx).await?;
+    status.check(None)?;
+
+    Ok(status)
+}
+
+/// Sends `amount` of `asset` from `user` to `to`, with the fee paid by `sponsor`.
+///
+/// `user` contributes only the `asset` coins and a witness; the base-asset
+/// inputs come from the sponsor predicate, and the change output returning
+/// what the fee leaves over goes back to it. `asset` must not be the base
+/// asset, which the sponsor predicate does not let the transaction pay out.
+pub async fn sponsored_transfer<A: Account>(
+    sponsor: &Predicate,
+    user: &A,
+    to: Address,
+    asset: AssetId,
+    amount: u64,
+    policies: TxPolicies,
+) -> Result<TxStatus> {
+    let provider = sponsor.try_provider()?;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+
+    let inputs = user.get_asset_inputs_for_amount(asset, u128::from(amount), None).await?;
+    let mut outputs = user.get_asset_outputs_for_amount(to, asset, amount);
+    outputs.push(Output::change(sponsor.address(), 0, base_asset_id));
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, policies);
+
+    sponsor.adjust_for_fee(&mut tb, 0).await?;
+    user.add_witnesses(&mut tb)?;
+
+    let tx = tb.build(provider).await?;
+    let status = provider.send_transaction_and_await_commit(tx).await?;
+    status.check(None)?;
+
+    Ok(status)
+}
+
+/// Index of `signer` in a predicate's signer list, the witness slot it fills.
+pub fn witness_index(expected: &[Address], signer: Address) -> Option<usize> {
+    expected.iter().position(|address| *address == signer)
+}
+
+/// Orders `signers` into the witness order a signer-list predicate expects.
+///
+/// The multi-sig predicate counts witness slot `i` only if `expected[i]`
+/// signed, and fails outright when a slot's signer is missing, so `k`
+/// signatures must come from the first `k` entries of the list. The signers
+/// are returned sorted by their index; an unknown or repeated signer, or a
+/// gap in the indices, is an error before anything is submitted.
+pub fn witness_order<'a, A: ViewOnlyAccount>(
+    expected: &[Address],
+    signers: &[&'a A],
+) -> Result<Vec<&'a A>> {
+    let mut indexed = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let address: Address = signer.address().into();
+        let index = witness_index(expected, address)
+            .ok_or_else(|| Error::Other(format!("{} is not a signer of the predicate", address)))?;
+        indexed.push((index, *signer));
+    }
+    indexed.sort_by_key(|(index, _)| *index);
+
+    for (slot, (index, _)) in indexed.iter().enumerate() {
+        if *index < slot {
+            return Err(Error::Other(format!("{} signs twice", expected[*index])));
+        }
+        if *index > slot {
+            return Err(Error::Other(format!(
+                "witness {} must be signed by {}, which is missing",
+                slot, expected[slot]
+            )));
+        }
+    }
+
+    Ok(indexed.into_iter().map(|(_, signer)| signer).collect())
+}
@@ -0,0 +1,170 @@
+//! Typed wrappers over the node's GraphQL queries.
+//!
+//! The provider exposes the node's GraphQL API as plain methods: coins of an
+//! owner, a transaction by id, a block by height, and paginated transaction
+//! history. The wrappers here flatten the SDK responses into small structs
+//! tests can assert on directly.
+
+use fuels::{
+    client::{PageDirection, PaginationRequest},
+    prelude::*,
+    tx::{TxId, UtxoId},
+    types::{
+        transaction::{Transaction, TransactionType},
+        transaction_response::TransactionResponse,
+        tx_status::TxStatus,
+        Address, Bytes32, ChainId,
+    },
+};
+
+/// A spendable coin of an owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoinSummary {
+    pub utxo_id: UtxoId,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// Outcome of a transaction as reported by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxOutcome {
+    Success,
+    Failure,
+    Pending,
+}
+
+/// The parts of a transaction tests usually look at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionSummary {
+    pub tx_id: TxId,
+    /// "script", "create", "mint", "upgrade", "upload", "blob" or "unknown".
+    pub kind: &'static str,
+    pub outcome: TxOutcome,
+    pub block_height: Option<u32>,
+    pub input_count: usize,
+    pub output_count: usize,
+    /// Fee charged, once the transaction has a final status.
+    pub total_fee: Option<u64>,
+}
+
+/// A block and the ids of the transactions it includes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSummary {
+    pub height: u32,
+    pub id: Bytes32,
+    pub tx_ids: Vec<TxId>,
+}
+
+/// Spendable coins of `owner` in `asset_id`.
+pub async fn coins_of(
+    provider: &Provider,
+    owner: &Address,
+    asset_id: AssetId,
+) -> Result<Vec<CoinSummary>> {
+    let coins = provider.get_coins(owner, asset_id).await?;
+
+    Ok(coins
+        .into_iter()
+        .map(|coin| CoinSummary {
+            utxo_id: coin.utxo_id,
+            asset_id: coin.asset_id,
+            amount: coin.amount,
+        })
+        .collect())
+}
+
+/// Looks up a transaction by id; `None` if the node does not know it.
+pub async fn transaction_by_id(
+    provider: &Provider,
+    tx_id: &TxId,
+) -> Result<Option<TransactionSummary>> {
+    let response = provider.get_transaction_by_id(tx_id).await?;
+
+    Ok(response.map(|response| summarize(*tx_id, response)))
+}
+
+/// Looks up a block by height; `None` past the chain tip.
+pub async fn block_by_height(provider: &Provider, height: u32) -> Result<Option<BlockSummary>> {
+    let block = provider.block_by_height(height.into()).await?;
+
+    Ok(block.map(|block| BlockSummary {
+        height: block.header.height,
+        id: block.id,
+        tx_ids: block.transactions,
+    }))
+}
+
+/// Every transaction involving `owner`, oldest first, fetched `page_size` at a time.
+pub async fn transactions_of(
+    provider: &Provider,
+    owner: &Address,
+    page_size: i32,
+) -> Result<Vec<TransactionSummary>> {
+    let chain_id = provider.consensus_parameters().await?.chain_id();
+
+    let mut summaries = vec![];
+    let mut cursor = None;
+    loop {
+        let page = provider
+            .get_transactions_by_owner(
+                owner,
+                PaginationRequest {
+                    cursor,
+                    results: page_size,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await?;
+
+        for response in page.results {
+            let tx_id = tx_id_of(&response, &chain_id);
+            summaries.push(summarize(tx_id, response));
+        }
+
+        if !page.has_next_page {
+            return Ok(summaries);
+        }
+        cursor = page.cursor;
+    }
+}
+
+fn summarize(tx_id: TxId, response: TransactionResponse) -> TransactionSummary {
+    let (kind, input_count, output_count) = match &response.transaction {
+        TransactionType::Script(tx) => ("script", tx.inputs().len(), tx.outputs().len()),
+        TransactionType::Create(tx) => ("create", tx.inputs().len(), tx.outputs().len()),
+        TransactionType::Mint(_) => ("mint", 0, 1),
+        TransactionType::Upgrade(tx) => ("upgrade", tx.inputs().len(), tx.outputs().len()),
+        TransactionType::Upload(tx) => ("upload", tx.inputs().len(), tx.outputs().len()),
+        TransactionType::Blob(tx) => ("blob", tx.inputs().len(), tx.outputs().len()),
+        _ => ("unknown", 0, 0),
+    };
+
+    let (outcome, total_fee) = match &response.status {
+        TxStatus::Success(success) => (TxOutcome::Success, Some(success.total_fee)),
+        TxStatus::Failure(failure) => (TxOutcome::Failure, Some(failure.total_fee)),
+        _ => (TxOutcome::Pending, None),
+    };
+
+    TransactionSummary {
+        tx_id,
+        kind,
+        outcome,
+        block_height: response.block_height.map(|height| *height),
+        input_count,
+        output_count,
+        total_fee,
+    }
+}
+
+// Paginated history does not carry ids, so they are recomputed from the transaction.
+// Mint transactions only pay block producers, so they never show up in an owner's history
+pub(crate) fn tx_id_of(response: &TransactionResponse, chain_id: &ChainId) -> TxId {
+    match &response.transaction {
+        TransactionType::Script(tx) => tx.id(*chain_id),
+        TransactionType::Create(tx) => tx.id(*chain_id),
+        TransactionType::Upgrade(tx) => tx.id(*chain_id),
+        TransactionType::Upload(tx) => tx.id(*chain_id),
+        TransactionType::Blob(tx) => tx.id(*chain_id),
+        _ => TxId::zeroed(),
+    }
+}
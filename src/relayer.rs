@@ -0,0 +1,111 @@
+//! Meta-transactions: transfers signed off chain and relayed through the Forwarder contract.
+//!
+//! A user's coins are held by the forwarder (credited with `deposit_for`). To
+//! move them the user signs a [`TransferIntent`] with their current nonce and a
+//! block-height deadline, without sending a transaction. A [`Relayer`] submits
+//! the intent from its own wallet and pays the fee, so the user never needs
+//! the base asset.
+//!
+//! ```ignore
+//! let intent = prepare_intent(&forwarder, user.address(), to, asset, 100, deadline).await?;
+//! let signature = sign_intent(&user_key, &intent, &forwarder.contract_id()).await?;
+//! Relayer::new(forwarder.with_account(relayer_wallet)).relay(intent, signature).await?;
+//! ```
+
+use fuels::{
+    crypto::Message,
+    prelude::*,
+    programs::responses::CallResponse,
+    types::{Address, ContractId, Identity, B512},
+};
+
+use crate::{permit::encode_identity, Forwarder, TransferIntent};
+
+/// The bytes the forwarder hashes, laid out as Sway's `Hash` writes them:
+/// contract id, sender and recipient (prefixed 0 for an address or 1 for a
+/// contract), asset, then amount, nonce and deadline as big-endian integers.
+pub fn encode_intent(intent: &TransferIntent, contract_id: &ContractId) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(32 + 32 + 33 + 32 + 8 + 8 + 4);
+    bytes.extend_from_slice(contract_id.as_ref());
+    bytes.extend_from_slice(intent.from.as_ref());
+    encode_identity(&mut bytes, &intent.to);
+    bytes.extend_from_slice(intent.asset.as_ref());
+    bytes.extend_from_slice(&intent.amount.to_be_bytes());
+    bytes.extend_from_slice(&intent.nonce.to_be_bytes());
+    bytes.extend_from_slice(&intent.deadline.to_be_bytes());
+    bytes
+}
+
+/// The message signed for the forwarder at `contract_id`.
+pub fn intent_message(intent: &TransferIntent, contract_id: &ContractId) -> Message {
+    Message::new(encode_intent(intent, contract_id))
+}
+
+/// Signs `intent` with `signer`, which must be its sender.
+pub async fn sign_intent(
+    signer: &impl Signer,
+    intent: &TransferIntent,
+    contract_id: &ContractId,
+) -> Result<B512> {
+    if signer.address() != intent.from {
+        return Err(Error::Other(format!(
+            "intent sender {} does not match signer {}",
+            intent.from,
+            signer.address()
+        )));
+    }
+    let signature = signer.sign(intent_message(intent, contract_id)).await?;
+    B512::try_from(signature.as_ref())
+}
+
+/// Builds an intent from `from` using the nonce currently stored in the forwarder.
+pub async fn prepare_intent<A: Account>(
+    forwarder: &Forwarder<A>,
+    from: Address,
+    to: Identity,
+    asset: AssetId,
+    amount: u64,
+    deadline: u32,
+) -> Result<TransferIntent> {
+    let nonce = forwarder.methods().nonce(from).call().await?.value;
+    Ok(TransferIntent {
+        from,
+        to,
+        asset,
+        amount,
+        nonce,
+        deadline,
+    })
+}
+
+/// Submits signed intents to a forwarder, paying the fees from its own wallet.
+pub struct Relayer<A> {
+    forwarder: Forwarder<A>,
+}
+
+impl<A: Account + Clone> Relayer<A> {
+    /// A relayer for `forwarder`, which must be bound to the relayer's wallet.
+    pub fn new(forwarder: Forwarder<A>) -> Self {
+        Self { forwarder }
+    }
+
+    /// The forwarder intents are relayed to, bound to the relayer's wallet.
+    pub fn forwarder(&self) -> &Forwarder<A> {
+        &self.forwarder
+    }
+
+    /// The wallet paying for relayed intents.
+    pub fn wallet(&self) -> &A {
+        self.forwarder.account()
+    }
+
+    /// Forwards `intent`, moving the coins from the sender's balance to its recipient.
+    pub async fn relay(&self, intent: TransferIntent, signature: B512) -> Result<CallResponse<()>> {
+        self.forwarder
+            .methods()
+            .forward(intent, signature)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await
+    }
+}
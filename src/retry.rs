@@ -0,0 +1,129 @@
+//! Retrying transient RPC failures against flaky endpoints.
+//!
+//! [`connect_with_retry`] connects to a node, retrying with exponential backoff
+//! and jitter, and configures the SDK to retry every later query of the
+//! provider as well. Deploy helpers and contract calls go through the wallet's
+//! provider, so a provider created this way makes the whole flow survive
+//! dropped connections and overloaded public endpoints.
+//!
+//! Only transport errors are retried; reverts and validation errors fail at once.
+
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use fuels::{
+    accounts::provider::{Backoff, RetryConfig},
+    prelude::*,
+};
+
+/// How often and how long to retry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts in total, the first one included.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of a single delay.
+    pub max_backoff: Duration,
+    /// Fraction of every delay that is randomized, between 0 and 1.
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that tries once and never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay before retry number `retry` (starting at 1), jitter included.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let capped = exponential.min(self.max_backoff);
+
+        // Scale by a random factor in [1 - jitter, 1]
+        let factor = 1.0 - self.jitter * random_unit();
+        capped.mul_f64(factor)
+    }
+
+    /// The SDK retry config applied to every query of a provider.
+    ///
+    /// The SDK backs off exponentially without jitter.
+    pub fn sdk_config(&self) -> Result<RetryConfig> {
+        RetryConfig::new(self.max_attempts, Backoff::Exponential(self.initial_backoff))
+    }
+}
+
+/// Whether `error` is a transport failure worth retrying.
+pub fn is_transient(error: &Error) -> bool {
+    matches!(error, Error::Provider(_) | Error::IO(_))
+}
+
+/// Runs `operation` until it succeeds, fails with a non-transient error or
+/// runs out of attempts, sleeping [`RetryPolicy::backoff`] between attempts.
+pub async fn retry<T, F, Fut>(policy: &RetryPolicy, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Err(error) if is_transient(&error) && attempt < policy.max_attempts => {
+                let delay = policy.backoff(attempt);
+                tracing::warn!(%error, attempt, ?delay, "transient failure, retrying");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Connects to `url`, retrying per `policy`, and makes the SDK retry the
+/// provider's later queries too.
+pub async fn connect_with_retry(url: &str, policy: &RetryPolicy) -> Result<Provider> {
+    let provider = retry(policy, || Provider::connect(url)).await?;
+    Ok(provider.with_retry_config(policy.sdk_config()?))
+}
+
+// A random number in [0, 1), good enough to spread retries apart
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,128 @@
+//! Decoding why a transaction reverted.
+//!
+//! A Sway revert carries a code in the `Revert` receipt: the standard library
+//! uses fixed signals for failed `require`s, asserts and transfers, and
+//! anything else is a `revert(code)` of the contract. For a failed `require`,
+//! the SDK also decodes the logged message. [`RevertReason`] maps the signals
+//! and the require messages of this repository's contracts to variants, so
+//! tests assert on the reason instead of searching error strings.
+//!
+//! ```ignore
+//! let error = vault.methods().withdraw(too_much).call().await.err().unwrap();
+//! assert_eq!(RevertReason::from_error(&error), Some(RevertReason::InsufficientBalance));
+//! ```
+
+use fuels::{
+    prelude::*,
+    types::{
+        errors::transaction::Reason,
+        tx_status::{Failure, TxStatus},
+    },
+};
+
+/// Revert code of a failed `require`.
+pub const FAILED_REQUIRE_SIGNAL: u64 = 0xffff_ffff_ffff_0000;
+/// Revert code of a failed `transfer` to an address.
+pub const FAILED_TRANSFER_TO_ADDRESS_SIGNAL: u64 = 0xffff_ffff_ffff_0001;
+/// Revert code of a failed `assert_eq`.
+pub const FAILED_ASSERT_EQ_SIGNAL: u64 = 0xffff_ffff_ffff_0003;
+/// Revert code of a failed `assert`.
+pub const FAILED_ASSERT_SIGNAL: u64 = 0xffff_ffff_ffff_0004;
+/// Revert code of a failed `assert_ne`.
+pub const FAILED_ASSERT_NE_SIGNAL: u64 = 0xffff_ffff_ffff_0005;
+/// Revert code of `revert_with_log`.
+pub const REVERT_WITH_LOG_SIGNAL: u64 = 0xffff_ffff_ffff_0006;
+
+/// Why a transaction reverted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// The caller may not do this, e.g. a mint by someone other than the admin.
+    /// Holds the require message.
+    Unauthorized(String),
+    /// More was withdrawn than deposited.
+    InsufficientBalance,
+    /// The token is paused.
+    Paused,
+    /// The recipient is frozen.
+    Frozen,
+    /// A mint without a sub id.
+    IncorrectSubId,
+    /// The call forwarded the wrong asset.
+    IncorrectAsset,
+    /// The call forwarded less than the amount it names.
+    IncorrectAmount,
+    /// Any other `require` message.
+    Require(String),
+    /// A failed `assert`.
+    AssertFailed,
+    /// A failed `assert_eq`.
+    AssertEqFailed,
+    /// A failed `assert_ne`.
+    AssertNeFailed,
+    /// A `transfer` to an address without a variable output to receive it.
+    TransferToAddressFailed,
+    /// A `revert(code)` with a contract-specific code.
+    Revert(u64),
+    /// No revert code; holds the SDK's description.
+    Unknown(String),
+}
+
+impl RevertReason {
+    /// Maps a `require` message of our contracts to its variant.
+    pub fn from_message(message: &str) -> Self {
+        match message {
+            "Insufficient balance" => RevertReason::InsufficientBalance,
+            "Paused" => RevertReason::Paused,
+            "Incorrect Sub Id" => RevertReason::IncorrectSubId,
+            "Incorrect asset provided" | "Wrong asset type" => RevertReason::IncorrectAsset,
+            "Incorrect amount provided" => RevertReason::IncorrectAmount,
+            message if message.starts_with("Frozen") => RevertReason::Frozen,
+            message if message.starts_with("Unauthorized") || message.starts_with("Only ") => {
+                RevertReason::Unauthorized(message.to_string())
+            }
+            message => RevertReason::Require(message.to_string()),
+        }
+    }
+
+    /// Decodes the SDK's failure description and revert code.
+    pub fn decode(reason: &str, revert_id: Option<u64>) -> Self {
+        match revert_id {
+            Some(FAILED_REQUIRE_SIGNAL) => Self::from_message(require_message(reason)),
+            Some(FAILED_TRANSFER_TO_ADDRESS_SIGNAL) => RevertReason::TransferToAddressFailed,
+            Some(FAILED_ASSERT_EQ_SIGNAL) => RevertReason::AssertEqFailed,
+            Some(FAILED_ASSERT_SIGNAL) => RevertReason::AssertFailed,
+            Some(FAILED_ASSERT_NE_SIGNAL) => RevertReason::AssertNeFailed,
+            Some(code) => RevertReason::Revert(code),
+            None => RevertReason::Unknown(reason.to_string()),
+        }
+    }
+
+    /// The revert reason of a failed call; `None` if the error is not a revert.
+    pub fn from_error(error: &Error) -> Option<Self> {
+        match error {
+            Error::Transaction(Reason::Failure {
+                reason, revert_id, ..
+            }) => Some(Self::decode(reason, *revert_id)),
+            _ => None,
+        }
+    }
+
+    /// The revert reason of a committed transaction; `None` unless it failed.
+    pub fn from_status(status: &TxStatus) -> Option<Self> {
+        match status {
+            TxStatus::Failure(Failure {
+                reason, revert_id, ..
+            }) => Some(Self::decode(reason, *revert_id)),
+            _ => None,
+        }
+    }
+}
+
+// The SDK renders the logged message of a require as a quoted string, possibly
+// inside a longer description
+fn require_message(reason: &str) -> &str {
+    match (reason.find('"'), reason.rfind('"')) {
+        (Some(start), Some(end)) if start < end => &reason[start + 1..end],
+        _ => reason.trim(),
+    }
+}
@@ -0,0 +1,61 @@
+//! Helpers for forwarding several assets through the router in one transaction.
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    programs::{calls::CallHandler, responses::CallResponse},
+    types::{ContractId, Identity},
+};
+
+use crate::CrossContractCall;
+
+/// Forwards the token and the base asset to the router in a single transaction.
+///
+/// The router sends the token into the vault on behalf of `user` and the base
+/// asset to `fee_sink`. Each call in the multi-call carries its own
+/// `CallParameters`, which is how one transaction forwards two different assets.
+#[allow(clippy::too_many_arguments)]
+pub async fn route_token_and_base_asset(
+    admin_wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    router: &CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>,
+    vault_id: ContractId,
+    user: Identity,
+    fee_sink: Identity,
+    token_asset_id: AssetId,
+    token_amount: u64,
+    base_amount: u64,
+) -> Result<CallResponse<((), ())>> {
+    let base_asset_id = *admin_wallet
+        .try_provider()?
+        .consensus_parameters()
+        .await?
+        .base_asset_id();
+
+    // Token leg: forwarded into the vault through cross_contract_deposit.
+    let token_call = router
+        .methods()
+        .route(vault_id, user, fee_sink)
+        .call_params(
+            CallParameters::default()
+                .with_amount(token_amount)
+                .with_asset_id(token_asset_id),
+        )?
+        .with_contract_ids(&[vault_id]);
+
+    // Base asset leg: transferred straight to the fee sink.
+    let base_call = router
+        .methods()
+        .route(vault_id, user, fee_sink)
+        .call_params(
+            CallParameters::default()
+                .with_amount(base_amount)
+                .with_asset_id(base_asset_id),
+        )?;
+
+    CallHandler::new_multi_call(admin_wallet.clone())
+        .add_call(token_call)
+        .add_call(base_call)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call::<((), ())>()
+        .await
+}
@@ -0,0 +1,532 @@
+//! The rosetta test flows as reusable scenarios.
+//!
+//! Each `run_*_scenario` function drives one flow from the test suite against
+//! a [`ScenarioEnv`] and returns a [`ScenarioReport`] of the checks it made
+//! instead of panicking. The token and vault are reached through the
+//! [`TokenHandle`] and [`VaultHandle`] traits, so authors of forked contracts
+//! can implement the traits for their own bindings and run the flows as a
+//! compatibility suite. Balance checks compare changes rather than absolute
+//! values, so the environment may already hold funds.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::ContractIdExt,
+    types::{AssetId, Identity, SubAssetId},
+};
+
+use crate::{
+    airdrop::{parse_csv, run_airdrop},
+    artifacts,
+    fees::fee_from_status,
+    predicates::{fund_predicate, spend_from_predicate},
+    MultiSigPredicateConfigurables, Src20Token, TestHarness, TokenVault, SUB_ID, SUB_ID_ARRAY,
+};
+
+/// Wallets the scenarios act with.
+#[derive(Clone)]
+pub struct ScenarioEnv {
+    pub provider: Provider,
+    pub admin: Wallet<Unlocked<PrivateKeySigner>>,
+    pub users: Vec<Wallet<Unlocked<PrivateKeySigner>>>,
+}
+
+impl ScenarioEnv {
+    pub fn new(
+        admin: Wallet<Unlocked<PrivateKeySigner>>,
+        users: Vec<Wallet<Unlocked<PrivateKeySigner>>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            provider: admin.try_provider()?.clone(),
+            admin,
+            users,
+        })
+    }
+
+    pub fn from_harness(harness: &TestHarness) -> Self {
+        Self {
+            provider: harness.provider.clone(),
+            admin: harness.admin.clone(),
+            users: harness.users.clone(),
+        }
+    }
+
+    /// The `index`-th user wallet, or an error naming how many users the scenario needs.
+    pub fn user(&self, index: usize) -> Result<&Wallet<Unlocked<PrivateKeySigner>>> {
+        self.users.get(index).ok_or_else(|| {
+            Error::Other(format!(
+                "scenario needs at least {} users, environment has {}",
+                index + 1,
+                self.users.len()
+            ))
+        })
+    }
+}
+
+/// The token operations the scenarios rely on.
+#[async_trait]
+pub trait TokenHandle: Send + Sync {
+    /// The asset the token mints.
+    fn asset_id(&self) -> AssetId;
+    /// Mints `amount` to `recipient` as the token's admin.
+    async fn mint(&self, recipient: Identity, amount: u64) -> Result<()>;
+    /// Burns `amount` held by `wallet`.
+    async fn burn(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>, amount: u64) -> Result<()>;
+    async fn total_supply(&self) -> Result<u64>;
+}
+
+/// The vault operations the scenarios rely on.
+#[async_trait]
+pub trait VaultHandle: Send + Sync {
+    async fn deposit(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<()>;
+    async fn withdraw(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<()>;
+    async fn deposit_of(&self, owner: Identity, asset_id: AssetId) -> Result<u64>;
+}
+
+#[async_trait]
+impl TokenHandle for Src20Token<Wallet<Unlocked<PrivateKeySigner>>> {
+    fn asset_id(&self) -> AssetId {
+        self.contract_id().asset_id(&SubAssetId::from(SUB_ID_ARRAY))
+    }
+
+    async fn mint(&self, recipient: Identity, amount: u64) -> Result<()> {
+        self.methods()
+            .mint(recipient, Some(SUB_ID), amount)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn burn(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>, amount: u64) -> Result<()> {
+        self.clone()
+            .with_account(wallet.clone())
+            .methods()
+            .burn(SUB_ID, amount)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(TokenHandle::asset_id(self)),
+            )?
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn total_supply(&self) -> Result<u64> {
+        let supply = self
+            .methods()
+            .total_supply(TokenHandle::asset_id(self))
+            .call()
+            .await?
+            .value;
+        Ok(supply.unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl VaultHandle for TokenVault<Wallet<Unlocked<PrivateKeySigner>>> {
+    async fn deposit(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<()> {
+        self.clone()
+            .with_account(wallet.clone())
+            .methods()
+            .deposit()
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(asset_id),
+            )?
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn withdraw(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<()> {
+        self.clone()
+            .with_account(wallet.clone())
+            .methods()
+            .withdraw(amount)
+            .call_params(CallParameters::default().with_asset_id(asset_id))?
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn deposit_of(&self, owner: Identity, asset_id: AssetId) -> Result<u64> {
+        Ok(self
+            .methods()
+            .get_deposit_for_asset(owner, asset_id)
+            .call()
+            .await?
+            .value)
+    }
+}
+
+/// One expectation checked by a scenario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// The checks made by one scenario run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub checks: Vec<Check>,
+}
+
+impl ScenarioReport {
+    pub fn new(scenario: impl Into<String>) -> Self {
+        Self {
+            scenario: scenario.into(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Records whether `actual` equals `expected`, compared by their `Debug` output.
+    pub fn check_eq<T: fmt::Debug>(
+        &mut self,
+        name: impl Into<String>,
+        expected: T,
+        actual: T,
+    ) {
+        self.checks.push(Check {
+            name: name.into(),
+            expected: format!("{:?}", expected),
+            actual: format!("{:?}", actual),
+        });
+    }
+
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(Check::passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|check| !check.passed())
+    }
+
+    /// The report itself when every check passed, otherwise an error listing the failures.
+    pub fn into_result(self) -> Result<Self> {
+        if self.passed() {
+            return Ok(self);
+        }
+        Err(Error::Other(self.to_string()))
+    }
+}
+
+impl fmt::Display for ScenarioReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}:", self.scenario)?;
+        for check in &self.checks {
+            if check.passed() {
+                writeln!(f, "  ✅ {}", check.name)?;
+            } else {
+                writeln!(
+                    f,
+                    "  ❌ {}: expected {}, got {}",
+                    check.name, check.expected, check.actual
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Amounts used by [`run_token_lifecycle_scenario`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenLifecycleParams {
+    pub mint_amount: u64,
+    pub transfer_amount: u64,
+    pub burn_amount: u64,
+}
+
+impl Default for TokenLifecycleParams {
+    fn default() -> Self {
+        Self {
+            mint_amount: 1_000_000,
+            transfer_amount: 250_000,
+            burn_amount: 100_000,
+        }
+    }
+}
+
+/// Mints to user 0, transfers part of it to user 1, and has user 1 burn part of that.
+pub async fn run_token_lifecycle_scenario(
+    env: &ScenarioEnv,
+    token: &impl TokenHandle,
+    params: TokenLifecycleParams,
+) -> Result<ScenarioReport> {
+    let mut report = ScenarioReport::new("token lifecycle");
+    let asset_id = token.asset_id();
+    let (alice, bob) = (env.user(0)?, env.user(1)?);
+
+    let supply_before = token.total_supply().await?;
+    let alice_before = alice.get_asset_balance(&asset_id).await?;
+    let bob_before = bob.get_asset_balance(&asset_id).await?;
+
+    token
+        .mint(Identity::Address(alice.address().into()), params.mint_amount)
+        .await?;
+    report.check_eq(
+        "mint credits the recipient",
+        alice_before + params.mint_amount as u128,
+        alice.get_asset_balance(&asset_id).await?,
+    );
+    report.check_eq(
+        "mint increases total supply",
+        supply_before + params.mint_amount,
+        token.total_supply().await?,
+    );
+
+    alice
+        .transfer(bob.address(), params.transfer_amount, asset_id, TxPolicies::default())
+        .await?;
+    report.check_eq(
+        "transfer debits the sender",
+        alice_before + (params.mint_amount - params.transfer_amount) as u128,
+        alice.get_asset_balance(&asset_id).await?,
+    );
+    report.check_eq(
+        "transfer credits the recipient",
+        bob_before + params.transfer_amount as u128,
+        bob.get_asset_balance(&asset_id).await?,
+    );
+
+    token.burn(bob, params.burn_amount).await?;
+    report.check_eq(
+        "burn debits the holder",
+        bob_before + (params.transfer_amount - params.burn_amount) as u128,
+        bob.get_asset_balance(&asset_id).await?,
+    );
+    report.check_eq(
+        "burn decreases total supply",
+        supply_before + params.mint_amount - params.burn_amount,
+        token.total_supply().await?,
+    );
+
+    Ok(report)
+}
+
+/// Amounts used by [`run_vault_lifecycle_scenario`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultLifecycleParams {
+    pub deposit_amount: u64,
+    pub withdraw_amount: u64,
+}
+
+impl Default for VaultLifecycleParams {
+    fn default() -> Self {
+        Self {
+            deposit_amount: 500_000,
+            withdraw_amount: 200_000,
+        }
+    }
+}
+
+/// Mints to user 0, who deposits into the vault and withdraws part of it again.
+pub async fn run_vault_lifecycle_scenario(
+    env: &ScenarioEnv,
+    token: &impl TokenHandle,
+    vault: &impl VaultHandle,
+    params: VaultLifecycleParams,
+) -> Result<ScenarioReport> {
+    let mut report = ScenarioReport::new("vault lifecycle");
+    let asset_id = token.asset_id();
+    let user = env.user(0)?;
+    let identity = Identity::Address(user.address().into());
+
+    token.mint(identity, params.deposit_amount).await?;
+    let wallet_before = user.get_asset_balance(&asset_id).await?;
+    let deposit_before = vault.deposit_of(identity, asset_id).await?;
+
+    vault.deposit(user, asset_id, params.deposit_amount).await?;
+    report.check_eq(
+        "deposit is recorded",
+        deposit_before + params.deposit_amount,
+        vault.deposit_of(identity, asset_id).await?,
+    );
+    report.check_eq(
+        "deposit leaves the wallet",
+        wallet_before - params.deposit_amount as u128,
+        user.get_asset_balance(&asset_id).await?,
+    );
+
+    vault.withdraw(user, asset_id, params.withdraw_amount).await?;
+    report.check_eq(
+        "withdrawal reduces the deposit",
+        deposit_before + params.deposit_amount - params.withdraw_amount,
+        vault.deposit_of(identity, asset_id).await?,
+    );
+    report.check_eq(
+        "withdrawal returns to the wallet",
+        wallet_before - (params.deposit_amount - params.withdraw_amount) as u128,
+        user.get_asset_balance(&asset_id).await?,
+    );
+
+    Ok(report)
+}
+
+/// Amounts and bytecode used by [`run_multisig_spend_scenario`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigSpendParams {
+    /// Predicate bytecode; must take the multi-sig predicate's configurables.
+    pub predicate_bin: String,
+    pub required_signatures: u64,
+    pub fund_amount: u64,
+    pub spend_amount: u64,
+}
+
+impl Default for MultisigSpendParams {
+    fn default() -> Self {
+        Self {
+            predicate_bin: artifacts::MULTI_SIG_PREDICATE_BIN.to_string(),
+            required_signatures: 2,
+            fund_amount: 500_000,
+            spend_amount: 300_000,
+        }
+    }
+}
+
+/// Funds a multi-sig predicate owned by the admin and users 0 and 1, then
+/// spends from it with the first `required_signatures` of them signing.
+pub async fn run_multisig_spend_scenario(
+    env: &ScenarioEnv,
+    params: MultisigSpendParams,
+) -> Result<ScenarioReport> {
+    let mut report = ScenarioReport::new("multisig spend");
+    let provider = &env.provider;
+    let asset_id = *provider.consensus_parameters().await?.base_asset_id();
+    let signers = [&env.admin, env.user(0)?, env.user(1)?];
+
+    let configurables = MultiSigPredicateConfigurables::default()
+        .with_SIGNERS(signers.map(|signer| signer.address().into()))?
+        .with_REQUIRED_SIGNATURES(params.required_signatures)?;
+    let predicate = Predicate::load_from(&params.predicate_bin)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables);
+
+    let predicate_before = predicate.get_asset_balance(&asset_id).await?;
+    fund_predicate(&env.admin, &predicate, params.fund_amount).await?;
+    report.check_eq(
+        "predicate is funded",
+        predicate_before + params.fund_amount as u128,
+        predicate.get_asset_balance(&asset_id).await?,
+    );
+
+    let payer = signers[0];
+    let payer_before = payer.get_asset_balance(&asset_id).await?;
+    let required = params.required_signatures as usize;
+    let status = spend_from_predicate(
+        &predicate,
+        &signers[..required.min(signers.len())],
+        payer.address().into(),
+        params.spend_amount,
+    )
+    .await?;
+    let fee = fee_from_status(&status)? as u128;
+
+    report.check_eq(
+        "predicate pays the spend and the fee",
+        predicate_before + (params.fund_amount - params.spend_amount) as u128 - fee,
+        predicate.get_asset_balance(&asset_id).await?,
+    );
+    report.check_eq(
+        "recipient receives the spend",
+        payer_before + params.spend_amount as u128,
+        payer.get_asset_balance(&asset_id).await?,
+    );
+
+    Ok(report)
+}
+
+/// Sizes used by [`run_airdrop_scenario`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AirdropScenarioParams {
+    /// Number of users to pay, starting at user 0.
+    pub recipients: usize,
+    pub amount_per_recipient: u64,
+    pub batch_size: Option<usize>,
+}
+
+impl Default for AirdropScenarioParams {
+    fn default() -> Self {
+        Self {
+            recipients: 2,
+            amount_per_recipient: 1_000,
+            batch_size: None,
+        }
+    }
+}
+
+/// Mints the airdrop total to the admin and pays it out to users through the airdrop script.
+pub async fn run_airdrop_scenario(
+    env: &ScenarioEnv,
+    token: &impl TokenHandle,
+    params: AirdropScenarioParams,
+) -> Result<ScenarioReport> {
+    let mut report = ScenarioReport::new("script airdrop");
+    let asset_id = token.asset_id();
+    let total = params.amount_per_recipient * params.recipients as u64;
+
+    let mut csv = String::new();
+    let mut before = Vec::new();
+    for index in 0..params.recipients {
+        let user = env.user(index)?;
+        csv.push_str(&format!("{},{}\n", user.address(), params.amount_per_recipient));
+        before.push(user.get_asset_balance(&asset_id).await?);
+    }
+
+    token
+        .mint(Identity::Address(env.admin.address().into()), total)
+        .await?;
+    let admin_before = env.admin.get_asset_balance(&asset_id).await?;
+
+    let airdrop = run_airdrop(&env.admin, asset_id, &parse_csv(&csv)?, params.batch_size).await?;
+    report.check_eq("every recipient is paid", 0, airdrop.failed().count());
+    report.check_eq(
+        "admin pays the total",
+        admin_before - total as u128,
+        env.admin.get_asset_balance(&asset_id).await?,
+    );
+    for (index, balance) in before.into_iter().enumerate() {
+        report.check_eq(
+            format!("user {} receives their share", index),
+            balance + params.amount_per_recipient as u128,
+            env.user(index)?.get_asset_balance(&asset_id).await?,
+        );
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,65 @@
+//! Hand-built script transactions.
+//!
+//! A script that moves the caller's coins (rather than forwarding them to a
+//! contract) needs those coins as inputs, which `ScriptCallHandler::call`
+//! does not add. [`build_script_tx`] starts from the call's own transaction
+//! builder and adds the rest: the coins for every asset the script spends, a
+//! change output per asset so nothing is burned, the fee and the signature.
+//!
+//! ```ignore
+//! let script_call = script.main(asset_id).with_variable_output_policy(policy);
+//! let tx = build_script_tx(&script_call, &wallet, &[(asset_id, 600)]).await?;
+//! let status = provider.send_transaction_and_await_commit(tx).await?;
+//! let response = script_call.get_response(status)?;
+//! ```
+
+use std::fmt::Debug;
+
+use fuels::{
+    core::traits::{Parameterize, Tokenizable},
+    prelude::*,
+    programs::calls::ScriptCall,
+    types::{output::Output, transaction::ScriptTransaction},
+};
+
+use crate::policies;
+
+/// Builds and signs a transaction running `script_call` on `wallet`'s coins.
+///
+/// `asset_needs` lists how much of each asset the script spends; the wallet
+/// must hold enough of every one. The transaction runs with
+/// [`policies::script_heavy`] and the variable output policy set on the call.
+/// Base asset needs are covered together with the fee.
+pub async fn build_script_tx<A, T>(
+    script_call: &CallHandler<A, ScriptCall, T>,
+    wallet: &A,
+    asset_needs: &[(AssetId, u128)],
+) -> Result<ScriptTransaction>
+where
+    A: Account,
+    T: Tokenizable + Parameterize + Debug,
+{
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+    let mut tb = script_call
+        .transaction_builder()
+        .await?
+        .with_tx_policies(policies::script_heavy());
+
+    let mut base_needed = 0;
+    for (asset_id, amount) in asset_needs {
+        if *asset_id == base_asset_id {
+            base_needed += *amount;
+            continue;
+        }
+        tb.inputs
+            .extend(wallet.get_asset_inputs_for_amount(*asset_id, *amount, None).await?);
+        tb.outputs
+            .push(Output::change(wallet.address(), 0, *asset_id));
+    }
+
+    wallet.adjust_for_fee(&mut tb, base_needed).await?;
+    wallet.add_witnesses(&mut tb)?;
+
+    tb.build(provider).await
+}
@@ -0,0 +1,119 @@
+//! One in-process node shared by every test of a test binary.
+//!
+//! Each `#[tokio::test]` runs on its own runtime, so a node launched inside a
+//! test dies with it. The shared node instead runs on a dedicated thread and
+//! runtime for the lifetime of the process, with a pool of pre-funded wallets.
+//! Tests take disjoint wallets from the pool and connect their own provider,
+//! so they can run in parallel without spending each other's coins.
+//!
+//! Select it with [`NodeMode::Shared`](crate::NodeMode::Shared) or by setting
+//! `ROSETTA_SHARED_NODE=1`. Tests that produce blocks or depend on block
+//! heights see the activity of every other test and should keep a local node.
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+};
+use tokio::sync::{oneshot, OnceCell};
+
+use crate::harness::indexed_signer;
+
+/// Environment variable that switches the default [`NodeMode`](crate::NodeMode) to the shared node.
+pub const SHARED_NODE_ENV: &str = "ROSETTA_SHARED_NODE";
+/// Number of funded wallets in the pool.
+pub const SHARED_POOL_SIZE: usize = 128;
+/// Base asset coins given to every pool wallet.
+pub const SHARED_COINS_PER_WALLET: u64 = 4;
+/// Amount of every base asset coin of a pool wallet.
+pub const SHARED_AMOUNT_PER_COIN: u64 = 1_000_000_000;
+
+static SHARED_NODE: OnceCell<SharedNode> = OnceCell::const_new();
+
+struct SharedNode {
+    url: String,
+    signers: Vec<PrivateKeySigner>,
+    next_wallet: AtomicUsize,
+}
+
+/// Takes `count` wallets from the shared node's pool, launching the node on first use.
+///
+/// Every call returns wallets no other caller has received, connected through a
+/// provider owned by the calling runtime. Fails once the pool is exhausted.
+pub async fn shared_wallets(count: usize) -> Result<Vec<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let node = SHARED_NODE.get_or_try_init(launch_shared_node).await?;
+
+    let start = node.next_wallet.fetch_add(count, Ordering::SeqCst);
+    if start + count > node.signers.len() {
+        return Err(Error::Other(format!(
+            "shared node wallet pool exhausted: {} of {} wallets requested",
+            start + count,
+            node.signers.len()
+        )));
+    }
+
+    let provider = Provider::connect(&node.url).await?;
+    Ok(node.signers[start..start + count]
+        .iter()
+        .map(|signer| Wallet::new(signer.clone(), provider.clone()))
+        .collect())
+}
+
+/// URL of the shared node, launching it on first use.
+pub async fn shared_node_url() -> Result<String> {
+    let node = SHARED_NODE.get_or_try_init(launch_shared_node).await?;
+    Ok(node.url.clone())
+}
+
+// Launches the node on a thread that keeps its runtime alive until the process exits
+async fn launch_shared_node() -> Result<SharedNode> {
+    let signers = (0..SHARED_POOL_SIZE)
+        .map(|index| indexed_signer(index as u64))
+        .collect::<Result<Vec<_>>>()?;
+
+    let coins = signers
+        .iter()
+        .flat_map(|signer| {
+            setup_single_asset_coins(
+                signer.address(),
+                AssetId::zeroed(),
+                SHARED_COINS_PER_WALLET,
+                SHARED_AMOUNT_PER_COIN,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let (sender, receiver) = oneshot::channel();
+    thread::Builder::new()
+        .name("rosetta-shared-node".to_string())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = sender.send(Err(Error::Other(e.to_string())));
+                    return;
+                }
+            };
+            runtime.block_on(async move {
+                let provider = setup_test_provider(coins, vec![], None, None).await;
+                let _ = sender.send(provider.map(|provider| provider.url().to_string()));
+                // The node lives as long as this runtime does
+                std::future::pending::<()>().await
+            });
+        })
+        .map_err(|e| Error::Other(e.to_string()))?;
+
+    let url = receiver
+        .await
+        .map_err(|_| Error::Other("shared node thread exited during startup".to_string()))??;
+
+    Ok(SharedNode {
+        url,
+        signers,
+        next_wallet: AtomicUsize::new(0),
+    })
+}
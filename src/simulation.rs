@@ -0,0 +1,103 @@
+//! Dry runs of contract calls.
+//!
+//! `CallHandler::simulate` executes a call against the current chain state
+//! without committing it: the response carries the return value, logs and gas
+//! of the call, but no state changes and no fee. [`simulate_then_call`] always
+//! simulates first, so a call that would revert fails before anything is paid,
+//! and only submits when the caller did not ask for a dry run:
+//!
+//! ```ignore
+//! let outcome = simulate_then_call(vault.methods().withdraw(200), dry_run).await?;
+//! println!("gas: {}", outcome.response().tx_status.total_gas);
+//! ```
+//!
+//! [`simulate_then_call_timed`] does the same through a
+//! [`TimedWallet`](crate::latency::TimedWallet) and also returns the latency
+//! breakdown of the submitted call.
+//!
+//! Errors carry the contract and method of the call and, once submitted, its
+//! transaction id and block (see [`ErrorContext`]).
+
+use std::fmt::Debug;
+
+use fuels::{
+    core::traits::{Parameterize, Tokenizable},
+    prelude::*,
+    programs::{calls::ContractCall, responses::CallResponse},
+};
+
+use crate::{
+    error::{call_with_context, ErrorContext, ResultExt, RosettaResult},
+    latency::{call_with_breakdown, LatencyBreakdown, TimedWallet},
+};
+
+/// What [`simulate_then_call`] did with a call.
+#[derive(Debug)]
+pub enum CallOutcome<T> {
+    /// Simulated only; nothing was submitted.
+    Simulated(CallResponse<T>),
+    /// Simulated, then submitted and committed.
+    Submitted(CallResponse<T>),
+}
+
+impl<T> CallOutcome<T> {
+    /// The simulation's response for a dry run, the committed one otherwise.
+    pub fn response(&self) -> &CallResponse<T> {
+        match self {
+            CallOutcome::Simulated(response) | CallOutcome::Submitted(response) => response,
+        }
+    }
+
+    pub fn into_response(self) -> CallResponse<T> {
+        match self {
+            CallOutcome::Simulated(response) | CallOutcome::Submitted(response) => response,
+        }
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        matches!(self, CallOutcome::Simulated(_))
+    }
+}
+
+/// Simulates `call` and, unless `dry_run` is set, submits it afterwards.
+///
+/// A call that reverts in the simulation returns its error without being
+/// submitted. The simulation runs against the latest block, so a submitted
+/// call can still revert if the state changes in between.
+pub async fn simulate_then_call<A, T>(
+    mut call: CallHandler<A, ContractCall, T>,
+    dry_run: bool,
+) -> RosettaResult<CallOutcome<T>>
+where
+    A: Account,
+    T: Tokenizable + Parameterize + Debug,
+{
+    let context = ErrorContext::for_call(&call.call);
+    let simulated = call.simulate(Execution::realistic()).await.context(&context)?;
+    if dry_run {
+        return Ok(CallOutcome::Simulated(simulated));
+    }
+
+    Ok(CallOutcome::Submitted(call_with_context(call).await?))
+}
+
+/// [`simulate_then_call`], submitting through [`call_with_breakdown`].
+///
+/// The breakdown is `None` for a dry run, since nothing was submitted.
+pub async fn simulate_then_call_timed<T>(
+    mut call: CallHandler<TimedWallet, ContractCall, T>,
+    wallet: &TimedWallet,
+    dry_run: bool,
+) -> RosettaResult<(CallOutcome<T>, Option<LatencyBreakdown>)>
+where
+    T: Tokenizable + Parameterize + Debug,
+{
+    let context = ErrorContext::for_call(&call.call);
+    let simulated = call.simulate(Execution::realistic()).await.context(&context)?;
+    if dry_run {
+        return Ok((CallOutcome::Simulated(simulated), None));
+    }
+
+    let (response, breakdown) = call_with_breakdown(call, wallet).await?;
+    Ok((CallOutcome::Submitted(response), Some(breakdown)))
+}
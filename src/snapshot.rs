@@ -0,0 +1,174 @@
+//! Snapshots of a local node's chain state.
+//!
+//! A long setup phase (deployments, mints, deposits) can be run once, dumped
+//! to disk and replayed as the genesis state of a fresh node on later runs.
+//! The node's GraphQL API cannot list everything it stores, so a snapshot
+//! covers the coins of the given owners and the code, storage slots and
+//! balances of the given contracts; everything else (messages, other owners'
+//! coins, block history) is left out. The restored chain starts again at
+//! height 0, so deadlines derived from the old block height need to be
+//! recomputed.
+//!
+//! ```ignore
+//! let snapshot = harness.snapshot().await?;
+//! snapshot.save("target/setup.snapshot.json")?;
+//! // on a later run
+//! let snapshot = HarnessSnapshot::load("target/setup.snapshot.json")?;
+//! let harness = TestHarness::restore(&snapshot).await?;
+//! ```
+
+use std::path::Path;
+
+use fuel_core_chain_config::{
+    ChainConfig, CoinConfig, ContractBalanceConfig, ContractConfig, ContractStateConfig,
+    StateConfig,
+};
+use fuel_core_client::client::{
+    pagination::{PageDirection, PaginationRequest},
+    FuelClient,
+};
+use futures::StreamExt;
+use fuels::{
+    prelude::*,
+    types::{Address, ContractId},
+};
+use serde::{Deserialize, Serialize};
+
+const PAGE_SIZE: i32 = 100;
+
+/// The state of a [`crate::TestHarness`]'s node, and how to find the harness in it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HarnessSnapshot {
+    /// The chain configuration the node ran with.
+    pub chain_config: ChainConfig,
+    /// Coins and contracts to replay as genesis state.
+    pub state: StateConfig,
+    pub admin: Address,
+    pub users: Vec<Address>,
+    pub token: ContractId,
+    pub vault: ContractId,
+    pub cross_contract_call: ContractId,
+    pub asset_id: AssetId,
+}
+
+impl HarnessSnapshot {
+    /// Writes the snapshot to `path` as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| Error::Other(format!("failed to encode snapshot: {}", e)))?;
+        std::fs::write(path.as_ref(), json).map_err(|e| {
+            Error::Other(format!("failed to write {}: {}", path.as_ref().display(), e))
+        })
+    }
+
+    /// Reads a snapshot written by [`HarnessSnapshot::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            Error::Other(format!("failed to read {}: {}", path.as_ref().display(), e))
+        })?;
+        serde_json::from_str(&json).map_err(|e| {
+            Error::Other(format!("invalid snapshot {}: {}", path.as_ref().display(), e))
+        })
+    }
+}
+
+/// Reads the coins of `owners` and the contracts in `contracts` from the node
+/// behind `provider` as genesis state.
+pub async fn capture_state(
+    provider: &Provider,
+    owners: &[Address],
+    contracts: &[ContractId],
+) -> Result<StateConfig> {
+    let client = FuelClient::new(provider.url()).map_err(client_error)?;
+
+    let mut state = StateConfig::default();
+    for owner in owners {
+        state.coins.extend(owner_coins(&client, owner).await?);
+    }
+    for contract_id in contracts {
+        state.contracts.push(contract_config(&client, contract_id).await?);
+    }
+
+    Ok(state)
+}
+
+// Every coin of `owner`, in all assets
+async fn owner_coins(client: &FuelClient, owner: &Address) -> Result<Vec<CoinConfig>> {
+    let mut coins = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = client
+            .coins(owner, None, page_request(cursor))
+            .await
+            .map_err(client_error)?;
+        coins.extend(page.results.into_iter().map(|coin| CoinConfig {
+            tx_id: *coin.utxo_id.tx_id(),
+            output_index: coin.utxo_id.output_index(),
+            owner: coin.owner,
+            amount: coin.amount,
+            asset_id: coin.asset_id,
+            ..Default::default()
+        }));
+        if !page.has_next_page {
+            return Ok(coins);
+        }
+        cursor = page.cursor;
+    }
+}
+
+// The code, storage slots and balances of `contract_id`
+async fn contract_config(client: &FuelClient, contract_id: &ContractId) -> Result<ContractConfig> {
+    let contract = client
+        .contract(contract_id)
+        .await
+        .map_err(client_error)?
+        .ok_or_else(|| Error::Other(format!("contract {} is not deployed", contract_id)))?;
+
+    let mut balances = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = client
+            .contract_balances(contract_id, page_request(cursor))
+            .await
+            .map_err(client_error)?;
+        balances.extend(page.results.into_iter().map(|balance| ContractBalanceConfig {
+            asset_id: balance.asset_id,
+            amount: balance.amount,
+        }));
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.cursor;
+    }
+
+    let states = client
+        .contract_storage_slots(contract_id)
+        .await
+        .map_err(client_error)?
+        .map(|slot| slot.map(|(key, value)| ContractStateConfig { key, value }))
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(client_error)?;
+
+    Ok(ContractConfig {
+        contract_id: *contract_id,
+        code: contract.bytecode,
+        states,
+        balances,
+        ..Default::default()
+    })
+}
+
+fn page_request(cursor: Option<String>) -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor,
+        results: PAGE_SIZE,
+        direction: PageDirection::Forward,
+    }
+}
+
+fn client_error(error: impl std::fmt::Display) -> Error {
+    Error::Other(format!("snapshot query failed: {}", error))
+}
@@ -0,0 +1,90 @@
+//! Atomic two-party swaps through the AtomicSwap script.
+//!
+//! Each party puts the coins of the asset they give into one transaction and
+//! signs it; the script pays each side's coins to the other party. Both
+//! transfers happen in the same script run, so if either party's coins are
+//! missing the script reverts and nobody receives anything.
+//!
+//! ```ignore
+//! let a = SwapLeg { owner: alice.address().into(), asset: x, amount: 100 };
+//! let b = SwapLeg { owner: bob.address().into(), asset: y, amount: 250 };
+//! let (call, tx) = build_swap_tx(&alice, &bob, a, b).await?;
+//! let status = provider.send_transaction_and_await_commit(tx).await?;
+//! let response = call.get_response(status)?;
+//! ```
+
+use fuels::{
+    prelude::*,
+    programs::calls::ScriptCall,
+    types::{output::Output, transaction::ScriptTransaction},
+};
+
+use crate::{artifacts, policies, AtomicSwap, SwapLeg};
+
+/// The AtomicSwap call exchanging `a` and `b`, run on `account`'s provider.
+pub fn swap_call<A: Account + Clone>(
+    account: &A,
+    a: SwapLeg,
+    b: SwapLeg,
+) -> CallHandler<A, ScriptCall, bool> {
+    AtomicSwap::new(account.clone(), artifacts::ATOMIC_SWAP_BIN)
+        .main(a, b)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+}
+
+/// Builds the swap transaction signed by both parties.
+///
+/// `party_a` and `party_b` must own legs `a` and `b`. Each party's coins of
+/// the asset they give are added with a change output back to them;
+/// `party_a` also pays the fee. Neither leg may be the base asset, since the
+/// fee's change output already returns base asset to `party_a`.
+pub async fn build_swap_tx<A, B>(
+    party_a: &A,
+    party_b: &B,
+    a: SwapLeg,
+    b: SwapLeg,
+) -> Result<(CallHandler<A, ScriptCall, bool>, ScriptTransaction)>
+where
+    A: Account + Clone,
+    B: Account,
+{
+    let provider = party_a.try_provider()?;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+    if a.asset == base_asset_id || b.asset == base_asset_id {
+        return Err(Error::Other("swap legs cannot be the base asset".to_string()));
+    }
+    for (party, leg) in [(party_a.address(), &a), (party_b.address(), &b)] {
+        if party != leg.owner {
+            return Err(Error::Other(format!(
+                "swap leg owner {} does not match party {}",
+                leg.owner, party
+            )));
+        }
+    }
+
+    let call = swap_call(party_a, a.clone(), b.clone());
+    let mut tb = call
+        .transaction_builder()
+        .await?
+        .with_tx_policies(policies::script_heavy());
+
+    tb.inputs.extend(
+        party_a
+            .get_asset_inputs_for_amount(a.asset, u128::from(a.amount), None)
+            .await?,
+    );
+    tb.outputs.push(Output::change(a.owner, 0, a.asset));
+    tb.inputs.extend(
+        party_b
+            .get_asset_inputs_for_amount(b.asset, u128::from(b.amount), None)
+            .await?,
+    );
+    tb.outputs.push(Output::change(b.owner, 0, b.asset));
+
+    party_a.adjust_for_fee(&mut tb, 0).await?;
+    party_a.add_witnesses(&mut tb)?;
+    party_b.add_witnesses(&mut tb)?;
+
+    let tx = tb.build(provider).await?;
+    Ok((call, tx))
+}
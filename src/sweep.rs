@@ -0,0 +1,103 @@
+//! Sweeping accrued vault fees into a treasury.
+//!
+//! The vault keeps its withdrawal fees per asset until the admin calls
+//! `collect_fees`, which pays them to the admin. [`sweep_fees`] is the admin
+//! job on top of that: it collects whatever has accrued and forwards it to a
+//! treasury address in the same run, e.g. the multi-sig predicate from
+//! [`multisig_predicate`](crate::predicates::multisig_predicate), so that
+//! spending the fees afterwards takes the signers' threshold (see
+//! [`spend_asset_from_predicate`](crate::predicates::spend_asset_from_predicate)).
+//!
+//! [`spawn_sweeper`] runs the job on a fixed interval in a background task:
+//!
+//! ```ignore
+//! let treasury = multisig_predicate([&alice, &bob, &carol], 2)?;
+//! let sweeper = spawn_sweeper(vault, asset_id, treasury.address(), Duration::from_secs(3600));
+//! ```
+
+use std::time::Duration;
+
+use fuels::{prelude::*, tx::TxId, types::Address};
+use tokio::task::JoinHandle;
+
+use crate::{
+    error::{call_with_context, RosettaResult},
+    TokenVault,
+};
+
+/// Fees moved from the vault to the treasury by one [`sweep_fees`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sweep {
+    pub amount: u64,
+    /// The transaction that paid the fees on to the treasury.
+    pub tx_id: TxId,
+}
+
+/// Collects the fees accrued in `asset_id` and transfers them to `treasury`.
+///
+/// `vault` must be connected to the vault's admin, which receives the fees
+/// and pays the transfer's fee. Returns `None` without submitting anything
+/// when no fees have accrued. If the transfer fails after the collection,
+/// the fees stay with the admin and the next sweep does not retry them.
+pub async fn sweep_fees<A>(
+    vault: &TokenVault<A>,
+    asset_id: AssetId,
+    treasury: Address,
+) -> RosettaResult<Option<Sweep>>
+where
+    A: Account + Clone,
+{
+    // `collect_fees` reverts when there is nothing to collect
+    let accrued = call_with_context(vault.methods().get_accrued_fees(asset_id))
+        .await?
+        .value;
+    if accrued == 0 {
+        return Ok(None);
+    }
+
+    let amount = call_with_context(
+        vault
+            .methods()
+            .collect_fees(asset_id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1)),
+    )
+    .await?
+    .value;
+
+    let response = vault
+        .account()
+        .transfer(treasury, amount, asset_id, TxPolicies::default())
+        .await?;
+
+    Ok(Some(Sweep {
+        amount,
+        tx_id: response.tx_id,
+    }))
+}
+
+/// Runs [`sweep_fees`] every `interval`, starting right away, until the
+/// returned task is aborted. A failed sweep is logged and retried on the
+/// next tick.
+pub fn spawn_sweeper<A>(
+    vault: TokenVault<A>,
+    asset_id: AssetId,
+    treasury: Address,
+    interval: Duration,
+) -> JoinHandle<()>
+where
+    A: Account + Clone + Send + Sync + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticks = tokio::time::interval(interval);
+        loop {
+            ticks.tick().await;
+            match sweep_fees(&vault, asset_id, treasury).await {
+                Ok(Some(sweep)) => {
+                    tracing::info!(amount = sweep.amount, tx_id = %sweep.tx_id, "swept vault fees")
+                }
+                Ok(None) => {}
+                Err(error) => tracing::warn!(%error, "vault fee sweep failed"),
+            }
+        }
+    })
+}
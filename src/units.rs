@@ -0,0 +1,16 @@
+//! Token amounts for people.
+//!
+//! Pure helpers with no node access, so they build on every target and for
+//! every feature set, including the browser subset behind the `wasm` feature.
+
+/// `amount` as a decimal number with `decimals` fractional digits, trailing zeros trimmed.
+pub fn format_units(amount: u128, decimals: u8) -> String {
+    let scale = 10u128.pow(decimals as u32);
+    let whole = amount / scale;
+    let fraction = amount % scale;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
@@ -0,0 +1,99 @@
+//! Read-only client subset for the browser (feature `wasm`).
+//!
+//! Asset id derivation, call encoding and chain state queries that need
+//! neither tokio, the in-process node nor a signer, so browser tools can share
+//! them with this crate. [`ReadOnlyClient`] talks to the node's GraphQL API
+//! directly instead of through the SDK's provider. Build it for the browser,
+//! without the native features, with:
+//!
+//! ```text
+//! cargo check --target wasm32-unknown-unknown --lib --no-default-features --features wasm
+//! ```
+//!
+//! Amounts are formatted with [`format_units`](crate::units::format_units).
+
+use fuel_core_client::client::FuelClient;
+use fuels::{
+    core::codec::{encode_fn_selector, ABIEncoder},
+    tx::ContractIdExt,
+    types::{
+        errors::{Error, Result},
+        Address, AssetId, Bits256, ContractId, SubAssetId, Token,
+    },
+};
+
+/// The asset `contract_id` mints under `sub_id`.
+pub fn asset_id(contract_id: &ContractId, sub_id: &Bits256) -> AssetId {
+    contract_id.asset_id(&SubAssetId::from(sub_id.0))
+}
+
+/// The selector and encoded arguments of a call to `method`, as the abigen
+/// bindings send them.
+pub fn encode_call(method: &str, args: &[Token]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let encoded_args = ABIEncoder::default().encode(args)?;
+    Ok((encode_fn_selector(method), encoded_args))
+}
+
+/// The minting contract and total supply of an asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetSupply {
+    pub contract_id: ContractId,
+    pub sub_id: Bits256,
+    pub total_supply: u128,
+}
+
+/// GraphQL queries that only read chain state.
+#[derive(Debug, Clone)]
+pub struct ReadOnlyClient {
+    client: FuelClient,
+}
+
+impl ReadOnlyClient {
+    /// A client for the node at `url`, e.g. `http://127.0.0.1:4000`.
+    pub fn new(url: &str) -> Result<Self> {
+        let client = FuelClient::new(url).map_err(query_error)?;
+        Ok(Self { client })
+    }
+
+    pub async fn latest_block_height(&self) -> Result<u32> {
+        let chain = self.client.chain_info().await.map_err(query_error)?;
+        Ok(chain.latest_block.header.height)
+    }
+
+    /// Spendable balance of `owner` in `asset_id`.
+    pub async fn balance(&self, owner: &Address, asset_id: &AssetId) -> Result<u128> {
+        self.client
+            .balance(owner, Some(asset_id))
+            .await
+            .map_err(query_error)
+    }
+
+    /// Balance `contract_id` holds of `asset_id`.
+    pub async fn contract_balance(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+    ) -> Result<u64> {
+        self.client
+            .contract_balance(contract_id, Some(asset_id))
+            .await
+            .map_err(query_error)
+    }
+
+    /// Total supply of `asset_id` and the contract that mints it.
+    ///
+    /// Served from the node's asset metadata index, which local nodes keep by
+    /// default; fails for the base asset and for assets never minted.
+    pub async fn asset_supply(&self, asset_id: &AssetId) -> Result<AssetSupply> {
+        let detail = self.client.asset_info(asset_id).await.map_err(query_error)?;
+        Ok(AssetSupply {
+            contract_id: detail.contract_id,
+            sub_id: Bits256(*detail.sub_id),
+            total_supply: detail.total_supply,
+        })
+    }
+}
+
+fn query_error(error: impl std::fmt::Display) -> Error {
+    Error::Other(format!("read-only query failed: {}", error))
+}
@@ -0,0 +1,164 @@
+// Admin Transfer Tests
+// 
+// This module contains tests for two-step admin handover including:
+// - Proposing and accepting a new admin on every contract
+// - Rejecting acceptance by an identity that was not proposed
+// - Admin-only operations following the new admin
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{HarnessConfig, TestHarness, SUB_ID, TOKEN_AMOUNT};
+
+// Test handing the token over and the old admin losing mint rights
+#[tokio::test]
+async fn test_token_admin_transfer() -> Result<()> {
+    println!("Testing token admin transfer...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let admin = Identity::Address(harness.admin.address().into());
+    let new_admin_wallet = harness.user(0);
+    let new_admin = Identity::Address(new_admin_wallet.address().into());
+    let new_admin_token = harness.token_as(new_admin_wallet);
+
+    assert_eq!(harness.token.methods().admin().call().await?.value, admin);
+
+    harness.token.methods().propose_admin(new_admin).call().await?;
+    assert_eq!(
+        harness.token.methods().pending_admin().call().await?.value,
+        Some(new_admin)
+    );
+    // Proposing alone changes nothing
+    assert_eq!(harness.token.methods().admin().call().await?.value, admin);
+
+    new_admin_token.methods().accept_admin().call().await?;
+    assert_eq!(harness.token.methods().admin().call().await?.value, new_admin);
+    assert_eq!(harness.token.methods().pending_admin().call().await?.value, None);
+
+    println!("❌ Old admin attempting to mint (should fail)...");
+    let recipient = Identity::Address(harness.user(1).address().into());
+    let result = harness
+        .token
+        .methods()
+        .mint(recipient, Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+
+    new_admin_token
+        .methods()
+        .mint(recipient, Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(harness.token_balance(harness.user(1)).await?, TOKEN_AMOUNT as u128);
+
+    println!("✅ Token admin transfer test passed");
+    Ok(())
+}
+
+// Test that only the proposed identity can accept, on every contract
+#[tokio::test]
+async fn test_accept_by_non_proposed_identity_fails() -> Result<()> {
+    println!("Testing admin acceptance by a non-proposed identity...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let admin = Identity::Address(harness.admin.address().into());
+    let proposed = Identity::Address(harness.user(0).address().into());
+    let intruder = harness.user(1);
+
+    harness.token.methods().propose_admin(proposed).call().await?;
+    harness.vault.methods().propose_admin(proposed).call().await?;
+    harness
+        .cross_contract_call
+        .methods()
+        .propose_admin(proposed)
+        .call()
+        .await?;
+
+    println!("❌ Accepting as a non-proposed identity (should fail)...");
+    let token_result = harness.token_as(intruder).methods().accept_admin().call().await;
+    let vault_result = harness.vault_as(intruder).methods().accept_admin().call().await;
+    let router_result = harness
+        .cross_contract_call
+        .clone()
+        .with_account(intruder.clone())
+        .methods()
+        .accept_admin()
+        .call()
+        .await;
+
+    for result in [
+        token_result.map(|_| ()),
+        vault_result.map(|_| ()),
+        router_result.map(|_| ()),
+    ] {
+        let err = result.expect_err("non-proposed identity accepted admin");
+        assert!(err.to_string().contains("Only the proposed admin can accept"));
+    }
+
+    assert_eq!(harness.token.methods().admin().call().await?.value, admin);
+    assert_eq!(harness.vault.methods().admin().call().await?.value, admin);
+    assert_eq!(harness.cross_contract_call.methods().admin().call().await?.value, admin);
+
+    // A non-admin cannot propose either
+    let result = harness
+        .token_as(intruder)
+        .methods()
+        .propose_admin(Identity::Address(intruder.address().into()))
+        .call()
+        .await;
+    assert!(result.is_err());
+
+    println!("✅ Non-proposed acceptance correctly rejected");
+    Ok(())
+}
+
+// Test handing over the vault and the router
+#[tokio::test]
+async fn test_vault_and_router_admin_transfer() -> Result<()> {
+    println!("Testing vault and router admin transfer...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let new_admin_wallet = harness.user(0);
+    let new_admin = Identity::Address(new_admin_wallet.address().into());
+
+    harness.vault.methods().propose_admin(new_admin).call().await?;
+    harness.vault_as(new_admin_wallet).methods().accept_admin().call().await?;
+    assert_eq!(harness.vault.methods().admin().call().await?.value, new_admin);
+
+    let new_admin_router = harness
+        .cross_contract_call
+        .clone()
+        .with_account(new_admin_wallet.clone());
+    harness
+        .cross_contract_call
+        .methods()
+        .propose_admin(new_admin)
+        .call()
+        .await?;
+    new_admin_router.methods().accept_admin().call().await?;
+    assert_eq!(new_admin_router.methods().admin().call().await?.value, new_admin);
+
+    // The router's admin-only deposit now follows the new admin
+    let user = Identity::Address(harness.user(1).address().into());
+    harness.mint_to(new_admin_wallet, TOKEN_AMOUNT).await?;
+    new_admin_router
+        .methods()
+        .deposit(harness.vault.contract_id(), user)
+        .call_params(
+            CallParameters::default()
+                .with_amount(TOKEN_AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_contract_ids(&[harness.vault.contract_id().clone()])
+        .call()
+        .await?;
+    assert_eq!(
+        harness.vault.methods().get_deposit(user).call().await?.value,
+        TOKEN_AMOUNT
+    );
+
+    println!("✅ Vault and router admin transfer test passed");
+    Ok(())
+}
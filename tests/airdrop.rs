@@ -0,0 +1,109 @@
+// Airdrop Tests
+// 
+// This module contains tests for the CSV-driven airdrop runner including:
+// - Parsing recipients files with headers, comments and bad rows
+// - Paying every recipient across several batched transactions
+// - Reporting recipients whose batch could not be paid
+
+use std::str::FromStr;
+
+use fuels::{prelude::*, types::Address};
+
+use rosetta_stone::{
+    airdrop::{parse_csv, run_airdrop, AirdropStatus},
+    HarnessConfig, TestHarness,
+};
+
+// Test parsing of the recipients file format
+#[tokio::test]
+async fn test_parse_airdrop_csv() -> Result<()> {
+    println!("Testing airdrop CSV parsing...");
+
+    let alice = format!("0x{}", "11".repeat(32));
+    let bob = format!("0x{}", "22".repeat(32));
+    let csv = format!(
+        "address,amount\n# early supporters\n{},100\n\n {} , 250 \n",
+        alice, bob
+    );
+
+    let entries = parse_csv(&csv)?;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].amount, 100);
+    assert_eq!(entries[0].line, 3);
+    assert_eq!(entries[1].recipient, Address::from_str(&bob).unwrap());
+    assert_eq!(entries[1].amount, 250);
+    println!("✅ Header, comments and blank lines skipped");
+
+    let bad_amount = format!("{},lots\n", alice);
+    assert!(parse_csv(&bad_amount).is_err());
+    let zero_amount = format!("{},0\n", alice);
+    assert!(parse_csv(&zero_amount).is_err());
+    assert!(parse_csv("not-an-address,10\n").is_err());
+    let extra_column = format!("{},10,memo\n", alice);
+    assert!(parse_csv(&extra_column).is_err());
+    println!("✅ Malformed rows rejected");
+
+    Ok(())
+}
+
+// Test an airdrop split across several transactions
+#[tokio::test]
+async fn test_airdrop_in_batches() -> Result<()> {
+    println!("Testing batched airdrop...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(5)).await?;
+    harness.mint_to(&harness.admin, 10_000).await?;
+
+    let csv: String = (0..5)
+        .map(|i| format!("{},{}\n", harness.user(i).address(), 100 * (i + 1)))
+        .collect();
+    let entries = parse_csv(&csv)?;
+
+    let report = run_airdrop(&harness.admin, harness.asset_id, &entries, Some(2)).await?;
+
+    assert_eq!(report.batches, 3);
+    assert!(report.is_complete(), "failed: {:?}", report.failed().collect::<Vec<_>>());
+    assert_eq!(report.total_paid(), 1_500);
+    println!("✅ {} recipients paid in {} transactions", report.results.len(), report.batches);
+
+    for i in 0..5 {
+        assert_eq!(
+            harness.token_balance(harness.user(i)).await?,
+            100 * (i as u128 + 1)
+        );
+    }
+    assert_eq!(harness.token_balance(&harness.admin).await?, 10_000 - 1_500);
+    println!("✅ Recipient balances match the file");
+
+    Ok(())
+}
+
+// Test that an unfunded batch is reported per recipient
+#[tokio::test]
+async fn test_airdrop_reports_failed_batches() -> Result<()> {
+    println!("Testing airdrop failure reporting...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    // Enough for the first batch only.
+    harness.mint_to(&harness.admin, 300).await?;
+
+    let csv: String = (0..3)
+        .map(|i| format!("{},{}\n", harness.user(i).address(), 150))
+        .collect();
+    let entries = parse_csv(&csv)?;
+
+    let report = run_airdrop(&harness.admin, harness.asset_id, &entries, Some(2)).await?;
+
+    assert_eq!(report.batches, 2);
+    assert_eq!(report.paid().count(), 2);
+    let failed: Vec<_> = report.failed().collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].entry.recipient, Address::from(harness.user(2).address()));
+    assert!(matches!(failed[0].status, AirdropStatus::Failed { .. }));
+    println!("❌ Unfunded recipient reported: line {}", failed[0].entry.line);
+
+    assert_eq!(harness.token_balance(harness.user(2)).await?, 0);
+    println!("✅ Paid and failed recipients reported separately");
+
+    Ok(())
+}
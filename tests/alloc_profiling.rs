@@ -0,0 +1,210 @@
+// Allocation Profiling Tests
+// 
+// This module contains memory regression tests for batch flows including:
+// - Running the CSV airdrop over 1,000 recipients
+// - Peak memory and allocations per 1,000 recipients
+// - Regression thresholds
+//
+// Run with: cargo test --features alloc-profiling --test alloc_profiling -- --nocapture
+//
+// The node runs in a child process started from this test binary, so only the
+// client side of the airdrop is counted. Thresholds can be tightened with
+// ROSETTA_MAX_ALLOCS_PER_1K and ROSETTA_MAX_PEAK_KIB.
+//
+// To re-measure the defaults, e.g. after an SDK upgrade, run with
+// ROSETTA_ALLOC_BASELINE=1: the test prints this run's numbers and the
+// defaults they give instead of asserting, ready to paste below.
+
+#![cfg(feature = "alloc-profiling")]
+
+use std::{
+    io::{BufRead, BufReader, Read},
+    process::{Child, ChildStdout, Command, Stdio},
+};
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner, crypto::SecretKey, prelude::*,
+    types::Address,
+};
+
+use rosetta_stone::{
+    airdrop::{run_airdrop, AirdropEntry},
+    alloc_profiling::{profile, AllocStats, CountingAllocator},
+    bench_report::BenchReport,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+const RECIPIENTS: usize = 1_000;
+// Printed by a ROSETTA_ALLOC_BASELINE=1 run with the node out of process,
+// which adds 25% headroom to the measured allocations and peak. Recorded for
+// the 1,000 transfers this test used to build (125,001 allocations, a 173 KiB
+// peak); re-run the baseline once the airdrop script is built and replace them.
+const DEFAULT_MAX_ALLOCS_PER_1K: usize = 157_000;
+const DEFAULT_MAX_PEAK_KIB: usize = 224;
+const HEADROOM: f64 = 1.25;
+
+const NODE_PROCESS_ENV: &str = "ROSETTA_ALLOC_PROFILING_NODE";
+const BASELINE_ENV: &str = "ROSETTA_ALLOC_BASELINE";
+const NODE_URL_PREFIX: &str = "node url: ";
+const SENDER_KEY: [u8; 32] = [1; 32];
+const AIRDROP_ASSET: [u8; 32] = [7; 32];
+
+fn signer(key: [u8; 32]) -> Result<PrivateKeySigner> {
+    let secret_key = SecretKey::try_from(key.as_slice())
+        .map_err(|e| Error::Other(format!("invalid test key: {}", e)))?;
+    Ok(PrivateKeySigner::new(secret_key))
+}
+
+// A node in a child process, stopped when dropped
+struct NodeProcess {
+    child: Child,
+    _stdout: BufReader<ChildStdout>,
+    url: String,
+}
+
+impl NodeProcess {
+    // Reruns this binary with only `node_process`, which prints the node's URL
+    // after libtest's `test node_process ... `
+    fn spawn() -> Result<Self> {
+        let mut child = Command::new(std::env::current_exe()?)
+            .args(["node_process", "--exact", "--ignored", "--nocapture"])
+            .env(NODE_PROCESS_ENV, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let url = (&mut stdout)
+            .lines()
+            .map_while(std::io::Result::ok)
+            .find_map(|line| Some(line.split_once(NODE_URL_PREFIX)?.1.to_string()))
+            .ok_or_else(|| Error::Other("node process exited before it started".to_string()))?;
+
+        Ok(Self {
+            child,
+            _stdout: stdout,
+            url,
+        })
+    }
+}
+
+impl Drop for NodeProcess {
+    fn drop(&mut self) {
+        // Closing stdin stops the node
+        drop(self.child.stdin.take());
+        let _ = self.child.wait();
+    }
+}
+
+// The node of `NodeProcess`; does nothing unless started by it
+#[tokio::test]
+#[ignore]
+async fn node_process() -> Result<()> {
+    if std::env::var_os(NODE_PROCESS_ENV).is_none() {
+        return Ok(());
+    }
+
+    // Base asset coins pay the fees, the airdrop pays out its own asset
+    let sender = signer(SENDER_KEY)?.address();
+    let mut coins = setup_single_asset_coins(sender, AssetId::zeroed(), 1, 1_000_000_000);
+    coins.extend(setup_single_asset_coins(sender, AssetId::new(AIRDROP_ASSET), 1, 1_000_000_000));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    println!("{}{}", NODE_URL_PREFIX, provider.url());
+
+    tokio::task::spawn_blocking(|| std::io::stdin().read_to_end(&mut Vec::new()))
+        .await
+        .map_err(|e| Error::Other(e.to_string()))??;
+    Ok(())
+}
+
+fn threshold(var: &str, default: usize) -> usize {
+    std::env::var(var)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+// Prints the numbers of this run and the defaults they give
+fn print_baseline(per_thousand: &AllocStats) {
+    let allocations = (per_thousand.allocations as f64 * HEADROOM).ceil() as usize;
+    let peak_kib = (per_thousand.peak_bytes as f64 * HEADROOM / 1024.0).ceil() as usize;
+    println!(
+        "📏 Measured {} allocations and a {} KiB peak per 1,000 recipients",
+        per_thousand.allocations,
+        per_thousand.peak_bytes / 1024
+    );
+    println!("const DEFAULT_MAX_ALLOCS_PER_1K: usize = {};", allocations);
+    println!("const DEFAULT_MAX_PEAK_KIB: usize = {};", peak_kib);
+}
+
+// 1,000 distinct recipients, each paid a different amount
+fn recipients() -> Vec<AirdropEntry> {
+    (0..RECIPIENTS)
+        .map(|i| {
+            let mut address = [0u8; 32];
+            address[24..].copy_from_slice(&(i as u64 + 1).to_be_bytes());
+            AirdropEntry {
+                recipient: Address::from(address),
+                amount: 1 + i as u64,
+                line: i + 1,
+            }
+        })
+        .collect()
+}
+
+// Test memory usage of an airdrop to 1,000 recipients
+#[tokio::test]
+async fn test_airdrop_allocations() -> Result<()> {
+    println!("Profiling allocations for an airdrop to {} recipients...", RECIPIENTS);
+
+    let node = NodeProcess::spawn()?;
+    let provider = Provider::connect(&node.url).await?;
+    let sender = Wallet::new(signer(SENDER_KEY)?, provider);
+    let entries = recipients();
+
+    let (report, stats) = profile(&ALLOCATOR, || async {
+        run_airdrop(&sender, AssetId::new(AIRDROP_ASSET), &entries, None).await
+    })
+    .await;
+    let report = report?;
+    assert!(report.is_complete(), "airdrop left recipients unpaid");
+    println!("✅ Paid {} recipients in {} transactions", RECIPIENTS, report.batches);
+
+    let per_thousand = stats.per_thousand(report.results.len());
+    println!("📈 Total: {}", stats);
+    println!("📈 Per 1,000 recipients: {}", per_thousand);
+
+    let mut bench = BenchReport::new();
+    bench.record("airdrop_allocs_per_1k", "allocations", per_thousand.allocations as f64);
+    bench.record(
+        "airdrop_peak_memory",
+        "MiB",
+        per_thousand.peak_bytes as f64 / (1024.0 * 1024.0),
+    );
+    bench.write_default()?;
+
+    if std::env::var_os(BASELINE_ENV).is_some() {
+        print_baseline(&per_thousand);
+        return Ok(());
+    }
+
+    let max_allocs = threshold("ROSETTA_MAX_ALLOCS_PER_1K", DEFAULT_MAX_ALLOCS_PER_1K);
+    let max_peak_kib = threshold("ROSETTA_MAX_PEAK_KIB", DEFAULT_MAX_PEAK_KIB);
+
+    assert!(
+        per_thousand.allocations <= max_allocs,
+        "Allocation regression: {} allocations per 1k > {}",
+        per_thousand.allocations,
+        max_allocs
+    );
+    assert!(
+        per_thousand.peak_bytes <= max_peak_kib * 1024,
+        "Peak memory regression: {} bytes > {} KiB",
+        per_thousand.peak_bytes,
+        max_peak_kib
+    );
+
+    println!("✅ Allocation profiling test passed");
+    Ok(())
+}
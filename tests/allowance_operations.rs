@@ -0,0 +1,144 @@
+// Allowance Operations Tests
+// 
+// This module contains tests for the TokenAllowance extension including:
+// - Approving a spender and querying the allowance
+// - Spending from an owner's deposit within the allowance
+// - Reverting when a spender exceeds the allowance
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{deploy_token_allowance, HarnessConfig, TestHarness, TOKEN_AMOUNT};
+
+// Test approval, allowance queries and a transfer_from within the allowance
+#[tokio::test]
+async fn test_approve_and_transfer_from() -> Result<()> {
+    println!("Testing approve and transfer_from...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let owner = harness.user(0);
+    let spender = harness.user(1);
+    let recipient = harness.user(2);
+    let owner_identity = Identity::Address(owner.address().into());
+    let spender_identity = Identity::Address(spender.address().into());
+    let recipient_identity = Identity::Address(recipient.address().into());
+
+    let allowance = deploy_token_allowance(harness.admin.clone(), harness.asset_id).await?;
+    harness.mint_to(owner, TOKEN_AMOUNT).await?;
+
+    // Owner deposits and approves the spender
+    let deposit_amount = 500_000;
+    let owner_allowance = allowance.clone().with_account(owner.clone());
+    owner_allowance
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(deposit_amount)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let approved_amount = 300_000;
+    owner_allowance
+        .methods()
+        .approve(spender_identity, approved_amount)
+        .call()
+        .await?;
+
+    let remaining = allowance
+        .methods()
+        .allowance(owner_identity, spender_identity)
+        .call()
+        .await?
+        .value;
+    assert_eq!(remaining, approved_amount);
+
+    // Spender moves part of the allowance to the recipient
+    let spend_amount = 200_000;
+    allowance
+        .clone()
+        .with_account(spender.clone())
+        .methods()
+        .transfer_from(owner_identity, recipient_identity, spend_amount)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let remaining = allowance
+        .methods()
+        .allowance(owner_identity, spender_identity)
+        .call()
+        .await?
+        .value;
+    let owner_deposit = allowance.methods().balance_of(owner_identity).call().await?.value;
+
+    assert_eq!(remaining, approved_amount - spend_amount);
+    assert_eq!(owner_deposit, deposit_amount - spend_amount);
+    assert_eq!(harness.token_balance(recipient).await?, spend_amount as u128);
+
+    println!("✅ Approve and transfer_from test passed");
+    println!("   Remaining allowance: {}", remaining);
+    Ok(())
+}
+
+// Test that spending beyond the allowance reverts and changes nothing
+#[tokio::test]
+async fn test_transfer_from_exceeding_allowance_fails() -> Result<()> {
+    println!("Testing transfer_from beyond the allowance...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let owner = harness.user(0);
+    let spender = harness.user(1);
+    let owner_identity = Identity::Address(owner.address().into());
+    let spender_identity = Identity::Address(spender.address().into());
+
+    let allowance = deploy_token_allowance(harness.admin.clone(), harness.asset_id).await?;
+    harness.mint_to(owner, TOKEN_AMOUNT).await?;
+
+    let owner_allowance = allowance.clone().with_account(owner.clone());
+    owner_allowance
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(TOKEN_AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let approved_amount = 100_000;
+    owner_allowance
+        .methods()
+        .approve(spender_identity, approved_amount)
+        .call()
+        .await?;
+
+    println!("❌ Attempting to spend more than approved (should fail)...");
+    let result = allowance
+        .clone()
+        .with_account(spender.clone())
+        .methods()
+        .transfer_from(owner_identity, spender_identity, approved_amount + 1)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(format!("{:?}", result.unwrap_err()).contains("Insufficient allowance"));
+
+    // Allowance and deposit are untouched
+    let remaining = allowance
+        .methods()
+        .allowance(owner_identity, spender_identity)
+        .call()
+        .await?
+        .value;
+    let owner_deposit = allowance.methods().balance_of(owner_identity).call().await?.value;
+    assert_eq!(remaining, approved_amount);
+    assert_eq!(owner_deposit, TOKEN_AMOUNT);
+    assert_eq!(harness.token_balance(spender).await?, 0);
+
+    println!("✅ Exceeding allowance correctly reverted");
+    Ok(())
+}
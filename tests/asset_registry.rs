@@ -0,0 +1,177 @@
+// Asset Registry Tests
+// 
+// This module contains tests for the asset registry and resolver including:
+// - Registering a token's metadata from the token contract
+// - Resolving asset ids to names, symbols and decimals
+// - Rendering amounts for registered and unknown assets
+// - Rejecting registrations that do not come from the minting contract
+// - Reading SRC-20 metadata of several sub ids straight from the contract
+
+use fuels::{
+    prelude::*,
+    types::{AssetId, Bits256, Identity},
+};
+
+use rosetta_stone::{
+    asset_resolver::{base_asset_info, format_units, short_id, src20_metadata, AssetResolver},
+    deploy_asset_registry, deploy_nft, deploy_src20_token, HarnessConfig, Src20Token, TestHarness,
+    SUB_ID,
+};
+
+// Test that a registered token resolves to its metadata
+#[tokio::test]
+async fn test_register_and_resolve() -> Result<()> {
+    println!("Testing asset registration...");
+
+    let harness =
+        TestHarness::new(HarnessConfig::default().with_token("RESOLVE", "RSOLV", 6)).await?;
+    let registry = deploy_asset_registry(harness.admin.clone()).await?;
+
+    let registered_id = harness
+        .token
+        .methods()
+        .register_with(
+            registry.contract_id().clone(),
+            "https://example.com/rsolv.png".to_string(),
+        )
+        .with_contract_ids(&[registry.contract_id().clone()])
+        .call()
+        .await?
+        .value;
+    assert_eq!(registered_id, harness.asset_id);
+    assert_eq!(registry.methods().total_registered().call().await?.value, 1);
+
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let mut resolver = AssetResolver::new(registry).with_known(base_asset_id, base_asset_info());
+
+    let info = resolver.resolve(harness.asset_id).await?.expect("token is registered");
+    assert_eq!(info.name, "RESOLVE");
+    assert_eq!(info.symbol, "RSOLV");
+    assert_eq!(info.decimals, 6);
+    assert_eq!(info.logo_uri, "https://example.com/rsolv.png");
+
+    assert_eq!(resolver.label(harness.asset_id).await?, "RSOLV");
+    assert_eq!(
+        resolver.format_amount(harness.asset_id, 1_500_000).await?,
+        "1.5 RSOLV"
+    );
+    assert_eq!(resolver.label(base_asset_id).await?, "ETH");
+
+    // Unregistered assets fall back to a shortened id
+    let unknown = AssetId::from([0xab; 32]);
+    assert_eq!(resolver.resolve(unknown).await?, None);
+    assert_eq!(resolver.label(unknown).await?, short_id(&unknown));
+
+    println!("✅ Asset registration test passed");
+    Ok(())
+}
+
+// Test that two tokens register independently and an address cannot register
+#[tokio::test]
+async fn test_registry_is_keyed_by_minting_contract() -> Result<()> {
+    println!("Testing registry ownership...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let registry = deploy_asset_registry(harness.admin.clone()).await?;
+    let other_token = deploy_src20_token(harness.admin.clone(), "OTHERTK", "OTHER", 2).await?;
+
+    for token in [&harness.token, &other_token] {
+        token
+            .methods()
+            .register_with(registry.contract_id().clone(), String::new())
+            .with_contract_ids(&[registry.contract_id().clone()])
+            .call()
+            .await?;
+    }
+    assert_eq!(registry.methods().total_registered().call().await?.value, 2);
+
+    let other_asset_id = other_token.methods().get_asset_id().call().await?.value;
+    let mut resolver = AssetResolver::new(registry.clone());
+    assert_eq!(resolver.format_amount(other_asset_id, 12_345).await?, "123.45 OTHER");
+
+    println!("❌ Registering directly from a wallet (should fail)...");
+    let result = registry
+        .methods()
+        .register(
+            SUB_ID,
+            "SPOOFED".to_string(),
+            "SPOOF".to_string(),
+            9,
+            String::new(),
+        )
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Only the minting contract can register"));
+
+    println!("✅ Registry ownership test passed");
+    Ok(())
+}
+
+// Test decimal formatting of raw amounts
+#[test]
+fn test_format_units() {
+    assert_eq!(format_units(0, 9), "0");
+    assert_eq!(format_units(1_000_000_000, 9), "1");
+    assert_eq!(format_units(1_500_000_000, 9), "1.5");
+    assert_eq!(format_units(1, 9), "0.000000001");
+    assert_eq!(format_units(42, 0), "42");
+}
+
+// Test reading the metadata of every minted sub id from the token itself
+#[tokio::test]
+async fn test_src20_metadata_per_sub_id() -> Result<()> {
+    println!("Testing SRC-20 metadata lookup...");
+
+    let harness =
+        TestHarness::new(HarnessConfig::default().with_token("METADAT", "META_", 6)).await?;
+    let (minted, unminted) = (Bits256([1u8; 32]), Bits256([2u8; 32]));
+    harness.mint_to(harness.user(0), 1_500_000).await?;
+    let minted_asset = harness.mint_sub_id_to(harness.user(0), minted, 250).await?;
+
+    let assets = src20_metadata(&harness.token, &[SUB_ID, minted, unminted]).await?;
+    assert_eq!(assets.len(), 2, "the unminted sub id has no supply");
+
+    assert_eq!(assets[0].asset_id, harness.asset_id);
+    assert_eq!(assets[0].total_supply, 1_500_000);
+    assert_eq!(assets[1].sub_id, minted);
+    assert_eq!(assets[1].asset_id, minted_asset);
+    assert_eq!(assets[1].total_supply, 250);
+    for asset in &assets {
+        assert_eq!(asset.name.as_deref(), Some("METADAT"));
+        assert_eq!(asset.symbol.as_deref(), Some("META_"));
+        assert_eq!(asset.decimals, Some(6));
+    }
+    println!("✅ {} assets read from the token", assets.len());
+
+    Ok(())
+}
+
+// Test that the lookup only relies on SRC-20, so it works on the NFT contract too
+#[tokio::test]
+async fn test_src20_metadata_of_other_contract() -> Result<()> {
+    println!("Testing SRC-20 metadata lookup on an NFT collection...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let nft = deploy_nft(harness.admin.clone(), "ROSETTA", "RSNFT").await?;
+    let sub_id = Bits256([5u8; 32]);
+    nft.methods()
+        .mint(Identity::Address(harness.user(0).address().into()), Some(sub_id), 1)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let as_src20 = Src20Token::new(nft.contract_id().clone(), harness.admin.clone());
+    let assets = src20_metadata(&as_src20, &[SUB_ID, sub_id]).await?;
+    assert_eq!(assets.len(), 1, "the collection never minted the default sub id");
+    assert_eq!(assets[0].sub_id, sub_id);
+    assert_eq!(assets[0].symbol.as_deref(), Some("RSNFT"));
+    assert_eq!(assets[0].decimals, Some(0));
+    assert_eq!(assets[0].total_supply, 1);
+    println!("✅ NFT metadata read through the SRC-20 bindings");
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// Atomic Swap Tests
+// 
+// This module contains tests for the AtomicSwap script including:
+// - Wallet A's token X and wallet B's token Y swapped in one transaction signed by both
+// - The whole transaction reverting when either party's input is missing
+// - Refusing to build a swap the parties cannot cover or do not own
+
+use fuels::{
+    prelude::*,
+    types::{output::Output, transaction::Transaction, tx_status::TxStatus, Bits256},
+};
+
+use rosetta_stone::{
+    expect_single_event,
+    swap::{build_swap_tx, swap_call},
+    HarnessConfig, SwapExecuted, SwapLeg, TestHarness, TestWallet,
+};
+
+const X_SUB_ID: Bits256 = Bits256([1u8; 32]);
+const Y_SUB_ID: Bits256 = Bits256([2u8; 32]);
+const X_AMOUNT: u64 = 400;
+const Y_AMOUNT: u64 = 900;
+
+// User 0 holds X_AMOUNT of token X, user 1 holds Y_AMOUNT of token Y
+async fn setup() -> Result<(TestHarness, SwapLeg, SwapLeg)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let x = harness.mint_sub_id_to(harness.user(0), X_SUB_ID, X_AMOUNT).await?;
+    let y = harness.mint_sub_id_to(harness.user(1), Y_SUB_ID, Y_AMOUNT).await?;
+
+    let a = SwapLeg {
+        owner: harness.user(0).address().into(),
+        asset: x,
+        amount: X_AMOUNT,
+    };
+    let b = SwapLeg {
+        owner: harness.user(1).address().into(),
+        asset: y,
+        amount: Y_AMOUNT,
+    };
+    Ok((harness, a, b))
+}
+
+// Balances of X and Y held by `wallet`
+async fn holdings(wallet: &TestWallet, a: &SwapLeg, b: &SwapLeg) -> Result<(u128, u128)> {
+    Ok((
+        wallet.get_asset_balance(&a.asset).await?,
+        wallet.get_asset_balance(&b.asset).await?,
+    ))
+}
+
+// Sends the swap with only the listed parties' coins as inputs; user 0 pays the fee
+async fn send_partial(
+    harness: &TestHarness,
+    a: &SwapLeg,
+    b: &SwapLeg,
+    parties: &[(&TestWallet, &SwapLeg)],
+) -> Result<TxStatus> {
+    let (party_a, party_b) = (harness.user(0), harness.user(1));
+    let mut tb = swap_call(party_a, a.clone(), b.clone())
+        .transaction_builder()
+        .await?;
+    for (party, leg) in parties {
+        tb.inputs.extend(
+            party
+                .get_asset_inputs_for_amount(leg.asset, u128::from(leg.amount), None)
+                .await?,
+        );
+        tb.outputs.push(Output::change(leg.owner, 0, leg.asset));
+    }
+    party_a.adjust_for_fee(&mut tb, 0).await?;
+    party_a.add_witnesses(&mut tb)?;
+    party_b.add_witnesses(&mut tb)?;
+
+    let tx = tb.build(&harness.provider).await?;
+    harness.provider.send_transaction_and_await_commit(tx).await
+}
+
+// Test that both legs are exchanged in a single transaction
+#[tokio::test]
+async fn test_atomic_swap() -> Result<()> {
+    println!("Testing an atomic two-party swap...");
+
+    let (harness, a, b) = setup().await?;
+    let (party_a, party_b) = (harness.user(0), harness.user(1));
+
+    let (call, tx) = build_swap_tx(party_a, party_b, a.clone(), b.clone()).await?;
+    assert_eq!(tx.witnesses().len(), 2);
+    let status = harness.provider.send_transaction_and_await_commit(tx).await?;
+    let response = call.get_response(status)?;
+    assert!(response.value);
+
+    let event: SwapExecuted = expect_single_event(&response)?;
+    assert_eq!(event.a, a);
+    assert_eq!(event.b, b);
+
+    assert_eq!(holdings(party_a, &a, &b).await?, (0, u128::from(Y_AMOUNT)));
+    assert_eq!(holdings(party_b, &a, &b).await?, (u128::from(X_AMOUNT), 0));
+    println!("✅ {} X swapped for {} Y in one transaction", X_AMOUNT, Y_AMOUNT);
+
+    Ok(())
+}
+
+// Test that a swap missing either party's coins reverts without moving anything
+#[tokio::test]
+async fn test_missing_input_reverts() -> Result<()> {
+    println!("Testing swaps missing one side's input...");
+
+    let (harness, a, b) = setup().await?;
+    let (party_a, party_b) = (harness.user(0), harness.user(1));
+    let a_before = holdings(party_a, &a, &b).await?;
+    let b_before = holdings(party_b, &a, &b).await?;
+
+    for (parties, missing) in [([(party_a, &a)], "B"), ([(party_b, &b)], "A")] {
+        let status = send_partial(&harness, &a, &b, &parties).await?;
+        assert!(matches!(status, TxStatus::Failure(_)), "{:?}", status);
+        assert_eq!(holdings(party_a, &a, &b).await?, a_before);
+        assert_eq!(holdings(party_b, &a, &b).await?, b_before);
+        println!("❌ Swap without {}'s input reverted; no tokens moved", missing);
+    }
+
+    // Both inputs present through the same path succeeds
+    let status = send_partial(&harness, &a, &b, &[(party_a, &a), (party_b, &b)]).await?;
+    assert!(matches!(status, TxStatus::Success { .. }), "{:?}", status);
+    assert_eq!(holdings(party_b, &a, &b).await?, (u128::from(X_AMOUNT), 0));
+    println!("✅ Same transaction with both inputs swapped");
+
+    Ok(())
+}
+
+// Test that swaps a party cannot cover, or does not own, are refused before submission
+#[tokio::test]
+async fn test_invalid_swaps_not_built() -> Result<()> {
+    println!("Testing swaps that cannot be built...");
+
+    let (harness, a, b) = setup().await?;
+    let (party_a, party_b) = (harness.user(0), harness.user(1));
+
+    let short = SwapLeg {
+        amount: Y_AMOUNT + 1,
+        ..b.clone()
+    };
+    assert!(build_swap_tx(party_a, party_b, a.clone(), short).await.is_err());
+    println!("❌ Leg larger than B's balance rejected");
+
+    assert!(build_swap_tx(party_b, party_a, a.clone(), b.clone()).await.is_err());
+    println!("❌ Parties not owning their legs rejected");
+
+    let base = SwapLeg {
+        asset: *harness.provider.consensus_parameters().await?.base_asset_id(),
+        ..b
+    };
+    assert!(build_swap_tx(party_a, party_b, a, base).await.is_err());
+    println!("❌ Base asset leg rejected");
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+// Auction Operations Tests
+// 
+// This module contains tests for the English auction contract including:
+// - Bids below the reserve or the current highest bid
+// - Refunding the outbid bidder
+// - Settling after produce_blocks passes the deadline
+// - Returning the item when nobody bids
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::ContractIdExt,
+    types::{AssetId, Bits256, Identity, SubAssetId},
+};
+
+use rosetta_stone::{deploy_auction, deploy_nft, Auction, HarnessConfig, TestHarness};
+
+const RESERVE_PRICE: u64 = 100;
+const DURATION: u32 = 20;
+
+// Mints an NFT to the seller (user 0) and returns its asset id
+async fn mint_item(harness: &TestHarness) -> Result<AssetId> {
+    let nft = deploy_nft(harness.admin.clone(), "AUCTION", "AUCTN").await?;
+    let sub_id = [1u8; 32];
+    nft.methods()
+        .mint(
+            Identity::Address(harness.user(0).address().into()),
+            Some(Bits256(sub_id)),
+            1,
+        )
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(nft.contract_id().asset_id(&SubAssetId::from(sub_id)))
+}
+
+async fn start_auction(
+    harness: &TestHarness,
+    auction: &Auction<Wallet<Unlocked<PrivateKeySigner>>>,
+    item: AssetId,
+) -> Result<u64> {
+    Ok(auction
+        .clone()
+        .with_account(harness.user(0).clone())
+        .methods()
+        .start(harness.asset_id, RESERVE_PRICE, DURATION)
+        .call_params(CallParameters::default().with_amount(1).with_asset_id(item))?
+        .call()
+        .await?
+        .value)
+}
+
+async fn bid(
+    harness: &TestHarness,
+    auction: &Auction<Wallet<Unlocked<PrivateKeySigner>>>,
+    bidder: &Wallet<Unlocked<PrivateKeySigner>>,
+    id: u64,
+    amount: u64,
+) -> Result<()> {
+    auction
+        .clone()
+        .with_account(bidder.clone())
+        .methods()
+        .bid(id)
+        .call_params(
+            CallParameters::default()
+                .with_amount(amount)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(())
+}
+
+// Test bidding, outbid refunds and settlement
+#[tokio::test]
+async fn test_auction_bid_and_settle() -> Result<()> {
+    println!("Testing English auction...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let (seller, alice, bob) = (harness.user(0), harness.user(1), harness.user(2));
+    harness.mint_to(alice, 1_000).await?;
+    harness.mint_to(bob, 1_000).await?;
+
+    let item = mint_item(&harness).await?;
+    let auction = deploy_auction(harness.admin.clone()).await?;
+    let id = start_auction(&harness, &auction, item).await?;
+    assert_eq!(seller.get_asset_balance(&item).await?, 0);
+    println!("✅ Auction {} started", id);
+
+    let result = bid(&harness, &auction, alice, id, RESERVE_PRICE - 1).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Bid below reserve"));
+
+    bid(&harness, &auction, alice, id, 100).await?;
+    assert_eq!(harness.token_balance(alice).await?, 900);
+
+    bid(&harness, &auction, bob, id, 150).await?;
+    assert_eq!(harness.token_balance(bob).await?, 850);
+    assert_eq!(harness.token_balance(alice).await?, 1_000);
+    println!("✅ Alice refunded when Bob outbid her");
+
+    let result = bid(&harness, &auction, alice, id, 150).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Bid too low"));
+
+    let result = auction
+        .methods()
+        .settle(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Auction still running"));
+    println!("✅ Low bids and early settlement rejected");
+
+    harness.provider.produce_blocks(DURATION, None).await?;
+
+    let result = bid(&harness, &auction, alice, id, 500).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Auction ended"));
+
+    auction
+        .methods()
+        .settle(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .call()
+        .await?;
+    assert_eq!(bob.get_asset_balance(&item).await?, 1);
+    assert_eq!(harness.token_balance(seller).await?, 150);
+    assert_eq!(harness.token_balance(bob).await?, 850);
+
+    let info = auction.methods().auction(id).call().await?.value.unwrap();
+    assert!(info.settled);
+    assert_eq!(info.highest_bidder, Some(Identity::Address(bob.address().into())));
+    println!("✅ Bob won the item, the seller received 150");
+
+    let result = auction
+        .methods()
+        .settle(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Auction already settled"));
+
+    Ok(())
+}
+
+// Test that an auction without bids returns the item to the seller
+#[tokio::test]
+async fn test_auction_without_bids() -> Result<()> {
+    println!("Testing auction without bids...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let seller = harness.user(0);
+
+    let item = mint_item(&harness).await?;
+    let auction = deploy_auction(harness.admin.clone()).await?;
+    let id = start_auction(&harness, &auction, item).await?;
+
+    harness.provider.produce_blocks(DURATION, None).await?;
+    auction
+        .methods()
+        .settle(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    assert_eq!(seller.get_asset_balance(&item).await?, 1);
+    assert_eq!(harness.token_balance(seller).await?, 0);
+    println!("✅ Unsold item returned to the seller");
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+// Balance Assertion Tests
+// 
+// This module contains tests for the balance-change helpers including:
+// - assert_balance_change! around a token transfer and a vault deposit
+// - BalanceSnapshot deltas for several holders and assets at once
+// - A readable panic when a balance changed by an unexpected amount
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    assert_balance_change, balances::BalanceSnapshot, HarnessConfig, TestHarness, TOKEN_AMOUNT,
+};
+
+// Test the macro around a transfer and a deposit
+#[tokio::test]
+async fn test_assert_balance_change_macro() -> Result<()> {
+    println!("Testing assert_balance_change!...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let asset_id = harness.asset_id;
+    harness.mint_to(alice, TOKEN_AMOUNT).await?;
+
+    let amount = 25_000;
+    assert_balance_change!(
+        &harness.provider,
+        [
+            (alice.address(), asset_id) => -i128::from(amount),
+            (bob.address(), asset_id) => amount,
+        ],
+        alice.transfer(bob.address(), amount, asset_id, TxPolicies::default())
+    )?;
+    println!("✅ Transfer moved {} from Alice to Bob", amount);
+
+    // Depositing only moves tokens out of the wallet; the vault is a contract
+    let deposit = 10_000;
+    let response = assert_balance_change!(
+        &harness.provider,
+        [(bob.address(), asset_id) => -i128::from(deposit)],
+        harness
+            .vault_as(bob)
+            .methods()
+            .deposit()
+            .call_params(CallParameters::default().with_amount(deposit).with_asset_id(asset_id))?
+            .call()
+    )?;
+    assert!(response.tx_status.total_fee > 0);
+    assert_eq!(
+        harness
+            .vault
+            .methods()
+            .get_deposit(Identity::Address(bob.address().into()))
+            .call()
+            .await?
+            .value,
+        deposit
+    );
+    println!("✅ Deposit took {} from Bob", deposit);
+
+    Ok(())
+}
+
+// Test snapshot deltas across holders and assets, fees included
+#[tokio::test]
+async fn test_snapshot_deltas() -> Result<()> {
+    println!("Testing BalanceSnapshot deltas...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+
+    let snapshot = BalanceSnapshot::take(
+        &harness.provider,
+        &[
+            (alice.address(), base_asset_id),
+            (bob.address(), base_asset_id),
+            (bob.address(), harness.asset_id),
+        ],
+    )
+    .await?;
+    assert_eq!(snapshot.balances()[2], 0);
+
+    let amount = 1_000;
+    let response = alice
+        .transfer(bob.address(), amount, base_asset_id, TxPolicies::default())
+        .await?;
+    harness.mint_to(bob, 500).await?;
+
+    // The admin paid for the mint, so only Alice's delta includes a fee
+    let fee = response.tx_status.total_fee;
+    assert_eq!(
+        snapshot.deltas().await?,
+        vec![-i128::from(amount + fee), i128::from(amount), 500]
+    );
+    snapshot
+        .assert_deltas(&[-i128::from(amount + fee), i128::from(amount), 500])
+        .await?;
+    println!("✅ Deltas match, Alice paid a fee of {}", fee);
+
+    Ok(())
+}
+
+// Test that a wrong expectation panics and names the holder
+#[tokio::test]
+#[should_panic(expected = "unexpected balance changes")]
+async fn test_unexpected_change_panics() {
+    let harness = TestHarness::new(HarnessConfig::default()).await.unwrap();
+    let alice = harness.user(0);
+
+    let snapshot = BalanceSnapshot::take(&harness.provider, &[(alice.address(), harness.asset_id)])
+        .await
+        .unwrap();
+    harness.mint_to(alice, 100).await.unwrap();
+
+    snapshot.assert_deltas(&[99]).await.unwrap();
+}
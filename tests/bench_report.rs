@@ -0,0 +1,49 @@
+// Bench Report Tests
+// 
+// This module contains tests for the benchmark report adapter including:
+// - github-action-benchmark JSON schema
+// - Merging several runs into one file
+
+use std::time::Duration;
+
+use rosetta_stone::bench_report::BenchReport;
+
+// Test that entries serialize as { name, unit, value }
+#[test]
+fn test_serializes_to_github_action_benchmark_schema() {
+    let mut report = BenchReport::new();
+    report.record_duration("mint", Duration::from_millis(12));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json[0]["name"], "mint");
+    assert_eq!(json[0]["unit"], "ms");
+    assert_eq!(json[0]["value"], 12.0);
+    assert!(json[0].get("extra").is_none());
+}
+
+// Test that writing merges with an existing file and replaces entries by name
+#[test]
+fn test_write_merges_and_replaces_by_name() {
+    let path = std::env::temp_dir().join(format!("rosetta-bench-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut first = BenchReport::new();
+    first.record("mint", "ms", 1.0);
+    first.record("transfer", "ms", 2.0);
+    first.write(&path).unwrap();
+
+    let mut second = BenchReport::new();
+    second.record("mint", "ms", 3.0);
+    second.write(&path).unwrap();
+
+    let merged: BenchReport =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let values: Vec<_> = merged
+        .entries()
+        .iter()
+        .map(|entry| (entry.name.as_str(), entry.value))
+        .collect();
+    assert_eq!(values, vec![("transfer", 2.0), ("mint", 3.0)]);
+
+    std::fs::remove_file(&path).unwrap();
+}
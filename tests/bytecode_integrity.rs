@@ -0,0 +1,98 @@
+// Bytecode Integrity Tests
+// 
+// This module contains tests for verifying contract binaries before deploying including:
+// - Recording a manifest of the suite's bytecode hashes and verifying it
+// - Round-tripping the manifest through its JSON file
+// - A changed binary failing with RosettaError::BytecodeMismatch
+// - Artifacts without a manifest entry being rejected
+
+use std::str::FromStr;
+
+use fuels::{prelude::*, types::Bytes32};
+
+use rosetta_stone::{
+    artifacts,
+    integrity::{bytecode_hash, BytecodeManifest, SUITE_ARTIFACTS},
+    RosettaError,
+};
+
+// A copy of the token binary in the temp dir that the test can modify
+fn scratch_artifact(name: &str) -> Result<String> {
+    let path = std::env::temp_dir().join(format!("rosetta-{}-{}.bin", name, std::process::id()));
+    std::fs::copy(artifacts::SRC20_TOKEN_BIN, &path)?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+// Test that freshly recorded hashes verify and survive a save/load round trip
+#[test]
+fn test_recorded_manifest_verifies() -> Result<()> {
+    println!("Testing bytecode manifest round trip...");
+
+    let manifest = BytecodeManifest::for_artifacts(&SUITE_ARTIFACTS)?;
+    assert_eq!(manifest.hashes.len(), SUITE_ARTIFACTS.len());
+    manifest.verify_all(&SUITE_ARTIFACTS)?;
+
+    let path = std::env::temp_dir().join(format!("rosetta-bytecode-{}.json", std::process::id()));
+    manifest.save(&path)?;
+    let loaded = BytecodeManifest::load(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(loaded, manifest);
+    loaded.verify_all(&SUITE_ARTIFACTS)?;
+
+    // The recorded value is the binary's SHA-256
+    let hash = bytecode_hash(artifacts::TOKEN_VAULT_BIN)?;
+    let recorded = &loaded.hashes[artifacts::TOKEN_VAULT_BIN];
+    assert_eq!(Bytes32::from_str(recorded).expect("recorded hash is hex"), hash);
+    println!("✅ {} artifacts verified", SUITE_ARTIFACTS.len());
+
+    Ok(())
+}
+
+// Test that a binary changed after recording fails with the mismatch error
+#[test]
+fn test_changed_bytecode_is_rejected() -> Result<()> {
+    println!("Testing bytecode mismatch...");
+
+    let artifact = scratch_artifact("mismatch")?;
+    let mut manifest = BytecodeManifest::default();
+    manifest.record(&artifact)?;
+    manifest.verify(&artifact)?;
+
+    // Simulate a rebuild from different sources
+    let mut bytecode = std::fs::read(&artifact)?;
+    bytecode.push(0);
+    std::fs::write(&artifact, bytecode)?;
+
+    let error = manifest.verify(&artifact).unwrap_err();
+    std::fs::remove_file(&artifact)?;
+
+    match &error {
+        RosettaError::BytecodeMismatch {
+            artifact: mismatched,
+            expected,
+            actual,
+        } => {
+            assert_eq!(mismatched, &artifact);
+            assert_eq!(expected, &manifest.hashes[&artifact]);
+            assert_ne!(expected, actual);
+        }
+        other => panic!("expected a bytecode mismatch, got {:?}", other),
+    }
+    println!("❌ {}", error);
+
+    Ok(())
+}
+
+// Test that an artifact missing from the manifest does not pass
+#[test]
+fn test_unrecorded_artifact_is_rejected() -> Result<()> {
+    println!("Testing an artifact without a manifest entry...");
+
+    let manifest = BytecodeManifest::for_artifacts(&[artifacts::SRC20_TOKEN_BIN])?;
+    let error = manifest.verify_all(&SUITE_ARTIFACTS).unwrap_err();
+    assert!(error.to_string().contains(artifacts::CROSS_CONTRACT_CALL_BIN));
+    println!("❌ {}", error);
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Chain Query Tests
+// 
+// This module contains tests for the typed GraphQL query wrappers including:
+// - Listing the coins an owner holds in an asset
+// - Looking up a transaction by id
+// - Looking up a block by height
+// - Paginating through the transactions of an owner
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    queries::{block_by_height, coins_of, transaction_by_id, transactions_of, TxOutcome},
+    HarnessConfig, TestHarness, SUB_ID,
+};
+
+// Test coin, transaction and block lookups around a single mint
+#[tokio::test]
+async fn test_query_coins_transaction_and_block() -> Result<()> {
+    println!("Testing chain queries...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    let response = harness
+        .token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 250)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let tx_id = response.tx_id.expect("mint has a transaction id");
+    let latest = harness.provider.latest_block_height().await?;
+
+    let coins = coins_of(&harness.provider, &user.address(), harness.asset_id).await?;
+    assert_eq!(coins.len(), 1);
+    assert_eq!(coins[0].amount, 250);
+    assert_eq!(coins[0].asset_id, harness.asset_id);
+    assert_eq!(coins[0].utxo_id.tx_id(), &tx_id);
+    println!("✅ User holds one coin of 250 created by the mint");
+
+    let summary = transaction_by_id(&harness.provider, &tx_id)
+        .await?
+        .expect("mint transaction is known");
+    assert_eq!(summary.tx_id, tx_id);
+    assert_eq!(summary.kind, "script");
+    assert_eq!(summary.outcome, TxOutcome::Success);
+    assert_eq!(summary.block_height, Some(latest));
+    assert!(summary.total_fee.is_some());
+    println!(
+        "✅ Mint found: {} inputs, {} outputs, fee {:?}",
+        summary.input_count, summary.output_count, summary.total_fee
+    );
+
+    assert!(transaction_by_id(&harness.provider, &TxId::zeroed()).await?.is_none());
+
+    let block = block_by_height(&harness.provider, latest)
+        .await?
+        .expect("latest block exists");
+    assert_eq!(block.height, latest);
+    assert!(block.tx_ids.contains(&tx_id));
+    println!("✅ Block {} includes the mint", block.height);
+
+    assert!(block_by_height(&harness.provider, latest + 1).await?.is_none());
+    println!("✅ No block past the chain tip");
+
+    Ok(())
+}
+
+// Test that paginated history returns every transaction of an owner
+#[tokio::test]
+async fn test_query_transactions_of_owner() -> Result<()> {
+    println!("Testing transaction history pagination...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let admin = harness.admin.address();
+
+    let mut minted = vec![];
+    for amount in [10, 20, 30] {
+        let response = harness
+            .token
+            .methods()
+            .mint(Identity::Address(admin.into()), Some(SUB_ID), amount)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        minted.push(response.tx_id.expect("mint has a transaction id"));
+    }
+
+    // A page size of 2 forces several round trips
+    let history = transactions_of(&harness.provider, &admin, 2).await?;
+    assert!(history.len() >= minted.len());
+
+    let ids: Vec<TxId> = history.iter().map(|summary| summary.tx_id).collect();
+    let positions: Vec<usize> = minted
+        .iter()
+        .map(|tx_id| ids.iter().position(|id| id == tx_id).expect("mint in history"))
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(history.iter().all(|summary| summary.outcome == TxOutcome::Success));
+    println!("✅ {} transactions of the admin, mints in order", history.len());
+
+    Ok(())
+}
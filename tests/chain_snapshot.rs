@@ -0,0 +1,147 @@
+// Chain Snapshot Tests
+// 
+// This module contains tests for dumping and relaunching a local node's state including:
+// - Wallet coins, contract storage and contract balances surviving a save and restore
+// - The restored harness accepting new transactions against the same contracts
+// - Capturing contracts and owners outside the harness suite
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{Address, Identity},
+};
+
+use rosetta_stone::{deploy_escrow, snapshot::HarnessSnapshot, Escrow, HarnessConfig, TestHarness};
+
+const DEPOSIT: u64 = 40_000;
+
+fn identity(wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Identity {
+    Identity::Address(wallet.address().into())
+}
+
+fn snapshot_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("rosetta-{}-{}.snapshot.json", name, std::process::id()))
+}
+
+// Mints to user 0 and deposits half of it into the vault
+async fn setup_phase(harness: &TestHarness) -> Result<()> {
+    harness.mint_to(harness.user(0), DEPOSIT * 2).await?;
+    harness
+        .vault_as(harness.user(0))
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    Ok(())
+}
+
+// Test that a harness restored from disk sees the same balances and storage
+#[tokio::test]
+async fn test_snapshot_round_trip() -> Result<()> {
+    println!("Testing a snapshot round trip...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    setup_phase(&harness).await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let admin_base = harness.admin.get_asset_balance(&base_asset_id).await?;
+
+    let path = snapshot_path("round-trip");
+    harness.snapshot().await?.save(&path)?;
+    let snapshot = HarnessSnapshot::load(&path)?;
+    std::fs::remove_file(&path).ok();
+    println!("✅ Snapshot with {} coins saved and loaded", snapshot.state.coins.len());
+
+    let restored = TestHarness::restore(&snapshot).await?;
+    assert_ne!(restored.provider.url(), harness.provider.url());
+    assert_eq!(restored.provider.latest_block_height().await?, 0);
+    assert_eq!(restored.token.contract_id(), harness.token.contract_id());
+    assert_eq!(restored.asset_id, harness.asset_id);
+
+    assert_eq!(restored.token_balance(restored.user(0)).await?, u128::from(DEPOSIT));
+    assert_eq!(restored.admin.get_asset_balance(&base_asset_id).await?, admin_base);
+    let deposit = restored
+        .vault
+        .methods()
+        .get_deposit(identity(restored.user(0)))
+        .call()
+        .await?
+        .value;
+    assert_eq!(deposit, DEPOSIT);
+    let supply = restored
+        .token
+        .methods()
+        .total_supply(restored.asset_id)
+        .call()
+        .await?
+        .value;
+    assert_eq!(supply, Some(DEPOSIT * 2));
+    println!("✅ Coins, vault deposit and token supply restored");
+
+    // The restored chain keeps going from the snapshot
+    restored.mint_to(restored.user(1), 1_000).await?;
+    restored
+        .vault_as(restored.user(0))
+        .methods()
+        .withdraw(DEPOSIT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(restored.token_balance(restored.user(0)).await?, u128::from(DEPOSIT * 2));
+    assert_eq!(restored.token_balance(restored.user(1)).await?, 1_000);
+    assert_eq!(harness.token_balance(harness.user(0)).await?, u128::from(DEPOSIT));
+    println!("✅ Restored node accepts new transactions; the original is untouched");
+
+    Ok(())
+}
+
+// Test capturing a contract and an owner outside the harness suite
+#[tokio::test]
+async fn test_snapshot_extra_state() -> Result<()> {
+    println!("Testing a snapshot of extra contracts and owners...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    harness.mint_to(harness.user(0), DEPOSIT).await?;
+    let escrow = deploy_escrow(harness.admin.clone()).await?;
+    let id = escrow
+        .clone()
+        .with_account(harness.user(0).clone())
+        .methods()
+        .create(identity(harness.user(1)), identity(harness.user(2)))
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?
+        .value;
+
+    // Coins of an address that is not a harness wallet
+    let outsider = Address::from([7u8; 32]);
+    harness.mint_to(&harness.admin, 5_000).await?;
+    harness
+        .admin
+        .transfer(outsider, 5_000, harness.asset_id, TxPolicies::default())
+        .await?;
+
+    let snapshot = harness.snapshot_with(&[outsider], &[escrow.contract_id()]).await?;
+    let restored = TestHarness::restore(&snapshot).await?;
+
+    let escrow = Escrow::new(escrow.contract_id(), restored.admin.clone());
+    let info = escrow.methods().escrow(id).call().await?.value.expect("escrow should exist");
+    assert_eq!(info.amount, DEPOSIT);
+    let provider = &restored.provider;
+    let escrow_balance = provider
+        .get_contract_asset_balance(&escrow.contract_id(), &harness.asset_id)
+        .await?;
+    assert_eq!(escrow_balance, DEPOSIT);
+    assert_eq!(provider.get_asset_balance(&outsider, &harness.asset_id).await?, 5_000);
+    println!("✅ Escrow {} and the outsider's coins restored", id);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Concurrent Operations Tests
+// 
+// This module contains tests for transactions submitted concurrently including:
+// - Token transfers from 10 wallets in flight at once
+// - Mints from 10 independent token admins in flight at once
+// - Final balances matching the sequential outcome
+
+use fuels::{prelude::*, types::Identity};
+use tokio::task::JoinSet;
+
+use rosetta_stone::{deploy_src20_token, HarnessConfig, TestHarness, SUB_ID};
+
+const NUM_WALLETS: usize = 10;
+const STARTING_TOKENS: u64 = 1_000;
+
+// Waits for every task, failing on the first error or panic
+async fn join_all<T: 'static>(mut tasks: JoinSet<Result<T>>) -> Result<Vec<T>> {
+    let mut results = vec![];
+    while let Some(joined) = tasks.join_next().await {
+        let result = joined.map_err(|e| Error::Other(format!("task panicked: {}", e)))?;
+        results.push(result?);
+    }
+    Ok(results)
+}
+
+// Amount user `i` sends to the next user in the ring
+fn sent_by(i: usize) -> u64 {
+    (i as u64 + 1) * 10
+}
+
+// Test that transfers from independent wallets can all be in flight at once
+#[tokio::test]
+async fn test_concurrent_transfers() -> Result<()> {
+    println!("Testing concurrent transfers...");
+
+    let harness =
+        TestHarness::new(HarnessConfig::default().with_num_users(NUM_WALLETS as u64)).await?;
+    for user in &harness.users {
+        harness.mint_to(user, STARTING_TOKENS).await?;
+    }
+
+    // Each user pays the next one; no two transactions spend the same coins
+    let mut tasks: JoinSet<Result<()>> = JoinSet::new();
+    for i in 0..NUM_WALLETS {
+        let sender = harness.user(i).clone();
+        let recipient = harness.user((i + 1) % NUM_WALLETS).address();
+        let asset_id = harness.asset_id;
+        tasks.spawn(async move {
+            sender
+                .transfer(recipient, sent_by(i), asset_id, TxPolicies::default())
+                .await?;
+            Ok(())
+        });
+    }
+    join_all(tasks).await?;
+    println!("✅ {} concurrent transfers succeeded", NUM_WALLETS);
+
+    for i in 0..NUM_WALLETS {
+        let received = sent_by((i + NUM_WALLETS - 1) % NUM_WALLETS);
+        let expected = STARTING_TOKENS - sent_by(i) + received;
+        assert_eq!(harness.token_balance(harness.user(i)).await?, expected as u128);
+    }
+    println!("✅ Every balance reflects one send and one receive");
+
+    Ok(())
+}
+
+// Test that mints from independent admins can all be in flight at once
+#[tokio::test]
+async fn test_concurrent_mints() -> Result<()> {
+    println!("Testing concurrent mints...");
+
+    let harness =
+        TestHarness::new(HarnessConfig::default().with_num_users(NUM_WALLETS as u64)).await?;
+
+    // Each wallet deploys a token it administers, then mints to itself
+    let mut tasks = JoinSet::new();
+    for (i, wallet) in harness.users.iter().cloned().enumerate() {
+        tasks.spawn(async move {
+            let amount = (i as u64 + 1) * 100;
+            let token = deploy_src20_token(wallet.clone(), "MYTOKEN", "TOKEN", 9).await?;
+            token
+                .methods()
+                .mint(Identity::Address(wallet.address().into()), Some(SUB_ID), amount)
+                .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+                .call()
+                .await?;
+            let asset_id = token.methods().get_asset_id().call().await?.value;
+            Ok::<_, Error>((wallet, asset_id, amount))
+        });
+    }
+    let minted = join_all(tasks).await?;
+    assert_eq!(minted.len(), NUM_WALLETS);
+    println!("✅ {} concurrent deploy-and-mint flows succeeded", NUM_WALLETS);
+
+    for (wallet, asset_id, amount) in &minted {
+        assert_eq!(wallet.get_asset_balance(asset_id).await?, *amount as u128);
+    }
+
+    // Every admin got its own token
+    let mut asset_ids: Vec<AssetId> = minted.iter().map(|(_, asset_id, _)| *asset_id).collect();
+    asset_ids.sort();
+    asset_ids.dedup();
+    assert_eq!(asset_ids.len(), NUM_WALLETS);
+    println!("✅ {} distinct tokens minted with correct balances", asset_ids.len());
+
+    Ok(())
+}
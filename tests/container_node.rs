@@ -0,0 +1,95 @@
+// Container Node Tests
+// 
+// This module contains tests for running the harness on fuel-core in Docker including:
+// - Booting the contract suite on a container of the selected fuel-core release
+// - Minting, transferring and producing blocks against the containerized node
+// - Custom genesis state reaching the container's snapshot
+//
+// Run with: ROSETTA_FUEL_CORE_VERSION=v0.44.0 cargo test --features docker --test container_node
+
+#![cfg(feature = "docker")]
+
+use fuel_core_chain_config::{CoinConfig, StateConfig};
+use fuels::{prelude::*, types::Address};
+
+use rosetta_stone::{
+    container::DEFAULT_FUEL_CORE_VERSION, harness::FUEL_CORE_VERSION_ENV, HarnessConfig,
+    NodeMode, TestHarness,
+};
+
+const TOKEN_AMOUNT: u64 = 10_000;
+
+// The release selected through ROSETTA_FUEL_CORE_VERSION, or the default one
+fn container_mode() -> NodeMode {
+    let version = std::env::var(FUEL_CORE_VERSION_ENV)
+        .ok()
+        .filter(|version| !version.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_FUEL_CORE_VERSION.to_string());
+    NodeMode::Container { version }
+}
+
+// Test the harness flows against a containerized node
+#[tokio::test]
+async fn test_harness_on_container() -> Result<()> {
+    let mode = container_mode();
+    println!("Testing the harness on {:?}...", mode);
+
+    let harness = TestHarness::new(HarnessConfig::default().with_node(mode)).await?;
+    let node_info = harness.provider.node_info().await?;
+    println!("✅ Suite deployed on fuel-core {}", node_info.node_version);
+
+    harness.mint_to(harness.user(0), TOKEN_AMOUNT).await?;
+    harness
+        .user(0)
+        .transfer(
+            harness.user(1).address(),
+            TOKEN_AMOUNT / 4,
+            harness.asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    assert_eq!(
+        harness.token_balance(harness.user(0)).await?,
+        u128::from(TOKEN_AMOUNT * 3 / 4)
+    );
+    assert_eq!(
+        harness.token_balance(harness.user(1)).await?,
+        u128::from(TOKEN_AMOUNT / 4)
+    );
+    println!("✅ Minted {} and transferred a quarter", TOKEN_AMOUNT);
+
+    // Debug mode is on, so blocks can be produced on demand
+    let height = harness.provider.latest_block_height().await?;
+    harness.provider.produce_blocks(3, None).await?;
+    assert_eq!(harness.provider.latest_block_height().await?, height + 3);
+    println!("✅ Produced 3 blocks from height {}", height);
+
+    Ok(())
+}
+
+// Test that extra genesis coins are written into the container's snapshot
+#[tokio::test]
+async fn test_container_genesis_state() -> Result<()> {
+    println!("Testing custom genesis state on a container...");
+
+    let outsider = Address::from([5u8; 32]);
+    let genesis_state = StateConfig {
+        coins: vec![CoinConfig {
+            owner: outsider,
+            amount: 123_456,
+            asset_id: AssetId::zeroed(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let config = HarnessConfig::default()
+        .with_node(container_mode())
+        .with_genesis_state(genesis_state);
+    let harness = TestHarness::new(config).await?;
+
+    let balance = harness.provider.get_asset_balance(&outsider, &AssetId::zeroed()).await?;
+    assert_eq!(balance, 123_456);
+    println!("✅ Genesis coin of {} present on the container", outsider);
+
+    Ok(())
+}
@@ -4,105 +4,16 @@
 // - Cross-contract calls
 // - Contract-to-contract interactions
 // - Multi-contract workflows
+// - Finding the vault a call depends on without listing it by hand
 
-use fuels::{
-    accounts::signers::private_key::PrivateKeySigner,
-    prelude::*,
-    types::{Bits256, ContractId, Identity, SizedAsciiString},
-};
-
-use fuels::accounts::wallet::Unlocked;
-
-// Load abi from json
-abigen!(
-    Contract(
-        name = "Src20Token",
-        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
-    ),
-    Contract(
-        name = "TokenVault",
-        abi = "contracts/token-vault/out/debug/token_vault-abi.json",
-    ),
-    Contract(
-        name = "CrossContractCall",
-        abi = "contracts/cross-contract-call/out/debug/cross_contract_call-abi.json",
-    ),
-);
-
-// Common test constants
-const TOKEN_AMOUNT: u64 = 1_000_000;
-const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
-const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
-
-// Deploys the SRC20 token contract with the given wallet and metadata
-async fn deploy_src20_token(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let name_bytes: SizedAsciiString<7> = name.try_into()?;
-    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
-
-    let configurables = Src20TokenConfigurables::default()
-        .with_NAME(name_bytes.clone())?
-        .with_SYMBOL(symbol_bytes.clone())?
-        .with_DECIMALS(decimals)?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/src20-token/out/debug/src20_token.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ Token '{}' ({}) deployed at: {}", name, symbol, contract_id.to_string());
-    Ok(Src20Token::new(contract_id, wallet))
-}
+use fuels::{prelude::*, types::Identity};
 
-// Deploys the CrossContractCall contract
-async fn deploy_cross_contract_call(
-    admin_wallet: Wallet<Unlocked<PrivateKeySigner>>,
-) -> Result<CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let configurables = CrossContractCallConfigurables::default()
-        .with_ADMIN(Identity::Address(admin_wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/cross-contract-call/out/debug/cross_contract_call.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&admin_wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ CrossContractCall deployed at: {}", contract_id.to_string());
-    Ok(CrossContractCall::new(contract_id, admin_wallet))
-}
-
-// Deploys the TokenVault contract
-async fn deploy_token_vault(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    cross_contract_call_contract_instance: &CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>,
-) -> Result<TokenVault<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let configurables = TokenVaultConfigurables::default()
-        .with_CROSS_CONTRACT_CALL(ContractId::from(
-            cross_contract_call_contract_instance.contract_id(),
-        ))?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/token-vault/out/debug/token_vault.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ TokenVault deployed at: {}", contract_id.to_string());
-    Ok(TokenVault::new(contract_id, wallet))
-}
+use rosetta_stone::{
+    dependencies::{dependencies_of, with_dependencies},
+    deploy_cross_contract_call, deploy_src20_token, deploy_token_vault, launch_wallets,
+    CrossContractCall, HarnessConfig, RosettaError, Src20Token, TestHarness, TokenVault, SUB_ID,
+    TOKEN_AMOUNT,
+};
 
 // Test cross-contract call functionality
 #[tokio::test]
@@ -119,8 +30,7 @@ async fn test_cross_contract_call() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallet = wallets.pop().unwrap();
@@ -208,7 +118,12 @@ async fn test_cross_contract_call() -> Result<()> {
             "❌ Admin has insufficient balance: {} < {}",
             admin_balance, deposit_amount
         );
-        return Err("Insufficient balance for deposit".into());
+        return Err(RosettaError::InsufficientBalance {
+            asset_id,
+            needed: deposit_amount as u128,
+            available: admin_balance,
+        }
+        .into());
     }
 
     let call_params = CallParameters::default()
@@ -219,17 +134,15 @@ async fn test_cross_contract_call() -> Result<()> {
     // 1. Admin wallet has the tokens
     // 2. Admin wallet is calling the CrossContractCall contract
     // 3. CrossContractCall contract will forward tokens to vault for the user (user_vault_contract)
-    match cross_contract_call_contract
+    // 4. The vault is found by estimating the call's dependencies, not listed by hand
+    let deposit_call = cross_contract_call_contract
         .methods()
         .deposit(
             user_vault_contract.contract_id(),
             user_wallet.address().into(),
         )
-        .call_params(call_params)?
-        .with_contract_ids(&[user_vault_contract.contract_id().clone()])
-        .call()
-        .await
-    {
+        .call_params(call_params)?;
+    match with_dependencies(deposit_call).await?.call().await {
         Ok(response) => {
             println!("✅ Cross-contract deposit successful");
             println!("📋 Transaction ID: {:?}", response.tx_id);
@@ -292,8 +205,7 @@ async fn test_cross_contract_call_user_sends() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallet = wallets.pop().unwrap();
@@ -348,17 +260,14 @@ async fn test_cross_contract_call_user_sends() -> Result<()> {
         .with_asset_id(asset_id);
 
     // This should fail because only admin can call the cross-contract function
-    match user_cross_contract_call
+    let deposit_call = user_cross_contract_call
         .methods()
         .deposit(
             vault_contract.contract_id(),
             user_wallet.address().into(),
         )
-        .call_params(call_params)?
-        .with_contract_ids(&[vault_contract.contract_id().clone()])
-        .call()
-        .await
-    {
+        .call_params(call_params)?;
+    match with_dependencies(deposit_call).await?.call().await {
         Ok(_) => {
             panic!("❌ This should have failed! User should not be able to call admin-only function");
         }
@@ -370,4 +279,43 @@ async fn test_cross_contract_call_user_sends() -> Result<()> {
 
     println!("✅ User authorization test passed");
     Ok(())
-}
\ No newline at end of file
+}
+
+// Test that a cross-contract call succeeds without hand-listing the vault
+#[tokio::test]
+async fn test_dependencies_are_estimated() -> Result<()> {
+    println!("Testing dependency estimation...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = Identity::Address(harness.user(0).address().into());
+    let vault_id = harness.vault.contract_id().clone();
+    harness.mint_to(&harness.admin, TOKEN_AMOUNT).await?;
+
+    let deposit_amount = 5_000;
+    let deposit = || {
+        harness
+            .cross_contract_call
+            .methods()
+            .deposit(vault_id, user)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(deposit_amount)
+                    .with_asset_id(harness.asset_id),
+            )
+    };
+
+    // Without the vault among the inputs the node cannot run the inner call
+    let result = deposit()?.call().await;
+    assert!(result.is_err(), "the vault was not an input of the transaction");
+    println!("❌ Call without its dependencies failed");
+
+    let call = with_dependencies(deposit()?).await?;
+    assert_eq!(dependencies_of(&call), &[vault_id]);
+    call.call().await?;
+
+    let credited = harness.vault.methods().get_deposit(user).call().await?.value;
+    assert_eq!(credited, deposit_amount);
+    println!("✅ Estimated the vault as the only dependency, {} deposited", credited);
+
+    Ok(())
+}
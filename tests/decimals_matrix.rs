@@ -0,0 +1,145 @@
+// Decimals Matrix Tests
+// 
+// This module contains tests for the SRC20 token deployed with different decimals including:
+// - Decimals 0, 6, 9 and 18 reported through the SRC20 metadata
+// - Minting raw amounts and reading back balances and supply
+// - Display formatting of balances and of a u64::MAX supply
+// - Supply overflowing at u64::MAX whatever the decimals
+//
+// 18 is the most decimals that still fit more than one whole token in a u64.
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    asset_resolver::{format_units, AssetResolver},
+    deploy_src20_token, launch_wallets, AssetInfo, SUB_ID,
+};
+
+const NAME: &str = "DECIMAL";
+const SYMBOL: &str = "DECML";
+
+/// One row of the matrix.
+struct DecimalsCase {
+    decimals: u8,
+    /// Raw amount minted to the user.
+    amount: u64,
+    /// `amount` as displayed with `decimals`.
+    display: &'static str,
+    /// u64::MAX as displayed with `decimals`.
+    max_display: &'static str,
+}
+
+// Deploys a token with the case's decimals and checks metadata, minting and display
+async fn run_decimals_case(case: DecimalsCase) -> Result<()> {
+    println!("Testing a token with {} decimals...", case.decimals);
+
+    let mut wallets =
+        launch_wallets(WalletsConfig::new(Some(2), Some(1), Some(1_000_000_000))).await?;
+    let admin = wallets.pop().unwrap();
+    let user = wallets.pop().unwrap();
+
+    let token = deploy_src20_token(admin.clone(), NAME, SYMBOL, case.decimals).await?;
+    let asset_id = token.methods().get_asset_id().call().await?.value;
+
+    // Metadata
+    let decimals = token.methods().decimals(asset_id).call().await?.value;
+    let name = token.methods().name(asset_id).call().await?.value;
+    let symbol = token.methods().symbol(asset_id).call().await?.value;
+    assert_eq!(decimals, Some(case.decimals));
+    assert_eq!(name.as_deref(), Some(NAME));
+    assert_eq!(symbol.as_deref(), Some(SYMBOL));
+
+    // Minting works in raw units; decimals only change how they are read
+    token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), case.amount)
+        .call()
+        .await?;
+    let balance = user.get_asset_balance(&asset_id).await?;
+    assert_eq!(balance, u128::from(case.amount));
+    assert_eq!(token.methods().total_supply(asset_id).call().await?.value, Some(case.amount));
+
+    // Display through the resolver, as a registry entry for this token would
+    let mut resolver = AssetResolver::offline().with_known(
+        asset_id,
+        AssetInfo {
+            name: NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+            decimals: case.decimals,
+            logo_uri: String::new(),
+        },
+    );
+    let formatted = resolver.format_amount(asset_id, balance).await?;
+    assert_eq!(formatted, format!("{} {}", case.display, SYMBOL));
+    println!("   Minted {} raw units, shown as {}", case.amount, formatted);
+
+    // Fill the supply up to u64::MAX; one more unit must not mint
+    token
+        .methods()
+        .mint(Identity::Address(admin.address().into()), Some(SUB_ID), u64::MAX - case.amount)
+        .call()
+        .await?;
+    let supply = token.methods().total_supply(asset_id).call().await?.value;
+    assert_eq!(supply, Some(u64::MAX));
+    assert_eq!(format_units(u128::from(u64::MAX), case.decimals), case.max_display);
+
+    let overflow = token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 1)
+        .call()
+        .await;
+    assert!(overflow.is_err(), "minting past u64::MAX must revert");
+    assert_eq!(user.get_asset_balance(&asset_id).await?, balance);
+    println!("❌ Supply capped at {} {}", case.max_display, SYMBOL);
+
+    println!("✅ {} decimals passed", case.decimals);
+    Ok(())
+}
+
+// Test a token without fractional units
+#[tokio::test]
+async fn test_zero_decimals() -> Result<()> {
+    run_decimals_case(DecimalsCase {
+        decimals: 0,
+        amount: 1_234,
+        display: "1234",
+        max_display: "18446744073709551615",
+    })
+    .await
+}
+
+// Test the decimals of most bridged stablecoins
+#[tokio::test]
+async fn test_six_decimals() -> Result<()> {
+    run_decimals_case(DecimalsCase {
+        decimals: 6,
+        amount: 1_500_000,
+        display: "1.5",
+        max_display: "18446744073709.551615",
+    })
+    .await
+}
+
+// Test the decimals of the base asset
+#[tokio::test]
+async fn test_nine_decimals() -> Result<()> {
+    run_decimals_case(DecimalsCase {
+        decimals: 9,
+        amount: 1_000_000_001,
+        display: "1.000000001",
+        max_display: "18446744073.709551615",
+    })
+    .await
+}
+
+// Test the EVM default, where a u64 holds fewer than 19 whole tokens
+#[tokio::test]
+async fn test_eighteen_decimals() -> Result<()> {
+    run_decimals_case(DecimalsCase {
+        decimals: 18,
+        amount: 2_500_000_000_000_000_000,
+        display: "2.5",
+        max_display: "18.446744073709551615",
+    })
+    .await
+}
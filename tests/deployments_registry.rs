@@ -0,0 +1,63 @@
+// Deployments Registry Tests
+// 
+// This module contains tests for the deployments registry including:
+// - Persisting contract ids, asset ids and configurables
+// - Loading a registry file and reusing the deployment
+// - Per-network file paths
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    deployments::{deploy_suite, Deployments},
+    launch_wallets, SUB_ID, TOKEN_AMOUNT,
+};
+
+// Test that a saved deployment can be loaded and used without redeploying
+#[tokio::test]
+async fn test_save_load_and_reuse_deployment() -> Result<()> {
+    println!("Testing deployments registry...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(2), Some(1), Some(1_000_000_000))).await?;
+    let admin_wallet = wallets[0].clone();
+    let user_wallet = wallets[1].clone();
+
+    let deployments = deploy_suite(&admin_wallet, "test", "REGTOKN", "REGTK", 6).await?;
+    assert_eq!(deployments.configurables.token_decimals, 6);
+    assert_eq!(deployments.configurables.admin, admin_wallet.address().to_string());
+
+    let path = std::env::temp_dir().join(format!("rosetta-deployments-{}.json", std::process::id()));
+    deployments.save(&path)?;
+
+    // Load as a follow-up run would and bind the contracts to the admin wallet
+    let loaded = Deployments::load(&path)?;
+    assert_eq!(loaded, deployments);
+
+    let suite = loaded.connect(&admin_wallet)?;
+    suite
+        .token
+        .methods()
+        .mint(Identity::Address(user_wallet.address().into()), Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let user_balance = user_wallet.get_asset_balance(&suite.asset_id).await?;
+    assert_eq!(user_balance, TOKEN_AMOUNT as u128);
+
+    let vault_total = suite.vault.methods().get_total_deposits(suite.asset_id).call().await?.value;
+    assert_eq!(vault_total, 0);
+
+    std::fs::remove_file(&path)?;
+
+    println!("✅ Deployments registry test passed");
+    Ok(())
+}
+
+// Test the per-network registry path
+#[test]
+fn test_registry_path_per_network() {
+    assert_eq!(
+        Deployments::path_for("testnet"),
+        std::path::PathBuf::from("deployments/testnet.json")
+    );
+}
@@ -0,0 +1,133 @@
+// Error Tests
+// 
+// This module contains tests for the typed RosettaError including:
+// - Deploy failures reported as DeployFailed with the contract name
+// - Reverts pulled out of SDK errors with their reason
+// - Converting back into the SDK error without losing the message
+// - Failed calls carrying their contract id, method, tx id and block height
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{
+    deploy_src20_token,
+    error::{call_with_context, ResultExt, SdkError},
+    ErrorContext, HarnessConfig, RosettaError, TestHarness, SUB_ID,
+};
+
+// Test that deploying from an unfunded wallet is a DeployFailed
+#[tokio::test]
+async fn test_deploy_failed() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let unfunded = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        harness.provider.clone(),
+    );
+
+    let error = deploy_src20_token(unfunded, "MYTOKEN", "TOKEN", 9)
+        .await
+        .err()
+        .expect("unfunded deploy must fail");
+    match &error {
+        RosettaError::DeployFailed { contract, .. } => assert_eq!(*contract, "Src20Token"),
+        other => panic!("expected DeployFailed, got {:?}", other),
+    }
+    assert!(error.to_string().starts_with("deploying Src20Token failed"));
+    println!("❌ {}", error);
+
+    Ok(())
+}
+
+// Test that a revert becomes a RevertDecoded carrying the require message
+#[tokio::test]
+async fn test_revert_decoded() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    let sdk_error = harness
+        .token_as(user)
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 100)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .err()
+        .expect("only the admin can mint");
+
+    let error = RosettaError::from(sdk_error);
+    assert!(matches!(error, RosettaError::RevertDecoded { .. }));
+    assert!(error.revert_reason().unwrap().contains("Unauthorized: Only admin can mint"));
+    println!("❌ {}", error);
+
+    // Converting back keeps the reason in the message
+    let sdk_error = SdkError::from(error);
+    assert!(sdk_error.to_string().contains("Unauthorized: Only admin can mint"));
+
+    Ok(())
+}
+
+// Test that a failed call carries where it happened
+#[tokio::test]
+async fn test_error_context() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    let call = harness
+        .token_as(user)
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 100)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+    let error = call_with_context(call).await.err().expect("only the admin can mint");
+
+    let context = error.context().expect("call errors carry a context");
+    assert_eq!(context.contract_id, Some(harness.token.contract_id()));
+    assert_eq!(context.method.as_deref(), Some("mint"));
+    let tx_id = context.tx_id.expect("the call was submitted");
+    let block_height = context.block_height.expect("the revert was committed");
+    assert!(block_height <= harness.provider.latest_block_height().await?);
+    println!("❌ {}", error);
+
+    // The revert is still matched through the context
+    assert!(matches!(error.kind(), RosettaError::RevertDecoded { .. }));
+    assert!(error.revert_reason().unwrap().contains("Unauthorized: Only admin can mint"));
+    assert!(error.to_string().contains(&format!("tx {}", tx_id)));
+
+    // A context attached later only fills what is missing
+    let error = Err::<(), _>(error)
+        .context(&ErrorContext::default().with_block_height(0))
+        .unwrap_err();
+    assert_eq!(error.context().unwrap().block_height, Some(block_height));
+
+    // Successful calls report their tx id
+    let response = call_with_context(
+        harness
+            .token
+            .methods()
+            .mint(Identity::Address(user.address().into()), Some(SUB_ID), 100)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1)),
+    )
+    .await?;
+    assert!(response.tx_id.is_some());
+    println!("✅ Successful call submitted as {:?}", response.tx_id);
+
+    Ok(())
+}
+
+// Test the messages of the variants built by callers
+#[test]
+fn test_error_messages() {
+    let error = RosettaError::InsufficientBalance {
+        asset_id: AssetId::zeroed(),
+        needed: 100,
+        available: 40,
+    };
+    assert!(error.to_string().ends_with("need 100, have 40"));
+    assert!(error.revert_reason().is_none());
+
+    let other = RosettaError::from(SdkError::Other("boom".to_string()));
+    assert!(matches!(other, RosettaError::Sdk(_)));
+    assert_eq!(other.to_string(), SdkError::Other("boom".to_string()).to_string());
+}
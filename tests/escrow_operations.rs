@@ -0,0 +1,235 @@
+// Escrow Operations Tests
+// 
+// This module contains tests for the escrow contract including:
+// - Buyer releasing funds to the seller
+// - Seller refunding the buyer
+// - Disputes resolved by the arbiter in either direction
+// - Reverts when the wrong party tries to settle
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{deploy_escrow, Escrow, EscrowState, HarnessConfig, TestHarness};
+
+const AMOUNT: u64 = 10_000;
+
+struct Parties {
+    harness: TestHarness,
+    escrow: Escrow<Wallet<Unlocked<PrivateKeySigner>>>,
+}
+
+impl Parties {
+    // Users 0, 1 and 2 act as buyer, seller and arbiter
+    async fn new() -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+        harness.mint_to(harness.user(0), AMOUNT * 2).await?;
+        let escrow = deploy_escrow(harness.admin.clone()).await?;
+        Ok(Self { harness, escrow })
+    }
+
+    fn buyer(&self) -> &Wallet<Unlocked<PrivateKeySigner>> {
+        self.harness.user(0)
+    }
+
+    fn seller(&self) -> &Wallet<Unlocked<PrivateKeySigner>> {
+        self.harness.user(1)
+    }
+
+    fn arbiter(&self) -> &Wallet<Unlocked<PrivateKeySigner>> {
+        self.harness.user(2)
+    }
+
+    fn escrow_as(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    ) -> Escrow<Wallet<Unlocked<PrivateKeySigner>>> {
+        self.escrow.clone().with_account(wallet.clone())
+    }
+
+    async fn create(&self) -> Result<u64> {
+        Ok(self
+            .escrow_as(self.buyer())
+            .methods()
+            .create(
+                Identity::Address(self.seller().address().into()),
+                Identity::Address(self.arbiter().address().into()),
+            )
+            .call_params(
+                CallParameters::default()
+                    .with_amount(AMOUNT)
+                    .with_asset_id(self.harness.asset_id),
+            )?
+            .call()
+            .await?
+            .value)
+    }
+
+    async fn state(&self, id: u64) -> Result<EscrowState> {
+        let escrow = self.escrow.methods().escrow(id).call().await?.value;
+        Ok(escrow.expect("escrow should exist").state)
+    }
+}
+
+// Test the happy path: the buyer releases to the seller
+#[tokio::test]
+async fn test_escrow_release() -> Result<()> {
+    println!("Testing escrow release...");
+
+    let parties = Parties::new().await?;
+    let id = parties.create().await?;
+    assert_eq!(parties.state(id).await?, EscrowState::Pending);
+    assert_eq!(parties.harness.token_balance(parties.buyer()).await?, AMOUNT as u128);
+    println!("✅ Escrow {} created", id);
+
+    for wallet in [parties.seller(), parties.arbiter()] {
+        let result = parties
+            .escrow_as(wallet)
+            .methods()
+            .release(id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await;
+        assert!(result.is_err());
+        assert!(format!("{:?}", result.unwrap_err()).contains("Only buyer can release"));
+    }
+    assert_eq!(parties.harness.token_balance(parties.seller()).await?, 0);
+    println!("✅ Seller and arbiter cannot release");
+
+    parties
+        .escrow_as(parties.buyer())
+        .methods()
+        .release(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(parties.state(id).await?, EscrowState::Released);
+    assert_eq!(parties.harness.token_balance(parties.seller()).await?, AMOUNT as u128);
+    println!("✅ Buyer released funds to the seller");
+
+    let result = parties
+        .escrow_as(parties.buyer())
+        .methods()
+        .release(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Escrow is not pending"));
+    println!("✅ Released escrow cannot be released twice");
+
+    Ok(())
+}
+
+// Test the seller refunding the buyer
+#[tokio::test]
+async fn test_escrow_refund() -> Result<()> {
+    println!("Testing escrow refund...");
+
+    let parties = Parties::new().await?;
+    let id = parties.create().await?;
+
+    let result = parties
+        .escrow_as(parties.buyer())
+        .methods()
+        .refund(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Only seller can refund"));
+    println!("✅ Buyer cannot refund themselves");
+
+    parties
+        .escrow_as(parties.seller())
+        .methods()
+        .refund(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(parties.state(id).await?, EscrowState::Refunded);
+    assert_eq!(
+        parties.harness.token_balance(parties.buyer()).await?,
+        (AMOUNT * 2) as u128
+    );
+    println!("✅ Seller refunded the buyer");
+
+    Ok(())
+}
+
+// Test disputes settled by the arbiter
+#[tokio::test]
+async fn test_escrow_dispute_resolution() -> Result<()> {
+    println!("Testing escrow dispute resolution...");
+
+    let parties = Parties::new().await?;
+    let refunded = parties.create().await?;
+    let paid = parties.create().await?;
+    assert_eq!(parties.escrow.methods().escrow_count().call().await?.value, 2);
+
+    let result = parties
+        .escrow_as(parties.arbiter())
+        .methods()
+        .dispute(refunded)
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Only buyer or seller can dispute"));
+
+    parties
+        .escrow_as(parties.buyer())
+        .methods()
+        .dispute(refunded)
+        .call()
+        .await?;
+    parties
+        .escrow_as(parties.seller())
+        .methods()
+        .dispute(paid)
+        .call()
+        .await?;
+    assert_eq!(parties.state(refunded).await?, EscrowState::Disputed);
+    println!("✅ Buyer and seller opened disputes");
+
+    // A disputed escrow can only be settled by the arbiter
+    let result = parties
+        .escrow_as(parties.buyer())
+        .methods()
+        .release(paid)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Escrow is not pending"));
+
+    let result = parties
+        .escrow_as(parties.seller())
+        .methods()
+        .resolve(paid, true)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Only arbiter can resolve"));
+    println!("✅ Parties cannot settle a disputed escrow themselves");
+
+    let arbiter = parties.escrow_as(parties.arbiter());
+    arbiter
+        .methods()
+        .resolve(refunded, false)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    arbiter
+        .methods()
+        .resolve(paid, true)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    assert_eq!(parties.state(refunded).await?, EscrowState::Refunded);
+    assert_eq!(parties.state(paid).await?, EscrowState::Released);
+    assert_eq!(parties.harness.token_balance(parties.buyer()).await?, AMOUNT as u128);
+    assert_eq!(parties.harness.token_balance(parties.seller()).await?, AMOUNT as u128);
+    assert_eq!(parties.harness.token_balance(parties.arbiter()).await?, 0);
+    println!("✅ Arbiter refunded one escrow and paid out the other");
+
+    Ok(())
+}
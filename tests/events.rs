@@ -0,0 +1,141 @@
+// Event Decoding Tests
+// 
+// This module contains tests for the typed event helpers including:
+// - Decoding Mint and Burn events from token calls
+// - Decoding Deposit and Withdraw events from vault calls
+// - Rejecting calls that logged an unexpected number of events
+// - Decoding mixed events from receipts in emission order
+// - EventDecoder agreeing with decode_all and skipping unwatched contracts
+
+use fuels::{prelude::*, programs::calls::CallHandler, types::Identity};
+
+use rosetta_stone::{
+    decode_events_of, expect_single_event, BurnEvent, ContractEvent, DepositEvent, EventDecoder,
+    HarnessConfig, MintEvent, TestHarness, WithdrawEvent, SUB_ID,
+};
+
+// Test typed decoding of token and vault events
+#[tokio::test]
+async fn test_decode_typed_events() -> Result<()> {
+    println!("Testing typed event decoding...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+
+    let response = harness
+        .token
+        .methods()
+        .mint(user_identity, Some(SUB_ID), 1_000)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let mint: MintEvent = expect_single_event(&response)?;
+    assert_eq!(mint.recipient, user_identity);
+    assert_eq!(mint.amount, 1_000);
+    assert_eq!(mint.asset_id, harness.asset_id);
+    assert!(decode_events_of::<BurnEvent, _>(&response)?.is_empty());
+    println!("✅ MintEvent decoded: {:?}", mint);
+
+    let response = harness
+        .token_as(user)
+        .methods()
+        .burn(SUB_ID, 100)
+        .call_params(
+            CallParameters::default()
+                .with_amount(100)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    let burn: BurnEvent = expect_single_event(&response)?;
+    assert_eq!(burn.amount, 100);
+    println!("✅ BurnEvent decoded: {:?}", burn);
+
+    let response = harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(600)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    let deposit: DepositEvent = expect_single_event(&response)?;
+    assert_eq!(deposit.user, user_identity);
+    assert_eq!(deposit.amount, 600);
+
+    let response = harness
+        .vault_as(user)
+        .methods()
+        .withdraw(250)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let withdraw: WithdrawEvent = expect_single_event(&response)?;
+    assert_eq!(withdraw.user, user_identity);
+    assert_eq!(withdraw.amount, 250);
+    println!("✅ DepositEvent and WithdrawEvent decoded");
+
+    let result = expect_single_event::<DepositEvent, _>(&response);
+    assert!(format!("{:?}", result.unwrap_err()).contains("found 0"));
+    println!("❌ Missing event reported");
+
+    Ok(())
+}
+
+// Test decoding a mix of token and vault events from the receipts of a multi-call
+#[tokio::test]
+async fn test_decode_all_in_order() -> Result<()> {
+    println!("Testing ordered decoding of mixed events...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    harness.mint_to(user, 1_000).await?;
+
+    // A burn followed by a deposit in one transaction
+    let forward = |amount| {
+        CallParameters::default()
+            .with_amount(amount)
+            .with_asset_id(harness.asset_id)
+    };
+    let burn = harness
+        .token_as(user)
+        .methods()
+        .burn(SUB_ID, 100)
+        .call_params(forward(100))?;
+    let deposit = harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(forward(300))?;
+    let response = CallHandler::new_multi_call(user.clone())
+        .add_call(burn)
+        .add_call(deposit)
+        .call::<((), ())>()
+        .await?;
+
+    // The decoder has to know the logs of both contracts
+    let mut decoder = harness.token.log_decoder();
+    decoder.merge(harness.vault.log_decoder());
+    let events = ContractEvent::decode_all(&decoder, &response.tx_status.receipts)?;
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], ContractEvent::Burn(event) if event.amount == 100));
+    assert!(matches!(&events[1], ContractEvent::Deposit(event) if event.amount == 300));
+    assert!(events.iter().all(|event| event.asset_id() == harness.asset_id));
+    println!("✅ Burn and deposit decoded in emission order");
+
+    // Only the vault is watched: the burn is skipped, also once its log id is known
+    let mut event_decoder = EventDecoder::new(decoder, vec![harness.vault.contract_id()]);
+    for _ in 0..2 {
+        let watched = event_decoder.decode(&response.tx_status.receipts)?;
+        assert_eq!(watched, vec![(harness.vault.contract_id(), events[1].clone())]);
+    }
+    println!("✅ EventDecoder kept the deposit of the watched vault only");
+
+    Ok(())
+}
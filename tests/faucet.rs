@@ -0,0 +1,162 @@
+// Faucet Tests
+// 
+// This module contains tests for funding wallets from a faucet including:
+// - Requesting funds and polling until the balance arrives
+// - Surfacing a faucet's refusal as an error
+// - Giving up when the funds never arrive
+//
+// The faucet is a minimal local HTTP server that pays out from a funded wallet
+// on a local node, so no network access is needed.
+
+use std::{str::FromStr, time::Duration};
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Address,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use rosetta_stone::{
+    faucet::{fund_from_faucet, wait_for_balance, DispenseRequest, FaucetConfig},
+    launch_wallets, RosettaError,
+};
+
+const DRIP: u64 = 500_000;
+
+// Reads one HTTP request and returns its body
+async fn read_body(stream: &mut TcpStream) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    loop {
+        let read = stream.read(&mut chunk).await.expect("request should be readable");
+        if read == 0 {
+            return Vec::new();
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        let Some(header_end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else {
+            continue;
+        };
+        let headers = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+        let length: usize = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map(|value| value.trim().parse().expect("content-length should be a number"))
+            .unwrap_or(0);
+        let body_start = header_end + 4;
+        if buffer.len() >= body_start + length {
+            return buffer[body_start..].to_vec();
+        }
+    }
+}
+
+// Serves dispense requests by transferring `DRIP` base asset from `funder`
+async fn spawn_faucet(funder: Wallet<Unlocked<PrivateKeySigner>>) -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let body = read_body(&mut stream).await;
+            let request: DispenseRequest =
+                serde_json::from_slice(&body).expect("body should be a dispense request");
+            let address = Address::from_str(&request.address).expect("address should be valid");
+            funder
+                .transfer(address, DRIP, AssetId::zeroed(), TxPolicies::default())
+                .await
+                .expect("faucet transfer should succeed");
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n{}")
+                .await;
+        }
+    });
+    Ok(url)
+}
+
+// Refuses every dispense request, like a faucet asking for a captcha
+async fn spawn_refusing_faucet() -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            read_body(&mut stream).await;
+            let body = r#"{"error":"captcha required"}"#;
+            let response = format!(
+                "HTTP/1.1 429 Too Many Requests\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+    Ok(url)
+}
+
+// A funded faucet wallet and an empty one on the same node
+async fn funder_and_fresh_wallet() -> Result<(
+    Wallet<Unlocked<PrivateKeySigner>>,
+    Wallet<Unlocked<PrivateKeySigner>>,
+)> {
+    let config = WalletsConfig::new(Some(1), Some(2), Some(1_000_000_000));
+    let mut wallets = launch_wallets(config).await?;
+    let funder = wallets.pop().expect("one wallet was launched");
+    let fresh = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        funder.try_provider()?.clone(),
+    );
+    Ok((funder, fresh))
+}
+
+fn fast_polling(url: String) -> FaucetConfig {
+    FaucetConfig::default()
+        .with_url(url)
+        .with_polling(Duration::from_millis(50), Duration::from_secs(10))
+}
+
+// Test that a fresh wallet receives the faucet's funds
+#[tokio::test]
+async fn test_fund_fresh_wallet_from_faucet() -> Result<()> {
+    let (funder, fresh) = funder_and_fresh_wallet().await?;
+    let url = spawn_faucet(funder).await?;
+
+    let balance = fund_from_faucet(&fresh, &fast_polling(url)).await?;
+
+    assert_eq!(balance, DRIP as u128);
+    assert_eq!(fresh.get_asset_balance(&AssetId::zeroed()).await?, DRIP as u128);
+
+    Ok(())
+}
+
+// Test that a refusing faucet fails the request instead of waiting
+#[tokio::test]
+async fn test_faucet_refusal_is_an_error() -> Result<()> {
+    let (_, fresh) = funder_and_fresh_wallet().await?;
+    let url = spawn_refusing_faucet().await?;
+
+    let error = fund_from_faucet(&fresh, &fast_polling(url))
+        .await
+        .expect_err("a refused request should fail");
+    assert!(error.to_string().contains("captcha required"));
+
+    Ok(())
+}
+
+// Test that waiting for funds that never arrive times out
+#[tokio::test]
+async fn test_wait_for_balance_times_out() -> Result<()> {
+    let (_, fresh) = funder_and_fresh_wallet().await?;
+
+    let error =
+        wait_for_balance(&fresh, 1, Duration::from_millis(10), Duration::from_millis(100))
+            .await
+            .expect_err("nobody funds the wallet");
+    assert!(matches!(
+        error.kind(),
+        RosettaError::InsufficientBalance { needed: 1, available: 0, .. }
+    ));
+    assert!(error.context().and_then(|context| context.block_height).is_some());
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Fee Assertion Tests
+// 
+// This module contains tests for the exact fee helpers including:
+// - Reading the fee of a transfer and a contract call
+// - Looking up a fee by transaction id
+// - Exact base asset accounting with the reported fee
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    balances::BalanceSnapshot,
+    fees::{assert_fee_paid, fee_of, FeePaid},
+    HarnessConfig, TestHarness, SUB_ID, TOKEN_AMOUNT,
+};
+
+// Test that the sender's base asset balance drops by exactly amount + fee
+#[tokio::test]
+async fn test_transfer_fee_is_exact() -> Result<()> {
+    println!("Testing exact transfer fee...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let sender = harness.user(0);
+    let receiver = harness.user(1);
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+
+    let snapshot = BalanceSnapshot::take(
+        &harness.provider,
+        &[(sender.address(), base_asset_id), (receiver.address(), base_asset_id)],
+    )
+    .await?;
+
+    let amount = 10_000;
+    let response = sender
+        .transfer(receiver.address(), amount, base_asset_id, TxPolicies::default())
+        .await?;
+    assert_fee_paid(&response, 1..amount);
+
+    let fee = fee_of(&harness.provider, &response.tx_id).await?;
+    assert_eq!(fee, response.fee_paid());
+    println!("   Transfer fee: {}", fee);
+
+    snapshot.assert_deltas(&[-i128::from(amount + fee), i128::from(amount)]).await?;
+
+    println!("✅ Exact transfer fee test passed");
+    Ok(())
+}
+
+// Test that a contract call reports the fee charged to the caller
+#[tokio::test]
+async fn test_call_fee_is_exact() -> Result<()> {
+    println!("Testing exact call fee...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let snapshot =
+        BalanceSnapshot::take(&harness.provider, &[(harness.admin.address(), base_asset_id)])
+            .await?;
+
+    let response = harness
+        .token
+        .methods()
+        .mint(
+            Identity::Address(harness.user(0).address().into()),
+            Some(SUB_ID),
+            TOKEN_AMOUNT,
+        )
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_fee_paid(&response, 1..);
+
+    snapshot.assert_deltas(&[-i128::from(response.fee_paid())]).await?;
+
+    println!("✅ Exact call fee test passed");
+    Ok(())
+}
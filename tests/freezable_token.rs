@@ -0,0 +1,71 @@
+// Freezable Token Tests
+// 
+// This module contains tests for freezing identities on the SRC20 token including:
+// - Frozen identities not receiving mints
+// - The revert reason naming the freeze
+// - Only the admin being able to freeze and unfreeze
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{HarnessConfig, TestHarness, SUB_ID, TOKEN_AMOUNT};
+
+// Test that a frozen identity cannot receive mints until unfrozen
+#[tokio::test]
+async fn test_frozen_identity_cannot_receive_mints() -> Result<()> {
+    println!("Testing frozen mint recipient...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let frozen_user = harness.user(0);
+    let other_user = harness.user(1);
+    let frozen_identity = Identity::Address(frozen_user.address().into());
+
+    harness.token.methods().freeze(frozen_identity).call().await?;
+    assert!(harness.token.methods().is_frozen(frozen_identity).call().await?.value);
+
+    println!("❌ Attempting to mint to a frozen identity (should fail)...");
+    let result = harness
+        .token
+        .methods()
+        .mint(frozen_identity, Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    let err = result.expect_err("mint to a frozen identity should revert");
+    println!("   Revert: {}", err);
+    assert!(err.to_string().contains("Frozen: recipient is frozen"));
+    assert_eq!(harness.token_balance(frozen_user).await?, 0);
+
+    // Other identities are unaffected
+    harness.mint_to(other_user, TOKEN_AMOUNT).await?;
+    assert_eq!(harness.token_balance(other_user).await?, TOKEN_AMOUNT as u128);
+
+    // Unfreezing restores minting
+    harness.token.methods().unfreeze(frozen_identity).call().await?;
+    harness.mint_to(frozen_user, TOKEN_AMOUNT).await?;
+    assert_eq!(harness.token_balance(frozen_user).await?, TOKEN_AMOUNT as u128);
+
+    println!("✅ Frozen mint recipient test passed");
+    Ok(())
+}
+
+// Test that only the admin can freeze identities
+#[tokio::test]
+async fn test_non_admin_cannot_freeze() -> Result<()> {
+    println!("Testing non-admin freeze...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let target = Identity::Address(harness.user(1).address().into());
+
+    let result = harness
+        .token_as(harness.user(0))
+        .methods()
+        .freeze(target)
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Unauthorized"));
+    assert!(!harness.token.methods().is_frozen(target).call().await?.value);
+
+    println!("✅ Non-admin freeze correctly rejected");
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// Genesis Config Tests
+// 
+// This module contains tests for harnesses launched with custom genesis conditions including:
+// - A chain with a non-default base asset paying every fee
+// - Extra genesis coins and messages next to the wallet funding
+// - Rejecting a custom genesis on a non-local node
+
+use fuel_core_chain_config::{ChainConfig, CoinConfig, MessageConfig, StateConfig};
+use fuels::{
+    prelude::*,
+    types::{Address, Nonce},
+};
+
+use rosetta_stone::{HarnessConfig, NodeMode, TestHarness, TOKEN_AMOUNT};
+
+const CUSTOM_BASE_ASSET: AssetId = AssetId::new([7; 32]);
+
+// Test that the harness runs on a chain with a custom base asset
+#[tokio::test]
+async fn test_custom_base_asset() -> Result<()> {
+    println!("Testing custom base asset...");
+
+    let mut chain_config = ChainConfig::local_testnet();
+    chain_config.consensus_parameters.set_base_asset_id(CUSTOM_BASE_ASSET);
+
+    let harness = TestHarness::new(
+        HarnessConfig::default()
+            .with_node(NodeMode::Local)
+            .with_chain_config(chain_config),
+    )
+    .await?;
+
+    let base_asset = *harness.provider.consensus_parameters().await?.base_asset_id();
+    assert_eq!(base_asset, CUSTOM_BASE_ASSET);
+
+    // Wallets are funded in the custom base asset, and the suite deployed with it
+    let config = HarnessConfig::default();
+    let funding = (config.coins_per_wallet * config.amount_per_coin) as u128;
+    assert_eq!(harness.user(0).get_asset_balance(&CUSTOM_BASE_ASSET).await?, funding);
+    assert_eq!(harness.user(0).get_asset_balance(&AssetId::zeroed()).await?, 0);
+    println!("✅ Wallets funded in the custom base asset");
+
+    harness.mint_to(harness.user(0), TOKEN_AMOUNT).await?;
+    assert_eq!(harness.token_balance(harness.user(0)).await?, TOKEN_AMOUNT as u128);
+    println!("✅ Contracts deployed and called on the custom chain");
+
+    Ok(())
+}
+
+// Test that extra genesis coins and messages exist from block zero
+#[tokio::test]
+async fn test_extra_genesis_state() -> Result<()> {
+    println!("Testing extra genesis state...");
+
+    let holder = Address::new([42; 32]);
+    let other_asset = AssetId::new([9; 32]);
+    let genesis_state = StateConfig {
+        coins: vec![CoinConfig {
+            tx_id: [1; 32].into(),
+            owner: holder,
+            amount: 5_000,
+            asset_id: other_asset,
+            ..Default::default()
+        }],
+        messages: vec![MessageConfig {
+            sender: Address::new([3; 32]),
+            recipient: holder,
+            nonce: Nonce::new([4; 32]),
+            amount: 250,
+            data: vec![],
+            da_height: Default::default(),
+        }],
+        ..Default::default()
+    };
+
+    let harness = TestHarness::new(
+        HarnessConfig::default()
+            .with_node(NodeMode::Local)
+            .with_genesis_state(genesis_state),
+    )
+    .await?;
+
+    assert_eq!(harness.provider.get_asset_balance(&holder, &other_asset).await?, 5_000);
+    let messages = harness.provider.get_messages(&holder).await?;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].amount, 250);
+    println!("✅ Genesis coin and message owned by {}", holder);
+
+    // The wallets are still funded as usual
+    assert!(harness.admin.get_asset_balance(&AssetId::zeroed()).await? > 0);
+
+    Ok(())
+}
+
+// Test that a custom genesis is refused when the node is not launched locally
+#[tokio::test]
+async fn test_custom_genesis_needs_local_node() -> Result<()> {
+    let config = HarnessConfig::default()
+        .with_node(NodeMode::Shared)
+        .with_genesis_state(StateConfig::default());
+
+    let result = TestHarness::new(config).await;
+    assert!(format!("{:?}", result.err().unwrap()).contains("needs a local node"));
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+// Golden Transaction Snapshot Tests
+// 
+// This module builds representative transactions from fixed keys, fixed coins
+// and fixed salts and compares their serialized bytes against the golden files
+// in `tests/golden/`. Any SDK upgrade that silently changes the encoding shows
+// up as a byte-level diff here.
+//
+// The file uses a custom harness so it can take a `--bless` flag:
+//
+//     cargo test --test golden_transactions -- --bless
+//
+// regenerates the golden files when an encoding change is intentional. A
+// snapshot with no golden file fails until it is blessed and committed.
+
+use std::path::PathBuf;
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    crypto::SecretKey,
+    prelude::*,
+    tx::{Transaction as FuelTransaction, UtxoId},
+    types::{
+        canonical::Serialize, coin::Coin, transaction_builders::ScriptTransactionBuilder,
+        Bytes32, Identity, SizedAsciiString,
+    },
+};
+
+use fuels::accounts::wallet::Unlocked;
+
+use rosetta_stone::{
+    artifacts, MultiAssetTransfer, MultiAssetTransferConfigurables,
+    MultiSigPredicateConfigurables, Src20Token, Src20TokenConfigurables, SUB_ID,
+};
+
+const GOLDEN_DIR: &str = "tests/golden";
+
+// Fixed policies so gas estimation never leaks into the encoded bytes
+fn fixed_policies() -> TxPolicies {
+    TxPolicies::default()
+        .with_script_gas_limit(1_000_000)
+        .with_max_fee(100_000)
+}
+
+// Builds a signer from a repeated byte so every run uses the same key
+fn fixed_signer(seed: u8) -> PrivateKeySigner {
+    let secret_key = SecretKey::try_from([seed; 32].as_slice()).expect("valid secret key");
+    PrivateKeySigner::new(secret_key)
+}
+
+// Builds coins with fixed UTXO ids (the SDK helpers randomize them)
+fn fixed_coins(owner: Address, seed: u8, num_coins: u8, amount: u64) -> Vec<Coin> {
+    (0..num_coins)
+        .map(|i| Coin {
+            owner,
+            utxo_id: UtxoId::new(Bytes32::from([seed.wrapping_add(i); 32]), 0),
+            amount,
+            asset_id: AssetId::zeroed(),
+        })
+        .collect()
+}
+
+// Launches a node whose genesis only contains the fixed coins of the given signers
+async fn launch_fixed_node(seeds: &[u8]) -> Result<Vec<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let signers: Vec<_> = seeds.iter().map(|seed| fixed_signer(*seed)).collect();
+
+    let coins = signers
+        .iter()
+        .zip(seeds)
+        .flat_map(|(signer, seed)| fixed_coins(signer.address(), seed * 16, 2, 1_000_000_000))
+        .collect();
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    Ok(signers
+        .into_iter()
+        .map(|signer| Wallet::new(signer, provider.clone()))
+        .collect())
+}
+
+async fn deploy_fixed_token(
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let name: SizedAsciiString<7> = "GOLDTOK".try_into()?;
+    let symbol: SizedAsciiString<5> = "GOLDT".try_into()?;
+
+    let configurables = Src20TokenConfigurables::default()
+        .with_NAME(name)?
+        .with_SYMBOL(symbol)?
+        .with_DECIMALS(9)?
+        .with_ADMIN(Identity::Address(wallet.address().into()))?;
+
+    let deploy_response = Contract::load_from(
+        artifacts::SRC20_TOKEN_BIN,
+        LoadConfiguration::default()
+            .with_configurables(configurables)
+            .with_salt([7u8; 32]),
+    )?
+    .deploy(wallet, fixed_policies())
+    .await?;
+
+    Ok(Src20Token::new(deploy_response.contract_id, wallet.clone()))
+}
+
+// Mint call against the token contract
+async fn build_mint_call() -> Result<Vec<u8>> {
+    let wallets = launch_fixed_node(&[1]).await?;
+    let admin_wallet = &wallets[0];
+
+    let token_contract = deploy_fixed_token(admin_wallet).await?;
+
+    let tx = token_contract
+        .methods()
+        .mint(Identity::Address(admin_wallet.address().into()), Some(SUB_ID), 1_000_000)
+        .with_tx_policies(fixed_policies())
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .build_tx()
+        .await?;
+
+    Ok(FuelTransaction::from(tx).to_bytes())
+}
+
+// Multi-asset transfer script sending the token to three recipients
+async fn build_script_airdrop() -> Result<Vec<u8>> {
+    let wallets = launch_fixed_node(&[1, 2, 3, 4]).await?;
+    let admin_wallet = &wallets[0];
+
+    let token_contract = deploy_fixed_token(admin_wallet).await?;
+    token_contract
+        .methods()
+        .mint(Identity::Address(admin_wallet.address().into()), Some(SUB_ID), 1_000_000)
+        .with_tx_policies(fixed_policies())
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let asset_id = token_contract.methods().get_asset_id().call().await?.value;
+
+    let recipients = [
+        Identity::Address(wallets[1].address().into()),
+        Identity::Address(wallets[2].address().into()),
+        Identity::Address(wallets[3].address().into()),
+    ];
+    let configurables = MultiAssetTransferConfigurables::default()
+        .with_RECIPIENTS(recipients)?
+        .with_AMOUNTS([100, 200, 300])?;
+
+    let token_inputs = admin_wallet
+        .get_asset_inputs_for_amount(asset_id, 600, None)
+        .await?;
+
+    let tx = MultiAssetTransfer::new(
+        admin_wallet.clone(),
+        artifacts::MULTI_ASSET_TRANSFER_BIN,
+    )
+    .with_configurables(configurables)
+    .main(asset_id)
+    .with_inputs(token_inputs)
+    .with_tx_policies(fixed_policies())
+    .with_variable_output_policy(VariableOutputPolicy::Exactly(3))
+    .build_tx()
+    .await?;
+
+    Ok(FuelTransaction::from(tx).to_bytes())
+}
+
+// 2-of-3 multisig predicate spend signed by the first two signers
+async fn build_predicate_spend() -> Result<Vec<u8>> {
+    let wallets = launch_fixed_node(&[1, 2, 3]).await?;
+    let provider = wallets[0].provider().clone();
+    let asset_id = AssetId::zeroed();
+
+    let signers = [
+        wallets[0].address().into(),
+        wallets[1].address().into(),
+        wallets[2].address().into(),
+    ];
+    let configurables = MultiSigPredicateConfigurables::default()
+        .with_SIGNERS(signers)?
+        .with_REQUIRED_SIGNATURES(2)?;
+
+    let predicate = Predicate::load_from(artifacts::MULTI_SIG_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables);
+
+    wallets[0]
+        .transfer(predicate.address(), 500_000, asset_id, fixed_policies())
+        .await?;
+
+    let inputs = predicate.get_asset_inputs_for_amount(asset_id, 1, None).await?;
+    let outputs = predicate.get_asset_outputs_for_amount(wallets[0].address().into(), asset_id, 300_000);
+
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, fixed_policies());
+    wallets[0].add_witnesses(&mut tb)?;
+    wallets[1].add_witnesses(&mut tb)?;
+
+    let tx = tb.build(&provider).await?;
+
+    Ok(FuelTransaction::from(tx).to_bytes())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(GOLDEN_DIR).join(format!("{}.hex", name))
+}
+
+// Compares (or with --bless, rewrites) a single golden file
+fn check_golden(name: &str, bytes: &[u8], bless: bool) -> std::result::Result<(), String> {
+    let path = golden_path(name);
+    let actual = to_hex(bytes);
+
+    if bless {
+        std::fs::create_dir_all(GOLDEN_DIR).map_err(|e| e.to_string())?;
+        std::fs::write(&path, format!("{}\n", actual)).map_err(|e| e.to_string())?;
+        println!("✍️  Blessed {} ({} bytes)", path.display(), bytes.len());
+        return Ok(());
+    }
+
+    // Without a golden file there is nothing to catch an encoding change with
+    if !path.exists() {
+        return Err(format!(
+            "{} is missing: run `cargo test --test golden_transactions -- --bless` and commit it",
+            path.display()
+        ));
+    }
+
+    let expected = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+
+    if expected.trim() != actual {
+        return Err(format!(
+            "{} changed: expected {} bytes, got {} bytes. If the encoding change is intentional, \
+             re-run with `-- --bless`",
+            path.display(),
+            expected.trim().len() / 2,
+            bytes.len()
+        ));
+    }
+
+    println!("✅ {} matches ({} bytes)", path.display(), bytes.len());
+    Ok(())
+}
+
+async fn run(bless: bool) -> Result<Vec<String>> {
+    let snapshots = [
+        ("mint_call", build_mint_call().await?),
+        ("script_airdrop", build_script_airdrop().await?),
+        ("predicate_spend", build_predicate_spend().await?),
+    ];
+
+    Ok(snapshots
+        .iter()
+        .filter_map(|(name, bytes)| check_golden(name, bytes, bless).err())
+        .collect())
+}
+
+fn main() {
+    let bless = std::env::args().any(|arg| arg == "--bless");
+
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start tokio runtime");
+    let failures = runtime
+        .block_on(run(bless))
+        .expect("failed to build golden transactions");
+
+    if !failures.is_empty() {
+        for failure in &failures {
+            eprintln!("❌ {}", failure);
+        }
+        std::process::exit(1);
+    }
+}
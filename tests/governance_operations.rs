@@ -0,0 +1,256 @@
+// Governance Operations Tests
+// 
+// This module contains tests for the governance contract including:
+// - Proposal creation and voting from multiple wallets
+// - Executing a passed proposal out of the treasury
+// - Proposals failing quorum or rejected by a majority
+// - Unlocking vote tokens once voting has closed
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{AssetId, Identity},
+};
+
+use rosetta_stone::{
+    deploy_governance, deploy_src20_token, Governance, HarnessConfig, TestHarness, SUB_ID,
+};
+
+const QUORUM: u64 = 600;
+const VOTING_PERIOD: u32 = 10;
+const TREASURY_FUNDS: u64 = 10_000;
+const PAYOUT: u64 = 500;
+
+struct Setup {
+    harness: TestHarness,
+    governance: Governance<Wallet<Unlocked<PrivateKeySigner>>>,
+    treasury_asset: AssetId,
+}
+
+impl Setup {
+    // Deploys governance voted on with the harness token and a funded treasury token.
+    // Users 0, 1 and 2 hold 400, 300 and 200 votes.
+    async fn new() -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+        for (i, votes) in [400, 300, 200].into_iter().enumerate() {
+            harness.mint_to(harness.user(i), votes).await?;
+        }
+
+        let treasury_token =
+            deploy_src20_token(harness.admin.clone(), "TREASRY", "TRSRY", 9).await?;
+        let treasury_asset = treasury_token.methods().get_asset_id().call().await?.value;
+        treasury_token
+            .methods()
+            .mint(
+                Identity::Address(harness.admin.address().into()),
+                Some(SUB_ID),
+                TREASURY_FUNDS,
+            )
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+
+        let governance =
+            deploy_governance(harness.admin.clone(), harness.asset_id, QUORUM, VOTING_PERIOD)
+                .await?;
+        governance
+            .methods()
+            .fund()
+            .call_params(
+                CallParameters::default()
+                    .with_amount(TREASURY_FUNDS)
+                    .with_asset_id(treasury_asset),
+            )?
+            .call()
+            .await?;
+
+        Ok(Self {
+            harness,
+            governance,
+            treasury_asset,
+        })
+    }
+
+    fn governance_as(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    ) -> Governance<Wallet<Unlocked<PrivateKeySigner>>> {
+        self.governance.clone().with_account(wallet.clone())
+    }
+
+    // User 0 proposes paying PAYOUT of the treasury token to user 2
+    async fn propose(&self) -> Result<u64> {
+        Ok(self
+            .governance_as(self.harness.user(0))
+            .methods()
+            .propose(
+                Identity::Address(self.harness.user(2).address().into()),
+                self.treasury_asset,
+                PAYOUT,
+            )
+            .call()
+            .await?
+            .value)
+    }
+
+    async fn vote(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        id: u64,
+        support: bool,
+        weight: u64,
+    ) -> Result<()> {
+        self.governance_as(wallet)
+            .methods()
+            .vote(id, support)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(weight)
+                    .with_asset_id(self.harness.asset_id),
+            )?
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn execute(&self, id: u64) -> Result<()> {
+        self.governance
+            .methods()
+            .execute(id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn unlock(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>, id: u64) -> Result<u64> {
+        Ok(self
+            .governance_as(wallet)
+            .methods()
+            .unlock(id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?
+            .value)
+    }
+}
+
+// Test a proposal that passes and is executed
+#[tokio::test]
+async fn test_governance_proposal_passes() -> Result<()> {
+    println!("Testing governance proposal execution...");
+
+    let setup = Setup::new().await?;
+    let (alice, bob, carol) = (setup.harness.user(0), setup.harness.user(1), setup.harness.user(2));
+
+    let id = setup.propose().await?;
+    assert_eq!(setup.governance.methods().proposal_count().call().await?.value, 1);
+    println!("✅ Proposal {} created", id);
+
+    setup.vote(alice, id, true, 400).await?;
+    setup.vote(bob, id, true, 300).await?;
+    setup.vote(carol, id, false, 200).await?;
+    assert_eq!(setup.harness.token_balance(alice).await?, 0);
+
+    let result = setup.vote(bob, id, true, 0).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Vote has no weight"));
+    setup.harness.mint_to(bob, 100).await?;
+    let result = setup.vote(bob, id, true, 100).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Already voted"));
+
+    let proposal = setup.governance.methods().proposal(id).call().await?.value.unwrap();
+    assert_eq!(proposal.votes_for, 700);
+    assert_eq!(proposal.votes_against, 200);
+    println!("✅ Three wallets voted 700 for, 200 against");
+
+    let result = setup.execute(id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Voting still open"));
+    let result = setup.unlock(alice, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Voting still open"));
+    println!("✅ Execution and unlocking rejected while voting is open");
+
+    setup.harness.provider.produce_blocks(VOTING_PERIOD, None).await?;
+
+    let result = setup.vote(bob, id, false, 100).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Voting closed"));
+
+    setup.execute(id).await?;
+    assert_eq!(carol.get_asset_balance(&setup.treasury_asset).await?, PAYOUT as u128);
+    assert_eq!(
+        setup
+            .governance
+            .methods()
+            .treasury_balance(setup.treasury_asset)
+            .call()
+            .await?
+            .value,
+        TREASURY_FUNDS - PAYOUT
+    );
+    let proposal = setup.governance.methods().proposal(id).call().await?.value.unwrap();
+    assert!(proposal.executed);
+    println!("✅ Passed proposal paid {} to Carol", PAYOUT);
+
+    let result = setup.execute(id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Proposal already executed"));
+
+    // Every voter gets their tokens back, winners and losers alike
+    assert_eq!(setup.unlock(alice, id).await?, 400);
+    assert_eq!(setup.unlock(carol, id).await?, 200);
+    assert_eq!(setup.harness.token_balance(alice).await?, 400);
+    assert_eq!(setup.harness.token_balance(carol).await?, 200);
+
+    let result = setup.unlock(alice, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Nothing to unlock"));
+    println!("✅ Vote tokens unlocked after voting closed");
+
+    Ok(())
+}
+
+// Test proposals that cannot be executed
+#[tokio::test]
+async fn test_governance_proposal_fails() -> Result<()> {
+    println!("Testing failing governance proposals...");
+
+    let setup = Setup::new().await?;
+    let (alice, bob, carol) = (setup.harness.user(0), setup.harness.user(1), setup.harness.user(2));
+
+    // Unanimous, but only 500 of the 600 votes needed
+    let short = setup.propose().await?;
+    setup.vote(alice, short, true, 200).await?;
+    setup.vote(bob, short, true, 300).await?;
+
+    // Quorum reached, but the majority is against
+    let rejected = setup.propose().await?;
+    setup.vote(alice, rejected, true, 200).await?;
+    setup.vote(carol, rejected, false, 200).await?;
+    setup.harness.mint_to(bob, 300).await?;
+    setup.vote(bob, rejected, false, 300).await?;
+
+    setup.harness.provider.produce_blocks(VOTING_PERIOD, None).await?;
+
+    let result = setup.execute(short).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Quorum not reached"));
+    println!("❌ Proposal with 500 of {} votes failed quorum", QUORUM);
+
+    let result = setup.execute(rejected).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Proposal rejected"));
+    println!("❌ Proposal with 200 for, 500 against rejected");
+
+    assert_eq!(carol.get_asset_balance(&setup.treasury_asset).await?, 0);
+    assert_eq!(
+        setup
+            .governance
+            .methods()
+            .treasury_balance(setup.treasury_asset)
+            .call()
+            .await?
+            .value,
+        TREASURY_FUNDS
+    );
+    println!("✅ Treasury untouched by failed proposals");
+
+    let result = setup.execute(2).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unknown proposal"));
+
+    Ok(())
+}
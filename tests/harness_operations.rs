@@ -0,0 +1,82 @@
+// Harness Operations Tests
+// 
+// This module contains tests for the TestHarness including:
+// - Booting a node with named wallets
+// - Deploying the full contract suite in one call
+// - Minting and depositing through the typed handles
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{HarnessConfig, TestHarness, TOKEN_AMOUNT};
+
+// Test that the harness deploys the suite and wires the handles together
+#[tokio::test]
+async fn test_harness_deploys_suite() -> Result<()> {
+    println!("Testing harness setup...");
+
+    let harness = TestHarness::new(
+        HarnessConfig::default()
+            .with_num_users(3)
+            .with_token("HARNESS", "HRNSS", 6),
+    )
+    .await?;
+
+    assert_eq!(harness.users.len(), 3);
+
+    // Metadata comes from the config
+    let decimals = harness
+        .token
+        .methods()
+        .decimals(harness.asset_id)
+        .call()
+        .await?
+        .value;
+    assert_eq!(decimals, Some(6));
+
+    // Mint and verify through the handles
+    harness.mint_to(harness.user(0), TOKEN_AMOUNT).await?;
+    assert_eq!(harness.token_balance(harness.user(0)).await?, TOKEN_AMOUNT as u128);
+
+    println!("✅ Harness setup test passed");
+    Ok(())
+}
+
+// Test a user deposit into the harness vault
+#[tokio::test]
+async fn test_harness_user_deposit() -> Result<()> {
+    println!("Testing harness user deposit...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    harness.mint_to(user, TOKEN_AMOUNT).await?;
+
+    let deposit_amount = 100_000;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(deposit_amount)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let deposit = harness
+        .vault
+        .methods()
+        .get_deposit(Identity::Address(user.address().into()))
+        .call()
+        .await?
+        .value;
+    assert_eq!(deposit, deposit_amount);
+    assert_eq!(
+        harness.token_balance(user).await?,
+        (TOKEN_AMOUNT - deposit_amount) as u128
+    );
+
+    println!("✅ Harness user deposit test passed");
+    Ok(())
+}
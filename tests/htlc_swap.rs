@@ -0,0 +1,217 @@
+// HTLC Swap Tests
+// 
+// This module contains tests for the hash-time-lock predicate including:
+// - An atomic swap of two assets between two wallets
+// - Reading the revealed preimage back from the chain
+// - Refunds after the timeout, and not before
+// - Rejecting a wrong preimage
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    crypto::Hasher,
+    prelude::*,
+    tx::TxId,
+    types::{
+        transaction::{Transaction, TransactionType},
+        transaction_builders::ScriptTransactionBuilder,
+        tx_status::TxStatus,
+        Address, AssetId, Bits256, Identity,
+    },
+};
+
+use rosetta_stone::{
+    artifacts, deploy_src20_token, HarnessConfig, HtlcPredicateConfigurables, HtlcPredicateEncoder,
+    TestHarness, SUB_ID,
+};
+
+const SWAP_AMOUNT: u64 = 5_000;
+const SECRET: [u8; 32] = [42u8; 32];
+
+// Loads an HTLC paying `recipient` on the preimage of `hash`, or `refund` from `timeout`
+fn htlc(
+    provider: &Provider,
+    hash: Bits256,
+    recipient: &Wallet<Unlocked<PrivateKeySigner>>,
+    refund: &Wallet<Unlocked<PrivateKeySigner>>,
+    timeout: u32,
+    preimage: Bits256,
+) -> Result<Predicate> {
+    let configurables = HtlcPredicateConfigurables::default()
+        .with_HASH(hash)?
+        .with_RECIPIENT(Address::from(recipient.address()))?
+        .with_REFUND(Address::from(refund.address()))?
+        .with_TIMEOUT(timeout)?;
+    let data = HtlcPredicateEncoder::default().encode_data(preimage)?;
+
+    Ok(Predicate::load_from(artifacts::HTLC_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables)
+        .with_data(data))
+}
+
+// Moves everything the HTLC holds of `asset_id` to `spender`, who signs and pays the fee
+async fn spend(
+    htlc: &Predicate,
+    spender: &Wallet<Unlocked<PrivateKeySigner>>,
+    asset_id: AssetId,
+    maturity: u32,
+) -> Result<(TxId, TxStatus)> {
+    let provider = spender.try_provider()?.clone();
+
+    let inputs = htlc
+        .get_asset_inputs_for_amount(asset_id, SWAP_AMOUNT as u128, None)
+        .await?;
+    let outputs =
+        htlc.get_asset_outputs_for_amount(spender.address().into(), asset_id, SWAP_AMOUNT);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_maturity(maturity),
+    );
+
+    // The spender's fee coin makes its signature the first witness
+    spender.adjust_for_fee(&mut tb, 0).await?;
+    spender.add_witnesses(&mut tb)?;
+
+    let tx = tb.build(provider.clone()).await?;
+    let tx_id = tx.id(provider.consensus_parameters().await?.chain_id());
+    let status = provider.send_transaction_and_await_commit(tx).await?;
+    Ok((tx_id, status))
+}
+
+// Recovers the preimage from the predicate data of a claim transaction
+async fn revealed_preimage(provider: &Provider, tx_id: &TxId) -> Result<Bits256> {
+    let response = provider
+        .get_transaction_by_id(tx_id)
+        .await?
+        .ok_or_else(|| Error::Other(format!("transaction {} not found", tx_id)))?;
+    let TransactionType::Script(tx) = response.transaction else {
+        return Err(Error::Other("claim is not a script transaction".to_string()));
+    };
+
+    let data = tx
+        .inputs()
+        .iter()
+        .find_map(|input| input.predicate_data())
+        .ok_or_else(|| Error::Other("claim has no predicate input".to_string()))?;
+    let preimage: [u8; 32] = data[..32]
+        .try_into()
+        .map_err(|_| Error::Other("predicate data too short".to_string()))?;
+    Ok(Bits256(preimage))
+}
+
+// Sets up Alice holding the harness token and Bob holding a second token
+async fn swap_parties() -> Result<(TestHarness, AssetId)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    harness.mint_to(harness.user(0), SWAP_AMOUNT).await?;
+
+    let other_token = deploy_src20_token(harness.admin.clone(), "SWAPTKN", "SWAPT", 9).await?;
+    other_token
+        .methods()
+        .mint(
+            Identity::Address(harness.user(1).address().into()),
+            Some(SUB_ID),
+            SWAP_AMOUNT,
+        )
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let other_asset_id = other_token.methods().get_asset_id().call().await?.value;
+
+    Ok((harness, other_asset_id))
+}
+
+// Test a full atomic swap: Alice's token for Bob's token
+#[tokio::test]
+async fn test_htlc_atomic_swap() -> Result<()> {
+    println!("Testing HTLC atomic swap...");
+
+    let (harness, bob_asset) = swap_parties().await?;
+    let provider = &harness.provider;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let alice_asset = harness.asset_id;
+
+    // Alice picks the secret; only its hash is shared
+    let hash = Bits256(*Hasher::hash(SECRET));
+    let height = provider.latest_block_height().await?;
+
+    // Alice locks first with the longer timeout, so Bob always has time to claim
+    let alice_lock = htlc(provider, hash, bob, alice, height + 20, Bits256::zeroed())?;
+    alice
+        .transfer(alice_lock.address(), SWAP_AMOUNT, alice_asset, TxPolicies::default())
+        .await?;
+    let bob_lock = htlc(provider, hash, alice, bob, height + 10, Bits256(SECRET))?;
+    bob.transfer(bob_lock.address(), SWAP_AMOUNT, bob_asset, TxPolicies::default())
+        .await?;
+    println!("✅ Both sides locked");
+
+    // Alice claims Bob's token, revealing the secret
+    let (claim_id, status) = spend(&bob_lock, alice, bob_asset, 0).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    assert_eq!(alice.get_asset_balance(&bob_asset).await?, SWAP_AMOUNT as u128);
+    println!("✅ Alice claimed with the preimage");
+
+    // Bob reads the secret from Alice's claim and claims Alice's token
+    let preimage = revealed_preimage(provider, &claim_id).await?;
+    assert_eq!(preimage, Bits256(SECRET));
+    let bob_claim = htlc(provider, hash, bob, alice, height + 20, preimage)?;
+    assert_eq!(bob_claim.address(), alice_lock.address());
+    let (_, status) = spend(&bob_claim, bob, alice_asset, 0).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+
+    assert_eq!(bob.get_asset_balance(&alice_asset).await?, SWAP_AMOUNT as u128);
+    assert_eq!(alice.get_asset_balance(&alice_asset).await?, 0);
+    assert_eq!(bob.get_asset_balance(&bob_asset).await?, 0);
+    println!("✅ Bob claimed with the preimage read from the chain");
+
+    Ok(())
+}
+
+// Test the timeout-refund path and a wrong preimage
+#[tokio::test]
+async fn test_htlc_timeout_refund() -> Result<()> {
+    println!("Testing HTLC timeout refund...");
+
+    let (harness, _) = swap_parties().await?;
+    let provider = &harness.provider;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let asset_id = harness.asset_id;
+
+    let hash = Bits256(*Hasher::hash(SECRET));
+    let timeout = provider.latest_block_height().await? + 10;
+
+    // Bob never learns the secret; his guess does not unlock the claim path
+    let lock = htlc(provider, hash, bob, alice, timeout, Bits256([7u8; 32]))?;
+    alice
+        .transfer(lock.address(), SWAP_AMOUNT, asset_id, TxPolicies::default())
+        .await?;
+
+    let result = spend(&lock, bob, asset_id, 0).await;
+    assert!(result.is_err());
+    println!("❌ Claim with a wrong preimage rejected");
+
+    // The refund path is closed until the timeout
+    let result = spend(&lock, alice, asset_id, timeout - 1).await;
+    assert!(result.is_err());
+    let result = spend(&lock, alice, asset_id, timeout).await;
+    assert!(result.is_err());
+    assert_eq!(
+        lock.get_asset_balance(&asset_id).await?,
+        SWAP_AMOUNT as u128
+    );
+    println!("❌ Refund before the timeout rejected");
+
+    provider.produce_blocks(10, None).await?;
+
+    // Only the refund address can use the refund path
+    let result = spend(&lock, bob, asset_id, timeout).await;
+    assert!(result.is_err());
+
+    let (_, status) = spend(&lock, alice, asset_id, timeout).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    assert_eq!(alice.get_asset_balance(&asset_id).await?, SWAP_AMOUNT as u128);
+    assert_eq!(lock.get_asset_balance(&asset_id).await?, 0);
+    println!("✅ Alice refunded after the timeout");
+
+    Ok(())
+}
@@ -0,0 +1,380 @@
+// Indexer Tests
+// 
+// This module contains tests for the event streaming indexer including:
+// - A mint event arriving through the stream
+// - Vault deposits arriving in order after token events
+// - Logs of unwatched contracts being skipped
+// - Catching up on past blocks in concurrent ranges, a small batch of transactions at a time
+// - Blocks held back until they have the configured number of confirmations
+// - A slow sink at the end of the pipeline receiving every event in order
+// - The pipeline's channels never holding more than their capacity
+// - The pipeline stopping when its sink fails
+// - A shutdown draining the events in flight into the sink
+// - A wallet's balance stream reporting each transfer's delta and counterparty
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    deploy_src20_token, wallet_balance_stream, ContractEvent, EventSink, HarnessConfig,
+    IndexedEvent, Indexer, IndexerConfig, IndexerPipeline, TestHarness, SUB_ID,
+};
+
+// A sink that takes its time with every batch, or fails after `fail_after` of them
+#[derive(Clone, Default)]
+struct SlowSink {
+    written: Arc<Mutex<Vec<IndexedEvent>>>,
+    fail_after: Option<usize>,
+}
+
+#[async_trait]
+impl EventSink for SlowSink {
+    async fn write(&mut self, events: Vec<IndexedEvent>) -> Result<()> {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let mut written = self.written.lock().unwrap();
+        if self.fail_after.is_some_and(|limit| written.len() >= limit) {
+            return Err(Error::Other("sink full".to_string()));
+        }
+        written.extend(events);
+        Ok(())
+    }
+}
+
+const WAIT: Duration = Duration::from_secs(10);
+
+// Test that a mint shows up on the stream with its block and transaction
+#[tokio::test]
+async fn test_indexer_streams_mint_event() -> Result<()> {
+    println!("Testing indexer mint stream...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let mut indexer =
+        Indexer::for_token_and_vault(&harness.token, &harness.vault, IndexerConfig::default())
+            .await?;
+
+    let response = harness
+        .token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 777)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let indexed = indexer.next_event_within(WAIT).await?;
+    assert_eq!(Some(indexed.tx_id), response.tx_id);
+    assert_eq!(indexed.contract_id, harness.token.contract_id().clone());
+    assert_eq!(
+        indexed.block_height,
+        harness.provider.latest_block_height().await?
+    );
+    let ContractEvent::Mint(mint) = indexed.event else {
+        panic!("expected a mint event, got {:?}", indexed.event);
+    };
+    assert_eq!(mint.amount, 777);
+    println!("✅ Mint of {} streamed from block {}", mint.amount, indexed.block_height);
+
+    Ok(())
+}
+
+// Test ordering across contracts and filtering of unwatched contracts
+#[tokio::test]
+async fn test_indexer_filters_and_orders() -> Result<()> {
+    println!("Testing indexer filtering...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let other_token = deploy_src20_token(harness.admin.clone(), "OTHERTK", "OTHER", 9).await?;
+
+    let mut indexer =
+        Indexer::for_token_and_vault(&harness.token, &harness.vault, IndexerConfig::default())
+            .await?;
+
+    // Same MintEvent type, but from a contract the indexer does not watch
+    other_token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 1)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    harness.mint_to(user, 500).await?;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(200)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let first = indexer.next_event_within(WAIT).await?;
+    let second = indexer.next_event_within(WAIT).await?;
+    assert!(matches!(first.event, ContractEvent::Mint(ref event) if event.amount == 500));
+    assert!(matches!(second.event, ContractEvent::Deposit(ref event) if event.amount == 200));
+    assert_eq!(second.contract_id, harness.vault.contract_id().clone());
+    assert!(first.block_height < second.block_height);
+    println!("✅ Token mint then vault deposit streamed in order");
+
+    // Nothing else was logged by the watched contracts
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert!(indexer.drain().is_empty());
+    println!("✅ Mint on the unwatched token skipped");
+
+    Ok(())
+}
+
+// Test catching up from a past height over several batched queries and ranges
+#[tokio::test]
+async fn test_indexer_batched_catch_up() -> Result<()> {
+    println!("Testing batched catch-up...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let from_height = harness.provider.latest_block_height().await? + 1;
+    for amount in 1..=5 {
+        harness.mint_to(user, amount).await?;
+    }
+
+    // Two transactions per query and two blocks per range, so the five mints
+    // and their blocks' mint transactions take several pages over ranges
+    // that three workers fetch at once
+    let config = IndexerConfig::default()
+        .with_from_height(from_height)
+        .with_batch_size(2)
+        .with_range_size(2)
+        .with_range_workers(3);
+    let mut indexer = Indexer::for_token_and_vault(&harness.token, &harness.vault, config).await?;
+
+    let mut last_height = from_height - 1;
+    for amount in 1..=5 {
+        let indexed = indexer.next_event_within(WAIT).await?;
+        assert!(matches!(indexed.event, ContractEvent::Mint(ref event) if event.amount == amount));
+        assert!(indexed.block_height > last_height);
+        last_height = indexed.block_height;
+    }
+    println!("✅ Five past mints streamed in order from block {}", from_height);
+
+    Ok(())
+}
+
+// Test that a slow sink holds the pipeline back without losing events
+#[tokio::test]
+async fn test_pipeline_backpressure() -> Result<()> {
+    println!("Testing the pipeline with a slow sink...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let from_height = harness.provider.latest_block_height().await? + 1;
+    for amount in 1..=5 {
+        harness.mint_to(user, amount).await?;
+    }
+
+    // One batch in flight per stage, so the follower waits for the sink
+    let config = IndexerConfig::default()
+        .with_from_height(from_height)
+        .with_batch_size(1)
+        .with_channel_capacity(1);
+    let mut decoder = harness.token.log_decoder();
+    decoder.merge(harness.vault.log_decoder());
+    let contracts = vec![harness.token.contract_id(), harness.vault.contract_id()];
+    let sink = SlowSink::default();
+    let pipeline = IndexerPipeline::spawn(
+        harness.provider.clone(),
+        contracts.clone(),
+        decoder.clone(),
+        config.clone(),
+        sink.clone(),
+    )
+    .await?;
+
+    // The channels never hold more than their capacity, however slow the sink
+    for _ in 0..100 {
+        let buffered = pipeline.buffered();
+        assert!(buffered.pages <= config.channel_capacity, "{buffered:?}");
+        assert!(buffered.batches <= config.channel_capacity, "{buffered:?}");
+        if sink.written.lock().unwrap().len() == 5 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let amounts: Vec<u64> = sink
+        .written
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|indexed| indexed.event.amount())
+        .collect();
+    assert_eq!(amounts, vec![1, 2, 3, 4, 5]);
+    println!("✅ All five mints written in order, at most one batch buffered per channel");
+
+    // A failing sink stops every stage
+    let failing = SlowSink {
+        fail_after: Some(2),
+        ..SlowSink::default()
+    };
+    let pipeline = IndexerPipeline::spawn(
+        harness.provider.clone(),
+        contracts,
+        decoder,
+        config,
+        failing.clone(),
+    )
+    .await?;
+    for _ in 0..100 {
+        if pipeline.is_finished() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(pipeline.is_finished());
+    assert_eq!(failing.written.lock().unwrap().len(), 2);
+    println!("❌ Pipeline stopped after its sink failed");
+
+    Ok(())
+}
+
+// Test that a block is only indexed once enough blocks are built on top of it
+#[tokio::test]
+async fn test_indexer_waits_for_confirmations() -> Result<()> {
+    println!("Testing indexer confirmation depth...");
+
+    const CONFIRMATIONS: u32 = 3;
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let config = IndexerConfig::default().with_confirmations(CONFIRMATIONS);
+    let mut indexer = Indexer::for_token_and_vault(&harness.token, &harness.vault, config).await?;
+
+    harness.mint_to(user, 42).await?;
+    let minted_at = harness.provider.latest_block_height().await?;
+    assert!(indexer.next_event_within(Duration::from_millis(500)).await.is_err());
+    println!("❌ Mint in block {} not indexed while it is the head", minted_at);
+
+    // One block short of the depth still holds it back
+    harness.provider.produce_blocks(CONFIRMATIONS - 1, None).await?;
+    assert!(indexer.next_event_within(Duration::from_millis(500)).await.is_err());
+
+    harness.provider.produce_blocks(1, None).await?;
+    let indexed = indexer.next_event_within(WAIT).await?;
+    assert_eq!(indexed.block_height, minted_at);
+    assert_eq!(indexed.event.amount(), 42);
+    println!("✅ Mint indexed once {} blocks followed it", CONFIRMATIONS);
+
+    Ok(())
+}
+
+// Test that shutting down writes everything already fetched before stopping
+#[tokio::test]
+async fn test_pipeline_drains_on_shutdown() -> Result<()> {
+    println!("Testing a graceful pipeline shutdown...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let from_height = harness.provider.latest_block_height().await? + 1;
+    for amount in 1..=5 {
+        harness.mint_to(user, amount).await?;
+    }
+
+    // A single range, so the follower hands over all five mints in one page
+    let config = IndexerConfig::default()
+        .with_from_height(from_height)
+        .with_range_size(1_000)
+        .with_batch_size(100);
+    let sink = SlowSink::default();
+    let pipeline = IndexerPipeline::spawn(
+        harness.provider.clone(),
+        vec![harness.token.contract_id()],
+        harness.token.log_decoder(),
+        config,
+        sink.clone(),
+    )
+    .await?;
+
+    // Once the first mint is written the rest are in flight behind the slow sink
+    for _ in 0..100 {
+        if !sink.written.lock().unwrap().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(sink.written.lock().unwrap().len() < 5);
+
+    tokio::time::timeout(WAIT, pipeline.shutdown())
+        .await
+        .map_err(|_| Error::Other("shutdown did not drain in time".to_string()))?;
+    let amounts: Vec<u64> = sink
+        .written
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|indexed| indexed.event.amount())
+        .collect();
+    assert_eq!(amounts, vec![1, 2, 3, 4, 5]);
+    println!("✅ Shutdown wrote the {} mints still in flight", amounts.len());
+
+    Ok(())
+}
+
+// Test that the balance stream follows tokens coming in and going out
+#[tokio::test]
+async fn test_wallet_balance_stream() -> Result<()> {
+    println!("Testing the wallet balance stream...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let mut stream = wallet_balance_stream(
+        harness.provider.clone(),
+        alice.address().into(),
+        harness.asset_id,
+        IndexerConfig::default(),
+    )
+    .await?;
+
+    // Minted by the token contract, so only its receipts name alice
+    harness.mint_to(alice, 1_000).await?;
+    let minted = stream.next_change_within(WAIT).await?;
+    assert_eq!((minted.previous, minted.balance), (0, 1_000));
+    assert_eq!(
+        minted.counterparty,
+        Some(Identity::ContractId(harness.token.contract_id()))
+    );
+    println!("✅ Mint of {} seen in block {}", minted.delta(), minted.block_height);
+
+    let response = alice
+        .transfer(bob.address(), 400, harness.asset_id, TxPolicies::default())
+        .await?;
+    let sent = stream.next_change_within(WAIT).await?;
+    assert_eq!(sent.tx_id, response.tx_id);
+    assert_eq!((sent.previous, sent.balance), (1_000, 600));
+    assert_eq!(sent.counterparty, Some(Identity::Address(bob.address().into())));
+    assert!(sent.block_height > minted.block_height);
+    println!("✅ Transfer of 400 out to bob seen as {}", sent.delta());
+
+    let response = bob
+        .transfer(alice.address(), 150, harness.asset_id, TxPolicies::default())
+        .await?;
+    let received = stream.next_change_within(WAIT).await?;
+    assert_eq!(received.tx_id, response.tx_id);
+    assert_eq!((received.previous, received.balance), (600, 750));
+    assert_eq!(received.counterparty, Some(Identity::Address(bob.address().into())));
+    println!("✅ Transfer of {} in from bob seen", received.delta());
+
+    // The deltas come from the transactions, so they add up to the live balance
+    assert_eq!(received.balance, alice.get_asset_balance(&harness.asset_id).await?);
+
+    // Transfers between other wallets do not show up
+    harness.mint_to(bob, 1).await?;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert!(stream.next_change_within(Duration::from_millis(100)).await.is_err());
+    println!("✅ Mint to another wallet skipped");
+
+    Ok(())
+}
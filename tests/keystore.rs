@@ -0,0 +1,66 @@
+// Keystore Tests
+// 
+// This module contains tests for encrypted JSON keystores including:
+// - Decrypting a keystore into the signer of the stored key
+// - Rejecting a wrong password
+// - Reading the password from an environment variable
+
+use fuels::{accounts::signers::private_key::PrivateKeySigner, crypto::SecretKey, prelude::*};
+
+use rosetta_stone::keys::{signer_from_keystore, signer_from_keystore_env};
+
+const PASSWORD: &str = "correct horse battery staple";
+
+// Encrypts a fresh key into a keystore file and returns its path and signer
+fn write_keystore(name: &str) -> (std::path::PathBuf, PrivateKeySigner) {
+    let mut rng = rand::thread_rng();
+    let secret_key = SecretKey::random(&mut rng);
+
+    let dir = std::env::temp_dir();
+    let name = format!("rosetta-keystore-{}-{}", name, std::process::id());
+    eth_keystore::encrypt_key(&dir, &mut rng, *secret_key, PASSWORD, Some(&name))
+        .expect("keystore should be written");
+
+    (dir.join(name), PrivateKeySigner::new(secret_key))
+}
+
+// Test that a keystore decrypts to the key it was written with
+#[tokio::test]
+async fn test_keystore_decrypts_to_stored_key() -> Result<()> {
+    let (path, expected) = write_keystore("decrypt");
+
+    let signer = signer_from_keystore(&path, PASSWORD)?;
+    assert_eq!(signer.address(), expected.address());
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+// Test that a wrong password is rejected
+#[tokio::test]
+async fn test_keystore_rejects_wrong_password() -> Result<()> {
+    let (path, _) = write_keystore("wrong-password");
+
+    let result = signer_from_keystore(&path, "wrong password");
+    assert!(result.is_err());
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+// Test that the password is read from the named environment variable
+#[tokio::test]
+async fn test_keystore_password_from_env() -> Result<()> {
+    let (path, expected) = write_keystore("env");
+    let password_env = "ROSETTA_TEST_KEYSTORE_PASSWORD";
+
+    // Unset variable
+    assert!(signer_from_keystore_env(&path, password_env).is_err());
+
+    std::env::set_var(password_env, PASSWORD);
+    let signer = signer_from_keystore_env(&path, password_env)?;
+    assert_eq!(signer.address(), expected.address());
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
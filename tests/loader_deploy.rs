@@ -0,0 +1,98 @@
+// Loader Deploy Tests
+// 
+// This module contains tests for blob + loader deployment including:
+// - A regular deployment rejected for exceeding the contract size limit
+// - Deploying the same contract as blobs plus a small loader
+// - Calling the loader-deployed contract like any other contract
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::{ConsensusParameters, ContractParameters},
+};
+
+use rosetta_stone::{artifacts, deploy_large_contract, launch_wallets, DEFAULT_BLOB_WORDS};
+
+// Launches a node whose contract size limit is half the LargeContract bytecode.
+// The contract is only moderately large, so the limit is lowered to make it oversized.
+async fn launch_size_limited_node() -> Result<(Wallet<Unlocked<PrivateKeySigner>>, u64)> {
+    let code_size =
+        Contract::load_from(artifacts::LARGE_CONTRACT_BIN, LoadConfiguration::default())?
+            .code()
+            .len() as u64;
+    let max_size = code_size / 2;
+
+    let mut consensus_parameters = ConsensusParameters::default();
+    consensus_parameters
+        .set_contract_params(ContractParameters::default().with_contract_max_size(max_size));
+    let chain_config = ChainConfig {
+        consensus_parameters,
+        ..ChainConfig::default()
+    };
+
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(1), Some(1), Some(1_000_000_000)),
+        None,
+        Some(chain_config),
+    )
+    .await?;
+    Ok((wallets.pop().unwrap(), max_size))
+}
+
+// Test that an oversized contract deploys through blobs and a loader
+#[tokio::test]
+async fn test_oversized_contract_via_loader() -> Result<()> {
+    println!("Testing blob + loader deployment...");
+
+    let (wallet, max_size) = launch_size_limited_node().await?;
+    let provider = wallet.try_provider()?.clone();
+    assert_eq!(
+        provider
+            .consensus_parameters()
+            .await?
+            .contract_params()
+            .contract_max_size(),
+        max_size
+    );
+
+    // A regular deployment puts the whole bytecode in one transaction
+    let result = Contract::load_from(artifacts::LARGE_CONTRACT_BIN, LoadConfiguration::default())?
+        .deploy(&wallet, TxPolicies::default())
+        .await;
+    assert!(result.is_err());
+    println!("❌ Regular deployment over the {} byte limit rejected", max_size);
+
+    // Small blobs so the split is visible; each blob is its own transaction
+    let blob_words = (max_size / 8) as usize;
+    let loader = Contract::load_from(artifacts::LARGE_CONTRACT_BIN, LoadConfiguration::default())?
+        .convert_to_loader(blob_words)?;
+    assert!(loader.blobs().len() > 1);
+    assert!((loader.code().len() as u64) < max_size);
+    println!("✅ Bytecode split into {} blobs", loader.blobs().len());
+
+    let contract = deploy_large_contract(wallet.clone(), blob_words).await?;
+    let methods = contract.methods();
+    assert_eq!(methods.prime_count().call().await?.value, 512);
+    assert_eq!(methods.prime(0).call().await?.value, 2);
+    assert_eq!(methods.prime(99).call().await?.value, 541);
+    assert_eq!(methods.prime(511).call().await?.value, 3671);
+
+    let result = methods.prime(512).call().await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Index out of range"));
+    println!("✅ Loader-deployed contract answers calls");
+
+    Ok(())
+}
+
+// Test the helper with its default blob size on a node with default limits
+#[tokio::test]
+async fn test_loader_deploy_default_blob_size() -> Result<()> {
+    println!("Testing loader deployment with default blob size...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(1), Some(1), Some(1_000_000_000))).await?;
+    let contract = deploy_large_contract(wallets[0].clone(), DEFAULT_BLOB_WORDS).await?;
+    assert_eq!(contract.methods().prime(9).call().await?.value, 29);
+    println!("✅ Loader deployment works where a regular one would too");
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Message Input Tests
+// 
+// This module contains tests for L1→Fuel messages including:
+// - Seeding deposit messages in the genesis config
+// - Consuming a data message as an input of a contract call
+// - Crediting the recipient carried in the message data
+// - Data-less messages spendable like base asset coins
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{coin_type::CoinType, input::Input, message::Message, Address, Bits256, Nonce},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+use rosetta_stone::{deploy_message_receiver, MessageReceiver};
+
+const DEPOSIT_AMOUNT: u64 = 25_000;
+const COIN_AMOUNT: u64 = 1_000_000_000;
+
+struct Setup {
+    relayer: Wallet<Unlocked<PrivateKeySigner>>,
+    recipient: Address,
+    l1_sender: Address,
+    receiver: MessageReceiver<Wallet<Unlocked<PrivateKeySigner>>>,
+}
+
+impl Setup {
+    // Launches a node whose genesis holds fee coins for the relayer plus a deposit
+    // message from an L1 address to the relayer crediting `recipient`, and a
+    // data-less message of the same amount
+    async fn new() -> Result<Self> {
+        let mut rng = StdRng::seed_from_u64(531);
+        let relayer_signer = PrivateKeySigner::random(&mut rng);
+        let recipient = PrivateKeySigner::random(&mut rng).address();
+        let l1_sender = Address::from([0xe1; 32]);
+
+        let coins =
+            setup_single_asset_coins(relayer_signer.address(), AssetId::zeroed(), 1, COIN_AMOUNT);
+        let messages = vec![
+            setup_single_message(
+                l1_sender,
+                relayer_signer.address(),
+                DEPOSIT_AMOUNT,
+                Nonce::from([1u8; 32]),
+                recipient.to_vec(),
+            ),
+            setup_single_message(
+                l1_sender,
+                relayer_signer.address(),
+                DEPOSIT_AMOUNT,
+                Nonce::from([2u8; 32]),
+                vec![],
+            ),
+        ];
+        let provider = setup_test_provider(coins, messages, None, None).await?;
+
+        let relayer = Wallet::new(relayer_signer, provider);
+        let receiver = deploy_message_receiver(relayer.clone()).await?;
+        Ok(Self {
+            relayer,
+            recipient,
+            l1_sender,
+            receiver,
+        })
+    }
+
+    // The relayer's spendable message carrying data, if any
+    async fn deposit_message(&self) -> Result<Option<Message>> {
+        let messages = self.relayer.get_messages().await?;
+        Ok(messages.into_iter().find(|message| !message.data.is_empty()))
+    }
+}
+
+// Test consuming a deposit message in a contract call
+#[tokio::test]
+async fn test_deposit_message_in_contract_call() -> Result<()> {
+    println!("Testing deposit message handling...");
+
+    let setup = Setup::new().await?;
+    let message = setup
+        .deposit_message()
+        .await?
+        .expect("genesis deposit message");
+    assert_eq!(message.sender, setup.l1_sender);
+    assert_eq!(message.amount, DEPOSIT_AMOUNT);
+    let nonce = Bits256(*message.nonce);
+    println!("✅ Deposit message of {} found in genesis", message.amount);
+
+    // Without the message input there is nothing to process
+    let result = setup.receiver.methods().process_messages().call().await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("No deposit messages"));
+
+    // The relayer signs for the message as its recipient
+    let input = Input::resource_signed(CoinType::Message(message));
+    let response = setup
+        .receiver
+        .methods()
+        .process_messages()
+        .with_inputs(vec![input])
+        .call()
+        .await?;
+    assert_eq!(response.value, 1);
+    println!("✅ Message consumed by the contract call");
+
+    let methods = setup.receiver.methods();
+    assert_eq!(
+        methods.deposits_of(setup.recipient).call().await?.value,
+        DEPOSIT_AMOUNT
+    );
+    assert!(methods.is_processed(nonce).call().await?.value);
+    assert!(setup.deposit_message().await?.is_none());
+    println!("✅ Recipient credited and message spent");
+
+    Ok(())
+}
+
+// Test that a data-less message is spendable base asset like a coin
+#[tokio::test]
+async fn test_dataless_message_is_spendable() -> Result<()> {
+    println!("Testing data-less message spending...");
+
+    let setup = Setup::new().await?;
+    let provider = setup.relayer.try_provider()?.clone();
+
+    // Only the data-less message counts towards the spendable balance
+    let base_asset = *provider.consensus_parameters().await?.base_asset_id();
+    let balance = setup.relayer.get_asset_balance(&base_asset).await?;
+    let coins: u128 = setup
+        .relayer
+        .get_coins(base_asset)
+        .await?
+        .iter()
+        .map(|coin| coin.amount as u128)
+        .sum();
+    assert_eq!(balance, coins + DEPOSIT_AMOUNT as u128);
+    println!("✅ Relayer balance {} includes the data-less message", balance);
+
+    // Spending more than the coins alone hold needs the message as an input
+    let stranger = Wallet::new(
+        PrivateKeySigner::random(&mut StdRng::seed_from_u64(1)),
+        provider.clone(),
+    );
+    let amount = balance as u64 - DEPOSIT_AMOUNT / 2;
+    setup
+        .relayer
+        .transfer(stranger.address(), amount, base_asset, TxPolicies::default())
+        .await?;
+    assert_eq!(
+        stranger.get_asset_balance(&base_asset).await?,
+        amount as u128
+    );
+
+    let remaining = setup.relayer.get_messages().await?;
+    assert!(remaining.iter().all(|message| !message.data.is_empty()));
+    println!("✅ Data-less message spent in a plain transfer");
+
+    Ok(())
+}
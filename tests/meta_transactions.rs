@@ -0,0 +1,212 @@
+// Meta-Transaction Tests
+// 
+// This module contains tests for transfers relayed through the Forwarder contract including:
+// - A user holding no base asset signing transfer intents off chain
+// - A relayer submitting them and paying every fee
+// - Rejecting replayed, tampered, expired and overdrawn intents
+// - Intents claiming a sender other than the one who signed them
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    prelude::*,
+    types::{Identity, B512},
+};
+
+use rosetta_stone::{
+    deploy_forwarder, expect_single_event,
+    relayer::{intent_message, prepare_intent, sign_intent, Relayer},
+    ForwardedEvent, HarnessConfig, RevertReason, TestHarness, TestWallet, TransferIntent,
+};
+
+const DEPOSIT: u64 = 100_000;
+const AMOUNT: u64 = 30_000;
+
+// Deploys the forwarder and credits DEPOSIT tokens to a fresh wallet that holds
+// no coins at all and only ever signs. User 0 relays; user 1 receives.
+async fn setup() -> Result<(TestHarness, TestWallet, Relayer<TestWallet>)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        harness.provider.clone(),
+    );
+
+    let forwarder = deploy_forwarder(harness.admin.clone()).await?;
+    harness.mint_to(&harness.admin, DEPOSIT).await?;
+    forwarder
+        .methods()
+        .deposit_for(user.address().into())
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let relayer = Relayer::new(forwarder.with_account(harness.user(0).clone()));
+    Ok((harness, user, relayer))
+}
+
+// An intent from `user` sending `amount` to user 1, valid for `blocks` more blocks
+async fn signed_intent(
+    harness: &TestHarness,
+    user: &TestWallet,
+    relayer: &Relayer<TestWallet>,
+    amount: u64,
+    blocks: u32,
+) -> Result<(TransferIntent, B512)> {
+    let forwarder = relayer.forwarder();
+    let deadline = harness.provider.latest_block_height().await? + blocks;
+    let intent = prepare_intent(
+        forwarder,
+        user.address().into(),
+        Identity::Address(harness.user(1).address().into()),
+        harness.asset_id,
+        amount,
+        deadline,
+    )
+    .await?;
+    let signature = sign_intent(user.signer(), &intent, &forwarder.contract_id()).await?;
+    Ok((intent, signature))
+}
+
+// The user's balance of the harness token held by the forwarder
+async fn forwarder_balance(
+    harness: &TestHarness,
+    relayer: &Relayer<TestWallet>,
+    user: &TestWallet,
+) -> Result<u64> {
+    let forwarder = relayer.forwarder();
+    let owner = user.address().into();
+    Ok(forwarder.methods().balance_of(owner, harness.asset_id).call().await?.value)
+}
+
+async fn relay_error(
+    relayer: &Relayer<TestWallet>,
+    intent: TransferIntent,
+    signature: B512,
+) -> Error {
+    relayer
+        .relay(intent, signature)
+        .await
+        .err()
+        .expect("relaying must revert")
+}
+
+// Test that a user without base asset moves tokens while the relayer pays the fees
+#[tokio::test]
+async fn test_relayed_transfers() -> Result<()> {
+    println!("Testing relayed transfer intents...");
+
+    let (harness, user, relayer) = setup().await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let recipient = harness.user(1);
+    let recipient_before = harness.token_balance(recipient).await?;
+    let relayer_before = relayer.wallet().get_asset_balance(&base_asset_id).await?;
+    assert_eq!(user.get_asset_balance(&base_asset_id).await?, 0);
+
+    for nonce in 0..2 {
+        let (intent, signature) = signed_intent(&harness, &user, &relayer, AMOUNT, 10).await?;
+        assert_eq!(intent.nonce, nonce);
+        let response = relayer.relay(intent.clone(), signature).await?;
+        let event: ForwardedEvent = expect_single_event(&response)?;
+        assert_eq!(event.from, intent.from);
+        assert_eq!(event.amount, AMOUNT);
+        assert_eq!(event.nonce, nonce);
+        assert_eq!(event.relayer, Identity::Address(relayer.wallet().address().into()));
+    }
+    println!("✅ Two intents relayed");
+
+    assert_eq!(
+        harness.token_balance(recipient).await?,
+        recipient_before + u128::from(2 * AMOUNT)
+    );
+    assert_eq!(
+        forwarder_balance(&harness, &relayer, &user).await?,
+        DEPOSIT - 2 * AMOUNT
+    );
+    assert_eq!(user.get_asset_balance(&base_asset_id).await?, 0);
+    assert!(relayer.wallet().get_asset_balance(&base_asset_id).await? < relayer_before);
+    println!("✅ Recipient received {}; the user spent no base asset", 2 * AMOUNT);
+
+    Ok(())
+}
+
+// Test that replayed, tampered, expired and overdrawn intents are rejected
+#[tokio::test]
+async fn test_rejected_intents() -> Result<()> {
+    println!("Testing rejected transfer intents...");
+
+    let (harness, user, relayer) = setup().await?;
+    let invalid_signature = Some(RevertReason::Require("Invalid signature".to_string()));
+
+    // A larger amount than the one signed
+    let (intent, signature) = signed_intent(&harness, &user, &relayer, AMOUNT, 10).await?;
+    let tampered = TransferIntent {
+        amount: AMOUNT * 2,
+        ..intent.clone()
+    };
+    let error = relay_error(&relayer, tampered, signature).await;
+    assert_eq!(RevertReason::from_error(&error), invalid_signature);
+    println!("❌ Tampered amount rejected");
+
+    // The genuine intent goes through once
+    relayer.relay(intent.clone(), signature).await?;
+    let error = relay_error(&relayer, intent, signature).await;
+    assert_eq!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Require("Invalid nonce".to_string()))
+    );
+    println!("❌ Replay rejected");
+
+    let (intent, signature) = signed_intent(&harness, &user, &relayer, AMOUNT, 1).await?;
+    harness.provider.produce_blocks(3, None).await?;
+    let error = relay_error(&relayer, intent, signature).await;
+    assert_eq!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Require("Intent expired".to_string()))
+    );
+    println!("❌ Expired intent rejected");
+
+    let (intent, signature) = signed_intent(&harness, &user, &relayer, DEPOSIT, 10).await?;
+    let error = relay_error(&relayer, intent, signature).await;
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::InsufficientBalance));
+    println!("❌ Intent above the user's balance rejected");
+
+    assert_eq!(
+        forwarder_balance(&harness, &relayer, &user).await?,
+        DEPOSIT - AMOUNT
+    );
+
+    Ok(())
+}
+
+// Test that a signature only moves the signer's own balance
+#[tokio::test]
+async fn test_intent_bound_to_sender() -> Result<()> {
+    println!("Testing an intent claiming another sender...");
+
+    let (harness, user, relayer) = setup().await?;
+    let forwarder = relayer.forwarder();
+
+    // The relayer signs an intent spending the user's balance
+    let (intent, _) = signed_intent(&harness, &user, &relayer, AMOUNT, 10).await?;
+    let message = intent_message(&intent, &forwarder.contract_id());
+    let forged = relayer.wallet().signer().sign(message).await?;
+    let forged = B512::try_from(forged.as_ref())?;
+    let error = relay_error(&relayer, intent.clone(), forged).await;
+    assert_eq!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Require("Invalid signature".to_string()))
+    );
+    assert!(sign_intent(relayer.wallet().signer(), &intent, &forwarder.contract_id())
+        .await
+        .is_err());
+    assert_eq!(
+        forwarder_balance(&harness, &relayer, &user).await?,
+        DEPOSIT
+    );
+    println!("❌ Intent signed by someone other than the sender rejected");
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// Mnemonic Wallet Tests
+// 
+// This module contains tests for wallets derived from a BIP-39 mnemonic including:
+// - Deriving the same accounts from the same phrase
+// - Distinct addresses for distinct accounts
+// - Account 0 using the default derivation path
+// - Rejecting invalid phrases
+// - Harness wallets derived from a mnemonic against an external node
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    harness::{launch_wallets_with, NodeMode},
+    keys::{
+        account_derivation_path, signer_from_mnemonic, wallets_from_mnemonic,
+        DEFAULT_DERIVATION_PATH,
+    },
+};
+
+const PHRASE: &str = "test test test test test test test test test test test junk";
+
+// Test that a phrase always derives the same accounts
+#[tokio::test]
+async fn test_mnemonic_derivation_is_deterministic() -> Result<()> {
+    let first = signer_from_mnemonic(PHRASE, &account_derivation_path(3))?;
+    let second = signer_from_mnemonic(PHRASE, &account_derivation_path(3))?;
+    assert_eq!(first.address(), second.address());
+
+    // Surrounding whitespace, e.g. from a file, does not change the accounts
+    let padded = signer_from_mnemonic(&format!("  {}\n", PHRASE), &account_derivation_path(3))?;
+    assert_eq!(first.address(), padded.address());
+
+    Ok(())
+}
+
+// Test that every account of a phrase has its own address
+#[tokio::test]
+async fn test_mnemonic_accounts_are_distinct() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    let wallets = wallets_from_mnemonic(PHRASE, 5, provider)?;
+
+    let mut addresses: Vec<_> = wallets.iter().map(|wallet| wallet.address()).collect();
+    addresses.sort();
+    addresses.dedup();
+    assert_eq!(addresses.len(), 5);
+
+    Ok(())
+}
+
+// Test that account 0 is the default derivation path
+#[tokio::test]
+async fn test_first_account_uses_default_path() -> Result<()> {
+    assert_eq!(account_derivation_path(0), DEFAULT_DERIVATION_PATH);
+
+    let account = signer_from_mnemonic(PHRASE, &account_derivation_path(0))?;
+    let default = signer_from_mnemonic(PHRASE, DEFAULT_DERIVATION_PATH)?;
+    assert_eq!(account.address(), default.address());
+
+    Ok(())
+}
+
+// Test that an invalid phrase is rejected
+#[tokio::test]
+async fn test_invalid_mnemonic_is_rejected() -> Result<()> {
+    let result = signer_from_mnemonic("not a valid mnemonic phrase", DEFAULT_DERIVATION_PATH);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+// Test that the harness derives external node wallets from a mnemonic
+#[tokio::test]
+async fn test_external_node_wallets_from_mnemonic() -> Result<()> {
+    // Fund the first two accounts of the phrase at genesis
+    let coins = (0..2)
+        .map(|index| signer_from_mnemonic(PHRASE, &account_derivation_path(index)))
+        .collect::<Result<Vec<_>>>()?
+        .iter()
+        .flat_map(|signer| setup_single_asset_coins(signer.address(), AssetId::zeroed(), 1, 1_000))
+        .collect();
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let mode = NodeMode::External {
+        url: provider.url().to_string(),
+        wallet_keys: vec![],
+        mnemonic: Some(PHRASE.to_string()),
+    };
+    let wallets = launch_wallets_with(WalletsConfig::new(Some(2), None, None), &mode).await?;
+
+    assert_eq!(wallets.len(), 2);
+    for wallet in &wallets {
+        assert_eq!(wallet.get_asset_balance(&AssetId::zeroed()).await?, 1_000);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// Multi-Asset Routing Tests
+// 
+// This module contains tests for forwarding more than one asset in a single
+// transaction including:
+// - Forwarding the SRC20 token and the base asset together
+// - Router splitting the token into the vault and the base asset to a fee sink
+// - Balance checks on both destinations
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    deploy_cross_contract_call, deploy_src20_token, deploy_token_vault, launch_wallets,
+    route_token_and_base_asset, SUB_ID, TOKEN_AMOUNT,
+};
+
+// Test forwarding two different assets to the router in one transaction
+#[tokio::test]
+async fn test_router_splits_token_and_base_asset() -> Result<()> {
+    println!("Testing multi-asset routing...");
+
+    // Set up test wallets
+    let num_wallets = 3;
+    let coins_per_wallet = 2;
+    let amount_per_coin = 1_000_000_000;
+    let config = WalletsConfig::new(
+        Some(num_wallets),
+        Some(coins_per_wallet),
+        Some(amount_per_coin),
+    );
+
+    let mut wallets = launch_wallets(config).await?;
+
+    let admin_wallet = wallets.pop().unwrap();
+    let user_wallet = wallets.pop().unwrap();
+    let fee_sink_wallet = wallets.pop().unwrap();
+
+    // Deploy contracts
+    let token_contract = deploy_src20_token(
+        admin_wallet.clone(),
+        "ROUTETK",
+        "ROUTE",
+        9,
+    ).await?;
+
+    let router_contract = deploy_cross_contract_call(admin_wallet.clone()).await?;
+
+    let vault_contract = deploy_token_vault(
+        admin_wallet.clone(),
+        &router_contract,
+    ).await?;
+
+    // Mint tokens to the admin, who forwards them through the router
+    token_contract
+        .methods()
+        .mint(
+            Identity::Address(admin_wallet.address().into()),
+            Some(SUB_ID),
+            TOKEN_AMOUNT,
+        )
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    let token_asset_id = token_contract.methods().get_asset_id().call().await?.value;
+    let base_asset_id = *admin_wallet
+        .try_provider()?
+        .consensus_parameters()
+        .await?
+        .base_asset_id();
+
+    let fee_sink_base_before = fee_sink_wallet.get_asset_balance(&base_asset_id).await?;
+
+    let token_amount: u64 = 250_000;
+    let fee_amount: u64 = 1_000;
+    let user = Identity::Address(user_wallet.address().into());
+    let fee_sink = Identity::Address(fee_sink_wallet.address().into());
+
+    println!(
+        "Routing {} tokens to the vault and {} base asset to the fee sink...",
+        token_amount, fee_amount
+    );
+
+    let response = route_token_and_base_asset(
+        &admin_wallet,
+        &router_contract,
+        vault_contract.contract_id().clone(),
+        user,
+        fee_sink,
+        token_asset_id,
+        token_amount,
+        fee_amount,
+    )
+    .await?;
+
+    println!("✅ Routed in transaction: {:?}", response.tx_id);
+
+    // Token leg landed in the vault, credited to the user
+    let user_deposit = vault_contract.methods().get_deposit(user).call().await?.value;
+    assert_eq!(user_deposit, token_amount, "Vault should credit the routed tokens");
+
+    let vault_token_balance = admin_wallet
+        .try_provider()?
+        .get_contract_asset_balance(vault_contract.contract_id(), &token_asset_id)
+        .await?;
+    assert_eq!(vault_token_balance, token_amount, "Vault should hold the routed tokens");
+
+    // Base asset leg landed in the fee sink
+    let fee_sink_base_after = fee_sink_wallet.get_asset_balance(&base_asset_id).await?;
+    assert_eq!(
+        fee_sink_base_after - fee_sink_base_before,
+        fee_amount as u128,
+        "Fee sink should receive the routed base asset"
+    );
+
+    println!("✅ Multi-asset routing test passed");
+    Ok(())
+}
@@ -0,0 +1,151 @@
+// Multi Sub Id Tests
+// 
+// This module contains tests for minting the SRC20 token under several sub ids including:
+// - Each sub id yielding a distinct AssetId, computed on and off chain
+// - Independent supply tracking per asset through mints, burns and transfers
+// - Shared metadata for minted assets and none for unminted ones
+// - Rejecting mints without a sub id and burns of mismatched assets
+
+use std::collections::HashSet;
+
+use fuels::{
+    prelude::*,
+    types::{Bits256, Identity},
+};
+
+use rosetta_stone::{HarnessConfig, RevertReason, TestHarness, SUB_ID};
+
+const SUB_IDS: [Bits256; 3] = [SUB_ID, Bits256([1u8; 32]), Bits256([2u8; 32])];
+const AMOUNTS: [u64; 3] = [1_000, 2_000, 3_000];
+
+// Mints AMOUNTS[i] under SUB_IDS[i] to the first user
+async fn mint_all(harness: &TestHarness) -> Result<Vec<AssetId>> {
+    let mut asset_ids = Vec::new();
+    for (sub_id, amount) in SUB_IDS.into_iter().zip(AMOUNTS) {
+        asset_ids.push(harness.mint_sub_id_to(harness.user(0), sub_id, amount).await?);
+    }
+    Ok(asset_ids)
+}
+
+async fn supply(harness: &TestHarness, asset_id: AssetId) -> Result<Option<u64>> {
+    Ok(harness.token.methods().total_supply(asset_id).call().await?.value)
+}
+
+// Test that every sub id is its own asset with its own supply
+#[tokio::test]
+async fn test_sub_ids_yield_distinct_assets() -> Result<()> {
+    println!("Testing minting under several sub ids...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let asset_ids = mint_all(&harness).await?;
+
+    // The default sub id is the harness asset
+    assert_eq!(asset_ids[0], harness.asset_id);
+    let distinct: HashSet<_> = asset_ids.iter().collect();
+    assert_eq!(distinct.len(), SUB_IDS.len());
+
+    for ((sub_id, asset_id), amount) in SUB_IDS.into_iter().zip(&asset_ids).zip(AMOUNTS) {
+        let on_chain = harness.token.methods().get_asset_id_for(sub_id).call().await?.value;
+        assert_eq!(on_chain, *asset_id);
+        assert_eq!(user.get_asset_balance(asset_id).await?, u128::from(amount));
+        assert_eq!(supply(&harness, *asset_id).await?, Some(amount));
+        println!("   {}: supply {}", asset_id, amount);
+    }
+
+    let total_assets = harness.token.methods().total_assets().call().await?.value;
+    assert_eq!(total_assets, SUB_IDS.len() as u64);
+
+    // Minted assets share the token's metadata; unminted ones have none
+    let decimals = harness.token.methods().decimals(asset_ids[2]).call().await?.value;
+    assert_eq!(decimals, Some(9));
+    let unminted = harness.asset_id_for(Bits256([9u8; 32]));
+    assert_eq!(supply(&harness, unminted).await?, None);
+    assert_eq!(harness.token.methods().name(unminted).call().await?.value, None);
+
+    println!("✅ {} sub ids minted as distinct assets", SUB_IDS.len());
+    Ok(())
+}
+
+// Test that burns and transfers only touch the asset they move
+#[tokio::test]
+async fn test_supplies_are_independent() -> Result<()> {
+    println!("Testing independent supplies...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (alice, bob) = (harness.user(0), harness.user(1));
+    let asset_ids = mint_all(&harness).await?;
+
+    // Burning part of the second asset leaves the others alone
+    let burned = 500;
+    harness
+        .token_as(alice)
+        .methods()
+        .burn(SUB_IDS[1], burned)
+        .call_params(
+            CallParameters::default()
+                .with_amount(burned)
+                .with_asset_id(asset_ids[1]),
+        )?
+        .call()
+        .await?;
+    assert_eq!(supply(&harness, asset_ids[0]).await?, Some(AMOUNTS[0]));
+    assert_eq!(supply(&harness, asset_ids[1]).await?, Some(AMOUNTS[1] - burned));
+    assert_eq!(supply(&harness, asset_ids[2]).await?, Some(AMOUNTS[2]));
+    println!("✅ Burned {} of the second asset only", burned);
+
+    // Minting more of one asset only grows that one
+    harness.mint_sub_id_to(bob, SUB_IDS[2], 100).await?;
+    assert_eq!(supply(&harness, asset_ids[2]).await?, Some(AMOUNTS[2] + 100));
+    assert_eq!(supply(&harness, asset_ids[1]).await?, Some(AMOUNTS[1] - burned));
+
+    // Transfers move balances, not supply
+    alice.transfer(bob.address(), 250, asset_ids[0], TxPolicies::default()).await?;
+    assert_eq!(bob.get_asset_balance(&asset_ids[0]).await?, 250);
+    assert_eq!(supply(&harness, asset_ids[0]).await?, Some(AMOUNTS[0]));
+    println!("✅ Supplies tracked per asset");
+
+    Ok(())
+}
+
+// Test the sub id checks on mint and burn
+#[tokio::test]
+async fn test_sub_id_checks() -> Result<()> {
+    println!("Testing sub id checks...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let alice = harness.user(0);
+    let asset_ids = mint_all(&harness).await?;
+
+    let error = harness
+        .token
+        .methods()
+        .mint(Identity::Address(alice.address().into()), None, 1)
+        .call()
+        .await
+        .err()
+        .expect("minting without a sub id must revert");
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::IncorrectSubId));
+    println!("❌ Mint without a sub id rejected");
+
+    // Forwarding the second asset while naming the third
+    let error = harness
+        .token_as(alice)
+        .methods()
+        .burn(SUB_IDS[2], 100)
+        .call_params(
+            CallParameters::default()
+                .with_amount(100)
+                .with_asset_id(asset_ids[1]),
+        )?
+        .call()
+        .await
+        .err()
+        .expect("burning a mismatched asset must revert");
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::IncorrectAsset));
+    assert_eq!(supply(&harness, asset_ids[1]).await?, Some(AMOUNTS[1]));
+    assert_eq!(supply(&harness, asset_ids[2]).await?, Some(AMOUNTS[2]));
+    println!("❌ Burn of a mismatched asset rejected");
+
+    Ok(())
+}
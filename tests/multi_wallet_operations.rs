@@ -6,54 +6,9 @@
 // - Multi-wallet balance management
 // - Complex wallet interactions
 
-use fuels::{
-    accounts::signers::private_key::PrivateKeySigner,
-    prelude::*,
-    types::{Bits256, Identity, SizedAsciiString},
-};
-
-use fuels::accounts::wallet::Unlocked;
-
-// Load abi from json
-abigen!(
-    Contract(
-        name = "Src20Token",
-        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
-    ),
-);
-
-// Common test constants
-const TOKEN_AMOUNT: u64 = 1_000_000;
-const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
-const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
-
-// Deploys the SRC20 token contract with the given wallet and metadata
-async fn deploy_src20_token(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let name_bytes: SizedAsciiString<7> = name.try_into()?;
-    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
-
-    let configurables = Src20TokenConfigurables::default()
-        .with_NAME(name_bytes.clone())?
-        .with_SYMBOL(symbol_bytes.clone())?
-        .with_DECIMALS(decimals)?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/src20-token/out/debug/src20_token.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ Token '{}' ({}) deployed at: {}", name, symbol, contract_id.to_string());
-    Ok(Src20Token::new(contract_id, wallet))
-}
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{deploy_src20_token, launch_wallets, Src20Token, SUB_ID, TOKEN_AMOUNT};
 
 // Test minting tokens to multiple users and transferring between them
 #[tokio::test]
@@ -70,8 +25,7 @@ async fn test_multi_wallet_interactions() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallets = wallets;
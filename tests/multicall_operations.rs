@@ -0,0 +1,93 @@
+// Multicall Operations Tests
+// 
+// This module contains tests for batching contract calls including:
+// - Bundling calls to several contracts into one transaction
+// - Decoding each return value from a single multi-call response
+// - Mixing state-changing calls with reads in the same batch
+
+use fuels::{
+    prelude::*,
+    programs::calls::CallHandler,
+    types::{AssetId, Identity},
+};
+
+use rosetta_stone::{HarnessConfig, TestHarness, SUB_ID, TOKEN_AMOUNT};
+
+// Test that mint, token reads and a vault read run in one transaction
+#[tokio::test]
+async fn test_multicall_mint_and_reads() -> Result<()> {
+    println!("Testing multicall mint and reads...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+
+    let mint_call = harness
+        .token
+        .methods()
+        .mint(user_identity, Some(SUB_ID), TOKEN_AMOUNT);
+    let supply_call = harness.token.methods().total_supply(harness.asset_id);
+    let asset_id_call = harness.token.methods().get_asset_id();
+    let deposit_call = harness.vault.methods().get_deposit(user_identity);
+
+    // Calls execute in order, so total_supply already sees the mint
+    let response = CallHandler::new_multi_call(harness.admin.clone())
+        .add_call(mint_call)
+        .add_call(supply_call)
+        .add_call(asset_id_call)
+        .add_call(deposit_call)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call::<((), Option<u64>, AssetId, u64)>()
+        .await?;
+
+    let ((), total_supply, asset_id, deposit) = response.value;
+    assert_eq!(total_supply, Some(TOKEN_AMOUNT));
+    assert_eq!(asset_id, harness.asset_id);
+    assert_eq!(deposit, 0);
+    assert_eq!(harness.token_balance(user).await?, TOKEN_AMOUNT as u128);
+
+    println!("✅ Multicall mint and reads test passed");
+    println!("   Total supply: {:?}", total_supply);
+    println!("   Asset ID: {:?}", asset_id);
+    Ok(())
+}
+
+// Test a deposit and the follow-up reads batched by the depositing user
+#[tokio::test]
+async fn test_multicall_deposit_and_reads() -> Result<()> {
+    println!("Testing multicall deposit and reads...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+
+    harness.mint_to(user, TOKEN_AMOUNT).await?;
+
+    let deposit_amount = 250_000;
+    let vault = harness.vault_as(user);
+    let deposit_call = vault.methods().deposit().call_params(
+        CallParameters::default()
+            .with_amount(deposit_amount)
+            .with_asset_id(harness.asset_id),
+    )?;
+    let user_deposit_call = vault.methods().get_deposit(user_identity);
+    let total_deposits_call = vault.methods().get_total_deposits(harness.asset_id);
+
+    let response = CallHandler::new_multi_call(user.clone())
+        .add_call(deposit_call)
+        .add_call(user_deposit_call)
+        .add_call(total_deposits_call)
+        .call::<((), u64, u64)>()
+        .await?;
+
+    let ((), user_deposit, total_deposits) = response.value;
+    assert_eq!(user_deposit, deposit_amount);
+    assert_eq!(total_deposits, deposit_amount);
+    assert_eq!(
+        harness.token_balance(user).await?,
+        (TOKEN_AMOUNT - deposit_amount) as u128
+    );
+
+    println!("✅ Multicall deposit and reads test passed");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+// Multisig Threshold Matrix Tests
+// 
+// This module contains tests for the multi-sig predicate across thresholds including:
+// - 1-of-3, 2-of-3 and 3-of-3 configurations
+// - Exactly the threshold signing, which spends
+// - One signer short of the threshold, which is rejected and leaves the coins in place
+// - More signers than required, which still spends
+//
+// Signers sign in the order of the predicate's SIGNERS list.
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    fees::fee_from_status,
+    predicates::{base_balance, funded_multisig, spend_from_predicate},
+    TestWallet,
+};
+
+const FUND_AMOUNT: u64 = 500_000;
+const SPEND_AMOUNT: u64 = 300_000;
+
+// Spends SPEND_AMOUNT signed by the first `signing` signers and checks both balances
+async fn spend(
+    signers: &[TestWallet],
+    recipient: &TestWallet,
+    predicate: &Predicate,
+    signing: usize,
+) -> Result<bool> {
+    let predicate_before = base_balance(predicate).await?;
+    let recipient_before = base_balance(recipient).await?;
+    let signing: Vec<&TestWallet> = signers.iter().take(signing).collect();
+
+    let result =
+        spend_from_predicate(predicate, &signing, recipient.address().into(), SPEND_AMOUNT).await;
+    match result {
+        Ok(status) => {
+            let fee = fee_from_status(&status)? as u128;
+            assert_eq!(
+                base_balance(predicate).await?,
+                predicate_before - u128::from(SPEND_AMOUNT) - fee
+            );
+            assert_eq!(
+                base_balance(recipient).await?,
+                recipient_before + u128::from(SPEND_AMOUNT)
+            );
+            Ok(true)
+        }
+        Err(_) => {
+            assert_eq!(base_balance(predicate).await?, predicate_before);
+            assert_eq!(base_balance(recipient).await?, recipient_before);
+            Ok(false)
+        }
+    }
+}
+
+// Checks the threshold-1 and threshold boundaries of a `required`-of-3 predicate
+async fn run_threshold_case(required: u64) -> Result<()> {
+    println!("Testing a {}-of-3 multi-sig predicate...", required);
+
+    let (signers, recipient, predicate) = funded_multisig(required, FUND_AMOUNT).await?;
+    let required = required as usize;
+
+    assert!(!spend(&signers, &recipient, &predicate, required - 1).await?);
+    println!("❌ {} of {} signatures rejected", required - 1, required);
+
+    assert!(spend(&signers, &recipient, &predicate, required).await?);
+    println!("✅ {} of {} signatures spent {}", required, required, SPEND_AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_one_of_three() -> Result<()> {
+    run_threshold_case(1).await
+}
+
+#[tokio::test]
+async fn test_two_of_three() -> Result<()> {
+    run_threshold_case(2).await
+}
+
+#[tokio::test]
+async fn test_three_of_three() -> Result<()> {
+    run_threshold_case(3).await
+}
+
+// Test that signatures beyond the threshold do not invalidate the spend
+#[tokio::test]
+async fn test_more_signers_than_required() -> Result<()> {
+    println!("Testing a 1-of-3 predicate signed by all three...");
+
+    let (signers, recipient, predicate) = funded_multisig(1, FUND_AMOUNT).await?;
+    assert!(spend(&signers, &recipient, &predicate, 3).await?);
+    println!("✅ 3 signatures spent from a 1-of-3 predicate");
+
+    Ok(())
+}
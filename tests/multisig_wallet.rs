@@ -0,0 +1,208 @@
+// Multisig Wallet Tests
+// 
+// This module contains tests for the contract-based multisig wallet including:
+// - Proposals created and approved by separate owner wallets
+// - Counting approvals once per owner up to the threshold
+// - Rejecting proposals and approvals from non-owners
+// - Replay protection: a proposal executes once and approvals don't carry over
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{deploy_multisig_wallet, HarnessConfig, MultisigWallet, TestHarness};
+
+const THRESHOLD: u64 = 2;
+const WALLET_FUNDS: u64 = 10_000;
+const PAYOUT: u64 = 1_500;
+
+struct Setup {
+    harness: TestHarness,
+    multisig: MultisigWallet<Wallet<Unlocked<PrivateKeySigner>>>,
+}
+
+impl Setup {
+    // Users 0, 1 and 2 own a 2-of-3 multisig funded with the harness token;
+    // user 3 is an outsider and the payee.
+    async fn new() -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default().with_num_users(4)).await?;
+        let owners = [0, 1, 2].map(|i| Identity::Address(harness.user(i).address().into()));
+        let multisig = deploy_multisig_wallet(harness.admin.clone(), owners, THRESHOLD).await?;
+
+        harness.mint_to(&harness.admin, WALLET_FUNDS).await?;
+        multisig
+            .methods()
+            .fund()
+            .call_params(
+                CallParameters::default()
+                    .with_amount(WALLET_FUNDS)
+                    .with_asset_id(harness.asset_id),
+            )?
+            .call()
+            .await?;
+
+        Ok(Self { harness, multisig })
+    }
+
+    fn multisig_as(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    ) -> MultisigWallet<Wallet<Unlocked<PrivateKeySigner>>> {
+        self.multisig.clone().with_account(wallet.clone())
+    }
+
+    fn payee(&self) -> Identity {
+        Identity::Address(self.harness.user(3).address().into())
+    }
+
+    async fn propose(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        amount: u64,
+    ) -> Result<u64> {
+        Ok(self
+            .multisig_as(wallet)
+            .methods()
+            .propose(self.payee(), self.harness.asset_id, amount)
+            .call()
+            .await?
+            .value)
+    }
+
+    async fn approve(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>, id: u64) -> Result<()> {
+        self.multisig_as(wallet).methods().approve(id).call().await?;
+        Ok(())
+    }
+
+    // Anyone may execute; the outsider does so here
+    async fn execute(&self, id: u64) -> Result<()> {
+        self.multisig_as(self.harness.user(3))
+            .methods()
+            .execute(id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn approvals(&self, id: u64) -> Result<u64> {
+        let proposal = self.multisig.methods().proposal(id).call().await?.value;
+        Ok(proposal.expect("proposal should exist").approvals)
+    }
+}
+
+// Test that approvals are counted once per owner and gate execution
+#[tokio::test]
+async fn test_multisig_approval_counting() -> Result<()> {
+    println!("Testing multisig approval counting...");
+
+    let setup = Setup::new().await?;
+    let (alice, bob, carol) = (setup.harness.user(0), setup.harness.user(1), setup.harness.user(2));
+
+    let id = setup.propose(alice, PAYOUT).await?;
+    assert_eq!(setup.multisig.methods().proposal_count().call().await?.value, 1);
+    assert_eq!(setup.approvals(id).await?, 0);
+
+    let result = setup.execute(id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Not enough approvals"));
+
+    setup.approve(alice, id).await?;
+    assert_eq!(setup.approvals(id).await?, 1);
+
+    let result = setup.approve(alice, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Already approved"));
+    assert_eq!(setup.approvals(id).await?, 1);
+    println!("✅ A second approval from the same owner is rejected");
+
+    let result = setup.execute(id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Not enough approvals"));
+
+    setup.approve(carol, id).await?;
+    assert_eq!(setup.approvals(id).await?, THRESHOLD);
+
+    let owner_of = |wallet: &Wallet<Unlocked<PrivateKeySigner>>| {
+        Identity::Address(wallet.address().into())
+    };
+    for (wallet, approved) in [(alice, true), (bob, false), (carol, true)] {
+        let value = setup
+            .multisig
+            .methods()
+            .has_approved(id, owner_of(wallet))
+            .call()
+            .await?
+            .value;
+        assert_eq!(value, approved);
+    }
+    println!("✅ {} of 3 owners approved", THRESHOLD);
+
+    setup.execute(id).await?;
+    assert_eq!(setup.harness.token_balance(setup.harness.user(3)).await?, PAYOUT as u128);
+    assert_eq!(
+        setup.multisig.methods().balance(setup.harness.asset_id).call().await?.value,
+        WALLET_FUNDS - PAYOUT
+    );
+    println!("✅ Proposal executed, {} paid out", PAYOUT);
+
+    Ok(())
+}
+
+// Test that only owners propose and approve
+#[tokio::test]
+async fn test_multisig_rejects_non_owners() -> Result<()> {
+    let setup = Setup::new().await?;
+    let (alice, outsider) = (setup.harness.user(0), setup.harness.user(3));
+
+    let result = setup.propose(outsider, PAYOUT).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unauthorized"));
+
+    let id = setup.propose(alice, PAYOUT).await?;
+    let result = setup.approve(outsider, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unauthorized"));
+    let result = setup.approve(&setup.harness.admin, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unauthorized"));
+    assert_eq!(setup.approvals(id).await?, 0);
+
+    Ok(())
+}
+
+// Test that an executed proposal cannot be replayed or re-approved
+#[tokio::test]
+async fn test_multisig_replay_protection() -> Result<()> {
+    println!("Testing multisig replay protection...");
+
+    let setup = Setup::new().await?;
+    let (alice, bob, carol) = (setup.harness.user(0), setup.harness.user(1), setup.harness.user(2));
+
+    let id = setup.propose(alice, PAYOUT).await?;
+    setup.approve(alice, id).await?;
+    setup.approve(bob, id).await?;
+    setup.execute(id).await?;
+
+    let result = setup.execute(id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Proposal already executed"));
+    let result = setup.approve(carol, id).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Proposal already executed"));
+    assert_eq!(setup.harness.token_balance(setup.harness.user(3)).await?, PAYOUT as u128);
+    println!("✅ Executed proposal cannot run again");
+
+    // An identical proposal needs its own approvals
+    let again = setup.propose(alice, PAYOUT).await?;
+    assert_ne!(again, id);
+    let result = setup.execute(again).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Not enough approvals"));
+    println!("✅ Approvals do not carry over to a new proposal");
+
+    // More than the wallet holds
+    let too_much = setup.propose(alice, WALLET_FUNDS).await?;
+    setup.approve(alice, too_much).await?;
+    setup.approve(bob, too_much).await?;
+    let result = setup.execute(too_much).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Insufficient balance"));
+
+    let result = setup.execute(99).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unknown proposal"));
+
+    Ok(())
+}
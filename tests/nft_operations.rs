@@ -0,0 +1,105 @@
+// NFT Operations Tests
+// 
+// This module contains tests for the one-of-one NFT collection including:
+// - Minting several NFTs under distinct sub ids
+// - Total supply of exactly 1 per asset
+// - Rejecting duplicate sub ids and amounts other than 1
+// - Transferring NFTs between wallets
+
+use fuels::{
+    prelude::*,
+    tx::ContractIdExt,
+    types::{Bits256, Identity, SubAssetId},
+};
+
+use rosetta_stone::{deploy_nft, HarnessConfig, TestHarness};
+
+// Test minting several NFTs and moving one between wallets
+#[tokio::test]
+async fn test_mint_and_transfer_nfts() -> Result<()> {
+    println!("Testing NFT minting...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let alice = harness.user(0);
+    let bob = harness.user(1);
+    let alice_identity = Identity::Address(alice.address().into());
+
+    let nft = deploy_nft(harness.admin.clone(), "ROSETTA", "RSNFT").await?;
+    let contract_id = nft.contract_id().clone();
+
+    let mut asset_ids = Vec::new();
+    for token_id in 1..=3u8 {
+        let sub_id = [token_id; 32];
+        nft.methods()
+            .mint(alice_identity, Some(Bits256(sub_id)), 1)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        asset_ids.push(contract_id.asset_id(&SubAssetId::from(sub_id)));
+    }
+
+    assert_eq!(nft.methods().total_assets().call().await?.value, 3);
+    for asset_id in &asset_ids {
+        assert_eq!(nft.methods().total_supply(*asset_id).call().await?.value, Some(1));
+        assert_eq!(nft.methods().decimals(*asset_id).call().await?.value, Some(0));
+        assert_eq!(alice.get_asset_balance(asset_id).await?, 1);
+    }
+    println!("✅ Minted {} NFTs with supply 1 each", asset_ids.len());
+
+    // Transfer the second NFT to bob
+    alice
+        .transfer(bob.address(), 1, asset_ids[1], TxPolicies::default())
+        .await?;
+    assert_eq!(alice.get_asset_balance(&asset_ids[1]).await?, 0);
+    assert_eq!(bob.get_asset_balance(&asset_ids[1]).await?, 1);
+    assert_eq!(alice.get_asset_balance(&asset_ids[0]).await?, 1);
+
+    // Transfers do not change supply
+    assert_eq!(nft.methods().total_supply(asset_ids[1]).call().await?.value, Some(1));
+
+    println!("✅ NFT mint and transfer test passed");
+    Ok(())
+}
+
+// Test that a sub id can only be minted once, with an amount of 1
+#[tokio::test]
+async fn test_nft_uniqueness() -> Result<()> {
+    println!("Testing NFT uniqueness...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let recipient = Identity::Address(harness.user(0).address().into());
+    let nft = deploy_nft(harness.admin.clone(), "ROSETTA", "RSNFT").await?;
+    let sub_id = Bits256([7u8; 32]);
+
+    println!("❌ Minting an amount other than 1 (should fail)...");
+    let result = nft
+        .methods()
+        .mint(recipient, Some(sub_id), 2)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+
+    nft.methods()
+        .mint(recipient, Some(sub_id), 1)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    println!("❌ Minting the same sub id twice (should fail)...");
+    let result = nft
+        .methods()
+        .mint(recipient, Some(sub_id), 1)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Sub Id already minted"));
+
+    let asset_id = nft.contract_id().asset_id(&SubAssetId::from([7u8; 32]));
+    assert_eq!(nft.methods().total_supply(asset_id).call().await?.value, Some(1));
+    assert_eq!(harness.user(0).get_asset_balance(&asset_id).await?, 1);
+
+    println!("✅ NFT uniqueness test passed");
+    Ok(())
+}
@@ -0,0 +1,165 @@
+// Node Tuning Tests
+// 
+// This module contains tests for launching the harness node with custom settings including:
+// - A slower block interval: blocks produced on a timer and transactions waiting for the next one
+// - A higher starting gas price raising the fee of the same transfer
+// - A larger max transaction size accepting transactions the default limit rejects
+
+use std::time::Duration;
+
+use fuels::{
+    prelude::*,
+    types::{transaction::Transaction, tx_status::TxStatus, Address},
+};
+
+use rosetta_stone::{
+    fees::fee_of, queries::transaction_by_id, HarnessConfig, NodeMode, TestHarness, TestWallet,
+};
+
+const BLOCK_TIME: Duration = Duration::from_secs(2);
+const TRANSFER_AMOUNT: u64 = 1_000;
+
+// Node settings only apply to a node the harness launches itself
+fn local_config() -> HarnessConfig {
+    HarnessConfig::default().with_node(NodeMode::Local)
+}
+
+// A base asset transfer from `wallet` to `to`, padded with `padding` bytes of script data
+async fn transfer_tx(
+    wallet: &TestWallet,
+    to: Address,
+    padding: usize,
+) -> Result<ScriptTransaction> {
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, TRANSFER_AMOUNT.into(), None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(to, base_asset_id, TRANSFER_AMOUNT);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default())
+        .with_script_data(vec![0u8; padding]);
+    wallet.adjust_for_fee(&mut tb, TRANSFER_AMOUNT.into()).await?;
+    wallet.add_witnesses(&mut tb)?;
+
+    tb.build(provider).await
+}
+
+// Test block production on a timer instead of one block per transaction
+#[tokio::test]
+async fn test_slow_block_interval() -> Result<()> {
+    println!("Testing a {:?} block interval...", BLOCK_TIME);
+
+    let harness = TestHarness::new(local_config().with_block_time(BLOCK_TIME)).await?;
+    let provider = &harness.provider;
+
+    // Blocks keep coming without any transactions, BLOCK_TIME apart
+    let start = provider.latest_block_height().await?;
+    tokio::time::sleep(BLOCK_TIME * 3).await;
+    let end = provider.latest_block_height().await?;
+    assert!(end >= start + 2, "only {} blocks in {:?}", end - start, BLOCK_TIME * 3);
+
+    let block_time = |height: u32| async move {
+        let block = provider.block_by_height(height.into()).await?;
+        let time = block.and_then(|block| block.header.time);
+        Ok::<_, Error>(time.expect("block should have a time"))
+    };
+    let gap = block_time(end).await? - block_time(end - 1).await?;
+    assert!(gap.num_milliseconds() >= 1_000, "blocks only {} apart", gap);
+    println!("✅ {} empty blocks produced, {} apart", end - start, gap);
+
+    // A submitted transfer stays pending until the next block
+    let chain_id = provider.consensus_parameters().await?.chain_id();
+    let tx = transfer_tx(harness.user(0), harness.user(1).address(), 0).await?;
+    let tx_id = tx.id(chain_id);
+    let submitted_at = provider.latest_block_height().await?;
+    provider.send_transaction(tx).await?;
+    let status = provider.tx_status(&tx_id).await?;
+    assert!(matches!(status, TxStatus::Submitted { .. }), "{:?}", status);
+    println!("✅ Transfer pending after submission");
+
+    let mut attempts = 0;
+    while matches!(provider.tx_status(&tx_id).await?, TxStatus::Submitted { .. }) {
+        attempts += 1;
+        assert!(attempts < 50, "transfer not committed within {:?}", BLOCK_TIME * 5);
+        tokio::time::sleep(BLOCK_TIME / 10).await;
+    }
+    let committed = transaction_by_id(provider, &tx_id).await?.expect("transfer should exist");
+    let height = committed.block_height.expect("transfer should be in a block");
+    assert!(height > submitted_at, "included at {}, submitted at {}", height, submitted_at);
+    println!("✅ Transfer committed in block {} after submission at {}", height, submitted_at);
+
+    Ok(())
+}
+
+// Test that the starting gas price feeds into the fees charged
+#[tokio::test]
+async fn test_starting_gas_price() -> Result<()> {
+    println!("Testing a higher starting gas price...");
+
+    let default = TestHarness::new(local_config()).await?;
+    let default_price = default.provider.latest_gas_price().await?.gas_price;
+    let starting_price = (default_price + 1) * 10;
+    let config = local_config().with_starting_gas_price(starting_price);
+    let tuned = TestHarness::new(config).await?;
+
+    let mut fees = vec![];
+    for harness in [&default, &tuned] {
+        let chain_id = harness.provider.consensus_parameters().await?.chain_id();
+        let tx = transfer_tx(harness.user(0), harness.user(1).address(), 0).await?;
+        let tx_id = tx.id(chain_id);
+        harness.provider.send_transaction_and_await_commit(tx).await?.check(None)?;
+        fees.push(fee_of(&harness.provider, &tx_id).await?);
+    }
+    let gas_prices = (
+        default.provider.latest_gas_price().await?.gas_price,
+        tuned.provider.latest_gas_price().await?.gas_price,
+    );
+    assert!(gas_prices.1 > gas_prices.0, "gas prices {:?}", gas_prices);
+    assert!(fees[1] > fees[0], "fees {:?}", fees);
+    println!(
+        "✅ Same transfer cost {} instead of {} starting at gas price {}",
+        fees[1], fees[0], starting_price
+    );
+
+    Ok(())
+}
+
+// Test that the max transaction size is enforced as configured
+#[tokio::test]
+async fn test_max_tx_size() -> Result<()> {
+    println!("Testing a larger max transaction size...");
+
+    let default = TestHarness::new(local_config()).await?;
+    let default_max = default.provider.consensus_parameters().await?.tx_params().max_size();
+    // The limit survives a chain config set after it
+    let config = local_config()
+        .with_max_tx_size(default_max * 2)
+        .with_chain_config(ChainConfig::local_testnet());
+    let tuned = TestHarness::new(config).await?;
+    let tuned_max = tuned.provider.consensus_parameters().await?.tx_params().max_size();
+    assert_eq!(tuned_max, default_max * 2);
+
+    // Padding just over the default limit
+    let padding = default_max as usize + 1_024;
+
+    let result = async {
+        let tx = transfer_tx(default.user(0), default.user(1).address(), padding).await?;
+        default.provider.send_transaction_and_await_commit(tx).await
+    }
+    .await;
+    assert!(result.is_err(), "{:?}", result);
+    println!("❌ Transaction over {} bytes rejected by default", default_max);
+
+    let recipient = tuned.user(1);
+    let before = recipient.get_asset_balance(&AssetId::zeroed()).await?;
+    let tx = transfer_tx(tuned.user(0), recipient.address(), padding).await?;
+    tuned.provider.send_transaction_and_await_commit(tx).await?.check(None)?;
+    assert_eq!(
+        recipient.get_asset_balance(&AssetId::zeroed()).await?,
+        before + u128::from(TRANSFER_AMOUNT)
+    );
+    println!("✅ Same size accepted with a {} byte limit", tuned_max);
+
+    Ok(())
+}
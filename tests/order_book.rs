@@ -0,0 +1,228 @@
+// Order Book Tests
+// 
+// This module contains tests for partially filled orders including:
+// - A maker posting an order and several takers filling parts of it across transactions
+// - The remaining size and the maker's proceeds after every fill
+// - Fills rounding down, and fills too small or larger than what is left being rejected
+// - The maker claiming proceeds and cancelling the unfilled remainder
+
+use fuels::{
+    prelude::*,
+    programs::responses::CallResponse,
+    types::{Bits256, Identity},
+};
+
+use rosetta_stone::{
+    deploy_order_book, expect_single_event, HarnessConfig, Order, OrderBook, OrderFilledEvent,
+    RevertReason, TestHarness, TestWallet,
+};
+
+// The maker sells SELL_AMOUNT of the harness token for BUY_AMOUNT of the quote token
+const SELL_AMOUNT: u64 = 1_000;
+const BUY_AMOUNT: u64 = 4_000;
+const QUOTE_SUB_ID: Bits256 = Bits256([9u8; 32]);
+
+struct Market {
+    harness: TestHarness,
+    book: OrderBook<TestWallet>,
+    quote: AssetId,
+}
+
+impl Market {
+    // User 0 makes and holds the harness token; users 1 and 2 take and hold the quote token
+    async fn new() -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+        harness.mint_to(harness.user(0), SELL_AMOUNT * 2).await?;
+        for taker in [harness.user(1), harness.user(2)] {
+            harness.mint_sub_id_to(taker, QUOTE_SUB_ID, BUY_AMOUNT).await?;
+        }
+        let quote = harness.asset_id_for(QUOTE_SUB_ID);
+        let book = deploy_order_book(harness.admin.clone()).await?;
+        Ok(Self {
+            harness,
+            book,
+            quote,
+        })
+    }
+
+    fn maker(&self) -> &TestWallet {
+        self.harness.user(0)
+    }
+
+    fn book_as(&self, wallet: &TestWallet) -> OrderBook<TestWallet> {
+        self.book.clone().with_account(wallet.clone())
+    }
+
+    async fn post(&self) -> Result<u64> {
+        Ok(self
+            .book_as(self.maker())
+            .methods()
+            .post_order(self.quote, BUY_AMOUNT)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(SELL_AMOUNT)
+                    .with_asset_id(self.harness.asset_id),
+            )?
+            .call()
+            .await?
+            .value)
+    }
+
+    async fn fill(&self, taker: &TestWallet, id: u64, paid: u64) -> Result<CallResponse<u64>> {
+        self.book_as(taker)
+            .methods()
+            .fill(id)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(paid)
+                    .with_asset_id(self.quote),
+            )?
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await
+    }
+
+    async fn fill_error(&self, taker: &TestWallet, id: u64, paid: u64) -> Option<RevertReason> {
+        let error = self.fill(taker, id, paid).await.err().expect("the fill must revert");
+        RevertReason::from_error(&error)
+    }
+
+    async fn order(&self, id: u64) -> Result<Order> {
+        let order = self.book.methods().order(id).call().await?.value;
+        Ok(order.expect("order should exist"))
+    }
+}
+
+// Test several takers filling one order in parts
+#[tokio::test]
+async fn test_partial_fills() -> Result<()> {
+    println!("Testing partial fills across transactions...");
+
+    let market = Market::new().await?;
+    let id = market.post().await?;
+    let order = market.order(id).await?;
+    assert_eq!(order.maker, Identity::Address(market.maker().address().into()));
+    assert_eq!((order.remaining, order.proceeds), (SELL_AMOUNT, 0));
+    println!("✅ Order {} posted: {} for {}", id, SELL_AMOUNT, BUY_AMOUNT);
+
+    // (taker, paid, received, remaining after the fill) at 4 quote per token
+    let fills = [(1, 1_200, 300, 700), (2, 2_000, 500, 200), (1, 800, 200, 0)];
+    let mut proceeds = 0;
+    for (taker, paid, received, remaining) in fills {
+        let taker = market.harness.user(taker);
+        let tokens_before = market.harness.token_balance(taker).await?;
+
+        let response = market.fill(taker, id, paid).await?;
+        assert_eq!(response.value, received);
+        let event: OrderFilledEvent = expect_single_event(&response)?;
+        assert_eq!((event.paid, event.received, event.remaining), (paid, received, remaining));
+
+        proceeds += paid;
+        let order = market.order(id).await?;
+        assert_eq!(order.remaining, remaining);
+        assert_eq!((order.proceeds, order.unclaimed), (proceeds, proceeds));
+        assert_eq!(
+            market.harness.token_balance(taker).await?,
+            tokens_before + u128::from(received)
+        );
+        println!("✅ Paid {} for {}; {} left, proceeds {}", paid, received, remaining, proceeds);
+    }
+    assert_eq!(proceeds, BUY_AMOUNT);
+
+    let error = market.fill_error(market.harness.user(2), id, 4).await;
+    assert_eq!(
+        error,
+        Some(RevertReason::Require("Fill exceeds remaining size".to_string()))
+    );
+    println!("❌ Fill of a fully filled order rejected");
+
+    let claimed = market
+        .book_as(market.maker())
+        .methods()
+        .claim_proceeds(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(claimed, BUY_AMOUNT);
+    assert_eq!(
+        market.maker().get_asset_balance(&market.quote).await?,
+        u128::from(BUY_AMOUNT)
+    );
+    assert_eq!(market.order(id).await?.unclaimed, 0);
+    println!("✅ Maker claimed {} in proceeds", claimed);
+
+    Ok(())
+}
+
+// Test rounding and fills the order cannot take
+#[tokio::test]
+async fn test_fill_limits() -> Result<()> {
+    println!("Testing fill limits...");
+
+    let market = Market::new().await?;
+    let id = market.post().await?;
+    let taker = market.harness.user(1);
+
+    let error = market.fill_error(taker, id, 3).await;
+    assert_eq!(error, Some(RevertReason::Require("Fill too small".to_string())));
+    println!("❌ Fill worth less than one token rejected");
+
+    // 7 quote buys 1 token; the extra 3 stay with the maker's proceeds
+    assert_eq!(market.fill(taker, id, 7).await?.value, 1);
+    let order = market.order(id).await?;
+    assert_eq!((order.remaining, order.proceeds), (SELL_AMOUNT - 1, 7));
+    println!("✅ Fill rounded down in the maker's favour");
+
+    // User 2 can pay the full price, for more than is left
+    let error = market.fill_error(market.harness.user(2), id, BUY_AMOUNT).await;
+    assert_eq!(
+        error,
+        Some(RevertReason::Require("Fill exceeds remaining size".to_string()))
+    );
+    assert_eq!(market.order(id).await?.remaining, SELL_AMOUNT - 1);
+    println!("❌ Fill larger than the remaining size rejected");
+
+    Ok(())
+}
+
+// Test cancelling the unfilled part of an order
+#[tokio::test]
+async fn test_cancel_remainder() -> Result<()> {
+    println!("Testing order cancellation...");
+
+    let market = Market::new().await?;
+    let id = market.post().await?;
+    let taker = market.harness.user(1);
+    market.fill(taker, id, 1_000).await?;
+
+    let book = market.book_as(taker);
+    assert!(book.methods().cancel(id).call().await.is_err());
+    assert!(book.methods().claim_proceeds(id).call().await.is_err());
+    println!("❌ Taker cannot cancel the order or claim its proceeds");
+
+    let maker_before = market.harness.token_balance(market.maker()).await?;
+    market
+        .book_as(market.maker())
+        .methods()
+        .cancel(id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(
+        market.harness.token_balance(market.maker()).await?,
+        maker_before + u128::from(SELL_AMOUNT - 250)
+    );
+    let order = market.order(id).await?;
+    assert_eq!((order.remaining, order.unclaimed), (0, 1_000));
+    println!("✅ Maker recovered the unfilled {}", SELL_AMOUNT - 250);
+
+    let error = market.fill_error(taker, id, 4).await;
+    assert_eq!(
+        error,
+        Some(RevertReason::Require("Fill exceeds remaining size".to_string()))
+    );
+    println!("❌ Cancelled order cannot be filled");
+
+    Ok(())
+}
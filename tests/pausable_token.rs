@@ -0,0 +1,101 @@
+// Pausable Token Tests
+// 
+// This module contains tests for pausing the SRC20 token including:
+// - Mint and burn reverting while paused
+// - Only the admin being able to pause
+// - Operations resuming after unpause
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{HarnessConfig, TestHarness, SUB_ID, TOKEN_AMOUNT};
+
+// Test that mint and burn revert while paused and resume after unpause
+#[tokio::test]
+async fn test_pause_blocks_mint_and_burn() -> Result<()> {
+    println!("Testing pause and unpause...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let recipient = Identity::Address(user.address().into());
+    harness.mint_to(user, TOKEN_AMOUNT).await?;
+
+    harness.token.methods().pause().call().await?;
+    assert!(harness.token.methods().is_paused().call().await?.value);
+
+    println!("❌ Attempting to mint while paused (should fail)...");
+    let mint_result = harness
+        .token
+        .methods()
+        .mint(recipient, Some(SUB_ID), TOKEN_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(mint_result.is_err());
+    assert!(format!("{:?}", mint_result.unwrap_err()).contains("Paused"));
+
+    println!("❌ Attempting to burn while paused (should fail)...");
+    let burn_amount = 1_000;
+    let burn_result = harness
+        .token_as(user)
+        .methods()
+        .burn(SUB_ID, burn_amount)
+        .call_params(
+            CallParameters::default()
+                .with_amount(burn_amount)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await;
+    assert!(burn_result.is_err());
+
+    let supply = harness.token.methods().total_supply(harness.asset_id).call().await?.value;
+    assert_eq!(supply, Some(TOKEN_AMOUNT));
+    assert_eq!(harness.token_balance(user).await?, TOKEN_AMOUNT as u128);
+    println!("✅ Mint and burn blocked while paused");
+
+    // Unpause and both operations work again
+    harness.token.methods().unpause().call().await?;
+    assert!(!harness.token.methods().is_paused().call().await?.value);
+
+    harness.mint_to(user, TOKEN_AMOUNT).await?;
+    harness
+        .token_as(user)
+        .methods()
+        .burn(SUB_ID, burn_amount)
+        .call_params(
+            CallParameters::default()
+                .with_amount(burn_amount)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let supply = harness.token.methods().total_supply(harness.asset_id).call().await?.value;
+    assert_eq!(supply, Some(2 * TOKEN_AMOUNT - burn_amount));
+
+    println!("✅ Pause and unpause test passed");
+    Ok(())
+}
+
+// Test that only the admin can pause or unpause
+#[tokio::test]
+async fn test_non_admin_cannot_pause() -> Result<()> {
+    println!("Testing non-admin pause...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user_token = harness.token_as(harness.user(0));
+
+    println!("❌ Attempting to pause as a user (should fail)...");
+    let result = user_token.methods().pause().call().await;
+    assert!(result.is_err());
+    assert!(format!("{:?}", result.unwrap_err()).contains("Unauthorized"));
+    assert!(!harness.token.methods().is_paused().call().await?.value);
+
+    // A user cannot lift an admin pause either
+    harness.token.methods().pause().call().await?;
+    assert!(user_token.methods().unpause().call().await.is_err());
+    assert!(harness.token.methods().is_paused().call().await?.value);
+
+    println!("✅ Non-admin pause correctly rejected");
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Payment Request Tests
+// 
+// This module contains tests for payment requests including:
+// - Encoding and decoding round trips
+// - Rejecting expired requests
+// - Rejecting payment in the wrong asset
+// - Paying a request end to end
+
+use fuels::{
+    prelude::*,
+    types::{Address, AssetId},
+};
+
+use rosetta_stone::{
+    payment_request::{unix_now, PaymentRequest, PAYMENT_REQUEST_PREFIX},
+    HarnessConfig, TestHarness, TOKEN_AMOUNT,
+};
+
+// Test that a request survives an encode/decode round trip
+#[test]
+fn test_payment_request_round_trip() -> Result<()> {
+    let request = PaymentRequest::new(Address::from([7u8; 32]), AssetId::from([9u8; 32]), 1_000)
+        .with_memo("order 42")
+        .with_expiry(1_900_000_000);
+
+    let encoded = request.encode()?;
+    assert!(encoded.starts_with(PAYMENT_REQUEST_PREFIX));
+    assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == ':'));
+
+    let decoded = PaymentRequest::decode(&encoded)?;
+    assert_eq!(decoded, request);
+    assert_eq!(decoded.recipient()?, Address::from([7u8; 32]));
+    assert_eq!(decoded.asset_id()?, AssetId::from([9u8; 32]));
+
+    assert!(PaymentRequest::decode("bitcoin:abc").is_err());
+    assert!(PaymentRequest::decode("fuelpay:0OIl").is_err());
+    Ok(())
+}
+
+// Test that expired requests are rejected
+#[test]
+fn test_expired_request_is_rejected() {
+    let asset_id = AssetId::from([9u8; 32]);
+    let request = PaymentRequest::new(Address::from([7u8; 32]), asset_id, 1_000).with_expiry(100);
+
+    assert!(request.verify(asset_id, 100).is_ok());
+    assert!(request.is_expired_at(101));
+    let err = request.verify(asset_id, 101).unwrap_err();
+    assert!(err.to_string().contains("expired"));
+}
+
+// Test that paying with a different asset is rejected
+#[test]
+fn test_wrong_asset_is_rejected() {
+    let request = PaymentRequest::new(Address::from([7u8; 32]), AssetId::from([9u8; 32]), 1_000);
+
+    let err = request.verify(AssetId::from([1u8; 32]), unix_now()).unwrap_err();
+    assert!(err.to_string().contains("asks for asset"));
+}
+
+// Test paying a request between two users
+#[tokio::test]
+async fn test_pay_request() -> Result<()> {
+    println!("Testing payment request payment...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let payer = harness.user(0);
+    let payee = harness.user(1);
+    harness.mint_to(payer, TOKEN_AMOUNT).await?;
+
+    let amount = 42_000;
+    let encoded = PaymentRequest::new(payee.address().into(), harness.asset_id, amount)
+        .with_memo("invoice 1")
+        .expires_in(3_600)
+        .encode()?;
+    println!("   Request: {}", encoded);
+
+    let request = PaymentRequest::decode(&encoded)?;
+    request.pay(payer, harness.asset_id).await?;
+
+    assert_eq!(harness.token_balance(payee).await?, amount as u128);
+    assert_eq!(
+        harness.token_balance(payer).await?,
+        (TOKEN_AMOUNT - amount) as u128
+    );
+
+    // The wrong asset is refused before anything is sent
+    let base_asset_id = AssetId::zeroed();
+    assert!(request.pay(payer, base_asset_id).await.is_err());
+    assert_eq!(harness.token_balance(payee).await?, amount as u128);
+
+    println!("✅ Payment request test passed");
+    Ok(())
+}
@@ -0,0 +1,182 @@
+// Permit Operations Tests
+// 
+// This module contains tests for off-chain signed approvals including:
+// - Submitting a permit together with transfer_from in one transaction
+// - Rejecting expired permits
+// - Rejecting replayed permits and permits signed by someone else
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{
+    deploy_token_allowance,
+    permit::{permit_and_transfer_from, prepare_permit},
+    HarnessConfig, TestHarness, TokenAllowance, TOKEN_AMOUNT,
+};
+
+// Deploys the allowance contract and deposits TOKEN_AMOUNT for user 0
+async fn setup() -> Result<(TestHarness, TokenAllowance<Wallet<Unlocked<PrivateKeySigner>>>)> {
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let owner = harness.user(0);
+    let allowance = deploy_token_allowance(harness.admin.clone(), harness.asset_id).await?;
+
+    harness.mint_to(owner, TOKEN_AMOUNT).await?;
+    allowance
+        .clone()
+        .with_account(owner.clone())
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(TOKEN_AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    Ok((harness, allowance))
+}
+
+// Test that a signed permit and transfer_from settle in one transaction
+#[tokio::test]
+async fn test_permit_and_transfer_from() -> Result<()> {
+    println!("Testing permit with transfer_from...");
+
+    let (harness, allowance) = setup().await?;
+    let owner = harness.user(0);
+    let spender = harness.user(1);
+    let recipient = harness.user(2);
+    let spender_identity = Identity::Address(spender.address().into());
+    let contract_id = allowance.contract_id();
+
+    // The owner signs off-chain; no transaction is sent by the owner
+    let deadline = harness.provider.latest_block_height().await? + 10;
+    let signed = prepare_permit(
+        &allowance,
+        owner.address().into(),
+        spender_identity,
+        400_000,
+        deadline,
+    )
+    .await?
+    .sign(owner.signer(), &contract_id)
+    .await?;
+
+    let spender_allowance = allowance.clone().with_account(spender.clone());
+    permit_and_transfer_from(
+        &spender_allowance,
+        &signed,
+        Identity::Address(recipient.address().into()),
+        150_000,
+    )
+    .await?;
+
+    let remaining = allowance
+        .methods()
+        .allowance(Identity::Address(owner.address().into()), spender_identity)
+        .call()
+        .await?
+        .value;
+    assert_eq!(remaining, 250_000);
+    assert_eq!(harness.token_balance(recipient).await?, 150_000);
+    assert_eq!(allowance.methods().nonce(owner.address().into()).call().await?.value, 1);
+
+    println!("✅ Permit with transfer_from test passed");
+    Ok(())
+}
+
+// Test that a permit past its deadline is rejected
+#[tokio::test]
+async fn test_expired_permit_is_rejected() -> Result<()> {
+    println!("Testing expired permit...");
+
+    let (harness, allowance) = setup().await?;
+    let owner = harness.user(0);
+    let spender = harness.user(1);
+    let spender_identity = Identity::Address(spender.address().into());
+
+    let deadline = harness.provider.latest_block_height().await? + 1;
+    let signed = prepare_permit(
+        &allowance,
+        owner.address().into(),
+        spender_identity,
+        1_000,
+        deadline,
+    )
+    .await?
+    .sign(owner.signer(), &allowance.contract_id())
+    .await?;
+
+    // Move the chain past the deadline
+    harness.provider.produce_blocks(3, None).await?;
+
+    let result = permit_and_transfer_from(
+        &allowance.clone().with_account(spender.clone()),
+        &signed,
+        spender_identity,
+        1_000,
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Permit expired"));
+    assert_eq!(harness.token_balance(spender).await?, 0);
+
+    println!("✅ Expired permit correctly rejected");
+    Ok(())
+}
+
+// Test that a permit cannot be replayed or signed by anyone but the owner
+#[tokio::test]
+async fn test_replayed_permit_is_rejected() -> Result<()> {
+    println!("Testing permit replay...");
+
+    let (harness, allowance) = setup().await?;
+    let owner = harness.user(0);
+    let spender = harness.user(1);
+    let spender_identity = Identity::Address(spender.address().into());
+    let spender_allowance = allowance.clone().with_account(spender.clone());
+    let deadline = harness.provider.latest_block_height().await? + 100;
+
+    let signed = prepare_permit(
+        &allowance,
+        owner.address().into(),
+        spender_identity,
+        1_000,
+        deadline,
+    )
+    .await?
+    .sign(owner.signer(), &allowance.contract_id())
+    .await?;
+    permit_and_transfer_from(&spender_allowance, &signed, spender_identity, 1_000).await?;
+
+    println!("❌ Replaying the same permit (should fail)...");
+    let result =
+        permit_and_transfer_from(&spender_allowance, &signed, spender_identity, 1_000).await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Invalid permit signature"));
+    assert_eq!(harness.token_balance(spender).await?, 1_000);
+
+    // A spender cannot forge a permit for the owner with their own key
+    let mut forged = prepare_permit(
+        &allowance,
+        spender.address().into(),
+        spender_identity,
+        1_000,
+        deadline,
+    )
+    .await?
+    .sign(spender.signer(), &allowance.contract_id())
+    .await?;
+    forged.permit.owner = owner.address().into();
+    forged.permit.nonce = 1;
+    let result =
+        permit_and_transfer_from(&spender_allowance, &forged, spender_identity, 1_000).await;
+    assert!(result.is_err());
+    assert_eq!(harness.token_balance(spender).await?, 1_000);
+
+    println!("✅ Replayed and forged permits correctly rejected");
+    Ok(())
+}
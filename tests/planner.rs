@@ -0,0 +1,114 @@
+// Planner Tests
+// 
+// This module contains tests for the transfer planner including:
+// - Planning deposits, withdrawals, transfers and mints from known balances
+// - Preferring transfers from surplus wallets over minting
+// - Executing a plan and comparing planned vs actual end state
+
+use std::collections::BTreeMap;
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    planner::{execute, observe, plan, plan_for, Account, Step, Target, UserBalances},
+    HarnessConfig, TestHarness,
+};
+
+fn balances(entries: &[(usize, u64, u64)]) -> BTreeMap<usize, UserBalances> {
+    entries
+        .iter()
+        .map(|&(user, wallet, vault)| (user, UserBalances { wallet, vault }))
+        .collect()
+}
+
+// Test that a vault target with no tokens anywhere is a mint followed by a deposit
+#[test]
+fn test_plan_mints_then_deposits() -> Result<()> {
+    let current = balances(&[(0, 0, 0)]);
+    let plan = plan(&current, &[Target::new(0).vault(10_000)])?;
+
+    assert_eq!(
+        plan.steps,
+        vec![
+            Step::Mint { to: 0, amount: 10_000 },
+            Step::Deposit { user: 0, amount: 10_000 },
+        ]
+    );
+    assert_eq!(plan.expected[&0], UserBalances { wallet: 0, vault: 10_000 });
+    Ok(())
+}
+
+// Test that surplus wallets fund deficits before anything is minted
+#[test]
+fn test_plan_prefers_transfers_over_mints() -> Result<()> {
+    let current = balances(&[(0, 0, 0), (1, 6_000, 0), (2, 0, 5_000)]);
+    let targets = [
+        Target::new(0).wallet(0).vault(10_000),
+        Target::new(1).wallet(1_000),
+        Target::new(2).wallet(1_000).vault(4_000),
+    ];
+    let plan = plan(&current, &targets)?;
+
+    assert_eq!(
+        plan.steps,
+        vec![
+            Step::Withdraw { user: 2, amount: 1_000 },
+            Step::Transfer { from: 1, to: Account::User(0), amount: 5_000 },
+            Step::Mint { to: 0, amount: 5_000 },
+            Step::Deposit { user: 0, amount: 10_000 },
+        ]
+    );
+    for step in &plan.steps {
+        println!("   {}", step);
+    }
+    Ok(())
+}
+
+// Test that surplus nobody needs goes back to the admin
+#[test]
+fn test_plan_returns_surplus_to_admin() -> Result<()> {
+    let current = balances(&[(0, 3_000, 2_000)]);
+    let plan = plan(&current, &[Target::new(0).wallet(0).vault(0)])?;
+
+    assert_eq!(
+        plan.steps,
+        vec![
+            Step::Withdraw { user: 0, amount: 2_000 },
+            Step::Transfer { from: 0, to: Account::Admin, amount: 5_000 },
+        ]
+    );
+    assert!(plan(&current, &[Target::new(1).vault(1)]).is_err());
+    Ok(())
+}
+
+// Test executing a plan against the harness and comparing planned vs actual balances
+#[tokio::test]
+async fn test_execute_plan() -> Result<()> {
+    println!("Testing planner execution...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    harness.mint_to(harness.user(1), 20_000).await?;
+
+    let targets = [
+        Target::new(0).vault(10_000),
+        Target::new(1).wallet(5_000),
+        Target::new(2).wallet(2_500).vault(2_500),
+    ];
+    let plan = plan_for(&harness, &targets).await?;
+    for step in &plan.steps {
+        println!("   {}", step);
+    }
+    // 15_000 of surplus from user 1 covers every deficit, so nothing is minted
+    assert!(!plan.steps.iter().any(|step| matches!(step, Step::Mint { .. })));
+
+    execute(&harness, &plan).await?;
+
+    let actual = observe(&harness, &[0, 1, 2]).await?;
+    assert_eq!(actual, plan.expected);
+
+    // Planning again from the reached state is a no-op
+    assert!(plan_for(&harness, &targets).await?.steps.is_empty());
+
+    println!("✅ Planner execution test passed");
+    Ok(())
+}
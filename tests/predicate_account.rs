@@ -0,0 +1,168 @@
+// Predicate Account Tests
+// 
+// This module contains tests for a predicate used as a user account including:
+// - Registering the predicate in the predicate registry
+// - Funding the predicate with the base asset and the harness token
+// - The predicate paying for and authorizing a vault deposit
+// - Calls rejected without the owner's signature
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{Address, Identity},
+};
+
+use rosetta_stone::{
+    artifacts, deploy_predicate_registry, AccountPredicateConfigurables, HarnessConfig,
+    PredicateRegistry, TestHarness, SUB_ID,
+};
+
+const FEE_FUNDS: u64 = 1_000_000;
+const TOKEN_FUNDS: u64 = 10_000;
+const DEPOSIT_AMOUNT: u64 = 4_000;
+
+// Loads the account predicate controlled by `owner`
+fn account_for(
+    owner: &Wallet<Unlocked<PrivateKeySigner>>,
+    provider: &Provider,
+) -> Result<Predicate> {
+    let configurables =
+        AccountPredicateConfigurables::default().with_OWNER(Address::from(owner.address()))?;
+
+    Ok(Predicate::load_from(artifacts::ACCOUNT_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables))
+}
+
+// Funds the account with base asset for fees and with the harness token
+async fn fund(harness: &TestHarness, account: &Predicate) -> Result<()> {
+    let base_asset = *harness.provider.consensus_parameters().await?.base_asset_id();
+    harness
+        .admin
+        .transfer(account.address(), FEE_FUNDS, base_asset, TxPolicies::default())
+        .await?;
+    harness
+        .token
+        .methods()
+        .mint(Identity::Address(account.address().into()), Some(SUB_ID), TOKEN_FUNDS)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(())
+}
+
+// Test registering a predicate account and depositing from it into the vault
+#[tokio::test]
+async fn test_predicate_account_flow() -> Result<()> {
+    println!("Testing predicate as account...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let owner = harness.user(0);
+    let account = account_for(owner, &harness.provider)?;
+    let account_address = Address::from(account.address());
+    fund(&harness, &account).await?;
+    println!("✅ Account {} funded", account_address);
+
+    // The predicate pays for its own registration; the owner's signature approves it
+    let registry = deploy_predicate_registry(harness.admin.clone()).await?;
+    registry
+        .clone()
+        .with_account(account.clone())
+        .methods()
+        .register(Address::from(owner.address()))
+        .add_signer(owner.signer().clone())
+        .call()
+        .await?;
+
+    let methods = registry.methods();
+    assert!(methods.is_valid(account_address).call().await?.value);
+    assert_eq!(
+        methods.owner_of(account_address).call().await?.value,
+        Some(Address::from(owner.address()))
+    );
+    assert_eq!(
+        methods.account_of(Address::from(owner.address())).call().await?.value,
+        Some(account_address)
+    );
+    println!("✅ Account registered to its owner");
+
+    let owner_base_before = owner.get_asset_balance(&AssetId::zeroed()).await?;
+
+    // The deposit is paid and authorized by the predicate, so the vault credits it
+    harness
+        .vault
+        .clone()
+        .with_account(account.clone())
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT_AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .add_signer(owner.signer().clone())
+        .call()
+        .await?;
+
+    let deposit = harness
+        .vault
+        .methods()
+        .get_deposit(Identity::Address(account_address))
+        .call()
+        .await?
+        .value;
+    assert_eq!(deposit, DEPOSIT_AMOUNT);
+    assert_eq!(
+        account.get_asset_balance(&harness.asset_id).await?,
+        (TOKEN_FUNDS - DEPOSIT_AMOUNT) as u128
+    );
+    assert_eq!(
+        owner.get_asset_balance(&AssetId::zeroed()).await?,
+        owner_base_before
+    );
+    println!("✅ Account deposited {} and paid the fee itself", deposit);
+
+    let result = registry
+        .with_account(account.clone())
+        .methods()
+        .register(Address::from(owner.address()))
+        .add_signer(owner.signer().clone())
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Already registered"));
+
+    Ok(())
+}
+
+// Test that the account rejects transactions its owner did not sign
+#[tokio::test]
+async fn test_predicate_account_requires_owner() -> Result<()> {
+    println!("Testing predicate account authorization...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(2)).await?;
+    let (owner, stranger) = (harness.user(0), harness.user(1));
+    let account = account_for(owner, &harness.provider)?;
+    fund(&harness, &account).await?;
+
+    let registry: PredicateRegistry<Predicate> = deploy_predicate_registry(harness.admin.clone())
+        .await?
+        .with_account(account.clone());
+    let result = registry
+        .methods()
+        .register(Address::from(stranger.address()))
+        .add_signer(stranger.signer().clone())
+        .call()
+        .await;
+    assert!(result.is_err());
+
+    let result = registry
+        .methods()
+        .register(Address::from(stranger.address()))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(registry.methods().registered_count().call().await?.value, 0);
+    println!("❌ Registration without the owner's signature rejected");
+
+    Ok(())
+}
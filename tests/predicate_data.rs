@@ -0,0 +1,157 @@
+// Predicate Data Tests
+// 
+// This module contains tests for passing arguments to a predicate at spend time including:
+// - Encoding predicate data with the abigen encoder when building the spend
+// - The predicate address not depending on its data
+// - Spends accepted only when the transaction matches the data
+// - Data outside the configured limit rejected
+// - Change that does not return to the predicate rejected
+
+use fuels::{
+    prelude::*,
+    types::{
+        output::Output, transaction_builders::ScriptTransactionBuilder, tx_status::TxStatus,
+        Address,
+    },
+};
+
+use rosetta_stone::{
+    artifacts, assert_balance_change, launch_wallets, PayoutPredicateConfigurables,
+    PayoutPredicateEncoder,
+};
+
+const FUND_AMOUNT: u64 = 500_000;
+const MAX_PAYOUT: u64 = 10_000;
+const PAYOUT: u64 = 1_000;
+
+// The payout predicate with `recipient` and `amount` as its predicate data
+fn payout_predicate(provider: &Provider, recipient: Address, amount: u64) -> Result<Predicate> {
+    let configurables = PayoutPredicateConfigurables::default()
+        .with_ASSET(AssetId::zeroed())?
+        .with_MAX_PAYOUT(MAX_PAYOUT)?;
+    let data = PayoutPredicateEncoder::default().encode_data(recipient, amount)?;
+
+    Ok(Predicate::load_from(artifacts::PAYOUT_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables)
+        .with_data(data))
+}
+
+// Pays `amount` to `to` out of the predicate, which also pays the fee
+async fn spend(predicate: &Predicate, to: Address, amount: u64) -> Result<TxStatus> {
+    spend_with_change(predicate, to, amount, predicate.address()).await
+}
+
+// Like `spend`, but sends the change of the predicate's coins to `change_to`
+async fn spend_with_change(
+    predicate: &Predicate,
+    to: Address,
+    amount: u64,
+    change_to: Address,
+) -> Result<TxStatus> {
+    let provider = predicate.try_provider()?.clone();
+    let asset_id = AssetId::zeroed();
+
+    // The inputs carry the predicate data, so the data is fixed per spend
+    let inputs = predicate.get_asset_inputs_for_amount(asset_id, amount.into(), None).await?;
+    let outputs = vec![
+        Output::coin(to, amount, asset_id),
+        Output::change(change_to, 0, asset_id),
+    ];
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    predicate.adjust_for_fee(&mut tb, amount.into()).await?;
+
+    let tx = tb.build(provider.clone()).await?;
+    provider.send_transaction_and_await_commit(tx).await
+}
+
+// Test that a spend matching the predicate data goes through
+#[tokio::test]
+async fn test_predicate_data_matching_spend() -> Result<()> {
+    println!("Testing predicate data...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(2), Some(1), Some(1_000_000))).await?;
+    let (funder, recipient) = (&wallets[0], &wallets[1]);
+    let provider = funder.try_provider()?.clone();
+
+    let predicate = payout_predicate(&provider, recipient.address(), PAYOUT)?;
+    funder
+        .transfer(predicate.address(), FUND_AMOUNT, AssetId::zeroed(), TxPolicies::default())
+        .await?;
+
+    // Data is not part of the predicate's bytecode, so every payout shares one address
+    let other = payout_predicate(&provider, funder.address(), MAX_PAYOUT)?;
+    assert_eq!(predicate.address(), other.address());
+    println!("✅ Predicate funded at {}", predicate.address());
+
+    let status = assert_balance_change!(
+        &provider,
+        [(recipient.address(), AssetId::zeroed()) => PAYOUT],
+        spend(&predicate, recipient.address(), PAYOUT)
+    )?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    println!("✅ Spend to the recipient named in the data succeeded");
+
+    Ok(())
+}
+
+// Test that spends disagreeing with the data, or beyond the limit, are rejected
+#[tokio::test]
+async fn test_predicate_data_mismatch_rejected() -> Result<()> {
+    let wallets = launch_wallets(WalletsConfig::new(Some(3), Some(1), Some(1_000_000))).await?;
+    let (funder, recipient, stranger) = (&wallets[0], &wallets[1], &wallets[2]);
+    let provider = funder.try_provider()?.clone();
+
+    let predicate = payout_predicate(&provider, recipient.address(), PAYOUT)?;
+    funder
+        .transfer(predicate.address(), FUND_AMOUNT, AssetId::zeroed(), TxPolicies::default())
+        .await?;
+
+    // Someone else than the recipient in the data
+    let result = spend(&predicate, stranger.address(), PAYOUT).await;
+    assert!(result.is_err());
+    println!("❌ Spend to another address rejected");
+
+    // Another amount than the one in the data
+    let result = spend(&predicate, recipient.address(), PAYOUT - 1).await;
+    assert!(result.is_err());
+    println!("❌ Spend of another amount rejected");
+
+    // Consistent data, but above MAX_PAYOUT
+    let greedy = payout_predicate(&provider, recipient.address(), MAX_PAYOUT + 1)?;
+    let result = spend(&greedy, recipient.address(), MAX_PAYOUT + 1).await;
+    assert!(result.is_err());
+    println!("❌ Payout above {} rejected", MAX_PAYOUT);
+
+    assert_eq!(
+        predicate.get_asset_balance(&AssetId::zeroed()).await?,
+        FUND_AMOUNT as u128
+    );
+
+    Ok(())
+}
+
+// Test that a spend paying the change of the predicate's coins to someone else is rejected
+#[tokio::test]
+async fn test_predicate_data_change_to_stranger_rejected() -> Result<()> {
+    let wallets = launch_wallets(WalletsConfig::new(Some(3), Some(1), Some(1_000_000))).await?;
+    let (funder, recipient, stranger) = (&wallets[0], &wallets[1], &wallets[2]);
+    let provider = funder.try_provider()?.clone();
+
+    let predicate = payout_predicate(&provider, recipient.address(), PAYOUT)?;
+    funder
+        .transfer(predicate.address(), FUND_AMOUNT, AssetId::zeroed(), TxPolicies::default())
+        .await?;
+
+    // Output 0 matches the data, but the rest of the coin would leave with the change
+    let result = spend_with_change(&predicate, recipient.address(), PAYOUT, stranger.address()).await;
+    assert!(result.is_err());
+    println!("❌ Spend sending the change to a stranger rejected");
+
+    assert_eq!(
+        predicate.get_asset_balance(&AssetId::zeroed()).await?,
+        FUND_AMOUNT as u128
+    );
+
+    Ok(())
+}
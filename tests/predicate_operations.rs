@@ -6,25 +6,23 @@
 // - Predicate balance checks
 // - Authorization workflows
 
-use fuels::{
-    prelude::*,
-    types::transaction_builders::ScriptTransactionBuilder,
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    artifacts,
+    fees::{fee_from_status, FeePaid},
+    launch_wallets,
+    predicates::{fund_predicate, spend_from_predicate},
+    MultiSigPredicateConfigurables,
 };
 
-abigen!(Predicate(
-    name = "MultiSigPredicate",
-    abi = "predicates/multi-sig/out/debug/multi_sig_predicate-abi.json",
-));
-
 // Test predicate authorization functionality
 #[tokio::test]
 async fn test_predicate_authorization() -> Result<()> {
     println!("Testing predicate authorization...");
 
-    let wallets = launch_custom_provider_and_get_wallets(
+    let wallets = launch_wallets(
         WalletsConfig::new(Some(3), Some(1), Some(1_000_000)),
-        None,
-        None,
     )
     .await?;
 
@@ -46,7 +44,7 @@ async fn test_predicate_authorization() -> Result<()> {
         .with_REQUIRED_SIGNATURES(2)?;
 
     // Load predicate
-    let predicate = Predicate::load_from("predicates/multi-sig/out/debug/multi_sig_predicate.bin")?
+    let predicate = Predicate::load_from(artifacts::MULTI_SIG_PREDICATE_BIN)?
         .with_provider(signer1.provider().clone())
         .with_configurables(configurables);
 
@@ -59,14 +57,7 @@ async fn test_predicate_authorization() -> Result<()> {
     println!("  Predicate balance: 0");
     println!("  Funding predicate with {} tokens...", fund_amount);
     
-    signer1
-        .transfer(
-            predicate.address(),
-            fund_amount,
-            AssetId::default(),
-            TxPolicies::default(),
-        )
-        .await?;
+    let funding = fund_predicate(signer1, &predicate, fund_amount).await?;
 
     // Verify predicate balance
     let predicate_balance = predicate.get_asset_balance(&AssetId::default()).await?;
@@ -75,9 +66,13 @@ async fn test_predicate_authorization() -> Result<()> {
     println!("  After funding predicate:");
     println!("  Signer1 balance: {} (was: {})", final_signer1_balance, initial_balance);
     println!("  Predicate balance: {}", predicate_balance);
-    println!("  Transfer fee: {}", initial_balance - final_signer1_balance - fund_amount as u128);
+    println!("  Transfer fee: {}", funding.fee_paid());
     
     assert_eq!(predicate_balance, fund_amount as u128);
+    assert_eq!(
+        initial_balance - final_signer1_balance,
+        (fund_amount + funding.fee_paid()) as u128
+    );
 
     println!("✅ Predicate authorization test completed");
 
@@ -89,10 +84,8 @@ async fn test_predicate_authorization() -> Result<()> {
 async fn test_predicate_spending_2_of_3() -> Result<()> {
     println!("Testing predicate spending with 2/3 signatures...");
 
-    let wallets = launch_custom_provider_and_get_wallets(
+    let wallets = launch_wallets(
         WalletsConfig::new(Some(3), Some(1), Some(1_000_000)),
-        None,
-        None,
     )
     .await?;
 
@@ -115,7 +108,7 @@ async fn test_predicate_spending_2_of_3() -> Result<()> {
         .with_REQUIRED_SIGNATURES(2)?;
 
     // Load predicate
-    let predicate = Predicate::load_from("predicates/multi-sig/out/debug/multi_sig_predicate.bin")?
+    let predicate = Predicate::load_from(artifacts::MULTI_SIG_PREDICATE_BIN)?
         .with_provider(provider.clone())
         .with_configurables(configurables);
 
@@ -128,14 +121,7 @@ async fn test_predicate_spending_2_of_3() -> Result<()> {
     println!("  Predicate balance: 0");
     println!("  Funding predicate with {} tokens...", fund_amount);
     
-    signer1
-        .transfer(
-            predicate.address(),
-            fund_amount,
-            asset_id,
-            TxPolicies::default(),
-        )
-        .await?;
+    let funding = fund_predicate(signer1, &predicate, fund_amount).await?;
 
     // Verify predicate is funded
     let predicate_balance = provider.get_asset_balance(&predicate.address(), &asset_id).await?;
@@ -144,44 +130,32 @@ async fn test_predicate_spending_2_of_3() -> Result<()> {
     println!("  After funding predicate:");
     println!("  Signer1 balance: {}", signer1_balance_after_funding);
     println!("  Predicate balance: {}", predicate_balance);
-    println!("  Transfer fee: {}", initial_balance - signer1_balance_after_funding - fund_amount as u128);
+    println!("  Transfer fee: {}", funding.fee_paid());
     
     assert_eq!(predicate_balance, fund_amount as u128);
+    assert_eq!(
+        initial_balance - signer1_balance_after_funding,
+        (fund_amount + funding.fee_paid()) as u128
+    );
 
     // Build transaction to spend from predicate
     let spend_amount = 300_000;
-    let gas_amount = 1; // Reserve some for gas
     
     println!("  Before spending from predicate:");
     println!("  Predicate balance: {}", provider.get_asset_balance(&predicate.address(), &asset_id).await?);
     println!("  Signer1 balance: {}", provider.get_asset_balance(&signer1.address(), &asset_id).await?);
-    println!("  Spending {} tokens (reserving {} for gas)...", spend_amount, gas_amount);
+    println!("  Spending {} tokens...", spend_amount);
     
-    let input_coin = predicate.get_asset_inputs_for_amount(asset_id, 1, None).await?;
-    let output_coin = predicate.get_asset_outputs_for_amount(
-        signer1.address().into(), 
-        asset_id, 
-        (spend_amount - gas_amount) as u64
-    );
-
-    let mut transaction_builder = ScriptTransactionBuilder::prepare_transfer(
-        input_coin,
-        output_coin,
-        TxPolicies::default(),
-    );
-
-    // For predicate spending with multiple signatures, we need to add both signers
-    // The predicate will verify the signatures in the witnesses
-    println!("🔐 Adding signatures from both signers...");
-    signer1.adjust_for_fee(&mut transaction_builder, 0).await?;
-    signer1.add_witnesses(&mut transaction_builder)?;
-    signer2.adjust_for_fee(&mut transaction_builder, 0).await?;
-    signer2.add_witnesses(&mut transaction_builder)?;
-
-    // Build and send transaction
-    println!("🚀 Building and sending transaction...");
-    let transaction = transaction_builder.build(provider.clone()).await?;
-    provider.send_transaction_and_await_commit(transaction).await?;
+    // The predicate verifies the signatures in the witnesses, so both signers add theirs
+    println!("🔐 Signing with both signers, sending transaction...");
+    let status = spend_from_predicate(
+        &predicate,
+        &[signer1, signer2],
+        signer1.address().into(),
+        spend_amount,
+    )
+    .await?;
+    let spend_fee = fee_from_status(&status)? as u128;
     println!("✅ Transaction executed successfully!");
 
     // Verify predicate balance decreased
@@ -192,10 +166,17 @@ async fn test_predicate_spending_2_of_3() -> Result<()> {
     println!("  Predicate balance: {} (was: {})", final_predicate_balance, fund_amount);
     println!("  Signer1 balance: {} (was: {})", final_signer1_balance, signer1_balance_after_funding);
     println!("  Amount spent: {}", spend_amount);
-    println!("  Gas used: {}", gas_amount);
+    println!("  Fee paid: {}", spend_fee);
     
-    assert_eq!(final_predicate_balance, (fund_amount - spend_amount) as u128);
-    assert!(final_signer1_balance > initial_balance - fund_amount as u128);
+    // The fee comes out of the predicate's change output
+    assert_eq!(
+        final_predicate_balance,
+        (fund_amount - spend_amount) as u128 - spend_fee
+    );
+    assert_eq!(
+        final_signer1_balance,
+        signer1_balance_after_funding + spend_amount as u128
+    );
 
     println!("✅ Predicate spending test completed successfully");
 
@@ -207,10 +188,8 @@ async fn test_predicate_spending_2_of_3() -> Result<()> {
 async fn test_predicate_spending_insufficient_signatures() -> Result<()> {
     println!("Testing predicate spending fails with insufficient signatures...");
 
-    let wallets = launch_custom_provider_and_get_wallets(
+    let wallets = launch_wallets(
         WalletsConfig::new(Some(3), Some(1), Some(1_000_000)),
-        None,
-        None,
     )
     .await?;
 
@@ -233,7 +212,7 @@ async fn test_predicate_spending_insufficient_signatures() -> Result<()> {
         .with_REQUIRED_SIGNATURES(2)?;
 
     // Load predicate
-    let predicate = Predicate::load_from("predicates/multi-sig/out/debug/multi_sig_predicate.bin")?
+    let predicate = Predicate::load_from(artifacts::MULTI_SIG_PREDICATE_BIN)?
         .with_provider(provider.clone())
         .with_configurables(configurables);
 
@@ -246,49 +225,20 @@ async fn test_predicate_spending_insufficient_signatures() -> Result<()> {
     println!("  Predicate balance: 0");
     println!("  Funding predicate with {} tokens...", fund_amount);
     
-    signer1
-        .transfer(
-            predicate.address(),
-            fund_amount,
-            asset_id,
-            TxPolicies::default(),
-        )
-        .await?;
+    fund_predicate(signer1, &predicate, fund_amount).await?;
 
     // Build transaction to spend from predicate
     let spend_amount = 300_000;
-    let gas_amount = 1;
     
     println!("  Before attempting to spend (insufficient signatures):");
     println!("  Predicate balance: {}", provider.get_asset_balance(&predicate.address(), &asset_id).await?);
     println!("  Signer1 balance: {}", provider.get_asset_balance(&signer1.address(), &asset_id).await?);
     println!("  Attempting to spend {} tokens with only 1 signature...", spend_amount);
     
-    let input_coin = predicate.get_asset_inputs_for_amount(asset_id, 1, None).await?;
-    let output_coin = predicate.get_asset_outputs_for_amount(
-        signer1.address().into(), 
-        asset_id, 
-        (spend_amount - gas_amount) as u64
-    );
-
-    let mut transaction_builder = ScriptTransactionBuilder::prepare_transfer(
-        input_coin,
-        output_coin,
-        TxPolicies::default(),
-    );
-
-    // Add fees and witnesses from only one signer (insufficient for 2/3 requirement)
-    println!("  Adding signature from only one signer (insufficient for 2/3 requirement)...");
-    signer1.adjust_for_fee(&mut transaction_builder, 0).await?;
-    signer1.add_witnesses(&mut transaction_builder)?;
-
-    // Build transaction
-    println!("  Building transaction with insufficient signatures...");
-    let transaction = transaction_builder.build(provider.clone()).await?;
-    
-    // Attempt to send transaction - should fail
+    // Only one signer's witness, insufficient for the 2/3 requirement
     println!("❌ Attempting to execute transaction (should fail due to insufficient signatures)...");
-    let result = provider.send_transaction_and_await_commit(transaction).await;
+    let result =
+        spend_from_predicate(&predicate, &[signer1], signer1.address().into(), spend_amount).await;
     assert!(result.is_err());
     println!("✅ Transaction correctly failed due to insufficient signatures");
 
@@ -0,0 +1,276 @@
+// Reentrancy Tests
+// 
+// This module contains tests for TokenVault against malicious contracts including:
+// - A contract depositor withdrawing its own deposit once
+// - Nested, reentrant withdrawals of the same deposit reverting
+// - Vault books and balances left untouched by the reverted attack
+// - A token whose `burn` re-enters `burn_deposit`, reverting for the same coins
+// - Re-entered burns of what is left of the deposit keeping the books consistent
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    tx::ContractIdExt,
+    types::{Identity, SubAssetId},
+};
+
+use rosetta_stone::{
+    artifacts, HarnessConfig, MaliciousToken, ReentrancyAttacker, TestHarness, SUB_ID,
+};
+
+const DEPOSIT: u64 = 10_000;
+
+// Deploys the attacker and deposits DEPOSIT of the harness token into the vault
+// through it, so the vault books the deposit under the attacker's contract id
+async fn deploy_funded_attacker(
+    harness: &TestHarness,
+) -> Result<ReentrancyAttacker<Wallet<Unlocked<PrivateKeySigner>>>> {
+    let deploy_response = Contract::load_from(
+        artifacts::REENTRANCY_ATTACKER_BIN,
+        LoadConfiguration::default(),
+    )?
+    .deploy(&harness.admin, TxPolicies::default())
+    .await?;
+    let attacker = ReentrancyAttacker::new(deploy_response.contract_id, harness.admin.clone());
+    println!("✅ Attacker deployed at: {}", deploy_response.contract_id.to_string());
+
+    harness.mint_to(&harness.admin, DEPOSIT).await?;
+    attacker
+        .methods()
+        .deposit(harness.vault.contract_id())
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_contract_ids(&[harness.vault.contract_id().clone()])
+        .call()
+        .await?;
+
+    Ok(attacker)
+}
+
+async fn attack(
+    harness: &TestHarness,
+    attacker: &ReentrancyAttacker<Wallet<Unlocked<PrivateKeySigner>>>,
+    amount: u64,
+    depth: u64,
+) -> Result<()> {
+    attacker
+        .methods()
+        .attack(harness.vault.contract_id(), harness.asset_id, amount, depth)
+        .with_contract_ids(&[harness.vault.contract_id().clone()])
+        .call()
+        .await?;
+    Ok(())
+}
+
+// Test that the attacker can withdraw its deposit once, like any depositor
+#[tokio::test]
+async fn test_single_withdrawal_succeeds() -> Result<()> {
+    println!("Testing a single contract withdrawal...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let attacker = deploy_funded_attacker(&harness).await?;
+    let attacker_identity = Identity::ContractId(attacker.contract_id().clone());
+
+    attack(&harness, &attacker, DEPOSIT, 1).await?;
+
+    let methods = harness.vault.methods();
+    assert_eq!(
+        methods.get_deposit_for_asset(attacker_identity, harness.asset_id).call().await?.value,
+        0
+    );
+    assert_eq!(methods.get_total_deposits(harness.asset_id).call().await?.value, 0);
+    assert_eq!(attacker.methods().balance(harness.asset_id).call().await?.value, DEPOSIT);
+    println!("✅ Attacker withdrew its own deposit");
+
+    Ok(())
+}
+
+// Test that reentrant withdrawals revert and leave the vault consistent
+#[tokio::test]
+async fn test_reentrant_withdrawal_reverts() -> Result<()> {
+    println!("Testing reentrant withdrawal...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let attacker = deploy_funded_attacker(&harness).await?;
+    let attacker_identity = Identity::ContractId(attacker.contract_id().clone());
+
+    // An honest user's deposit is what a successful drain would steal
+    let user = harness.user(0);
+    harness.mint_to(user, DEPOSIT).await?;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    for depth in [2, 3] {
+        let error = attack(&harness, &attacker, DEPOSIT, depth).await.unwrap_err();
+        assert!(format!("{:?}", error).contains("Insufficient balance"));
+        println!("❌ Attack with {} nested withdrawals reverted", depth);
+    }
+
+    // The reverted transactions changed nothing
+    let methods = harness.vault.methods();
+    assert_eq!(
+        methods.get_deposit_for_asset(attacker_identity, harness.asset_id).call().await?.value,
+        DEPOSIT
+    );
+    let user_identity = Identity::Address(user.address().into());
+    assert_eq!(methods.get_deposit(user_identity).call().await?.value, DEPOSIT);
+    assert_eq!(methods.get_total_deposits(harness.asset_id).call().await?.value, 2 * DEPOSIT);
+    assert_eq!(attacker.methods().balance(harness.asset_id).call().await?.value, 0);
+
+    let vault_balance = harness
+        .provider
+        .get_contract_asset_balance(&harness.vault.contract_id(), &harness.asset_id)
+        .await?;
+    assert_eq!(vault_balance as u64, 2 * DEPOSIT);
+
+    // The attacker still gets exactly its own deposit back
+    attack(&harness, &attacker, DEPOSIT, 1).await?;
+    assert_eq!(attacker.methods().balance(harness.asset_id).call().await?.value, DEPOSIT);
+    assert_eq!(methods.get_deposit(user_identity).call().await?.value, DEPOSIT);
+    println!("✅ Vault state unchanged by the attack");
+
+    Ok(())
+}
+
+// Deploys the malicious token and deposits DEPOSIT of its own asset into the
+// vault through it
+async fn deploy_malicious_token(
+    harness: &TestHarness,
+) -> Result<(MaliciousToken<Wallet<Unlocked<PrivateKeySigner>>>, AssetId)> {
+    let deploy_response =
+        Contract::load_from(artifacts::MALICIOUS_TOKEN_BIN, LoadConfiguration::default())?
+            .deploy(&harness.admin, TxPolicies::default())
+            .await?;
+    let token = MaliciousToken::new(deploy_response.contract_id, harness.admin.clone());
+    let asset_id = deploy_response
+        .contract_id
+        .asset_id(&SubAssetId::from(SUB_ID.0));
+
+    token
+        .methods()
+        .deposit(harness.vault.contract_id(), DEPOSIT)
+        .with_contract_ids(&[harness.vault.contract_id().clone()])
+        .call()
+        .await?;
+
+    Ok((token, asset_id))
+}
+
+async fn burn_attack(
+    harness: &TestHarness,
+    token: &MaliciousToken<Wallet<Unlocked<PrivateKeySigner>>>,
+    amount: u64,
+    depth: u64,
+) -> Result<()> {
+    token
+        .methods()
+        .attack(harness.vault.contract_id(), amount, depth)
+        .with_contract_ids(&[harness.vault.contract_id().clone()])
+        .call()
+        .await?;
+    Ok(())
+}
+
+// The token's deposit, the vault's total and the vault's balance of `asset_id`
+async fn burn_books(
+    harness: &TestHarness,
+    token: &MaliciousToken<Wallet<Unlocked<PrivateKeySigner>>>,
+    asset_id: AssetId,
+) -> Result<(u64, u64, u64)> {
+    let methods = harness.vault.methods();
+    let token_identity = Identity::ContractId(token.contract_id().clone());
+    let deposit = methods
+        .get_deposit_for_asset(token_identity, asset_id)
+        .call()
+        .await?
+        .value;
+    let total = methods.get_total_deposits(asset_id).call().await?.value;
+    let balance = harness
+        .provider
+        .get_contract_asset_balance(&harness.vault.contract_id(), &asset_id)
+        .await?;
+    Ok((deposit, total, balance as u64))
+}
+
+// Test that a token re-entering burn_deposit for the same coins reverts
+#[tokio::test]
+async fn test_reentrant_burn_deposit_reverts() -> Result<()> {
+    println!("Testing a token re-entering burn_deposit...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (token, asset_id) = deploy_malicious_token(&harness).await?;
+    assert_eq!(burn_books(&harness, &token, asset_id).await?, (DEPOSIT, DEPOSIT, DEPOSIT));
+
+    for depth in [2, 3] {
+        let error = burn_attack(&harness, &token, DEPOSIT, depth).await.unwrap_err();
+        assert!(format!("{:?}", error).contains("Insufficient balance"));
+        println!("❌ Burn re-entered {} times for the whole deposit reverted", depth - 1);
+    }
+
+    // The reverted transactions changed nothing
+    assert_eq!(burn_books(&harness, &token, asset_id).await?, (DEPOSIT, DEPOSIT, DEPOSIT));
+    assert_eq!(token.methods().burned().call().await?.value, 0);
+
+    // Without re-entering, the token burns exactly the deposit
+    burn_attack(&harness, &token, DEPOSIT, 1).await?;
+    assert_eq!(burn_books(&harness, &token, asset_id).await?, (0, 0, 0));
+    assert_eq!(token.methods().burned().call().await?.value, DEPOSIT);
+    println!("✅ Vault books unchanged by the attack, deposit burned once");
+
+    Ok(())
+}
+
+// Test that burns re-entered for what is left of the deposit keep the books consistent
+#[tokio::test]
+async fn test_reentered_burns_keep_books_consistent() -> Result<()> {
+    println!("Testing re-entered burns within the deposit...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let (token, asset_id) = deploy_malicious_token(&harness).await?;
+
+    // An honest deposit of another asset must not absorb any of the burns
+    let user = harness.user(0);
+    harness.mint_to(user, DEPOSIT).await?;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    // Four quarter burns, three of them nested inside the vault's call to the token
+    burn_attack(&harness, &token, DEPOSIT / 4, 4).await?;
+    assert_eq!(burn_books(&harness, &token, asset_id).await?, (0, DEPOSIT, 0));
+    assert_eq!(token.methods().burned().call().await?.value, DEPOSIT);
+
+    let user_identity = Identity::Address(user.address().into());
+    let methods = harness.vault.methods();
+    assert_eq!(methods.get_deposit(user_identity).call().await?.value, DEPOSIT);
+    let token_identity = Identity::ContractId(token.contract_id().clone());
+    assert_eq!(methods.get_deposit(token_identity).call().await?.value, 0);
+    println!("✅ Four nested burns of {} left the books consistent", DEPOSIT / 4);
+
+    // Nothing is left to burn
+    let error = burn_attack(&harness, &token, 1, 1).await.unwrap_err();
+    assert!(format!("{:?}", error).contains("Insufficient balance"));
+    println!("❌ Burn beyond the deposit reverted");
+
+    Ok(())
+}
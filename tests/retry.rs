@@ -0,0 +1,107 @@
+// Retry Tests
+// 
+// This module contains tests for retrying transient RPC failures including:
+// - Retrying transient errors until an attempt succeeds
+// - Failing at once on non-transient errors
+// - Giving up after the configured number of attempts
+// - Exponential, capped and jittered backoff delays
+// - Connecting to a node with the retrying provider
+
+use std::{cell::Cell, time::Duration};
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    retry::{connect_with_retry, is_transient, retry, RetryPolicy},
+    shared_node::shared_node_url,
+};
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(5))
+}
+
+// Test that transient errors are retried until the operation succeeds
+#[tokio::test]
+async fn test_retry_recovers_from_transient_errors() -> Result<()> {
+    let attempts = Cell::new(0);
+    let value = retry(&fast_policy(), || async {
+        attempts.set(attempts.get() + 1);
+        if attempts.get() < 3 {
+            Err(Error::Provider("connection reset".to_string()))
+        } else {
+            Ok(42)
+        }
+    })
+    .await?;
+
+    assert_eq!(value, 42);
+    assert_eq!(attempts.get(), 3);
+    println!("✅ Succeeded on attempt {}", attempts.get());
+    Ok(())
+}
+
+// Test that only transport failures are retried, and only so often
+#[tokio::test]
+async fn test_retry_gives_up() -> Result<()> {
+    assert!(is_transient(&Error::IO("timed out".to_string())));
+    assert!(!is_transient(&Error::Other("Insufficient balance".to_string())));
+
+    let attempts = Cell::new(0);
+    let result: Result<()> = retry(&fast_policy(), || async {
+        attempts.set(attempts.get() + 1);
+        Err(Error::Other("Insufficient balance".to_string()))
+    })
+    .await;
+    assert!(result.is_err());
+    assert_eq!(attempts.get(), 1);
+    println!("✅ Non-transient error not retried");
+
+    attempts.set(0);
+    let policy = fast_policy().with_max_attempts(4);
+    let result: Result<()> = retry(&policy, || async {
+        attempts.set(attempts.get() + 1);
+        Err(Error::Provider("503 Service Unavailable".to_string()))
+    })
+    .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("503"));
+    assert_eq!(attempts.get(), 4);
+    println!("✅ Gave up after {} attempts", attempts.get());
+
+    Ok(())
+}
+
+// Test that delays double, stay under the cap and are jittered downwards
+#[test]
+fn test_backoff_delays() {
+    let exact = RetryPolicy::default()
+        .with_backoff(Duration::from_millis(100), Duration::from_millis(1_000))
+        .with_jitter(0.0);
+    assert_eq!(exact.backoff(1), Duration::from_millis(100));
+    assert_eq!(exact.backoff(2), Duration::from_millis(200));
+    assert_eq!(exact.backoff(4), Duration::from_millis(800));
+    assert_eq!(exact.backoff(5), Duration::from_millis(1_000));
+    assert_eq!(exact.backoff(60), Duration::from_millis(1_000));
+
+    let jittered = exact.clone().with_jitter(0.5);
+    for retry in 1..10 {
+        let delay = jittered.backoff(retry);
+        let bound = exact.backoff(retry);
+        assert!(delay <= bound && delay >= bound / 2, "{:?} outside jitter of {:?}", delay, bound);
+    }
+    println!("✅ Backoff doubles, caps and jitters");
+}
+
+// Test connecting to a live node and to an unreachable one
+#[tokio::test]
+async fn test_connect_with_retry() -> Result<()> {
+    let url = shared_node_url().await?;
+    let provider = connect_with_retry(&url, &fast_policy()).await?;
+    assert!(provider.latest_block_height().await.is_ok());
+    println!("✅ Connected to {}", url);
+
+    let policy = fast_policy().with_max_attempts(2);
+    assert!(connect_with_retry("127.0.0.1:1", &policy).await.is_err());
+    println!("✅ Unreachable node reported after retries");
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+// Revert Decoding Tests
+// 
+// This module contains tests for decoding revert reasons including:
+// - An unauthorized mint decoded as Unauthorized with its message
+// - An over-withdraw from the vault decoded as InsufficientBalance
+// - Mapping standard library revert signals and require messages
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    revert::{FAILED_ASSERT_EQ_SIGNAL, FAILED_REQUIRE_SIGNAL, FAILED_TRANSFER_TO_ADDRESS_SIGNAL},
+    HarnessConfig, RevertReason, RosettaError, TestHarness, SUB_ID,
+};
+
+// Test that a mint by a non-admin decodes to Unauthorized
+#[tokio::test]
+async fn test_unauthorized_mint_reason() -> Result<()> {
+    println!("Testing unauthorized mint revert...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    let error = harness
+        .token_as(user)
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), 100)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .err()
+        .expect("only the admin can mint");
+
+    let reason = RevertReason::from_error(&error).expect("mint reverted");
+    assert_eq!(
+        reason,
+        RevertReason::Unauthorized("Unauthorized: Only admin can mint".to_string())
+    );
+    println!("❌ Decoded {:?}", reason);
+
+    // The typed error carries the same decoding
+    let error = RosettaError::from(error);
+    assert_eq!(error.decoded_revert(), Some(&reason));
+
+    Ok(())
+}
+
+// Test that withdrawing more than deposited decodes to InsufficientBalance
+#[tokio::test]
+async fn test_over_withdraw_reason() -> Result<()> {
+    println!("Testing over-withdraw revert...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let vault = harness.vault_as(user);
+
+    harness.mint_to(user, 1_000).await?;
+    vault
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(400)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let error = vault
+        .methods()
+        .withdraw(401)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .err()
+        .expect("withdrawing more than deposited must revert");
+
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::InsufficientBalance));
+    println!("❌ Over-withdraw decoded as InsufficientBalance");
+
+    Ok(())
+}
+
+// Test the mapping of revert signals and require messages
+#[test]
+fn test_decode_signals_and_messages() {
+    assert_eq!(
+        RevertReason::decode("\"Paused\"", Some(FAILED_REQUIRE_SIGNAL)),
+        RevertReason::Paused
+    );
+    assert_eq!(
+        RevertReason::decode("\"Frozen: recipient is frozen\"", Some(FAILED_REQUIRE_SIGNAL)),
+        RevertReason::Frozen
+    );
+    assert_eq!(
+        RevertReason::decode("\"Deadline passed\"", Some(FAILED_REQUIRE_SIGNAL)),
+        RevertReason::Require("Deadline passed".to_string())
+    );
+    assert_eq!(
+        RevertReason::decode("assert_eq failed", Some(FAILED_ASSERT_EQ_SIGNAL)),
+        RevertReason::AssertEqFailed
+    );
+    assert_eq!(
+        RevertReason::decode("", Some(FAILED_TRANSFER_TO_ADDRESS_SIGNAL)),
+        RevertReason::TransferToAddressFailed
+    );
+    assert_eq!(RevertReason::decode("Revert(42)", Some(42)), RevertReason::Revert(42));
+    assert_eq!(
+        RevertReason::decode("OutOfGas", None),
+        RevertReason::Unknown("OutOfGas".to_string())
+    );
+    assert_eq!(
+        RevertReason::from_message("Only admin can deposit"),
+        RevertReason::Unauthorized("Only admin can deposit".to_string())
+    );
+}
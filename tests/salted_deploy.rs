@@ -0,0 +1,69 @@
+// Salted Deploy Tests
+// 
+// This module contains tests for salted, deterministic deployment including:
+// - Predicting a contract id offline and deploying to exactly that address
+// - Predictions for contracts with and without configurables
+// - Different salts giving different addresses for the same contract
+// - Redeploying with a used salt being rejected
+
+use fuels::{
+    core::Configurables,
+    prelude::*,
+    types::{Identity, Salt, SizedAsciiString},
+};
+
+use rosetta_stone::{
+    artifacts, deploy_escrow_with_salt, deploy_src20_token_with_salt, launch_wallets,
+    predict_contract_id, RosettaError, Src20TokenConfigurables,
+};
+
+// Test that predicted ids match the deployed addresses
+#[tokio::test]
+async fn test_predicted_id_matches_deployment() -> Result<()> {
+    println!("Testing salted deployment prediction...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(1), Some(1), Some(1_000_000_000))).await?;
+    let wallet = wallets[0].clone();
+    let salt = Salt::from([7u8; 32]);
+
+    // The token's configurables include the deploying wallet as admin
+    let name: SizedAsciiString<7> = "SALTTOK".try_into()?;
+    let symbol: SizedAsciiString<5> = "SALTT".try_into()?;
+    let configurables = Src20TokenConfigurables::default()
+        .with_NAME(name)?
+        .with_SYMBOL(symbol)?
+        .with_DECIMALS(9)?
+        .with_ADMIN(Identity::Address(wallet.address().into()))?;
+    let predicted = predict_contract_id(artifacts::SRC20_TOKEN_BIN, configurables, salt)?;
+
+    let token = deploy_src20_token_with_salt(wallet.clone(), "SALTTOK", "SALTT", 9, salt).await?;
+    assert_eq!(token.contract_id().clone(), predicted);
+    println!("✅ Token deployed at the predicted id {}", predicted);
+
+    let predicted = predict_contract_id(artifacts::ESCROW_BIN, Configurables::default(), salt)?;
+    let escrow = deploy_escrow_with_salt(wallet.clone(), salt).await?;
+    assert_eq!(escrow.contract_id().clone(), predicted);
+    println!("✅ Escrow deployed at the predicted id {}", predicted);
+
+    Ok(())
+}
+
+// Test that the salt alone decides between addresses of the same contract
+#[tokio::test]
+async fn test_salt_determines_address() -> Result<()> {
+    println!("Testing salt uniqueness...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(1), Some(1), Some(1_000_000_000))).await?;
+    let wallet = wallets[0].clone();
+
+    let first = deploy_escrow_with_salt(wallet.clone(), Salt::from([1u8; 32])).await?;
+    let second = deploy_escrow_with_salt(wallet.clone(), Salt::from([2u8; 32])).await?;
+    assert_ne!(first.contract_id(), second.contract_id());
+    println!("✅ Different salts deployed to different ids");
+
+    let result = deploy_escrow_with_salt(wallet.clone(), Salt::from([1u8; 32])).await;
+    assert!(matches!(result, Err(RosettaError::DeployFailed { contract: "Escrow", .. })));
+    println!("❌ Redeploying with a used salt rejected");
+
+    Ok(())
+}
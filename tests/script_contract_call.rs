@@ -0,0 +1,109 @@
+// Script Contract Call Tests
+// 
+// This module contains tests for a script that calls the Src20Token contract including:
+// - Attaching the token as a contract input to the script transaction
+// - Minting through the token ABI and reading the total supply back
+// - Failing without the contract input
+// - The token's admin check applying to the script's caller
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{input::Input, transaction::Transaction, ContractId, Identity},
+};
+
+use rosetta_stone::{artifacts, HarnessConfig, RevertReason, TestHarness, TokenCall};
+
+const MINT_AMOUNT: u64 = 1_000;
+
+fn token_call(
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+) -> TokenCall<Wallet<Unlocked<PrivateKeySigner>>> {
+    TokenCall::new(wallet.clone(), artifacts::TOKEN_CALL_BIN)
+}
+
+// Test that the script mints through the token once the token is a contract input
+#[tokio::test]
+async fn test_script_mints_through_token_abi() -> Result<()> {
+    println!("Testing a script calling the token contract...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(1)).await?;
+    let token_id = harness.token.contract_id().clone();
+    let recipient = Identity::Address(harness.user(0).address().into());
+
+    // The contract input is what lets the script reach the token
+    let tx = token_call(&harness.admin)
+        .main(token_id, recipient, MINT_AMOUNT)
+        .with_contract_ids(&[token_id])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .build_tx()
+        .await?;
+    assert!(tx.inputs().iter().any(
+        |input| matches!(input, Input::Contract { contract_id, .. } if *contract_id == token_id)
+    ));
+    println!("✅ Token {} attached as a contract input", token_id);
+
+    let supply = token_call(&harness.admin)
+        .main(token_id, recipient, MINT_AMOUNT)
+        .with_contract_ids(&[token_id])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(supply, MINT_AMOUNT);
+    assert_eq!(harness.token_balance(harness.user(0)).await?, MINT_AMOUNT as u128);
+
+    // The script reads the supply after its own mint
+    let supply = token_call(&harness.admin)
+        .main(token_id, recipient, MINT_AMOUNT)
+        .with_contract_ids(&[token_id])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(supply, 2 * MINT_AMOUNT);
+    println!("✅ Script minted twice, total supply {}", supply);
+
+    Ok(())
+}
+
+// Test that the script fails without the token as a contract input
+#[tokio::test]
+async fn test_script_without_contract_input_fails() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(1)).await?;
+    let token_id = harness.token.contract_id().clone();
+    let recipient = Identity::Address(harness.user(0).address().into());
+
+    let result = token_call(&harness.admin)
+        .main(token_id, recipient, MINT_AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(harness.token_balance(harness.user(0)).await?, 0);
+
+    Ok(())
+}
+
+// Test that the token's admin check sees the script's caller
+#[tokio::test]
+async fn test_script_mint_requires_admin() -> Result<()> {
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(1)).await?;
+    let token_id = harness.token.contract_id().clone();
+    let user = harness.user(0);
+
+    let error = token_call(user)
+        .main(token_id, Identity::Address(user.address().into()), MINT_AMOUNT)
+        .with_contract_ids(&[token_id])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .err()
+        .expect("a mint by a non-admin should revert");
+    assert!(matches!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Unauthorized(_))
+    ));
+
+    Ok(())
+}
@@ -3,56 +3,14 @@
 // This test focuses on a single working script execution pattern
 
 use fuels::{
-    accounts::signers::private_key::PrivateKeySigner,
     prelude::*,
-    types::{Bits256, Identity, SizedAsciiString, tx_status::TxStatus},
+    types::{transaction::Transaction, tx_status::TxStatus, Identity},
 };
 
-use fuels::accounts::wallet::Unlocked;
-
-// Load abi from json
-abigen!(
-    Contract(
-        name = "Src20Token",
-        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
-    ),
-    Script(
-        name = "MultiAssetTransfer",
-        abi = "scripts/multi-asset-transfer/out/debug/multi_asset_transfer-abi.json",
-    ),
-);
-
-const TOKEN_AMOUNT: u64 = 1_000_000;
-const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
-const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
-
-// Deploys the SRC20 token contract with the given wallet and metadata
-async fn deploy_src20_token(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let name_bytes: SizedAsciiString<7> = name.try_into()?;
-    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
-
-    let configurables = Src20TokenConfigurables::default()
-        .with_NAME(name_bytes.clone())?
-        .with_SYMBOL(symbol_bytes.clone())?
-        .with_DECIMALS(decimals)?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/src20-token/out/debug/src20_token.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ Token '{}' ({}) deployed at: {}", name, symbol, contract_id.to_string());
-    Ok(Src20Token::new(contract_id, wallet))
-}
+use rosetta_stone::{
+    artifacts, deploy_src20_token, launch_wallets, script_tx::build_script_tx, MultiAssetTransfer,
+    MultiAssetTransferConfigurables, RosettaError, Src20Token, SUB_ID,
+};
 
 // Test simple script execution
 #[tokio::test]
@@ -69,8 +27,7 @@ async fn test_simple_script_execution() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let recipient_wallet_1 = wallets.pop().unwrap();
@@ -97,7 +54,7 @@ async fn test_simple_script_execution() -> Result<()> {
         Identity::Address(recipient_wallet_3.address().into()),
     ];
     let amounts = [100u64, 200u64, 300u64]; // Three amounts as expected
-    let total_amount = 100 + 200 + 300; // = 600
+    let total_amount: u128 = 100 + 200 + 300; // = 600
 
     let admin_token_contract =
         Src20Token::new(token_contract.contract_id().clone(), admin_wallet.clone());
@@ -137,53 +94,26 @@ async fn test_simple_script_execution() -> Result<()> {
     // Create script instance
     let script_instance = MultiAssetTransfer::new(
         admin_wallet.clone(),
-        "scripts/multi-asset-transfer/out/debug/multi_asset_transfer.bin",
+        artifacts::MULTI_ASSET_TRANSFER_BIN,
     )
     .with_configurables(configurables);
 
     // Execute script using manual transaction building
     println!("Executing script with manual transaction building...");
     
-    let script_call = script_instance.main(asset_id);
-    let mut tb = script_call.transaction_builder().await?;
-
-    // Add the token inputs to the script transaction
-    println!("Adding token inputs to script transaction...");
-    let token_inputs = admin_wallet
-        .get_asset_inputs_for_amount(asset_id, total_amount as u128, None)
-        .await?;
-    
-    println!("Found {} token inputs for script", token_inputs.len());
-    for (i, input) in token_inputs.iter().enumerate() {
-        println!("  Input {}: {:?}", i + 1, input);
-    }
-    
-    tb.inputs.extend(token_inputs);
-
-    // Enable burning for unused tokens
-    tb = tb.enable_burn(true);
-
-    // Set transaction policies
-    let tx_policies = TxPolicies::default()
-        .with_script_gas_limit(2_000_000)
-        .with_max_fee(1_000_000);
-    
-    tb = tb
-        .with_tx_policies(tx_policies)
-        .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
-
-    // Add fees and witnesses
-    admin_wallet.adjust_for_fee(&mut tb, 0).await?;
-    admin_wallet.add_witnesses(&mut tb)?;
-
-    println!("Transaction builder state:");
-    println!("  - Inputs: {}", tb.inputs.len());
-    println!("  - Outputs: {}", tb.outputs.len());
-    println!("  - Witnesses: {}", tb.witnesses.len());
-
-    // Build and send transaction
+    let script_call = script_instance
+        .main(asset_id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(amounts.len()));
+
+    // Add the token inputs, change, fee and witnesses to the script transaction
+    let tx = build_script_tx(&script_call, &admin_wallet, &[(asset_id, total_amount)]).await?;
+    println!("Transaction built:");
+    println!("  - Inputs: {}", tx.inputs().len());
+    println!("  - Outputs: {}", tx.outputs().len());
+    println!("  - Witnesses: {}", tx.witnesses().len());
+
+    // Send transaction
     let provider = admin_wallet.try_provider()?.clone();
-    let tx = tb.build(&provider).await?;
     let tx_id = provider.send_transaction(tx).await?;
     
     println!("Transaction sent: {:?}", tx_id);
@@ -241,15 +171,16 @@ async fn test_simple_script_execution() -> Result<()> {
             
             let balance_decrease = admin_balance - admin_balance_after;
             println!("Admin balance decreased by: {}", balance_decrease);
+            assert_eq!(balance_decrease, total_amount);
 
             println!("✅ Simple script execution test passed!");
         }
         TxStatus::Failure(failure) => {
             println!("❌ Script execution failed: {:?}", failure);
-            return Err("Script execution failed".into());
+            return Err(RosettaError::TransactionFailed(failure.reason).into());
         }
         _ => {
-            return Err("Transaction still pending".into());
+            return Err(RosettaError::TransactionPending.into());
         }
     }
 
@@ -0,0 +1,142 @@
+// Script Transaction Builder Tests
+// 
+// This module contains tests for build_script_tx including:
+// - Adding the coins a script spends as inputs, with change instead of a burn
+// - Covering base asset needs together with the fee, with a single base change output
+// - Failing to build when the wallet cannot cover an asset need
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    programs::calls::ScriptCall,
+    types::{
+        output::Output,
+        transaction::{ScriptTransaction, Transaction},
+        tx_status::TxStatus,
+        Identity,
+    },
+};
+
+use rosetta_stone::{
+    artifacts, script_tx::build_script_tx, HarnessConfig, MultiAssetTransfer,
+    MultiAssetTransferConfigurables, TestHarness,
+};
+
+const AMOUNTS: [u64; 3] = [100, 200, 300];
+const TOTAL: u128 = 600;
+
+// The MultiAssetTransfer call paying AMOUNTS of `asset_id` from the admin to the three users
+fn transfer_call(
+    harness: &TestHarness,
+    asset_id: AssetId,
+) -> Result<CallHandler<Wallet<Unlocked<PrivateKeySigner>>, ScriptCall, bool>> {
+    let recipients = [0, 1, 2].map(|i| Identity::Address(harness.user(i).address().into()));
+    let configurables = MultiAssetTransferConfigurables::default()
+        .with_RECIPIENTS(recipients)?
+        .with_AMOUNTS(AMOUNTS)?;
+
+    Ok(
+        MultiAssetTransfer::new(harness.admin.clone(), artifacts::MULTI_ASSET_TRANSFER_BIN)
+            .with_configurables(configurables)
+            .main(asset_id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(AMOUNTS.len())),
+    )
+}
+
+// Change outputs of `asset_id` in `tx`
+fn change_outputs(tx: &ScriptTransaction, asset_id: AssetId) -> usize {
+    tx.outputs()
+        .iter()
+        .filter(|output| matches!(output, Output::Change { asset_id: id, .. } if *id == asset_id))
+        .count()
+}
+
+// Sum of the inputs of `asset_id` in `tx`
+fn input_total(tx: &ScriptTransaction, asset_id: AssetId, base_asset_id: AssetId) -> u128 {
+    tx.inputs()
+        .iter()
+        .filter(|input| input.asset_id(&base_asset_id) == Some(&asset_id))
+        .filter_map(|input| input.amount())
+        .map(u128::from)
+        .sum()
+}
+
+async fn send(harness: &TestHarness, tx: ScriptTransaction) -> Result<TxStatus> {
+    let status = harness.provider.send_transaction_and_await_commit(tx).await?;
+    assert!(matches!(status, TxStatus::Success { .. }), "script failed: {:?}", status);
+    Ok(status)
+}
+
+// Test that the script's token coins become inputs and the remainder comes back as change
+#[tokio::test]
+async fn test_build_script_tx_spends_tokens() -> Result<()> {
+    println!("Testing a script spending tokens...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    harness.mint_to(&harness.admin, 10_000).await?;
+    let admin_before = harness.token_balance(&harness.admin).await?;
+
+    let script_call = transfer_call(&harness, harness.asset_id)?;
+    let tx = build_script_tx(&script_call, &harness.admin, &[(harness.asset_id, TOTAL)]).await?;
+    assert!(input_total(&tx, harness.asset_id, base_asset_id) >= TOTAL);
+    assert_eq!(change_outputs(&tx, harness.asset_id), 1);
+    assert_eq!(tx.witnesses().len(), 1);
+    println!("   Built with {} inputs and {} outputs", tx.inputs().len(), tx.outputs().len());
+
+    let status = send(&harness, tx).await?;
+    assert!(script_call.get_response(status)?.value);
+
+    for (i, amount) in AMOUNTS.into_iter().enumerate() {
+        assert_eq!(harness.token_balance(harness.user(i)).await?, u128::from(amount));
+    }
+    // Nothing burned: the admin lost exactly what the script paid out
+    assert_eq!(harness.token_balance(&harness.admin).await?, admin_before - TOTAL);
+    println!("✅ Paid {} tokens, change returned to the admin", TOTAL);
+
+    Ok(())
+}
+
+// Test that a base asset need is covered by the same inputs as the fee
+#[tokio::test]
+async fn test_build_script_tx_spends_base_asset() -> Result<()> {
+    println!("Testing a script spending the base asset...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let mut before = Vec::new();
+    for i in 0..AMOUNTS.len() {
+        before.push(harness.user(i).get_asset_balance(&base_asset_id).await?);
+    }
+
+    let script_call = transfer_call(&harness, base_asset_id)?;
+    let tx = build_script_tx(&script_call, &harness.admin, &[(base_asset_id, TOTAL)]).await?;
+    assert!(input_total(&tx, base_asset_id, base_asset_id) > TOTAL);
+    assert_eq!(change_outputs(&tx, base_asset_id), 1);
+
+    send(&harness, tx).await?;
+    for (i, amount) in AMOUNTS.into_iter().enumerate() {
+        let balance = harness.user(i).get_asset_balance(&base_asset_id).await?;
+        assert_eq!(balance, before[i] + u128::from(amount));
+    }
+    println!("✅ Paid {} of the base asset next to the fee", TOTAL);
+
+    Ok(())
+}
+
+// Test that an asset need the wallet cannot cover fails before anything is sent
+#[tokio::test]
+async fn test_build_script_tx_insufficient_funds() -> Result<()> {
+    println!("Testing a script the wallet cannot fund...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    harness.mint_to(&harness.admin, 500).await?;
+
+    let script_call = transfer_call(&harness, harness.asset_id)?;
+    let result = build_script_tx(&script_call, &harness.admin, &[(harness.asset_id, TOTAL)]).await;
+    assert!(result.is_err(), "500 tokens cannot cover a need of {}", TOTAL);
+    assert_eq!(harness.token_balance(&harness.admin).await?, 500);
+    println!("❌ Building rejected with only 500 tokens");
+
+    Ok(())
+}
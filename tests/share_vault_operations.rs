@@ -0,0 +1,145 @@
+// Share Vault Operations Tests
+// 
+// This module contains tests for the SRC-6 style ShareVault including:
+// - Minting shares on deposit
+// - Exchange rate changes after donated yield
+// - Burning shares on withdraw for a proportional part of the underlying
+
+use fuels::{
+    prelude::*,
+    types::{AssetId, Identity},
+};
+
+use rosetta_stone::{deploy_share_vault, HarnessConfig, TestHarness, TOKEN_AMOUNT};
+
+// Test the share/asset exchange rate across several deposits and withdrawals
+#[tokio::test]
+async fn test_share_vault_exchange_rate() -> Result<()> {
+    println!("Testing share vault exchange rate...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let alice = harness.user(0);
+    let bob = harness.user(1);
+    harness.mint_to(alice, TOKEN_AMOUNT).await?;
+    harness.mint_to(bob, TOKEN_AMOUNT).await?;
+    harness.mint_to(&harness.admin, TOKEN_AMOUNT).await?;
+
+    let vault = deploy_share_vault(harness.admin.clone(), harness.asset_id).await?;
+    let share_asset_id = vault.methods().share_asset_id().call().await?.value;
+
+    // First deposit mints shares 1:1
+    let alice_vault = vault.clone().with_account(alice.clone());
+    let alice_shares = alice_vault
+        .methods()
+        .deposit(Identity::Address(alice.address().into()))
+        .call_params(
+            CallParameters::default()
+                .with_amount(100_000)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(alice_shares, 100_000);
+    assert_eq!(alice.get_asset_balance(&share_asset_id).await?, 100_000);
+
+    // Yield raises the value of each share to 1.5 underlying
+    vault
+        .methods()
+        .donate()
+        .call_params(
+            CallParameters::default()
+                .with_amount(50_000)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    assert_eq!(vault.methods().total_assets().call().await?.value, 150_000);
+    assert_eq!(vault.methods().preview_withdraw(100_000).call().await?.value, 150_000);
+    assert_eq!(vault.methods().preview_deposit(150_000).call().await?.value, 100_000);
+
+    // A later deposit of the same value gets fewer shares
+    let bob_vault = vault.clone().with_account(bob.clone());
+    let bob_shares = bob_vault
+        .methods()
+        .deposit(Identity::Address(bob.address().into()))
+        .call_params(
+            CallParameters::default()
+                .with_amount(150_000)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(bob_shares, 100_000);
+    assert_eq!(vault.methods().total_shares().call().await?.value, 200_000);
+    println!("✅ Shares minted at the current exchange rate");
+
+    // Withdrawing burns shares and returns the proportional underlying
+    let alice_assets = alice_vault
+        .methods()
+        .withdraw(Identity::Address(alice.address().into()))
+        .call_params(
+            CallParameters::default()
+                .with_amount(alice_shares)
+                .with_asset_id(share_asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(alice_assets, 150_000);
+    assert_eq!(alice.get_asset_balance(&share_asset_id).await?, 0);
+    assert_eq!(
+        harness.token_balance(alice).await?,
+        (TOKEN_AMOUNT + 50_000) as u128
+    );
+
+    // Half of bob's shares redeem half of what is left
+    let bob_assets = bob_vault
+        .methods()
+        .withdraw(Identity::Address(bob.address().into()))
+        .call_params(
+            CallParameters::default()
+                .with_amount(bob_shares / 2)
+                .with_asset_id(share_asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(bob_assets, 75_000);
+    assert_eq!(vault.methods().total_assets().call().await?.value, 75_000);
+    assert_eq!(vault.methods().total_shares().call().await?.value, 50_000);
+
+    println!("✅ Share vault exchange rate test passed");
+    Ok(())
+}
+
+// Test that only the underlying asset is accepted
+#[tokio::test]
+async fn test_share_vault_rejects_wrong_asset() -> Result<()> {
+    println!("Testing share vault wrong asset deposit...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let vault = deploy_share_vault(harness.admin.clone(), harness.asset_id).await?;
+
+    let result = vault
+        .methods()
+        .deposit(Identity::Address(harness.admin.address().into()))
+        .call_params(
+            CallParameters::default()
+                .with_amount(1_000)
+                .with_asset_id(AssetId::zeroed()),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(vault.methods().total_shares().call().await?.value, 0);
+
+    println!("✅ Wrong asset deposit correctly rejected");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Shared Node Tests
+// 
+// This module contains tests for the shared node fixture including:
+// - Harnesses on the shared node seeing each other's transactions
+// - Every caller getting distinct, funded wallets from the pool
+
+use std::collections::HashSet;
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    harness::launch_wallets_with,
+    shared_node::{shared_node_url, SHARED_AMOUNT_PER_COIN, SHARED_COINS_PER_WALLET},
+    HarnessConfig, NodeMode, TestHarness, TOKEN_AMOUNT,
+};
+
+// Test that two harnesses run on one node with their own wallets
+#[tokio::test]
+async fn test_harnesses_share_one_node() -> Result<()> {
+    println!("Testing harnesses on the shared node...");
+
+    let config = HarnessConfig::default().with_node(NodeMode::Shared);
+    let first = TestHarness::new(config.clone()).await?;
+    let second = TestHarness::new(config).await?;
+
+    let url = shared_node_url().await?;
+    assert_eq!(first.provider.url(), url);
+    assert_eq!(second.provider.url(), url);
+    assert_ne!(first.admin.address(), second.admin.address());
+    println!("✅ Both harnesses connected to {}", url);
+
+    // A mint through one harness is visible from the other's provider
+    first.mint_to(first.user(0), TOKEN_AMOUNT).await?;
+    let balance = second
+        .provider
+        .get_asset_balance(&first.user(0).address(), &first.asset_id)
+        .await?;
+    assert_eq!(balance, TOKEN_AMOUNT as u128);
+    println!("✅ Mint on the first harness seen by the second");
+
+    Ok(())
+}
+
+// Test that concurrent callers never receive the same pool wallet
+#[tokio::test]
+async fn test_shared_wallets_are_distinct_and_funded() -> Result<()> {
+    println!("Testing shared wallet pool...");
+
+    // Coin settings are ignored on the shared node
+    let config = WalletsConfig::new(Some(3), Some(1), Some(1));
+    let (first, second) = tokio::join!(
+        launch_wallets_with(config.clone(), &NodeMode::Shared),
+        launch_wallets_with(config, &NodeMode::Shared),
+    );
+    let wallets: Vec<_> = first?.into_iter().chain(second?).collect();
+
+    let addresses: HashSet<_> = wallets.iter().map(|wallet| wallet.address()).collect();
+    assert_eq!(addresses.len(), 6);
+    println!("✅ {} distinct wallets handed out", addresses.len());
+
+    let base_asset = *wallets[0].try_provider()?.consensus_parameters().await?.base_asset_id();
+    for wallet in &wallets {
+        assert_eq!(
+            wallet.get_asset_balance(&base_asset).await?,
+            (SHARED_COINS_PER_WALLET * SHARED_AMOUNT_PER_COIN) as u128
+        );
+    }
+    println!("✅ Every wallet holds its pool funding");
+
+    Ok(())
+}
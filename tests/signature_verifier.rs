@@ -0,0 +1,180 @@
+// Signature Verifier Tests
+// 
+// This module contains tests for signing messages off chain and verifying them on chain including:
+// - The off-chain digest matching the one the contract computes
+// - A signer signing an attestation without a transaction and a relayer submitting it
+// - Rejecting tampered attestations, signatures by another key and replays
+// - Signatures being bound to one deployment of the contract
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{Bits256, B512},
+};
+
+use rosetta_stone::{
+    attestation::{attestation_message, prepare_attestation, sign_attestation},
+    deploy_signature_verifier, deploy_signature_verifier_with_salt, expect_single_event,
+    Attestation, AttestationRecordedEvent, HarnessConfig, RevertReason, SignatureVerifier,
+    TestHarness,
+};
+
+const SUBJECT: Bits256 = Bits256([7u8; 32]);
+const SCORE: u64 = 42;
+
+type Verifier = SignatureVerifier<Wallet<Unlocked<PrivateKeySigner>>>;
+
+// Deploys the verifier, bound to user 1 as the relayer submitting attestations
+async fn setup() -> Result<(TestHarness, Verifier)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let verifier = deploy_signature_verifier(harness.admin.clone())
+        .await?
+        .with_account(harness.user(1).clone());
+    Ok((harness, verifier))
+}
+
+// User 0 signs an attestation of SUBJECT with `score` for `verifier`
+async fn signed(
+    harness: &TestHarness,
+    verifier: &Verifier,
+    score: u64,
+) -> Result<(Attestation, B512)> {
+    let signer = harness.user(0);
+    let attestation =
+        prepare_attestation(verifier, signer.address().into(), SUBJECT, score).await?;
+    let signature = sign_attestation(signer.signer(), &attestation, &verifier.contract_id()).await?;
+    Ok((attestation, signature))
+}
+
+async fn submit_error(verifier: &Verifier, attestation: Attestation, signature: B512) -> Error {
+    verifier
+        .methods()
+        .submit(attestation, signature)
+        .call()
+        .await
+        .err()
+        .expect("submitting must revert")
+}
+
+// Test that the digest signed off chain is the one the contract recovers from
+#[tokio::test]
+async fn test_digest_matches_contract() -> Result<()> {
+    println!("Testing the attestation digest...");
+
+    let (harness, verifier) = setup().await?;
+    let (attestation, _) = signed(&harness, &verifier, SCORE).await?;
+
+    let on_chain = verifier.methods().digest(attestation.clone()).call().await?.value;
+    let off_chain = attestation_message(&attestation, &verifier.contract_id());
+    assert_eq!(on_chain, Bits256(*off_chain));
+    println!("✅ Digest {} computed identically on and off chain", on_chain);
+
+    Ok(())
+}
+
+// Test the full sign-off-chain, verify-on-chain flow
+#[tokio::test]
+async fn test_sign_off_chain_verify_on_chain() -> Result<()> {
+    println!("Testing an attestation signed off chain...");
+
+    let (harness, verifier) = setup().await?;
+    let signer = harness.user(0);
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let signer_before = signer.get_asset_balance(&base_asset_id).await?;
+
+    // The signer only signs; no transaction is sent from their wallet
+    let (attestation, signature) = signed(&harness, &verifier, SCORE).await?;
+    let valid = verifier
+        .methods()
+        .verify(attestation.clone(), signature)
+        .call()
+        .await?
+        .value;
+    assert!(valid);
+
+    let response = verifier
+        .methods()
+        .submit(attestation.clone(), signature)
+        .call()
+        .await?;
+    let event: AttestationRecordedEvent = expect_single_event(&response)?;
+    assert_eq!(event.signer, attestation.signer);
+    assert_eq!(event.score, SCORE);
+
+    let score = verifier
+        .methods()
+        .score(attestation.signer, SUBJECT)
+        .call()
+        .await?
+        .value;
+    assert_eq!(score, Some(SCORE));
+    assert_eq!(verifier.methods().nonce(attestation.signer).call().await?.value, 1);
+    assert_eq!(signer.get_asset_balance(&base_asset_id).await?, signer_before);
+    println!("✅ Relayer recorded score {}; the signer paid nothing", SCORE);
+
+    Ok(())
+}
+
+// Test that tampered, misattributed and replayed attestations are rejected
+#[tokio::test]
+async fn test_rejected_attestations() -> Result<()> {
+    println!("Testing rejected attestations...");
+
+    let (harness, verifier) = setup().await?;
+    let invalid_signature = Some(RevertReason::Require("Invalid signature".to_string()));
+    let (attestation, signature) = signed(&harness, &verifier, SCORE).await?;
+
+    // A different score than the one signed
+    let tampered = Attestation {
+        score: SCORE + 1,
+        ..attestation.clone()
+    };
+    assert!(!verifier.methods().verify(tampered.clone(), signature).call().await?.value);
+    let error = submit_error(&verifier, tampered, signature).await;
+    assert_eq!(RevertReason::from_error(&error), invalid_signature);
+    println!("❌ Tampered score rejected");
+
+    // User 0's signature over an attestation claiming to be user 1's
+    let claimed = Attestation {
+        signer: harness.user(1).address().into(),
+        ..attestation.clone()
+    };
+    let message = attestation_message(&claimed, &verifier.contract_id());
+    let forged = harness.user(0).signer().sign(message).await?;
+    let forged = B512::try_from(forged.as_ref())?;
+    let error = submit_error(&verifier, claimed.clone(), forged).await;
+    assert_eq!(RevertReason::from_error(&error), invalid_signature);
+    assert!(sign_attestation(harness.user(0).signer(), &claimed, &verifier.contract_id())
+        .await
+        .is_err());
+    println!("❌ Signature by another key rejected");
+
+    // The genuine attestation goes through once
+    verifier.methods().submit(attestation.clone(), signature).call().await?;
+    let error = submit_error(&verifier, attestation, signature).await;
+    assert_eq!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Require("Invalid nonce".to_string()))
+    );
+    println!("❌ Replay rejected");
+
+    Ok(())
+}
+
+// Test that a signature for one deployment is not valid on another
+#[tokio::test]
+async fn test_signature_bound_to_contract() -> Result<()> {
+    println!("Testing signatures across deployments...");
+
+    let (harness, verifier) = setup().await?;
+    let other =
+        deploy_signature_verifier_with_salt(harness.admin.clone(), Salt::from([1u8; 32])).await?;
+    assert_ne!(other.contract_id(), verifier.contract_id());
+
+    let (attestation, signature) = signed(&harness, &verifier, SCORE).await?;
+    assert!(verifier.methods().verify(attestation.clone(), signature).call().await?.value);
+    assert!(!other.methods().verify(attestation, signature).call().await?.value);
+    println!("✅ Signature only valid on the contract it was signed for");
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+// Signing Vector Tests
+// 
+// This module checks the deterministic mock signer against the fixture in
+// `tests/golden/signing_vectors.json` including:
+// - Addresses derived from the fixed keys
+// - Byte-exact signatures over a fixed message and a fixed transaction id
+// - A transfer built twice producing the fixture's transaction id and witness
+// - The signer recording the transaction id it signed
+//
+// The fixture's `tx_id` is the id of that transfer. The addresses and
+// signatures were computed independently of the SDK (RFC 6979 ECDSA over
+// secp256k1), so an SDK upgrade that changes key derivation, signing or the
+// transfer's encoding fails here byte for byte.
+
+use fuels::{
+    core::traits::Signer,
+    crypto::Message,
+    prelude::*,
+    tx::UtxoId,
+    types::{
+        coin::Coin, transaction::Transaction, transaction_builders::ScriptTransactionBuilder,
+        Bytes32,
+    },
+};
+use serde::Deserialize;
+
+use rosetta_stone::mock_signer::MockSigner;
+
+const FIXTURE: &str = "tests/golden/signing_vectors.json";
+
+#[derive(Debug, Deserialize)]
+struct Fixture {
+    message: String,
+    tx_id: String,
+    transfer_witness: String,
+    signers: Vec<SignerVector>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignerVector {
+    seed: u8,
+    address: String,
+    message_signature: String,
+    tx_id_signature: String,
+}
+
+fn load_fixture() -> Fixture {
+    let json = std::fs::read_to_string(FIXTURE).expect("fixture should exist");
+    serde_json::from_str(&json).expect("fixture should parse")
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("fixture should be hex"))
+        .collect()
+}
+
+// Test that the fixed keys derive the fixture's addresses
+#[tokio::test]
+async fn test_fixed_signer_addresses() -> Result<()> {
+    for vector in load_fixture().signers {
+        let signer = MockSigner::fixed(vector.seed);
+        assert_eq!(to_hex(&*signer.address()), vector.address, "seed {}", vector.seed);
+    }
+
+    Ok(())
+}
+
+// Test that signatures over the fixed message and tx id match byte for byte
+#[tokio::test]
+async fn test_fixed_signer_signatures() -> Result<()> {
+    let fixture = load_fixture();
+    let message = Message::new(fixture.message.as_bytes());
+    let tx_id: [u8; 32] = from_hex(&fixture.tx_id).try_into().expect("tx id is 32 bytes");
+    let tx_id_message = Message::from_bytes(tx_id);
+
+    for vector in fixture.signers {
+        let signer = MockSigner::fixed(vector.seed);
+
+        let signature = signer.sign(message).await?;
+        assert_eq!(to_hex(&*signature), vector.message_signature, "seed {}", vector.seed);
+
+        let signature = signer.sign(tx_id_message).await?;
+        assert_eq!(to_hex(&*signature), vector.tx_id_signature, "seed {}", vector.seed);
+
+        assert_eq!(signer.signed().len(), 2);
+    }
+
+    Ok(())
+}
+
+// Test that a transfer signed by a fixed key is reproducible and signs its tx id
+#[tokio::test]
+async fn test_transfer_is_reproducible() -> Result<()> {
+    let fixture = load_fixture();
+    let signer = MockSigner::fixed(1);
+    let recipient = MockSigner::fixed(2).address();
+
+    // Fixed UTXO ids; the SDK helpers randomize them
+    let coins = (0..2u8)
+        .map(|i| Coin {
+            owner: signer.address(),
+            utxo_id: UtxoId::new(Bytes32::from([16 + i; 32]), 0),
+            amount: 1_000_000_000,
+            asset_id: AssetId::zeroed(),
+        })
+        .collect();
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    let chain_id = provider.consensus_parameters().await?.chain_id();
+    let wallet = Wallet::new(signer.clone(), provider.clone());
+
+    let policies = TxPolicies::default()
+        .with_script_gas_limit(1_000_000)
+        .with_max_fee(100_000);
+    let mut transactions = Vec::new();
+    for _ in 0..2 {
+        let inputs = wallet
+            .get_asset_inputs_for_amount(AssetId::zeroed(), 1_000, None)
+            .await?;
+        let outputs = wallet.get_asset_outputs_for_amount(recipient, AssetId::zeroed(), 1_000);
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, policies);
+        wallet.add_witnesses(&mut tb)?;
+        transactions.push(tb.build(&provider).await?);
+    }
+
+    let ids: Vec<_> = transactions.iter().map(|tx| tx.id(chain_id)).collect();
+    assert_eq!(ids[0], ids[1]);
+    assert_eq!(to_hex(&*ids[0]), fixture.tx_id);
+    assert_eq!(transactions[0].witnesses(), transactions[1].witnesses());
+    let witnesses = transactions[0].witnesses();
+    assert_eq!(witnesses.len(), 1);
+    assert_eq!(to_hex(witnesses[0].as_vec()), fixture.transfer_witness);
+
+    // Each build signed exactly its tx id, with a signature of this key
+    let signed = signer.signed();
+    assert_eq!(signed.len(), 2);
+    for (message, signature) in signed {
+        assert_eq!(message, Message::from_bytes(*ids[0]));
+        let public_key = signature.recover(&message).expect("signature should recover");
+        assert_eq!(Address::from(*public_key.hash()), signer.address());
+    }
+
+    Ok(())
+}
@@ -7,6 +7,8 @@ use fuels::{
     types::{Bits256, Identity, SizedAsciiString},
 };
 
+use rosetta_stone::launch_wallets;
+
 
 // Load abi from json
 abigen!(
@@ -35,8 +37,7 @@ async fn test_simple_token_operations() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallet = wallets.pop().unwrap();
@@ -160,8 +161,7 @@ async fn test_token_minting_scenarios() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
 
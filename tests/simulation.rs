@@ -0,0 +1,206 @@
+// Simulation Tests
+// 
+// This module contains tests for dry-running contract calls with `simulate()` including:
+// - Simulated mint, deposit and withdraw returning their logs without changing state
+// - A simulated share vault deposit returning the shares a real one would mint
+// - simulate_then_call submitting only when not a dry run
+// - Reverting calls failing in the simulation, before any fee is paid
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    deploy_share_vault, expect_single_event,
+    simulation::{simulate_then_call, CallOutcome},
+    DepositEvent, HarnessConfig, MintEvent, RevertReason, TestHarness, WithdrawEvent, SUB_ID,
+    TOKEN_AMOUNT,
+};
+
+const AMOUNT: u64 = 10_000;
+
+// The token's supply and the first user's token balance and vault deposit
+async fn state(harness: &TestHarness) -> Result<(Option<u64>, u128, u64)> {
+    let user = harness.user(0);
+    let supply = harness.token.methods().total_supply(harness.asset_id).call().await?.value;
+    let deposit = harness
+        .vault
+        .methods()
+        .get_deposit(Identity::Address(user.address().into()))
+        .call()
+        .await?
+        .value;
+    Ok((supply, harness.token_balance(user).await?, deposit))
+}
+
+// Test that simulated mint, deposit and withdraw report logs but change nothing
+#[tokio::test]
+async fn test_simulate_mint_deposit_withdraw() -> Result<()> {
+    println!("Testing simulated calls...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    harness.mint_to(user, TOKEN_AMOUNT).await?;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    let before = state(&harness).await?;
+
+    let response = harness
+        .token
+        .methods()
+        .mint(Identity::Address(user.address().into()), Some(SUB_ID), AMOUNT)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .simulate(Execution::realistic())
+        .await?;
+    let mint: MintEvent = expect_single_event(&response)?;
+    assert_eq!(mint.amount, AMOUNT);
+    println!("   Simulated mint logged {:?}", mint);
+
+    let response = harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(AMOUNT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .simulate(Execution::realistic())
+        .await?;
+    let deposit: DepositEvent = expect_single_event(&response)?;
+    assert_eq!(deposit.amount, AMOUNT);
+    println!("   Simulated deposit logged {:?}", deposit);
+
+    let response = harness
+        .vault_as(user)
+        .methods()
+        .withdraw(AMOUNT)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .simulate(Execution::realistic())
+        .await?;
+    let withdraw: WithdrawEvent = expect_single_event(&response)?;
+    assert_eq!(withdraw.amount, AMOUNT);
+    assert!(response.tx_status.total_gas > 0);
+    println!("   Simulated withdraw used {} gas", response.tx_status.total_gas);
+
+    assert_eq!(state(&harness).await?, before);
+    println!("✅ Supply, balance and deposit unchanged after three simulations");
+
+    Ok(())
+}
+
+// Test that a simulation returns the value the real call would
+#[tokio::test]
+async fn test_simulate_return_value() -> Result<()> {
+    println!("Testing a simulated return value...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let alice = harness.user(0);
+    harness.mint_to(alice, TOKEN_AMOUNT).await?;
+    let vault = deploy_share_vault(harness.admin.clone(), harness.asset_id).await?;
+    let share_asset_id = vault.methods().share_asset_id().call().await?.value;
+
+    let alice_vault = vault.clone().with_account(alice.clone());
+    let receiver = Identity::Address(alice.address().into());
+    let params = CallParameters::default()
+        .with_amount(AMOUNT)
+        .with_asset_id(harness.asset_id);
+
+    let simulated = alice_vault
+        .methods()
+        .deposit(receiver)
+        .call_params(params.clone())?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .simulate(Execution::realistic())
+        .await?
+        .value;
+    assert_eq!(simulated, AMOUNT);
+    assert_eq!(alice.get_asset_balance(&share_asset_id).await?, 0);
+    assert_eq!(vault.methods().total_shares().call().await?.value, 0);
+    println!("   Simulation would mint {} shares", simulated);
+
+    let minted = alice_vault
+        .methods()
+        .deposit(receiver)
+        .call_params(params)?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(minted, simulated);
+    assert_eq!(alice.get_asset_balance(&share_asset_id).await?, u128::from(minted));
+    println!("✅ Real deposit minted the simulated {} shares", minted);
+
+    Ok(())
+}
+
+// Test that simulate_then_call only submits when not a dry run
+#[tokio::test]
+async fn test_simulate_then_call() -> Result<()> {
+    println!("Testing simulate_then_call...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let recipient = Identity::Address(harness.user(0).address().into());
+    let mint = || {
+        harness
+            .token
+            .methods()
+            .mint(recipient, Some(SUB_ID), AMOUNT)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    };
+
+    let outcome = simulate_then_call(mint(), true).await?;
+    assert!(outcome.is_dry_run());
+    let event: MintEvent = expect_single_event(outcome.response())?;
+    assert_eq!(event.amount, AMOUNT);
+    assert_eq!(harness.token_balance(harness.user(0)).await?, 0);
+    println!("   Dry run left the balance at 0");
+
+    let outcome = simulate_then_call(mint(), false).await?;
+    assert!(matches!(outcome, CallOutcome::Submitted(_)));
+    assert!(outcome.response().tx_id.is_some());
+    assert_eq!(harness.token_balance(harness.user(0)).await?, u128::from(AMOUNT));
+    println!("✅ Submitted mint credited {}", AMOUNT);
+
+    Ok(())
+}
+
+// Test that a call reverting in the simulation is never submitted
+#[tokio::test]
+async fn test_reverting_call_is_not_submitted() -> Result<()> {
+    println!("Testing a reverting call...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let base_before = user.get_asset_balance(&base_asset_id).await?;
+
+    let call = harness
+        .vault_as(user)
+        .methods()
+        .withdraw(AMOUNT)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1));
+    let error = simulate_then_call(call, false)
+        .await
+        .err()
+        .expect("withdrawing without a deposit must revert");
+    assert_eq!(error.decoded_revert(), Some(&RevertReason::InsufficientBalance));
+    let context = error.context().expect("simulation errors carry the call");
+    assert_eq!(context.method.as_deref(), Some("withdraw"));
+    assert_eq!(context.tx_id, None);
+
+    // A submitted revert would have cost a fee
+    assert_eq!(user.get_asset_balance(&base_asset_id).await?, base_before);
+    println!("❌ Withdraw rejected in the simulation, no fee paid");
+
+    Ok(())
+}
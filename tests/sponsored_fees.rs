@@ -0,0 +1,149 @@
+// Sponsored Fee Tests
+// 
+// This module contains tests for the gas-sponsorship predicate including:
+// - A user without base asset sending tokens while the sponsor pays the fee
+// - The sponsor losing exactly the fee and keeping the rest as change
+// - Rejecting transactions that pay the sponsor's base asset out to someone
+// - Rejecting transactions whose max fee is above the sponsor's cap
+
+use fuels::{
+    accounts::signers::private_key::PrivateKeySigner,
+    prelude::*,
+    types::{output::Output, transaction_builders::ScriptTransactionBuilder, Address},
+};
+
+use rosetta_stone::{
+    artifacts,
+    fees::fee_from_status,
+    predicates::{base_balance, fund_predicate, sponsored_transfer},
+    HarnessConfig, SponsorPredicateConfigurables, TestHarness, TestWallet,
+};
+
+const MAX_FEE: u64 = 100_000;
+const SPONSOR_FUNDS: u64 = 1_000_000;
+const AMOUNT: u64 = 5_000;
+
+// A sponsor predicate funded by the admin, and a user holding tokens but no base asset
+async fn setup() -> Result<(TestHarness, Predicate, TestWallet)> {
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+
+    let configurables = SponsorPredicateConfigurables::default().with_MAX_FEE(MAX_FEE)?;
+    let sponsor = Predicate::load_from(artifacts::SPONSOR_PREDICATE_BIN)?
+        .with_provider(harness.provider.clone())
+        .with_configurables(configurables);
+    fund_predicate(&harness.admin, &sponsor, SPONSOR_FUNDS).await?;
+
+    let user = Wallet::new(
+        PrivateKeySigner::random(&mut rand::thread_rng()),
+        harness.provider.clone(),
+    );
+    harness.mint_to(&user, AMOUNT * 2).await?;
+
+    Ok((harness, sponsor, user))
+}
+
+// Test that the user's tokens move while the sponsor pays the fee
+#[tokio::test]
+async fn test_sponsored_transfer() -> Result<()> {
+    println!("Testing a sponsored token transfer...");
+
+    let (harness, sponsor, user) = setup().await?;
+    let recipient = harness.user(1);
+    let recipient_before = harness.token_balance(recipient).await?;
+    let sponsor_before = base_balance(&sponsor).await?;
+    assert_eq!(base_balance(&user).await?, 0);
+
+    let status = sponsored_transfer(
+        &sponsor,
+        &user,
+        recipient.address().into(),
+        harness.asset_id,
+        AMOUNT,
+        TxPolicies::default(),
+    )
+    .await?;
+    let fee = fee_from_status(&status)?;
+    assert!(fee > 0 && fee <= MAX_FEE);
+
+    assert_eq!(harness.token_balance(&user).await?, u128::from(AMOUNT));
+    assert_eq!(
+        harness.token_balance(recipient).await?,
+        recipient_before + u128::from(AMOUNT)
+    );
+    assert_eq!(base_balance(&user).await?, 0);
+    assert_eq!(base_balance(&sponsor).await?, sponsor_before - u128::from(fee));
+    println!("✅ User sent {} tokens; the sponsor paid the {} fee", AMOUNT, fee);
+
+    // The sponsor's change is spendable for the next transfer
+    sponsored_transfer(
+        &sponsor,
+        &user,
+        recipient.address().into(),
+        harness.asset_id,
+        AMOUNT,
+        TxPolicies::default(),
+    )
+    .await?;
+    assert_eq!(harness.token_balance(&user).await?, 0);
+    println!("✅ Second transfer sponsored from the returned change");
+
+    Ok(())
+}
+
+// Test that the sponsor's base asset cannot be sent anywhere but back to it
+#[tokio::test]
+async fn test_sponsor_cannot_be_drained() -> Result<()> {
+    println!("Testing a transaction taking the sponsor's base asset...");
+
+    let (harness, sponsor, _) = setup().await?;
+    let thief = harness.user(0);
+    let provider = &harness.provider;
+    let base_asset_id = *provider.consensus_parameters().await?.base_asset_id();
+    let sponsor_before = base_balance(&sponsor).await?;
+
+    let inputs = sponsor
+        .get_asset_inputs_for_amount(base_asset_id, u128::from(AMOUNT), None)
+        .await?;
+    let outputs = vec![
+        Output::coin(thief.address().into(), AMOUNT, base_asset_id),
+        Output::change(sponsor.address(), 0, base_asset_id),
+    ];
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    sponsor.adjust_for_fee(&mut tb, u128::from(AMOUNT)).await?;
+
+    let result = match tb.build(provider).await {
+        Ok(tx) => provider.send_transaction_and_await_commit(tx).await.and_then(|s| s.check(None)),
+        Err(error) => Err(error),
+    };
+    assert!(result.is_err());
+    assert_eq!(base_balance(&sponsor).await?, sponsor_before);
+    println!("❌ Paying the sponsor's coins to another address rejected");
+
+    Ok(())
+}
+
+// Test that the sponsor refuses to cover a max fee above its cap
+#[tokio::test]
+async fn test_fee_above_cap() -> Result<()> {
+    println!("Testing a sponsored transfer above the fee cap...");
+
+    let (harness, sponsor, user) = setup().await?;
+    let recipient: Address = harness.user(1).address().into();
+    let sponsor_before = base_balance(&sponsor).await?;
+
+    let result = sponsored_transfer(
+        &sponsor,
+        &user,
+        recipient,
+        harness.asset_id,
+        AMOUNT,
+        TxPolicies::default().with_max_fee(MAX_FEE + 1),
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(base_balance(&sponsor).await?, sponsor_before);
+    assert_eq!(harness.token_balance(&user).await?, u128::from(AMOUNT * 2));
+    println!("❌ Max fee of {} above the {} cap rejected", MAX_FEE + 1, MAX_FEE);
+
+    Ok(())
+}
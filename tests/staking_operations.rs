@@ -0,0 +1,212 @@
+// Staking Operations Tests
+// 
+// This module contains tests for the staking contract including:
+// - Rewards accruing per block to a single staker
+// - Rewards split in proportion to stake between two stakers
+// - Unstaking while keeping accrued rewards
+// - Claims without rewards or without funded rewards
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{AssetId, Identity},
+};
+
+use rosetta_stone::{deploy_src20_token, deploy_staking, HarnessConfig, Staking, TestHarness, SUB_ID};
+
+const REWARD_PER_BLOCK: u64 = 100;
+const REWARD_FUNDS: u64 = 1_000_000;
+
+struct Setup {
+    harness: TestHarness,
+    staking: Staking<Wallet<Unlocked<PrivateKeySigner>>>,
+    reward_asset: AssetId,
+}
+
+impl Setup {
+    // Deploys a reward token and the staking contract, funding `reward_funds` rewards
+    async fn new(reward_funds: u64) -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default()).await?;
+        let reward_token = deploy_src20_token(harness.admin.clone(), "REWARDS", "RWRDS", 9).await?;
+        let reward_asset = reward_token.methods().get_asset_id().call().await?.value;
+        let staking = deploy_staking(
+            harness.admin.clone(),
+            harness.asset_id,
+            reward_asset,
+            REWARD_PER_BLOCK,
+        )
+        .await?;
+
+        if reward_funds > 0 {
+            reward_token
+                .methods()
+                .mint(
+                    Identity::Address(harness.admin.address().into()),
+                    Some(SUB_ID),
+                    reward_funds,
+                )
+                .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+                .call()
+                .await?;
+            staking
+                .methods()
+                .fund_rewards()
+                .call_params(
+                    CallParameters::default()
+                        .with_amount(reward_funds)
+                        .with_asset_id(reward_asset),
+                )?
+                .call()
+                .await?;
+        }
+
+        Ok(Self {
+            harness,
+            staking,
+            reward_asset,
+        })
+    }
+
+    fn staking_as(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    ) -> Staking<Wallet<Unlocked<PrivateKeySigner>>> {
+        self.staking.clone().with_account(wallet.clone())
+    }
+
+    // Stakes `amount` and returns the block it was staked in
+    async fn stake(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        amount: u64,
+    ) -> Result<u32> {
+        self.harness.mint_to(wallet, amount).await?;
+        self.staking_as(wallet)
+            .methods()
+            .stake()
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(self.harness.asset_id),
+            )?
+            .call()
+            .await?;
+        self.harness.provider.latest_block_height().await
+    }
+
+    // Claims rewards and returns the amount and the block it was claimed in
+    async fn claim(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Result<(u64, u32)> {
+        let amount = self
+            .staking_as(wallet)
+            .methods()
+            .claim()
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?
+            .value;
+        Ok((amount, self.harness.provider.latest_block_height().await?))
+    }
+}
+
+// Test reward accrual for one and then two stakers
+#[tokio::test]
+async fn test_staking_reward_accrual() -> Result<()> {
+    println!("Testing staking reward accrual...");
+
+    let setup = Setup::new(REWARD_FUNDS).await?;
+    let provider = &setup.harness.provider;
+    let (alice, bob) = (setup.harness.user(0), setup.harness.user(1));
+
+    // Alice stakes alone and earns the whole emission
+    let alice_staked_at = setup.stake(alice, 1_000).await?;
+    provider.produce_blocks(10, None).await?;
+    let (claimed, claimed_at) = setup.claim(alice).await?;
+    let mut alice_expected = (claimed_at - alice_staked_at) as u64 * REWARD_PER_BLOCK;
+    assert_eq!(claimed, alice_expected);
+    println!("✅ Alice earned {} over {} blocks alone", claimed, claimed_at - alice_staked_at);
+
+    // Bob stakes three times as much; from then on Alice earns 1/4 and Bob 3/4
+    let bob_staked_at = setup.stake(bob, 3_000).await?;
+    alice_expected += (bob_staked_at - claimed_at) as u64 * REWARD_PER_BLOCK;
+    provider.produce_blocks(10, None).await?;
+
+    let (alice_claim, alice_claimed_at) = setup.claim(alice).await?;
+    alice_expected += (alice_claimed_at - bob_staked_at) as u64 * REWARD_PER_BLOCK / 4;
+    assert_eq!(claimed + alice_claim, alice_expected);
+
+    let (bob_claim, bob_claimed_at) = setup.claim(bob).await?;
+    assert_eq!(
+        bob_claim,
+        (bob_claimed_at - bob_staked_at) as u64 * REWARD_PER_BLOCK * 3 / 4
+    );
+    println!("✅ Rewards split 1:3 after Bob staked");
+
+    assert_eq!(
+        alice.get_asset_balance(&setup.reward_asset).await?,
+        alice_expected as u128
+    );
+    assert_eq!(
+        setup.staking.methods().reward_reserve().call().await?.value,
+        REWARD_FUNDS - alice_expected - bob_claim
+    );
+
+    // Alice leaves; Bob earns the whole emission again
+    setup
+        .staking_as(alice)
+        .methods()
+        .unstake(1_000)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    let alice_left_at = provider.latest_block_height().await?;
+    assert_eq!(setup.harness.token_balance(alice).await?, 1_000);
+    assert_eq!(setup.staking.methods().total_staked().call().await?.value, 3_000);
+
+    provider.produce_blocks(5, None).await?;
+    let (bob_second_claim, bob_second_claimed_at) = setup.claim(bob).await?;
+    assert_eq!(
+        bob_second_claim,
+        (alice_left_at - bob_claimed_at) as u64 * REWARD_PER_BLOCK * 3 / 4
+            + (bob_second_claimed_at - alice_left_at) as u64 * REWARD_PER_BLOCK
+    );
+    println!("✅ Bob earned the full emission after Alice unstaked");
+
+    // Alice's stake is gone; the rewards she accrued before leaving are still hers
+    let (alice_final_claim, _) = setup.claim(alice).await?;
+    assert_eq!(
+        alice_final_claim,
+        (alice_left_at - alice_claimed_at) as u64 * REWARD_PER_BLOCK / 4
+    );
+    println!("✅ Rewards accrued before unstaking remain claimable");
+
+    Ok(())
+}
+
+// Test claims that cannot be paid
+#[tokio::test]
+async fn test_staking_claim_failures() -> Result<()> {
+    println!("Testing staking claim failures...");
+
+    let setup = Setup::new(0).await?;
+    let (alice, bob) = (setup.harness.user(0), setup.harness.user(1));
+
+    let result = setup.claim(bob).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("No rewards to claim"));
+
+    setup.stake(alice, 1_000).await?;
+    setup.harness.provider.produce_blocks(5, None).await?;
+    let result = setup.claim(alice).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Insufficient reward funds"));
+
+    let result = setup
+        .staking_as(alice)
+        .methods()
+        .unstake(1_001)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Insufficient stake"));
+    println!("✅ Unpayable claims and oversized unstakes rejected");
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// Timelock Predicate Tests
+// 
+// This module contains tests for the timelock predicate including:
+// - Spends rejected before the unlock height
+// - Spends accepted after produce_blocks advances the chain
+// - Spends rejected without the owner's signature
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::{transaction_builders::ScriptTransactionBuilder, tx_status::TxStatus, Address},
+};
+
+use rosetta_stone::{artifacts, launch_wallets, TimelockPredicateConfigurables};
+
+const FUND_AMOUNT: u64 = 500_000;
+const SPEND_AMOUNT: u64 = 300_000;
+// Blocks between funding the predicate and the unlock height
+const LOCK_BLOCKS: u32 = 10;
+
+// Spends from the predicate to `recipient`, signed by `signer`, with the given maturity
+async fn spend(
+    predicate: &Predicate,
+    signer: &Wallet<Unlocked<PrivateKeySigner>>,
+    recipient: &Wallet<Unlocked<PrivateKeySigner>>,
+    maturity: u32,
+) -> Result<TxStatus> {
+    let provider = signer.try_provider()?.clone();
+    let asset_id = AssetId::default();
+
+    let inputs = predicate.get_asset_inputs_for_amount(asset_id, 1, None).await?;
+    let outputs =
+        predicate.get_asset_outputs_for_amount(recipient.address().into(), asset_id, SPEND_AMOUNT);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_maturity(maturity),
+    );
+
+    // The signer's fee coin makes its signature the first witness
+    signer.adjust_for_fee(&mut tb, 0).await?;
+    signer.add_witnesses(&mut tb)?;
+
+    let tx = tb.build(provider.clone()).await?;
+    provider.send_transaction_and_await_commit(tx).await
+}
+
+// Test that the predicate only spends once the unlock height is reached
+#[tokio::test]
+async fn test_timelock_spend_after_height() -> Result<()> {
+    println!("Testing timelock predicate...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(2), Some(1), Some(1_000_000))).await?;
+    let owner = &wallets[0];
+    let recipient = &wallets[1];
+    let provider = owner.try_provider()?.clone();
+
+    let unlock_height = provider.latest_block_height().await? + LOCK_BLOCKS;
+    let configurables = TimelockPredicateConfigurables::default()
+        .with_OWNER(Address::from(owner.address()))?
+        .with_UNLOCK_HEIGHT(unlock_height)?;
+    let predicate = Predicate::load_from(artifacts::TIMELOCK_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables);
+
+    owner
+        .transfer(
+            predicate.address(),
+            FUND_AMOUNT,
+            AssetId::default(),
+            TxPolicies::default(),
+        )
+        .await?;
+    println!("✅ Predicate funded, unlocks at block {}", unlock_height);
+
+    // A maturity below the unlock height fails the predicate
+    let current_height = provider.latest_block_height().await?;
+    let result = spend(&predicate, owner, recipient, current_height).await;
+    assert!(result.is_err());
+    println!("❌ Spend with maturity {} rejected by the predicate", current_height);
+
+    // The right maturity is refused by the node until the chain reaches it
+    let result = spend(&predicate, owner, recipient, unlock_height).await;
+    assert!(result.is_err());
+    assert_eq!(
+        predicate.get_asset_balance(&AssetId::default()).await?,
+        FUND_AMOUNT as u128
+    );
+    println!("❌ Spend before block {} rejected by the node", unlock_height);
+
+    let recipient_before = recipient.get_asset_balance(&AssetId::default()).await?;
+    provider.produce_blocks(LOCK_BLOCKS, None).await?;
+    assert!(provider.latest_block_height().await? >= unlock_height);
+
+    let status = spend(&predicate, owner, recipient, unlock_height).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    assert_eq!(
+        recipient.get_asset_balance(&AssetId::default()).await?,
+        recipient_before + SPEND_AMOUNT as u128
+    );
+    println!("✅ Spend succeeded after produce_blocks passed the unlock height");
+
+    Ok(())
+}
+
+// Test that only the owner can spend, even after the unlock height
+#[tokio::test]
+async fn test_timelock_requires_owner_signature() -> Result<()> {
+    println!("Testing timelock predicate owner check...");
+
+    let wallets = launch_wallets(WalletsConfig::new(Some(2), Some(1), Some(1_000_000))).await?;
+    let owner = &wallets[0];
+    let stranger = &wallets[1];
+    let provider = owner.try_provider()?.clone();
+
+    let configurables = TimelockPredicateConfigurables::default()
+        .with_OWNER(Address::from(owner.address()))?
+        .with_UNLOCK_HEIGHT(provider.latest_block_height().await?)?;
+    let predicate = Predicate::load_from(artifacts::TIMELOCK_PREDICATE_BIN)?
+        .with_provider(provider.clone())
+        .with_configurables(configurables);
+
+    owner
+        .transfer(
+            predicate.address(),
+            FUND_AMOUNT,
+            AssetId::default(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let height = provider.latest_block_height().await?;
+    let result = spend(&predicate, stranger, stranger, height).await;
+    assert!(result.is_err());
+    assert_eq!(
+        predicate.get_asset_balance(&AssetId::default()).await?,
+        FUND_AMOUNT as u128
+    );
+    println!("✅ Unlocked coins still require the owner's signature");
+
+    Ok(())
+}
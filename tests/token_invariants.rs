@@ -0,0 +1,163 @@
+// Token Invariant Property Tests
+// 
+// This module contains proptest-based tests for the SRC20 token including:
+// - Random sequences of mints, burns and transfers against a local node
+// - Total supply equal to the sum of all wallet balances after every operation
+// - Balances matching a model that never goes negative: overdrafts must fail
+//   and leave every balance unchanged
+//
+// PROPTEST_CASES sets the number of generated sequences (default 12).
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+};
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestRunner},
+};
+
+use rosetta_stone::{HarnessConfig, TestHarness, SUB_ID};
+
+const NUM_USERS: usize = 3;
+const MAX_AMOUNT: u64 = 5_000;
+const DEFAULT_CASES: u32 = 12;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Mint { to: usize, amount: u64 },
+    Burn { from: usize, amount: u64 },
+    Transfer { from: usize, to: usize, amount: u64 },
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let user = 0..NUM_USERS;
+    let amount = 1..=MAX_AMOUNT;
+    prop_oneof![
+        (user.clone(), amount.clone()).prop_map(|(to, amount)| Op::Mint { to, amount }),
+        (user.clone(), amount.clone()).prop_map(|(from, amount)| Op::Burn { from, amount }),
+        (user.clone(), user, amount)
+            .prop_map(|(from, to, amount)| Op::Transfer { from, to, amount }),
+    ]
+}
+
+// Wallets holding the token: the users, then the admin
+fn holders(harness: &TestHarness) -> Vec<&Wallet<Unlocked<PrivateKeySigner>>> {
+    harness.users.iter().chain([&harness.admin]).collect()
+}
+
+async fn balances(harness: &TestHarness) -> Result<Vec<i128>> {
+    let mut balances = Vec::new();
+    for wallet in holders(harness) {
+        balances.push(harness.token_balance(wallet).await? as i128);
+    }
+    Ok(balances)
+}
+
+async fn total_supply(harness: &TestHarness) -> Result<i128> {
+    let supply = harness.token.methods().total_supply(harness.asset_id).call().await?.value;
+    Ok(supply.unwrap_or(0) as i128)
+}
+
+// Submits `op`; `Ok(false)` if the node or SDK refused it
+async fn apply(harness: &TestHarness, op: &Op) -> Result<bool> {
+    let result = match *op {
+        Op::Mint { to, amount } => harness.mint_to(harness.user(to), amount).await,
+        Op::Burn { from, amount } => harness
+            .token_as(harness.user(from))
+            .methods()
+            .burn(SUB_ID, amount)
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(harness.asset_id),
+            )?
+            .call()
+            .await
+            .map(|_| ()),
+        Op::Transfer { from, to, amount } => harness
+            .user(from)
+            .transfer(
+                harness.user(to).address(),
+                amount,
+                harness.asset_id,
+                TxPolicies::default(),
+            )
+            .await
+            .map(|_| ()),
+    };
+    Ok(result.is_ok())
+}
+
+// Runs `ops` and checks the invariants after each one. The model starts from
+// the balances left by earlier cases, so cases can share one node.
+async fn check_sequence(
+    harness: &TestHarness,
+    ops: Vec<Op>,
+) -> std::result::Result<(), TestCaseError> {
+    let mut model = balances(harness).await?;
+    prop_assert_eq!(total_supply(harness).await?, model.iter().sum::<i128>());
+
+    for op in &ops {
+        let mut expected = model.clone();
+        let overdraft = match *op {
+            Op::Mint { to, amount } => {
+                expected[to] += amount as i128;
+                false
+            }
+            Op::Burn { from, amount } => {
+                expected[from] -= amount as i128;
+                model[from] < amount as i128
+            }
+            // Checked before crediting, so a self-transfer cannot cover itself
+            Op::Transfer { from, to, amount } => {
+                expected[from] -= amount as i128;
+                expected[to] += amount as i128;
+                model[from] < amount as i128
+            }
+        };
+
+        let accepted = apply(harness, op).await?;
+        prop_assert_eq!(accepted, !overdraft, "{:?} accepted: {}", op, accepted);
+        if accepted {
+            model = expected;
+        }
+
+        let actual = balances(harness).await?;
+        prop_assert_eq!(&actual, &model, "balances after {:?}", op);
+        prop_assert!(actual.iter().all(|balance| *balance >= 0));
+        prop_assert_eq!(total_supply(harness).await?, actual.iter().sum::<i128>());
+    }
+    Ok(())
+}
+
+// Test that random mint/burn/transfer sequences keep supply and balances consistent
+#[test]
+fn test_token_invariants_hold_for_random_sequences() {
+    println!("Testing token invariants with random operation sequences...");
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let harness = runtime
+        .block_on(TestHarness::new(
+            HarnessConfig::default().with_num_users(NUM_USERS as u64),
+        ))
+        .unwrap();
+
+    let cases = std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    let mut runner = TestRunner::new(ProptestConfig {
+        cases,
+        // Every case talks to the node; shrinking a failure takes long enough
+        max_shrink_iters: 64,
+        ..ProptestConfig::default()
+    });
+
+    runner
+        .run(&prop::collection::vec(op_strategy(), 1..8), |ops| {
+            runtime.block_on(check_sequence(&harness, ops))
+        })
+        .unwrap();
+    println!("✅ Invariants held for {} sequences", cases);
+}
@@ -0,0 +1,117 @@
+// Token Metadata Tests
+// 
+// This module contains tests for SRC-7 metadata on the SRC20 token including:
+// - Writing String, Bytes and Int metadata from Rust
+// - Reading metadata back with typed decoding
+// - Admin-only metadata updates
+
+use fuels::{
+    prelude::*,
+    types::{AssetId, Bytes},
+};
+
+use rosetta_stone::{HarnessConfig, Metadata, TestHarness};
+
+// Test that String, Bytes and Int metadata round-trip through the contract
+#[tokio::test]
+async fn test_metadata_round_trip() -> Result<()> {
+    println!("Testing SRC-7 metadata round trip...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let asset_id = harness.asset_id;
+
+    let entries = [
+        ("description", Metadata::String("Rosetta Stone test token".to_string())),
+        ("logo", Metadata::Bytes(Bytes(vec![0x89, 0x50, 0x4e, 0x47]))),
+        ("max_supply", Metadata::Int(21_000_000)),
+    ];
+
+    for (key, metadata) in entries.iter().cloned() {
+        harness
+            .token
+            .methods()
+            .set_metadata(asset_id, key.to_string(), metadata)
+            .call()
+            .await?;
+    }
+
+    for (key, expected) in entries {
+        let stored = harness
+            .token
+            .methods()
+            .metadata(asset_id, key.to_string())
+            .call()
+            .await?
+            .value;
+        println!("   {}: {:?}", key, stored);
+        assert_eq!(stored, Some(expected));
+    }
+
+    // Typed decoding of a single entry
+    match harness
+        .token
+        .methods()
+        .metadata(asset_id, "max_supply".to_string())
+        .call()
+        .await?
+        .value
+    {
+        Some(Metadata::Int(max_supply)) => assert_eq!(max_supply, 21_000_000),
+        other => panic!("expected Int metadata, got {:?}", other),
+    }
+
+    // Unknown keys and other assets have no metadata
+    let missing = harness
+        .token
+        .methods()
+        .metadata(asset_id, "website".to_string())
+        .call()
+        .await?
+        .value;
+    assert_eq!(missing, None);
+
+    let other_asset = harness
+        .token
+        .methods()
+        .metadata(AssetId::zeroed(), "description".to_string())
+        .call()
+        .await?
+        .value;
+    assert_eq!(other_asset, None);
+
+    println!("✅ SRC-7 metadata round trip test passed");
+    Ok(())
+}
+
+// Test that only the admin can set metadata
+#[tokio::test]
+async fn test_non_admin_cannot_set_metadata() -> Result<()> {
+    println!("Testing non-admin metadata update...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+
+    let result = harness
+        .token_as(harness.user(0))
+        .methods()
+        .set_metadata(
+            harness.asset_id,
+            "description".to_string(),
+            Metadata::String("spoofed".to_string()),
+        )
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Unauthorized"));
+
+    let stored = harness
+        .token
+        .methods()
+        .metadata(harness.asset_id, "description".to_string())
+        .call()
+        .await?
+        .value;
+    assert_eq!(stored, None);
+
+    println!("✅ Non-admin metadata update correctly rejected");
+    Ok(())
+}
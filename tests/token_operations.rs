@@ -7,63 +7,12 @@
 // - Balance queries
 // - Token metadata
 
-use fuels::{
-    accounts::signers::private_key::PrivateKeySigner,
-    prelude::*,
-    types::{Bits256, Identity, SizedAsciiString},
-};
-
-use fuels::accounts::wallet::Unlocked;
-
-// Load abi from json
-abigen!(
-    Contract(
-        name = "Src20Token",
-        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
-    ),
-);
-
-const TOKEN_AMOUNT: u64 = 1_000_000;
-const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
-const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
-
-// Deploys the SRC20 token contract with the given wallet and metadata
-// Returns a contract instance for further interaction
-async fn deploy_src20_token(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
-    // Convert name and symbol to SizedAsciiString for contract configurables
-    let name_bytes: SizedAsciiString<7> = name.try_into()?;
-    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
-
-    // Set up contract configurables (name, symbol, decimals, admin)
-    let configurables = Src20TokenConfigurables::default()
-        .with_NAME(name_bytes.clone())?
-        .with_SYMBOL(symbol_bytes.clone())?
-        .with_DECIMALS(decimals)?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    // Deploy the contract to the local node
-    let deploy_response = Contract::load_from(
-        "contracts/src20-token/out/debug/src20_token.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
+use fuels::{prelude::*, types::Identity};
 
-    println!(
-        "✅ Token '{}' ({}) deployed at: {}",
-        name,
-        symbol,
-        contract_id.to_string()
-    );
-    Ok(Src20Token::new(contract_id, wallet))
-}
+use rosetta_stone::{
+    deploy_src20_token, expect_single_event, launch_wallets, MintEvent, Src20Token, SUB_ID,
+    TOKEN_AMOUNT,
+};
 
 // Test basic token operations including minting, transfers, and supply checks
 #[tokio::test]
@@ -80,8 +29,7 @@ async fn test_token_operations() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallet = wallets.pop().unwrap();
@@ -118,10 +66,11 @@ async fn test_token_operations() -> Result<()> {
         .await?;
 
     println!("Mint transaction successful!");
-    println!("Mint transaction: {:?}", mint_tx.decode_logs().results[0]);
 
-    let mint_logs = mint_tx.decode_logs();
-    assert!(!mint_logs.results.is_empty(), "Should have mint logs");
+    let mint_event: MintEvent = expect_single_event(&mint_tx)?;
+    println!("Mint event: {:?}", mint_event);
+    assert_eq!(mint_event.recipient, recipient);
+    assert_eq!(mint_event.amount, mint_amount);
 
     // Calculate the correct asset ID from contract ID and sub ID
     let asset_id = admin_token_contract
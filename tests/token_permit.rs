@@ -0,0 +1,200 @@
+// Token Permit Tests
+// 
+// This module contains tests for gasless approvals on the SRC20 token including:
+// - The owner signing off chain and a relayer submitting the approval
+// - The owner paying no fee while the relayer pays for the transaction
+// - Rejecting expired and replayed permits
+// - Permits landing only in the order they were signed, and cancelling one
+// - An altered permit approving nothing
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{
+    expect_single_event,
+    permit::{prepare_token_permit, submit_token_permit, SignedPermit},
+    HarnessConfig, PermitEvent, RevertReason, TestHarness,
+};
+
+const AMOUNT: u64 = 25_000;
+
+fn identity(wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Identity {
+    Identity::Address(wallet.address().into())
+}
+
+// A permit of `amount` from user 0 to user 2 at the owner's current nonce,
+// valid for `blocks` more blocks
+async fn signed_permit(harness: &TestHarness, amount: u64, blocks: u32) -> Result<SignedPermit> {
+    let owner = harness.user(0);
+    let deadline = harness.provider.latest_block_height().await? + blocks;
+    prepare_token_permit(
+        &harness.token,
+        owner.address().into(),
+        identity(harness.user(2)),
+        amount,
+        deadline,
+    )
+    .await?
+    .sign(owner.signer(), &harness.token.contract_id())
+    .await
+}
+
+async fn allowance(harness: &TestHarness, owner: Identity, spender: Identity) -> Result<u64> {
+    Ok(harness.token.methods().allowance(owner, spender).call().await?.value)
+}
+
+fn assert_reverts_with(error: Error, message: &str) {
+    assert_eq!(
+        RevertReason::from_error(&error),
+        Some(RevertReason::Require(message.to_string()))
+    );
+}
+
+// Test that an approval signed off chain is submitted and paid for by a relayer
+#[tokio::test]
+async fn test_relayed_permit() -> Result<()> {
+    println!("Testing a relayed token permit...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let (owner, relayer, spender) = (harness.user(0), harness.user(1), harness.user(2));
+    let base_asset_id = *harness.provider.consensus_parameters().await?.base_asset_id();
+    let owner_before = owner.get_asset_balance(&base_asset_id).await?;
+    let relayer_before = relayer.get_asset_balance(&base_asset_id).await?;
+
+    // The owner only signs
+    let signed = signed_permit(&harness, AMOUNT, 10).await?;
+    assert_eq!(signed.permit.nonce, 0);
+
+    let response = submit_token_permit(&harness.token_as(relayer), &signed).await?;
+    let event: PermitEvent = expect_single_event(&response)?;
+    assert_eq!(event.owner, identity(owner));
+    assert_eq!(event.amount, AMOUNT);
+
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, AMOUNT);
+    assert_eq!(harness.token.methods().nonce(owner.address().into()).call().await?.value, 1);
+    assert_eq!(owner.get_asset_balance(&base_asset_id).await?, owner_before);
+    assert!(relayer.get_asset_balance(&base_asset_id).await? < relayer_before);
+    println!("✅ Spender approved for {}; the relayer paid the fee", AMOUNT);
+
+    // A direct approval overrides it, paid by the owner
+    harness
+        .token_as(owner)
+        .methods()
+        .approve(identity(spender), 0)
+        .call()
+        .await?;
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, 0);
+    println!("✅ Owner revoked the approval on chain");
+
+    Ok(())
+}
+
+// Test that expired and replayed permits are rejected
+#[tokio::test]
+async fn test_expired_and_replayed_permits() -> Result<()> {
+    println!("Testing expired and replayed token permits...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let (owner, relayer, spender) = (harness.user(0), harness.user(1), harness.user(2));
+    let token = harness.token_as(relayer);
+
+    let expired = signed_permit(&harness, AMOUNT, 1).await?;
+    harness.provider.produce_blocks(3, None).await?;
+    let error = submit_token_permit(&token, &expired)
+        .await
+        .err()
+        .expect("an expired permit must revert");
+    assert_reverts_with(error, "Permit expired");
+    println!("❌ Expired permit rejected");
+
+    let signed = signed_permit(&harness, AMOUNT, 100).await?;
+    submit_token_permit(&token, &signed).await?;
+
+    // The spender's approval is spent down elsewhere; replaying must not restore it
+    harness
+        .token_as(owner)
+        .methods()
+        .approve(identity(spender), 5_000)
+        .call()
+        .await?;
+    let error = submit_token_permit(&token, &signed)
+        .await
+        .err()
+        .expect("a replayed permit must revert");
+    assert_reverts_with(error, "Invalid permit signature");
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, 5_000);
+    println!("❌ Replayed permit rejected");
+
+    Ok(())
+}
+
+// Test that permits only land in the order they were signed, so a newer one cancels an older one
+#[tokio::test]
+async fn test_permit_ordering_and_cancellation() -> Result<()> {
+    println!("Testing token permit ordering...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let (owner, relayer, spender) = (harness.user(0), harness.user(1), harness.user(2));
+    let token = harness.token_as(relayer);
+
+    // Sign 100 at nonce 0, then 50 at nonce 1
+    let first = signed_permit(&harness, 100, 100).await?;
+    let mut second = first.permit;
+    second.amount = 50;
+    second.nonce = 1;
+    let second = second.sign(owner.signer(), &token.contract_id()).await?;
+
+    let error = submit_token_permit(&token, &second)
+        .await
+        .err()
+        .expect("a permit ahead of the owner's nonce must revert");
+    assert_reverts_with(error, "Invalid permit signature");
+    println!("❌ Later permit cannot land before the earlier one");
+
+    submit_token_permit(&token, &first).await?;
+    submit_token_permit(&token, &second).await?;
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, 50);
+    println!("✅ Permits applied in signing order");
+
+    // The owner cancels a pending permit by landing a newer one at the same nonce
+    let pending = signed_permit(&harness, AMOUNT, 100).await?;
+    let cancel = signed_permit(&harness, 0, 100).await?;
+    assert_eq!(pending.permit.nonce, cancel.permit.nonce);
+    submit_token_permit(&harness.token_as(owner), &cancel).await?;
+
+    let error = submit_token_permit(&token, &pending)
+        .await
+        .err()
+        .expect("a cancelled permit must revert");
+    assert_reverts_with(error, "Invalid permit signature");
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, 0);
+    println!("❌ Cancelled permit rejected before its deadline");
+
+    Ok(())
+}
+
+// Test that changing a signed permit approves nothing
+#[tokio::test]
+async fn test_altered_permit() -> Result<()> {
+    println!("Testing an altered token permit...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let (owner, relayer, spender) = (harness.user(0), harness.user(1), harness.user(2));
+
+    let mut signed = signed_permit(&harness, AMOUNT, 100).await?;
+    signed.permit.amount = AMOUNT * 10;
+
+    // The signature no longer recovers to the owner
+    let error = submit_token_permit(&harness.token_as(relayer), &signed)
+        .await
+        .err()
+        .expect("an altered permit must revert");
+    assert_reverts_with(error, "Invalid permit signature");
+    assert_eq!(allowance(&harness, identity(owner), identity(spender)).await?, 0);
+    println!("❌ Altered permit approved nothing on behalf of the owner");
+
+    Ok(())
+}
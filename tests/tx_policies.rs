@@ -0,0 +1,65 @@
+// Transaction Policy Preset Tests
+// 
+// This module contains tests for the TxPolicies presets including:
+// - The limits each preset sets
+// - Contract calls submitted with the fast and cheap presets
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{
+    policies::{self, CHEAP_MAX_FEE, FAST_TIP, SCRIPT_HEAVY_GAS_LIMIT, SCRIPT_HEAVY_MAX_FEE},
+    HarnessConfig, TestHarness, SUB_ID,
+};
+
+// Test the values each preset sets and leaves to estimation
+#[test]
+fn test_preset_values() {
+    let fast = policies::fast();
+    assert_eq!(fast.tip(), Some(FAST_TIP));
+    assert_eq!(fast.script_gas_limit(), None);
+
+    let cheap = policies::cheap();
+    assert_eq!(cheap.tip(), Some(0));
+    assert_eq!(cheap.max_fee(), Some(CHEAP_MAX_FEE));
+
+    let heavy = policies::script_heavy();
+    assert_eq!(heavy.script_gas_limit(), Some(SCRIPT_HEAVY_GAS_LIMIT));
+    assert_eq!(heavy.max_fee(), Some(SCRIPT_HEAVY_MAX_FEE));
+
+    // Presets are ordinary policies and can be adjusted further
+    assert_eq!(policies::fast().with_maturity(5).maturity(), Some(5));
+    println!("✅ Presets set the expected policies");
+}
+
+// Mints 100 tokens to user 0 with `tx_policies`, returning the fee paid
+async fn mint_with(harness: &TestHarness, tx_policies: TxPolicies) -> Result<u64> {
+    let response = harness
+        .token
+        .methods()
+        .mint(Identity::Address(harness.user(0).address().into()), Some(SUB_ID), 100)
+        .with_tx_policies(tx_policies)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(response.tx_status.total_fee)
+}
+
+// Test mints submitted with the presets
+#[tokio::test]
+async fn test_calls_with_presets() -> Result<()> {
+    println!("Testing calls with policy presets...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+
+    // The tip is part of the fee
+    let fast_fee = mint_with(&harness, policies::fast()).await?;
+    assert!(fast_fee >= FAST_TIP);
+    println!("✅ Mint with the fast preset paid {}", fast_fee);
+
+    let cheap_fee = mint_with(&harness, policies::cheap()).await?;
+    assert!(cheap_fee <= CHEAP_MAX_FEE);
+    println!("✅ Mint with the cheap preset paid {}", cheap_fee);
+
+    assert_eq!(harness.token_balance(harness.user(0)).await?, 200);
+    Ok(())
+}
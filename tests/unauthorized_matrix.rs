@@ -0,0 +1,171 @@
+// Unauthorized Access Matrix Tests
+// 
+// This module contains tests for every admin-gated method of the core contracts including:
+// - Src20Token: mint, set_metadata, pause, unpause, freeze, unfreeze, register_with,
+//   propose_admin
+// - TokenVault: collect_fees, cross_contract_deposit, propose_admin
+// - CrossContractCall: deposit, route, propose_admin
+// - Each call from a non-admin wallet decoding to RevertReason::Unauthorized
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{HarnessConfig, Metadata, RevertReason, TestHarness, SUB_ID};
+
+// Calls `$call` from a non-admin handle and asserts that it reverted with
+// `RevertReason::Unauthorized($message)`
+macro_rules! assert_unauthorized {
+    ($method:literal, $call:expr, $message:literal) => {{
+        let error = $call
+            .call()
+            .await
+            .expect_err(concat!($method, " succeeded for a non-admin"));
+        assert_eq!(
+            RevertReason::from_error(&error),
+            Some(RevertReason::Unauthorized($message.to_string())),
+            "{}",
+            $method
+        );
+        println!("❌ {} rejected: {}", $method, $message);
+    }};
+}
+
+// Test every admin-only method of the token
+#[tokio::test]
+async fn test_token_admin_methods_reject_non_admin() -> Result<()> {
+    println!("Testing Src20Token admin methods from a non-admin...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+    let token = harness.token_as(user);
+    let methods = token.methods();
+
+    assert_unauthorized!(
+        "mint",
+        methods
+            .mint(user_identity, Some(SUB_ID), 100)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1)),
+        "Unauthorized: Only admin can mint"
+    );
+    assert_unauthorized!(
+        "set_metadata",
+        methods.set_metadata(harness.asset_id, "k".to_string(), Metadata::Int(1)),
+        "Unauthorized: Only admin can set metadata"
+    );
+    assert_unauthorized!("pause", methods.pause(), "Unauthorized: Only admin can pause");
+    assert_unauthorized!("unpause", methods.unpause(), "Unauthorized: Only admin can unpause");
+    assert_unauthorized!(
+        "freeze",
+        methods.freeze(user_identity),
+        "Unauthorized: Only admin can freeze"
+    );
+    assert_unauthorized!(
+        "unfreeze",
+        methods.unfreeze(user_identity),
+        "Unauthorized: Only admin can unfreeze"
+    );
+    assert_unauthorized!(
+        "register_with",
+        methods.register_with(harness.vault.contract_id().clone(), String::new()),
+        "Unauthorized: Only admin can register"
+    );
+    assert_unauthorized!(
+        "propose_admin",
+        methods.propose_admin(user_identity),
+        "Unauthorized: Only admin can propose admin"
+    );
+
+    // Nothing changed hands
+    let admin_methods = harness.token.methods();
+    assert!(!admin_methods.is_paused().call().await?.value);
+    assert_eq!(admin_methods.pending_admin().call().await?.value, None);
+
+    Ok(())
+}
+
+// Test every admin-only method of the vault
+#[tokio::test]
+async fn test_vault_admin_methods_reject_non_admin() -> Result<()> {
+    println!("Testing TokenVault admin methods from a non-admin...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+    harness.mint_to(user, 1_000).await?;
+
+    let vault = harness.vault_as(user);
+    let methods = vault.methods();
+
+    assert_unauthorized!(
+        "collect_fees",
+        methods
+            .collect_fees(harness.asset_id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1)),
+        "Unauthorized: Only admin can collect fees"
+    );
+    // Gated to the CrossContractCall contract rather than the admin
+    assert_unauthorized!(
+        "cross_contract_deposit",
+        methods.cross_contract_deposit(user_identity).call_params(
+            CallParameters::default()
+                .with_amount(1_000)
+                .with_asset_id(harness.asset_id),
+        )?,
+        "Only cross-contract call can cross-contract deposit"
+    );
+    assert_unauthorized!(
+        "propose_admin",
+        methods.propose_admin(user_identity),
+        "Unauthorized: Only admin can propose admin"
+    );
+
+    assert_eq!(harness.vault.methods().get_deposit(user_identity).call().await?.value, 0);
+    assert_eq!(harness.vault.methods().pending_admin().call().await?.value, None);
+
+    Ok(())
+}
+
+// Test every admin-only method of the cross-contract router
+#[tokio::test]
+async fn test_router_admin_methods_reject_non_admin() -> Result<()> {
+    println!("Testing CrossContractCall admin methods from a non-admin...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let user_identity = Identity::Address(user.address().into());
+    harness.mint_to(user, 1_000).await?;
+
+    let router = harness.cross_contract_call.clone().with_account(user.clone());
+    let methods = router.methods();
+    let vault_id = harness.vault.contract_id().clone();
+    let forwarded = CallParameters::default()
+        .with_amount(1_000)
+        .with_asset_id(harness.asset_id);
+
+    assert_unauthorized!(
+        "deposit",
+        methods
+            .deposit(vault_id, user_identity)
+            .call_params(forwarded.clone())?
+            .with_contract_ids(&[vault_id]),
+        "Only admin can deposit"
+    );
+    assert_unauthorized!(
+        "route",
+        methods
+            .route(vault_id, user_identity, user_identity)
+            .call_params(forwarded)?
+            .with_contract_ids(&[vault_id]),
+        "Only admin can route"
+    );
+    assert_unauthorized!(
+        "propose_admin",
+        methods.propose_admin(user_identity),
+        "Unauthorized: Only admin can propose admin"
+    );
+
+    assert_eq!(harness.vault.methods().get_deposit(user_identity).call().await?.value, 0);
+    assert_eq!(harness.token_balance(user).await?, 1_000);
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+// Vault Burn Tests
+// 
+// This module contains tests for burning deposits from the TokenVault including:
+// - Burning part of a deposit through a cross-contract call to the token
+// - The vault's books, its holdings and the token's total supply all dropping
+// - Burning a deposit of another sub id without touching the default asset
+// - Rejecting burns beyond the deposit and rolling back when the token refuses
+
+use fuels::{
+    prelude::*,
+    types::{Bits256, ContractId, Identity},
+};
+
+use rosetta_stone::{
+    expect_single_event, DepositBurnedEvent, HarnessConfig, RevertReason, TestHarness, SUB_ID,
+};
+
+const DEPOSIT: u64 = 40_000;
+const BURN: u64 = 15_000;
+
+// Mints DEPOSIT under `sub_id` to the first user, deposits it and returns its asset id
+async fn deposit(harness: &TestHarness, sub_id: Bits256) -> Result<AssetId> {
+    let user = harness.user(0);
+    let asset_id = harness.mint_sub_id_to(user, sub_id, DEPOSIT).await?;
+    harness
+        .vault_as(user)
+        .methods()
+        .deposit()
+        .call_params(CallParameters::default().with_amount(DEPOSIT).with_asset_id(asset_id))?
+        .call()
+        .await?;
+    Ok(asset_id)
+}
+
+// The first user's deposit, the vault's total and its holdings of `asset_id`
+async fn vault_state(harness: &TestHarness, asset_id: AssetId) -> Result<(u64, u64, u64)> {
+    let user = Identity::Address(harness.user(0).address().into());
+    let deposit = harness
+        .vault
+        .methods()
+        .get_deposit_for_asset(user, asset_id)
+        .call()
+        .await?
+        .value;
+    let total = harness.vault.methods().get_total_deposits(asset_id).call().await?.value;
+    let held = harness
+        .provider
+        .get_contract_asset_balance(&harness.vault.contract_id(), &asset_id)
+        .await?;
+    Ok((deposit, total, held as u64))
+}
+
+async fn supply(harness: &TestHarness, asset_id: AssetId) -> Result<u64> {
+    let supply = harness.token.methods().total_supply(asset_id).call().await?.value;
+    Ok(supply.unwrap_or(0))
+}
+
+fn token_id(harness: &TestHarness) -> ContractId {
+    harness.token.contract_id().clone()
+}
+
+// Test that burning a deposit shrinks the vault's books and the token's supply
+#[tokio::test]
+async fn test_burn_deposit() -> Result<()> {
+    println!("Testing burning a vault deposit...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let asset_id = deposit(&harness, SUB_ID).await?;
+    assert_eq!(vault_state(&harness, asset_id).await?, (DEPOSIT, DEPOSIT, DEPOSIT));
+    assert_eq!(supply(&harness, asset_id).await?, DEPOSIT);
+
+    let response = harness
+        .vault_as(harness.user(0))
+        .methods()
+        .burn_deposit(token_id(&harness), SUB_ID, BURN)
+        .with_contract_ids(&[token_id(&harness)])
+        .call()
+        .await?;
+
+    let event: DepositBurnedEvent = expect_single_event(&response)?;
+    assert_eq!(event.asset_id, asset_id);
+    assert_eq!(event.amount, BURN);
+
+    let left = DEPOSIT - BURN;
+    assert_eq!(vault_state(&harness, asset_id).await?, (left, left, left));
+    assert_eq!(supply(&harness, asset_id).await?, left);
+    println!("✅ Burned {} from the vault, {} left", BURN, left);
+
+    Ok(())
+}
+
+// Test burning a deposit of a non-default sub id
+#[tokio::test]
+async fn test_burn_deposit_of_other_sub_id() -> Result<()> {
+    println!("Testing burning a deposit of another sub id...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let sub_id = Bits256([3u8; 32]);
+    let default_asset = deposit(&harness, SUB_ID).await?;
+    let other_asset = deposit(&harness, sub_id).await?;
+
+    harness
+        .vault_as(harness.user(0))
+        .methods()
+        .burn_deposit(token_id(&harness), sub_id, DEPOSIT)
+        .with_contract_ids(&[token_id(&harness)])
+        .call()
+        .await?;
+
+    assert_eq!(vault_state(&harness, other_asset).await?, (0, DEPOSIT, 0));
+    assert_eq!(supply(&harness, other_asset).await?, 0);
+    assert_eq!(vault_state(&harness, default_asset).await?, (DEPOSIT, DEPOSIT, DEPOSIT));
+    assert_eq!(supply(&harness, default_asset).await?, DEPOSIT);
+    println!("✅ Only the other sub id's asset was burned");
+
+    Ok(())
+}
+
+// Test that a burn beyond the deposit, or one the token refuses, changes nothing
+#[tokio::test]
+async fn test_rejected_burns_leave_state_unchanged() -> Result<()> {
+    println!("Testing rejected burns...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let asset_id = deposit(&harness, SUB_ID).await?;
+    let vault = harness.vault_as(harness.user(0));
+
+    let error = vault
+        .methods()
+        .burn_deposit(token_id(&harness), SUB_ID, DEPOSIT + 1)
+        .with_contract_ids(&[token_id(&harness)])
+        .call()
+        .await
+        .err()
+        .expect("burning more than deposited must revert");
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::InsufficientBalance));
+    println!("❌ Burn beyond the deposit rejected");
+
+    // The token reverts while paused, which reverts the vault's bookkeeping too
+    harness.token.methods().pause().call().await?;
+    let error = vault
+        .methods()
+        .burn_deposit(token_id(&harness), SUB_ID, BURN)
+        .with_contract_ids(&[token_id(&harness)])
+        .call()
+        .await
+        .err()
+        .expect("burning while the token is paused must revert");
+    assert_eq!(RevertReason::from_error(&error), Some(RevertReason::Paused));
+    println!("❌ Burn while the token is paused rejected");
+
+    assert_eq!(vault_state(&harness, asset_id).await?, (DEPOSIT, DEPOSIT, DEPOSIT));
+    assert_eq!(supply(&harness, asset_id).await?, DEPOSIT);
+    println!("✅ Vault and supply unchanged");
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Vault Claim Token Tests
+// 
+// This module contains tests for the TokenVault claim tokens including:
+// - Minting a claim token for every token deposited through `deposit_for_claim`
+// - Transferring claim tokens and withdrawing from the receiving wallet
+// - Burning the returned claim tokens and keeping the vault totals in step
+// - Rejecting withdrawals without claim tokens or with the wrong token
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{HarnessConfig, TestHarness};
+
+const DEPOSIT: u64 = 50_000;
+
+// Deposits DEPOSIT of the harness token from `wallet` for claim tokens and
+// returns the claim token's asset id
+async fn deposit_for_claim(
+    harness: &TestHarness,
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+) -> Result<AssetId> {
+    harness.mint_to(wallet, DEPOSIT).await?;
+    harness
+        .vault_as(wallet)
+        .methods()
+        .deposit_for_claim()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    Ok(harness.vault.methods().claim_asset_id(harness.asset_id).call().await?.value)
+}
+
+// Withdraws `amount` of the harness token by returning claim tokens of `claim_asset`
+async fn withdraw_with_claim(
+    harness: &TestHarness,
+    wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    claim_asset: AssetId,
+    amount: u64,
+) -> Result<()> {
+    harness
+        .vault_as(wallet)
+        .methods()
+        .withdraw_with_claim(harness.asset_id)
+        .call_params(
+            CallParameters::default()
+                .with_amount(amount)
+                .with_asset_id(claim_asset),
+        )?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(())
+}
+
+// Test that a deposit mints the same amount of claim tokens
+#[tokio::test]
+async fn test_deposit_mints_claim_tokens() -> Result<()> {
+    println!("Testing claim token minting...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+
+    let claim_asset = deposit_for_claim(&harness, user).await?;
+    assert_ne!(claim_asset, harness.asset_id);
+
+    assert_eq!(user.get_asset_balance(&claim_asset).await?, DEPOSIT as u128);
+    assert_eq!(harness.token_balance(user).await?, 0);
+
+    // The deposit follows the claim tokens, not the depositor
+    let methods = harness.vault.methods();
+    let user_identity = Identity::Address(user.address().into());
+    assert_eq!(methods.get_deposit(user_identity).call().await?.value, 0);
+    assert_eq!(methods.get_outstanding_claims(harness.asset_id).call().await?.value, DEPOSIT);
+    assert_eq!(methods.get_total_deposits(harness.asset_id).call().await?.value, DEPOSIT);
+    println!("✅ Minted {} claim tokens", DEPOSIT);
+
+    Ok(())
+}
+
+// Test that a wallet receiving the claim tokens can withdraw the deposit
+#[tokio::test]
+async fn test_transferred_claim_withdraws_from_other_wallet() -> Result<()> {
+    println!("Testing withdrawal with transferred claim tokens...");
+
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(2)).await?;
+    let depositor = harness.user(0);
+    let holder = harness.user(1);
+
+    let claim_asset = deposit_for_claim(&harness, depositor).await?;
+    depositor
+        .transfer(holder.address(), DEPOSIT, claim_asset, TxPolicies::default())
+        .await?;
+    assert_eq!(holder.get_asset_balance(&claim_asset).await?, DEPOSIT as u128);
+
+    // Withdraw in two parts; each burns the claim tokens it returns
+    let first = 20_000;
+    withdraw_with_claim(&harness, holder, claim_asset, first).await?;
+    assert_eq!(harness.token_balance(holder).await?, first as u128);
+    assert_eq!(holder.get_asset_balance(&claim_asset).await?, (DEPOSIT - first) as u128);
+
+    withdraw_with_claim(&harness, holder, claim_asset, DEPOSIT - first).await?;
+    assert_eq!(harness.token_balance(holder).await?, DEPOSIT as u128);
+    assert_eq!(holder.get_asset_balance(&claim_asset).await?, 0);
+
+    // The depositor has given the deposit away with the claim tokens
+    let error = withdraw_with_claim(&harness, depositor, claim_asset, DEPOSIT).await.unwrap_err();
+    println!("❌ Depositor withdrawal rejected: {:?}", error);
+    assert_eq!(harness.token_balance(depositor).await?, 0);
+
+    let methods = harness.vault.methods();
+    assert_eq!(methods.get_outstanding_claims(harness.asset_id).call().await?.value, 0);
+    assert_eq!(methods.get_total_deposits(harness.asset_id).call().await?.value, 0);
+    println!("✅ Holder withdrew the full deposit");
+
+    Ok(())
+}
+
+// Test that a withdrawal needs the deposit's claim tokens
+#[tokio::test]
+async fn test_withdraw_requires_claim_token() -> Result<()> {
+    println!("Testing claim token requirements...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    deposit_for_claim(&harness, user).await?;
+
+    // Nothing forwarded
+    let error = harness
+        .vault_as(user)
+        .methods()
+        .withdraw_with_claim(harness.asset_id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .unwrap_err();
+    assert!(format!("{:?}", error).contains("No claim tokens sent"));
+
+    // The deposited token itself is not a claim
+    harness.mint_to(user, DEPOSIT).await?;
+    let error = withdraw_with_claim(&harness, user, harness.asset_id, DEPOSIT).await.unwrap_err();
+    assert!(format!("{:?}", error).contains("Wrong claim token"));
+    println!("❌ Withdrawals without the claim token rejected");
+
+    assert_eq!(
+        harness.vault.methods().get_outstanding_claims(harness.asset_id).call().await?.value,
+        DEPOSIT
+    );
+
+    Ok(())
+}
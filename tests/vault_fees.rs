@@ -0,0 +1,236 @@
+// Vault Withdrawal Fee Tests
+// 
+// This module contains tests for the TokenVault withdrawal fee including:
+// - Users receiving the withdrawal minus the basis-point fee
+// - Fees accumulating per asset across withdrawals and users
+// - Only the admin collecting the fees, which resets the accrued amount
+// - A fee-free vault paying out in full and rejecting fee rates above 100%
+
+use fuels::{
+    accounts::{signers::private_key::PrivateKeySigner, wallet::Unlocked},
+    prelude::*,
+    types::Identity,
+};
+
+use rosetta_stone::{
+    assert_balance_change, deploy_token_vault_with_fee, HarnessConfig, TestHarness, TokenVault,
+    MAX_FEE_BPS,
+};
+
+// 2.5%
+const FEE_BPS: u64 = 250;
+const DEPOSIT: u64 = 100_000;
+
+struct Setup {
+    harness: TestHarness,
+    vault: TokenVault<Wallet<Unlocked<PrivateKeySigner>>>,
+}
+
+impl Setup {
+    // A vault charging FEE_BPS, with DEPOSIT of the harness token deposited by
+    // users 0 and 1
+    async fn new() -> Result<Self> {
+        let harness = TestHarness::new(HarnessConfig::default().with_num_users(2)).await?;
+        let vault = deploy_token_vault_with_fee(
+            harness.admin.clone(),
+            &harness.cross_contract_call,
+            FEE_BPS,
+        )
+        .await?;
+
+        let setup = Self { harness, vault };
+        for index in 0..2 {
+            let user = setup.harness.user(index);
+            setup.harness.mint_to(user, DEPOSIT).await?;
+            setup
+                .vault_as(user)
+                .methods()
+                .deposit()
+                .call_params(
+                    CallParameters::default()
+                        .with_amount(DEPOSIT)
+                        .with_asset_id(setup.harness.asset_id),
+                )?
+                .call()
+                .await?;
+        }
+
+        Ok(setup)
+    }
+
+    fn vault_as(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+    ) -> TokenVault<Wallet<Unlocked<PrivateKeySigner>>> {
+        self.vault.clone().with_account(wallet.clone())
+    }
+
+    async fn withdraw(
+        &self,
+        wallet: &Wallet<Unlocked<PrivateKeySigner>>,
+        amount: u64,
+    ) -> Result<()> {
+        self.vault_as(wallet)
+            .methods()
+            .withdraw(amount)
+            .call_params(CallParameters::default().with_asset_id(self.harness.asset_id))?
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    async fn collect_fees(&self, wallet: &Wallet<Unlocked<PrivateKeySigner>>) -> Result<u64> {
+        Ok(self
+            .vault_as(wallet)
+            .methods()
+            .collect_fees(self.harness.asset_id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?
+            .value)
+    }
+
+    async fn accrued_fees(&self) -> Result<u64> {
+        Ok(self
+            .vault
+            .methods()
+            .get_accrued_fees(self.harness.asset_id)
+            .call()
+            .await?
+            .value)
+    }
+}
+
+fn fee_of(amount: u64) -> u64 {
+    amount * FEE_BPS / 10_000
+}
+
+// Test that a withdrawal pays out the amount minus the fee
+#[tokio::test]
+async fn test_withdrawal_pays_out_amount_minus_fee() -> Result<()> {
+    println!("Testing withdrawal payout with fee...");
+
+    let setup = Setup::new().await?;
+    let user = setup.harness.user(0);
+    let withdrawal = 40_000;
+
+    assert_eq!(setup.vault.methods().get_withdrawal_fee_bps().call().await?.value, FEE_BPS);
+
+    setup.withdraw(user, withdrawal).await?;
+
+    // 2.5% of 40_000 stays in the vault
+    let fee = fee_of(withdrawal);
+    assert_eq!(fee, 1_000);
+    assert_eq!(setup.harness.token_balance(user).await?, (withdrawal - fee) as u128);
+
+    // The deposit shrinks by the full amount and the fee is accounted separately
+    let deposit = setup
+        .vault
+        .methods()
+        .get_deposit(Identity::Address(user.address().into()))
+        .call()
+        .await?
+        .value;
+    assert_eq!(deposit, DEPOSIT - withdrawal);
+    assert_eq!(setup.accrued_fees().await?, fee);
+    println!("✅ Paid out {} and kept {} as fee", withdrawal - fee, fee);
+
+    Ok(())
+}
+
+// Test that fees accumulate across withdrawals and that the admin collects them
+#[tokio::test]
+async fn test_fees_accumulate_and_admin_collects() -> Result<()> {
+    println!("Testing fee accumulation and collection...");
+
+    let setup = Setup::new().await?;
+    let withdrawals = [(0, 40_000), (1, 10_000), (0, 60_000)];
+    let mut expected_fees = 0;
+    for (index, amount) in withdrawals {
+        setup.withdraw(setup.harness.user(index), amount).await?;
+        expected_fees += fee_of(amount);
+        assert_eq!(setup.accrued_fees().await?, expected_fees);
+    }
+    assert_eq!(expected_fees, 2_750);
+
+    let admin = &setup.harness.admin;
+    let collected = assert_balance_change!(
+        &setup.harness.provider,
+        [(admin.address(), setup.harness.asset_id) => expected_fees],
+        setup.collect_fees(admin)
+    )?;
+    assert_eq!(collected, expected_fees);
+    assert_eq!(setup.accrued_fees().await?, 0);
+
+    // Nothing is left to collect until the next withdrawal
+    let error = setup.collect_fees(admin).await.unwrap_err();
+    assert!(format!("{:?}", error).contains("No fees to collect"));
+    println!("✅ Admin collected {} in fees", collected);
+
+    Ok(())
+}
+
+// Test that only the admin can collect fees
+#[tokio::test]
+async fn test_only_admin_collects_fees() -> Result<()> {
+    println!("Testing fee collection authorization...");
+
+    let setup = Setup::new().await?;
+    let user = setup.harness.user(0);
+    setup.withdraw(user, DEPOSIT).await?;
+
+    let balance_before = setup.harness.token_balance(user).await?;
+    let error = setup.collect_fees(user).await.unwrap_err();
+    assert!(format!("{:?}", error).contains("Unauthorized: Only admin can collect fees"));
+    println!("❌ User collect rejected");
+
+    // The fees stay in the vault and the user got nothing
+    assert_eq!(setup.accrued_fees().await?, fee_of(DEPOSIT));
+    assert_eq!(setup.harness.token_balance(user).await?, balance_before);
+
+    Ok(())
+}
+
+// Test that the default vault charges no fee and that fee rates above 100% are refused
+#[tokio::test]
+async fn test_zero_fee_vault_and_fee_bounds() -> Result<()> {
+    println!("Testing fee-free vault and fee bounds...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    harness.mint_to(user, DEPOSIT).await?;
+
+    let vault = harness.vault_as(user);
+    vault
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+    vault
+        .methods()
+        .withdraw(DEPOSIT)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    assert_eq!(harness.token_balance(user).await?, DEPOSIT as u128);
+    assert_eq!(vault.methods().get_accrued_fees(harness.asset_id).call().await?.value, 0);
+
+    let result = deploy_token_vault_with_fee(
+        harness.admin.clone(),
+        &harness.cross_contract_call,
+        MAX_FEE_BPS + 1,
+    )
+    .await;
+    assert!(result.is_err());
+    println!("✅ Full payout without fee, {} bps refused", MAX_FEE_BPS + 1);
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+// Vault Fuzz Tests
+// 
+// This module contains fuzz-style tests for TokenVault accounting including:
+// - Random interleavings of deposits and withdrawals from several users
+// - Per-user deposits matching a model, with over-withdrawals reverting
+// - Total deposits equal to the sum of user deposits and to the tokens the
+//   vault actually holds after every operation
+//
+// PROPTEST_CASES sets the number of generated sequences (default 12).
+
+use fuels::{prelude::*, types::Identity};
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestRunner},
+};
+
+use rosetta_stone::{HarnessConfig, RevertReason, TestHarness};
+
+const NUM_USERS: usize = 3;
+const MAX_AMOUNT: u64 = 5_000;
+// Enough for every case to keep depositing
+const WALLET_TOKENS: u64 = 1_000_000;
+const DEFAULT_CASES: u32 = 12;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Deposit { user: usize, amount: u64 },
+    Withdraw { user: usize, amount: u64 },
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let user = 0..NUM_USERS;
+    let amount = 1..=MAX_AMOUNT;
+    prop_oneof![
+        (user.clone(), amount.clone()).prop_map(|(user, amount)| Op::Deposit { user, amount }),
+        (user, amount).prop_map(|(user, amount)| Op::Withdraw { user, amount }),
+    ]
+}
+
+async fn deposits(harness: &TestHarness) -> Result<Vec<u64>> {
+    let mut deposits = Vec::with_capacity(NUM_USERS);
+    for user in &harness.users {
+        let identity = Identity::Address(user.address().into());
+        let deposit = harness
+            .vault
+            .methods()
+            .get_deposit_for_asset(identity, harness.asset_id)
+            .call()
+            .await?
+            .value;
+        deposits.push(deposit);
+    }
+    Ok(deposits)
+}
+
+async fn vault_holdings(harness: &TestHarness) -> Result<u64> {
+    let balance = harness
+        .provider
+        .get_contract_asset_balance(&harness.vault.contract_id(), &harness.asset_id)
+        .await?;
+    Ok(balance as u64)
+}
+
+// Submits `op` from its user, returning the error if it reverted
+async fn apply(harness: &TestHarness, op: &Op) -> Result<()> {
+    match *op {
+        Op::Deposit { user, amount } => {
+            harness
+                .vault_as(harness.user(user))
+                .methods()
+                .deposit()
+                .call_params(
+                    CallParameters::default()
+                        .with_amount(amount)
+                        .with_asset_id(harness.asset_id),
+                )?
+                .call()
+                .await?;
+        }
+        Op::Withdraw { user, amount } => {
+            harness
+                .vault_as(harness.user(user))
+                .methods()
+                .withdraw(amount)
+                .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+                .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+                .call()
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+// Runs `ops` and checks the vault's books after each one. The model starts from
+// the deposits left by earlier cases, so cases can share one node.
+async fn check_sequence(
+    harness: &TestHarness,
+    ops: Vec<Op>,
+) -> std::result::Result<(), TestCaseError> {
+    let mut model = deposits(harness).await?;
+
+    for op in &ops {
+        match (*op, apply(harness, op).await) {
+            (Op::Deposit { user, amount }, result) => {
+                prop_assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                model[user] += amount;
+            }
+            (Op::Withdraw { user, amount }, Ok(())) => {
+                prop_assert!(amount <= model[user], "{:?} overdrew {}", op, model[user]);
+                model[user] -= amount;
+            }
+            (Op::Withdraw { user, amount }, Err(error)) => {
+                prop_assert!(amount > model[user], "{:?} failed: {:?}", op, error);
+                prop_assert_eq!(
+                    RevertReason::from_error(&error),
+                    Some(RevertReason::InsufficientBalance)
+                );
+            }
+        }
+
+        let actual = deposits(harness).await?;
+        prop_assert_eq!(&actual, &model, "deposits after {:?}", op);
+
+        let total = harness
+            .vault
+            .methods()
+            .get_total_deposits(harness.asset_id)
+            .call()
+            .await?
+            .value;
+        prop_assert_eq!(total, actual.iter().sum::<u64>(), "total after {:?}", op);
+        prop_assert_eq!(vault_holdings(harness).await?, total, "holdings after {:?}", op);
+    }
+    Ok(())
+}
+
+// Test that random deposit/withdraw interleavings keep the vault's books equal to its holdings
+#[test]
+fn test_vault_accounting_matches_holdings() {
+    println!("Testing vault accounting with random deposit/withdraw sequences...");
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let harness = runtime
+        .block_on(async {
+            let harness =
+                TestHarness::new(HarnessConfig::default().with_num_users(NUM_USERS as u64))
+                    .await?;
+            for user in &harness.users {
+                harness.mint_to(user, WALLET_TOKENS).await?;
+            }
+            Ok::<_, Error>(harness)
+        })
+        .unwrap();
+
+    let cases = std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    let mut runner = TestRunner::new(ProptestConfig {
+        cases,
+        // Every case talks to the node; shrinking a failure takes long enough
+        max_shrink_iters: 64,
+        ..ProptestConfig::default()
+    });
+
+    runner
+        .run(&prop::collection::vec(op_strategy(), 1..10), |ops| {
+            runtime.block_on(check_sequence(&harness, ops))
+        })
+        .unwrap();
+    println!("✅ Vault books matched its holdings for {} sequences", cases);
+}
@@ -5,102 +5,14 @@
 // - Vault withdrawals
 // - Vault balance checks
 // - Admin operations
+// - Per-asset deposit accounting
 
-use fuels::{
-    accounts::signers::private_key::PrivateKeySigner,
-    prelude::*,
-    types::{Bits256, ContractId, Identity, SizedAsciiString},
-};
-
-use fuels::accounts::wallet::Unlocked;
-
-// Load abi from json
-abigen!(
-    Contract(
-        name = "Src20Token",
-        abi = "contracts/src20-token/out/debug/src20_token-abi.json",
-    ),
-    Contract(
-        name = "TokenVault",
-        abi = "contracts/token-vault/out/debug/token_vault-abi.json",
-    ),
-    Contract(
-        name = "CrossContractCall",
-        abi = "contracts/cross-contract-call/out/debug/cross_contract_call-abi.json",
-    ),
-);
-
-// Common test constants
-const TOKEN_AMOUNT: u64 = 1_000_000;
-const SUB_ID_ARRAY: [u8; 32] = [0u8; 32];
-const SUB_ID: Bits256 = Bits256(SUB_ID_ARRAY);
-
-// Deploys the SRC20 token contract with the given wallet and metadata
-async fn deploy_src20_token(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-) -> Result<Src20Token<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let name_bytes: SizedAsciiString<7> = name.try_into()?;
-    let symbol_bytes: SizedAsciiString<5> = symbol.try_into()?;
-
-    let configurables = Src20TokenConfigurables::default()
-        .with_NAME(name_bytes.clone())?
-        .with_SYMBOL(symbol_bytes.clone())?
-        .with_DECIMALS(decimals)?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/src20-token/out/debug/src20_token.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ Token '{}' ({}) deployed at: {}", name, symbol, contract_id.to_string());
-    Ok(Src20Token::new(contract_id, wallet))
-}
-
-// Deploys the CrossContractCall contract
-async fn deploy_cross_contract_call(
-    admin_wallet: Wallet<Unlocked<PrivateKeySigner>>,
-) -> Result<CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let deploy_response = Contract::load_from(
-        "contracts/cross-contract-call/out/debug/cross_contract_call.bin",
-        LoadConfiguration::default(),
-    )?
-    .deploy(&admin_wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ CrossContractCall deployed at: {}", contract_id.to_string());
-    Ok(CrossContractCall::new(contract_id, admin_wallet))
-}
+use fuels::{prelude::*, types::Identity};
 
-// Deploys the TokenVault contract
-async fn deploy_token_vault(
-    wallet: Wallet<Unlocked<PrivateKeySigner>>,
-    cross_contract_call_contract_instance: CrossContractCall<Wallet<Unlocked<PrivateKeySigner>>>,
-) -> Result<TokenVault<Wallet<Unlocked<PrivateKeySigner>>>> {
-    let configurables = TokenVaultConfigurables::default()
-        .with_CROSS_CONTRACT_CALL(ContractId::from(
-            cross_contract_call_contract_instance.contract_id(),
-        ))?
-        .with_ADMIN(Identity::Address(wallet.address().into()))?;
-
-    let deploy_response = Contract::load_from(
-        "contracts/token-vault/out/debug/token_vault.bin",
-        LoadConfiguration::default().with_configurables(configurables),
-    )?
-    .deploy(&wallet, TxPolicies::default())
-    .await?;
-
-    let contract_id = deploy_response.contract_id;
-    println!("✅ TokenVault deployed at: {}", contract_id.to_string());
-    Ok(TokenVault::new(contract_id, wallet))
-}
+use rosetta_stone::{
+    deploy_cross_contract_call, deploy_src20_token, deploy_token_vault, launch_wallets,
+    RosettaError, Src20Token, SUB_ID, TOKEN_AMOUNT,
+};
 
 // Test vault deposit and withdrawal functionality
 #[tokio::test]
@@ -117,8 +29,7 @@ async fn test_vault_deposit() -> Result<()> {
         Some(amount_per_coin),
     );
     
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None)
-        .await?;
+    let mut wallets = launch_wallets(config).await?;
 
     let admin_wallet = wallets.pop().unwrap();
     let user_wallet = wallets.pop().unwrap();
@@ -137,7 +48,7 @@ async fn test_vault_deposit() -> Result<()> {
 
     let vault_contract = deploy_token_vault(
         admin_wallet.clone(),
-        cross_contract_call_contract.clone(),
+        &cross_contract_call_contract,
     ).await?;
 
     // Mint tokens to the user wallet
@@ -188,7 +99,12 @@ async fn test_vault_deposit() -> Result<()> {
             "❌ User has insufficient balance: {} < {}",
             user_balance, deposit_amount
         );
-        return Err("Insufficient balance for deposit".into());
+        return Err(RosettaError::InsufficientBalance {
+            asset_id,
+            needed: deposit_amount,
+            available: user_balance,
+        }
+        .into());
     }
 
     let call_params = CallParameters::default()
@@ -289,4 +205,96 @@ async fn test_vault_deposit() -> Result<()> {
 
     println!("✅ Vault deposit test passed");
     Ok(())
-} 
\ No newline at end of file
+} 
+// Test deposits of two different tokens into the same vault
+#[tokio::test]
+async fn test_vault_multi_asset_deposits() -> Result<()> {
+    println!("Testing multi-asset vault deposits...");
+
+    let config = WalletsConfig::new(Some(2), Some(2), Some(1_000_000_000));
+    let mut wallets = launch_wallets(config).await?;
+
+    let admin_wallet = wallets.pop().unwrap();
+    let user_wallet = wallets.pop().unwrap();
+    let user = Identity::Address(user_wallet.address().into());
+
+    // Two independent SRC-20 tokens
+    let token_a = deploy_src20_token(admin_wallet.clone(), "TOKENAA", "TOKNA", 9).await?;
+    let token_b = deploy_src20_token(admin_wallet.clone(), "TOKENBB", "TOKNB", 6).await?;
+    let cross_contract_call_contract = deploy_cross_contract_call(admin_wallet.clone()).await?;
+    let vault_contract = deploy_token_vault(admin_wallet.clone(), &cross_contract_call_contract).await?;
+    let user_vault = vault_contract.clone().with_account(user_wallet.clone());
+
+    let mut asset_ids = Vec::new();
+    for token in [&token_a, &token_b] {
+        token
+            .methods()
+            .mint(user, Some(SUB_ID), TOKEN_AMOUNT)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call()
+            .await?;
+        asset_ids.push(token.methods().get_asset_id().call().await?.value);
+    }
+    let (asset_a, asset_b) = (asset_ids[0], asset_ids[1]);
+    assert_ne!(asset_a, asset_b);
+
+    // Deposit different amounts of each token
+    let deposit_a = 300_000;
+    let deposit_b = 700_000;
+    for (asset_id, amount) in [(asset_a, deposit_a), (asset_b, deposit_b)] {
+        user_vault
+            .methods()
+            .deposit()
+            .call_params(
+                CallParameters::default()
+                    .with_amount(amount)
+                    .with_asset_id(asset_id),
+            )?
+            .call()
+            .await?;
+    }
+
+    let methods = vault_contract.methods();
+    assert_eq!(methods.get_deposit_for_asset(user, asset_a).call().await?.value, deposit_a);
+    assert_eq!(methods.get_deposit_for_asset(user, asset_b).call().await?.value, deposit_b);
+    assert_eq!(methods.get_deposit(user).call().await?.value, deposit_a + deposit_b);
+    println!("✅ Per-asset deposits tracked separately");
+
+    // Withdrawing token A only touches the token A deposit
+    let withdrawal_amount = 100_000;
+    user_vault
+        .methods()
+        .withdraw(withdrawal_amount)
+        .call_params(CallParameters::default().with_asset_id(asset_a))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    assert_eq!(
+        methods.get_deposit_for_asset(user, asset_a).call().await?.value,
+        deposit_a - withdrawal_amount
+    );
+    assert_eq!(methods.get_deposit_for_asset(user, asset_b).call().await?.value, deposit_b);
+
+    // Token B withdrawals are capped by the token B deposit alone
+    let result = user_vault
+        .methods()
+        .withdraw(deposit_b + 1)
+        .call_params(CallParameters::default().with_asset_id(asset_b))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+
+    assert_eq!(
+        user_wallet.get_asset_balance(&asset_a).await?,
+        (TOKEN_AMOUNT - deposit_a + withdrawal_amount) as u128
+    );
+    assert_eq!(
+        user_wallet.get_asset_balance(&asset_b).await?,
+        (TOKEN_AMOUNT - deposit_b) as u128
+    );
+
+    println!("✅ Multi-asset vault test passed");
+    Ok(())
+}
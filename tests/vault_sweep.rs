@@ -0,0 +1,143 @@
+// Vault Fee Sweep Tests
+// 
+// This module contains end-to-end tests for sweeping vault fees into a treasury including:
+// - The admin job collecting accrued withdrawal fees into the multi-sig predicate
+// - A sweep with nothing accrued submitting nothing
+// - The swept fees leaving the treasury only with the signers' threshold
+// - The background sweeper picking up fees as they accrue
+
+use std::time::Duration;
+
+use fuels::prelude::*;
+
+use rosetta_stone::{
+    deploy_token_vault_with_fee,
+    predicates::{fund_predicate, multisig_predicate, spend_asset_from_predicate},
+    sweep::{spawn_sweeper, sweep_fees},
+    HarnessConfig, TestHarness, TestWallet, TokenVault,
+};
+
+// 2.5%
+const FEE_BPS: u64 = 250;
+const DEPOSIT: u64 = 100_000;
+// Base asset for the treasury's own transaction fees
+const TREASURY_GAS: u64 = 500_000;
+
+// A vault charging FEE_BPS with a deposit from user 0, and a 2-of-3 treasury
+// over users 0, 1 and 2 holding some base asset
+async fn setup() -> Result<(TestHarness, TokenVault<TestWallet>, Predicate)> {
+    let harness = TestHarness::new(HarnessConfig::default().with_num_users(3)).await?;
+    let vault = deploy_token_vault_with_fee(
+        harness.admin.clone(),
+        &harness.cross_contract_call,
+        FEE_BPS,
+    )
+    .await?;
+
+    let user = harness.user(0);
+    harness.mint_to(user, DEPOSIT).await?;
+    vault
+        .clone()
+        .with_account(user.clone())
+        .methods()
+        .deposit()
+        .call_params(
+            CallParameters::default()
+                .with_amount(DEPOSIT)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let treasury = multisig_predicate([harness.user(0), harness.user(1), harness.user(2)], 2)?;
+    fund_predicate(&harness.admin, &treasury, TREASURY_GAS).await?;
+    Ok((harness, vault, treasury))
+}
+
+// Withdraws `amount` for user 0, accruing its fee in the vault
+async fn withdraw(
+    harness: &TestHarness,
+    vault: &TokenVault<TestWallet>,
+    amount: u64,
+) -> Result<u64> {
+    vault
+        .clone()
+        .with_account(harness.user(0).clone())
+        .methods()
+        .withdraw(amount)
+        .call_params(CallParameters::default().with_asset_id(harness.asset_id))?
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    Ok(amount * FEE_BPS / 10_000)
+}
+
+// Test the sweep from accrued fees to a threshold-signed spend out of the treasury
+#[tokio::test]
+async fn test_sweep_fees_into_multisig_treasury() -> Result<()> {
+    println!("Testing the vault fee sweep...");
+
+    let (harness, vault, treasury) = setup().await?;
+    let asset_id = harness.asset_id;
+
+    assert_eq!(sweep_fees(&vault, asset_id, treasury.address()).await?, None);
+    println!("✅ Nothing swept before any fee accrued");
+
+    let mut fees = withdraw(&harness, &vault, 40_000).await?;
+    fees += withdraw(&harness, &vault, 20_000).await?;
+    let sweep = sweep_fees(&vault, asset_id, treasury.address())
+        .await?
+        .expect("fees accrued");
+    assert_eq!(sweep.amount, fees);
+    assert_eq!(treasury.get_asset_balance(&asset_id).await?, u128::from(fees));
+    assert_eq!(vault.methods().get_accrued_fees(asset_id).call().await?.value, 0);
+    assert_eq!(harness.token_balance(&harness.admin).await?, 0);
+    println!("✅ Swept {} in fees into the treasury", sweep.amount);
+
+    // One signer is not enough to move the fees out again
+    let recipient = harness.admin.address().into();
+    let result =
+        spend_asset_from_predicate(&treasury, &[harness.user(0)], recipient, asset_id, fees).await;
+    assert!(result.is_err());
+    assert_eq!(treasury.get_asset_balance(&asset_id).await?, u128::from(fees));
+    println!("❌ Spend signed by one of three rejected");
+
+    let signers = [harness.user(0), harness.user(1)];
+    spend_asset_from_predicate(&treasury, &signers, recipient, asset_id, fees).await?;
+    assert_eq!(treasury.get_asset_balance(&asset_id).await?, 0);
+    assert_eq!(harness.token_balance(&harness.admin).await?, u128::from(fees));
+    println!("✅ Spend signed by two of three paid out the swept fees");
+
+    Ok(())
+}
+
+// Test that the scheduled sweeper moves fees as they accrue
+#[tokio::test]
+async fn test_sweeper_runs_on_interval() -> Result<()> {
+    println!("Testing the scheduled sweeper...");
+
+    let (harness, vault, treasury) = setup().await?;
+    let asset_id = harness.asset_id;
+    let sweeper = spawn_sweeper(
+        vault.clone(),
+        asset_id,
+        treasury.address(),
+        Duration::from_millis(100),
+    );
+
+    let mut expected = 0;
+    for amount in [40_000, 20_000] {
+        expected += withdraw(&harness, &vault, amount).await?;
+        for _ in 0..100 {
+            if treasury.get_asset_balance(&asset_id).await? == u128::from(expected) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(treasury.get_asset_balance(&asset_id).await?, u128::from(expected));
+        println!("✅ Treasury holds {} after the next sweep", expected);
+    }
+
+    sweeper.abort();
+    Ok(())
+}
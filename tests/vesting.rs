@@ -0,0 +1,192 @@
+// Vesting Tests
+// 
+// This module contains tests for the vesting contract including:
+// - Nothing claimable before the cliff
+// - Linear release after the cliff, advanced with produce_blocks
+// - Claims never exceeding the vested amount
+// - Claiming the remainder once the schedule has ended
+
+use fuels::{prelude::*, types::Identity};
+
+use rosetta_stone::{deploy_vesting, HarnessConfig, TestHarness};
+
+const TOTAL: u64 = 1_000;
+const CLIFF: u32 = 10;
+const DURATION: u32 = 100;
+
+// Amount a schedule starting at `start` has vested at block `height`
+fn expected_vested(start: u32, height: u32) -> u64 {
+    if height < start + CLIFF {
+        return 0;
+    }
+    let elapsed = (height - start).min(DURATION) as u64;
+    TOTAL * elapsed / DURATION as u64
+}
+
+// Test the full vesting schedule from cliff to end
+#[tokio::test]
+async fn test_vesting_cliff_and_linear_release() -> Result<()> {
+    println!("Testing vesting cliff and linear release...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let provider = &harness.provider;
+    let beneficiary = harness.user(0);
+    let beneficiary_identity = Identity::Address(beneficiary.address().into());
+
+    harness.mint_to(&harness.admin, TOTAL).await?;
+    let vesting = deploy_vesting(harness.admin.clone(), harness.asset_id).await?;
+    let vesting_as_beneficiary = vesting.clone().with_account(beneficiary.clone());
+
+    vesting
+        .methods()
+        .create_schedule(beneficiary_identity, CLIFF, DURATION)
+        .call_params(
+            CallParameters::default()
+                .with_amount(TOTAL)
+                .with_asset_id(harness.asset_id),
+        )?
+        .call()
+        .await?;
+
+    let schedule = vesting
+        .methods()
+        .schedule(beneficiary_identity)
+        .call()
+        .await?
+        .value
+        .expect("schedule should exist");
+    assert_eq!(schedule.total, TOTAL);
+    let start = schedule.start;
+    println!("✅ Schedule created at block {}", start);
+
+    // Before the cliff nothing can be claimed
+    let result = vesting_as_beneficiary
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(format!("{:?}", result.unwrap_err()).contains("Nothing vested to claim"));
+    assert_eq!(harness.token_balance(beneficiary).await?, 0);
+    println!("✅ Claim before the cliff rejected");
+
+    // Halfway through the schedule
+    let height = provider.latest_block_height().await?;
+    provider
+        .produce_blocks(start + DURATION / 2 - height, None)
+        .await?;
+
+    let first_claim = vesting_as_beneficiary
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    let claimed_at = provider.latest_block_height().await?;
+    assert_eq!(first_claim, expected_vested(start, claimed_at));
+    assert!(first_claim < TOTAL);
+    assert_eq!(harness.token_balance(beneficiary).await?, first_claim as u128);
+    println!("✅ Claimed {} at block {}", first_claim, claimed_at);
+
+    // The unvested remainder stays locked in the contract
+    let locked = provider
+        .get_contract_asset_balance(vesting.contract_id(), &harness.asset_id)
+        .await?;
+    assert_eq!(locked, TOTAL - first_claim);
+
+    let second_claim = vesting_as_beneficiary
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    let claimed_at = provider.latest_block_height().await?;
+    assert_eq!(first_claim + second_claim, expected_vested(start, claimed_at));
+    assert!(first_claim + second_claim < TOTAL);
+    println!("✅ A second claim only releases what vested since the first");
+
+    // Someone without a schedule cannot claim
+    let result = vesting
+        .clone()
+        .with_account(harness.user(1).clone())
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    assert!(format!("{:?}", result.unwrap_err()).contains("No vesting schedule"));
+
+    // After the end everything is claimable
+    provider.produce_blocks(DURATION, None).await?;
+    let final_claim = vesting_as_beneficiary
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?
+        .value;
+    assert_eq!(first_claim + second_claim + final_claim, TOTAL);
+    assert_eq!(harness.token_balance(beneficiary).await?, TOTAL as u128);
+    assert_eq!(
+        provider
+            .get_contract_asset_balance(vesting.contract_id(), &harness.asset_id)
+            .await?,
+        0
+    );
+    println!("✅ Remainder claimed after the schedule ended");
+
+    let result = vesting_as_beneficiary
+        .methods()
+        .claim()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await;
+    assert!(result.is_err());
+    println!("✅ Nothing left to claim");
+
+    Ok(())
+}
+
+// Test schedule validation
+#[tokio::test]
+async fn test_vesting_schedule_validation() -> Result<()> {
+    println!("Testing vesting schedule validation...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    harness.mint_to(&harness.admin, TOTAL * 3).await?;
+    let vesting = deploy_vesting(harness.admin.clone(), harness.asset_id).await?;
+    let beneficiary = Identity::Address(harness.user(0).address().into());
+    let funding = CallParameters::default()
+        .with_amount(TOTAL)
+        .with_asset_id(harness.asset_id);
+
+    let result = vesting
+        .methods()
+        .create_schedule(beneficiary, DURATION + 1, DURATION)
+        .call_params(funding.clone())?
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Cliff exceeds duration"));
+
+    vesting
+        .methods()
+        .create_schedule(beneficiary, CLIFF, DURATION)
+        .call_params(funding.clone())?
+        .call()
+        .await?;
+
+    let result = vesting
+        .methods()
+        .create_schedule(beneficiary, CLIFF, DURATION)
+        .call_params(funding)?
+        .call()
+        .await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("Schedule already exists"));
+    println!("✅ Invalid and duplicate schedules rejected");
+
+    Ok(())
+}
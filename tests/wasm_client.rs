@@ -0,0 +1,100 @@
+// Wasm Client Tests
+// 
+// This module contains tests for the read-only client subset behind the `wasm` feature including:
+// - Deriving the token's asset id without the abigen bindings
+// - Encoding a call exactly like the generated bindings
+// - Reading the supply and a balance of a minted asset over GraphQL
+// - The supply checks headless in a browser against a running node
+//
+// Run natively with: cargo test --features wasm --test wasm_client
+//
+// Run in a browser against a node where the token has minted, e.g. after `rosetta deploy` and
+// `rosetta mint`; the node URL and token id are read when the test is built:
+// ROSETTA_NODE_URL=http://127.0.0.1:4000 ROSETTA_TOKEN_ID=0x... wasm-pack test --headless \
+//     --firefox -- --no-default-features --features wasm --test wasm_client
+
+#![cfg(feature = "wasm")]
+
+use fuels::types::{errors::Result, ContractId};
+
+use rosetta_stone::{
+    wasm::{asset_id, ReadOnlyClient},
+    SUB_ID,
+};
+
+// The supply of the token's default asset as a read-only client sees it
+async fn token_supply(url: &str, token_id: ContractId) -> Result<u128> {
+    let client = ReadOnlyClient::new(url)?;
+    let supply = client.asset_supply(&asset_id(&token_id, &SUB_ID)).await?;
+    assert_eq!(supply.contract_id, token_id);
+    assert_eq!(supply.sub_id, SUB_ID);
+    assert!(client.latest_block_height().await? > 0);
+
+    Ok(supply.total_supply)
+}
+
+// Test the read-only client against the harness's token
+#[cfg(all(not(target_arch = "wasm32"), feature = "abigen"))]
+#[tokio::test]
+async fn test_read_only_client() -> Result<()> {
+    use fuels::{core::traits::Tokenizable, prelude::*};
+    use rosetta_stone::{units::format_units, wasm::encode_call, HarnessConfig, TestHarness};
+
+    println!("Testing the read-only client...");
+
+    let harness = TestHarness::new(HarnessConfig::default()).await?;
+    let user = harness.user(0);
+    let token_id = harness.token.contract_id().clone();
+    assert_eq!(asset_id(&token_id, &SUB_ID), harness.asset_id);
+    println!("✅ Asset id derived without the bindings");
+
+    let call = harness.token.methods().total_supply(harness.asset_id).call;
+    let encoded = encode_call("total_supply", &[harness.asset_id.into_token()])?;
+    assert_eq!(encoded, (call.encoded_selector, call.encoded_args?));
+    println!("✅ total_supply encoded like the generated bindings");
+
+    harness.mint_to(user, 1_500_000_000).await?;
+    let supply = token_supply(harness.provider.url(), token_id).await?;
+    let expected = harness
+        .token
+        .methods()
+        .total_supply(harness.asset_id)
+        .call()
+        .await?
+        .value;
+    assert_eq!(Some(supply), expected.map(u128::from));
+    println!("✅ Supply of {} read over GraphQL", format_units(supply, 9));
+
+    let client = ReadOnlyClient::new(harness.provider.url())?;
+    let balance = client.balance(&user.address(), &harness.asset_id).await?;
+    assert_eq!(balance, user.get_asset_balance(&harness.asset_id).await?);
+    println!("✅ User balance of {} matches the provider's", balance);
+
+    Ok(())
+}
+
+// Test the read-only client headless in a browser
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen_test::{console_log, wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_read_only_client_in_browser() {
+        console_log!("Testing the read-only client in the browser...");
+
+        let (Some(url), Some(token_id)) =
+            (option_env!("ROSETTA_NODE_URL"), option_env!("ROSETTA_TOKEN_ID"))
+        else {
+            panic!("build the test with ROSETTA_NODE_URL and ROSETTA_TOKEN_ID set");
+        };
+        let token_id: ContractId = token_id.parse().expect("ROSETTA_TOKEN_ID is a contract id");
+
+        let supply = token_supply(url, token_id).await.expect("read-only queries succeed");
+        assert!(supply > 0);
+        console_log!("✅ Supply of {} read from {}", supply, url);
+    }
+}
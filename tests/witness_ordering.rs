@@ -0,0 +1,100 @@
+// Witness Ordering Tests
+// 
+// This module contains tests for the multi-sig predicate's witness requirements including:
+// - witness_order sorting shuffled signers into SIGNERS order
+// - Rejecting unknown, repeated and non-prefix signer sets before submission
+// - Shuffled witnesses from the first k signers still spending
+// - Two valid signers that skip SIGNERS[0] being rejected whatever their order
+//
+// The predicate counts witness slot i only when SIGNERS[i] signed one of the
+// witnesses, and fails when it did not, so k signatures must come from the first
+// k signers. Their order among the witnesses does not matter.
+
+use fuels::{prelude::*, types::Address};
+
+use rosetta_stone::{
+    launch_wallets,
+    predicates::{funded_multisig, spend_from_predicate, witness_index, witness_order},
+    TestWallet,
+};
+
+const FUND_AMOUNT: u64 = 500_000;
+const SPEND_AMOUNT: u64 = 100_000;
+
+fn address(wallet: &TestWallet) -> Address {
+    wallet.address().into()
+}
+
+// Test the ordering helper on its own
+#[tokio::test]
+async fn test_witness_order() -> Result<()> {
+    println!("Testing witness_order...");
+
+    let wallets =
+        launch_wallets(WalletsConfig::new(Some(4), Some(1), Some(1_000_000))).await?;
+    let (s1, s2, s3, outsider) = (&wallets[0], &wallets[1], &wallets[2], &wallets[3]);
+    let expected = [address(s1), address(s2), address(s3)];
+
+    assert_eq!(witness_index(&expected, address(s3)), Some(2));
+    assert_eq!(witness_index(&expected, address(outsider)), None);
+
+    let ordered = witness_order(&expected, &[s3, s1, s2])?;
+    let ordered: Vec<Address> = ordered.into_iter().map(address).collect();
+    assert_eq!(ordered, expected);
+    let ordered = witness_order(&expected, &[s2, s1])?;
+    assert_eq!(ordered.into_iter().map(address).collect::<Vec<_>>(), expected[..2]);
+    println!("✅ Shuffled signers sorted into SIGNERS order");
+
+    assert!(witness_order(&expected, &[s1, outsider]).is_err());
+    println!("❌ Unknown signer rejected");
+    assert!(witness_order(&expected, &[s1, s1]).is_err());
+    println!("❌ Repeated signer rejected");
+    let error = witness_order(&expected, &[s3, s2]).err().expect("s1 is missing");
+    assert!(error.to_string().contains("witness 0"), "{}", error);
+    println!("❌ Signer set skipping SIGNERS[0] rejected: {}", error);
+
+    Ok(())
+}
+
+// Test that the order of witnesses from the first k signers does not matter
+#[tokio::test]
+async fn test_shuffled_prefix_spends() -> Result<()> {
+    println!("Testing shuffled witnesses...");
+
+    let (signers, recipient, predicate) = funded_multisig(2, FUND_AMOUNT).await?;
+    let (s1, s2) = (&signers[0], &signers[1]);
+    spend_from_predicate(&predicate, &[s2, s1], address(&recipient), SPEND_AMOUNT).await?;
+    println!("✅ 2-of-3 spent with witnesses [s2, s1]");
+
+    let (signers, recipient, predicate) = funded_multisig(3, FUND_AMOUNT).await?;
+    let (s1, s2, s3) = (&signers[0], &signers[1], &signers[2]);
+    spend_from_predicate(&predicate, &[s3, s1, s2], address(&recipient), SPEND_AMOUNT).await?;
+    println!("✅ 3-of-3 spent with witnesses [s3, s1, s2]");
+
+    Ok(())
+}
+
+// Test that two valid signers are not enough when SIGNERS[0] is not one of them
+#[tokio::test]
+async fn test_non_prefix_signers_rejected() -> Result<()> {
+    println!("Testing signers that skip the first slot...");
+
+    let (signers, recipient, predicate) = funded_multisig(2, FUND_AMOUNT).await?;
+    let (s2, s3) = (&signers[1], &signers[2]);
+    let before = predicate.get_asset_balance(&AssetId::default()).await?;
+
+    for order in [[s2, s3], [s3, s2]] {
+        let result =
+            spend_from_predicate(&predicate, &order, address(&recipient), SPEND_AMOUNT).await;
+        assert!(result.is_err());
+    }
+    assert_eq!(predicate.get_asset_balance(&AssetId::default()).await?, before);
+    println!("❌ [s2, s3] and [s3, s2] rejected on chain");
+
+    // Ordering through the helper catches it before submission
+    let expected = [0, 1, 2].map(|i| address(&signers[i]));
+    assert!(witness_order(&expected, &[s3, s2]).is_err());
+    println!("✅ witness_order refuses the same set up front");
+
+    Ok(())
+}